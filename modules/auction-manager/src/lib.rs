@@ -24,6 +24,9 @@
 //! business. Auction types include:
 //!   - `collateral auction`: sell collateral assets for getting stable currency to eliminate the
 //!     system's bad debit by auction
+//!
+//! At most `MaxAuctionsPerBlock` collateral auctions are created in a block,
+//! the rest are queued and created in subsequent blocks in order.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -43,7 +46,7 @@ use sp_runtime::{
 		storage_lock::{StorageLock, Time},
 		Duration,
 	},
-	traits::{CheckedDiv, Saturating, Zero},
+	traits::{CheckedDiv, Saturating, UniqueSaturatedInto, Zero},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
 	},
@@ -123,6 +126,24 @@ impl<AccountId, BlockNumber> CollateralAuctionItem<AccountId, BlockNumber> {
 	}
 }
 
+/// Information of a collateral auction waiting to be created
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug)]
+pub struct PendingCollateralAuction<AccountId> {
+	/// Refund recipient for may receive refund
+	refund_recipient: AccountId,
+	/// Collateral type for sale
+	currency_id: CurrencyId,
+	/// Collateral amount for sale
+	#[codec(compact)]
+	amount: Balance,
+	/// Target sales amount of this auction
+	#[codec(compact)]
+	target: Balance,
+	/// Reserve price of this auction
+	reserve_price: Option<Balance>,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -135,6 +156,12 @@ pub mod module {
 		#[pallet::constant]
 		type MinimumIncrementSize: Get<Rate>;
 
+		/// The extra multiple of the minimum increment size required at the
+		/// close of the auction, it's scaled up linearly within the time to
+		/// close
+		#[pallet::constant]
+		type MinimumIncrementCloseMultiplier: Get<Rate>;
+
 		/// The extended time for the auction to end after each successful bid
 		#[pallet::constant]
 		type AuctionTimeToClose: Get<Self::BlockNumber>;
@@ -144,6 +171,22 @@ pub mod module {
 		#[pallet::constant]
 		type AuctionDurationSoftCap: Get<Self::BlockNumber>;
 
+		/// When a bid arrives within this window before the auction ends,
+		/// extend the auction end by this window. Should be less than
+		/// `AuctionTimeToClose`, otherwise every bid extends the auction
+		#[pallet::constant]
+		type AuctionExtensionWindow: Get<Self::BlockNumber>;
+
+		/// The maximum number of times an auction can be extended by late bids
+		#[pallet::constant]
+		type MaxAuctionExtensions: Get<u32>;
+
+		/// The maximum number of collateral auctions created in a block, the
+		/// rest are queued and created in subsequent blocks. Zero means no
+		/// limit.
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+
 		/// The stable currency id
 		#[pallet::constant]
 		type GetStableCurrencyId: Get<CurrencyId>;
@@ -173,6 +216,9 @@ pub mod module {
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
+		/// The origin which may cancel all live collateral auctions.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -191,6 +237,13 @@ pub mod module {
 		InvalidBidPrice,
 		/// Invalid input amount
 		InvalidAmount,
+		/// Bid price is lower than the reserve price of the collateral auction
+		BidBelowReservePrice,
+		/// The reserve price is higher than the target of the collateral auction,
+		/// which is the most stable currency the bids can pay
+		InvalidReservePrice,
+		/// The number of live collateral auctions exceeds the witness
+		InvalidAuctionCountWitness,
 	}
 
 	#[pallet::event]
@@ -208,6 +261,23 @@ pub mod module {
 		/// Dex take collateral auction. \[auction_id, collateral_type,
 		/// collateral_amount, turnover\]
 		DEXTakeCollateralAuction(AuctionId, CurrencyId, Balance, Balance),
+		/// Collateral auction extended by a late bid. \[auction_id,
+		/// new_end_time\]
+		CollateralAuctionExtended(AuctionId, T::BlockNumber),
+		/// Collateral auction ended without qualifying bid and the collateral
+		/// is retained by CDP treasury. \[auction_id, collateral_type,
+		/// collateral_amount\]
+		CollateralAuctionRetained(AuctionId, CurrencyId, Balance),
+		/// The stable currency of the outbid bidder is refunded by the new
+		/// bidder. \[auction_id, bidder, refund_amount\]
+		BidRefunded(AuctionId, T::AccountId, Balance),
+		/// Collateral auction queued since the number of auctions created in
+		/// this block reaches `MaxAuctionsPerBlock`. \[collateral_type,
+		/// collateral_amount, target_bid_price\]
+		CollateralAuctionQueued(CurrencyId, Balance, Balance),
+		/// Queued collateral auction cancelled after emergency shutdown.
+		/// \[collateral_type, collateral_amount, target_bid_price\]
+		CancelQueuedCollateralAuction(CurrencyId, Balance, Balance),
 	}
 
 	/// Mapping from auction id to collateral auction info
@@ -233,11 +303,122 @@ pub mod module {
 	#[pallet::getter(fn total_target_in_auction)]
 	pub type TotalTargetInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// Mapping from auction id to the number of times it has been extended
+	/// by late bids
+	///
+	/// AuctionExtensions: map AuctionId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn auction_extensions)]
+	pub type AuctionExtensions<T: Config> = StorageMap<_, Twox64Concat, AuctionId, u32, ValueQuery>;
+
+	/// Mapping from auction id to the reserve price of collateral auction,
+	/// the auction can not be closed below the reserve price
+	///
+	/// CollateralAuctionReservePrices: map AuctionId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_auction_reserve_prices)]
+	pub type CollateralAuctionReservePrices<T: Config> = StorageMap<_, Twox64Concat, AuctionId, Balance, OptionQuery>;
+
+	/// The block number and the number of collateral auctions created in
+	/// that block, the number of a previous block counts as zero
+	///
+	/// AuctionsCreatedInBlock: (BlockNumber, u32)
+	#[pallet::storage]
+	pub type AuctionsCreatedInBlock<T: Config> = StorageValue<_, (T::BlockNumber, u32), ValueQuery>;
+
+	/// The queue of collateral auctions exceeding `MaxAuctionsPerBlock`,
+	/// which are created in subsequent blocks. The queued auctions are
+	/// indexed from `PendingCollateralAuctionsHead` to
+	/// `PendingCollateralAuctionsTail`.
+	///
+	/// PendingCollateralAuctions: map u32 => Option<PendingCollateralAuction>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_collateral_auctions)]
+	pub type PendingCollateralAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, u32, PendingCollateralAuction<T::AccountId>, OptionQuery>;
+
+	/// The index of the first queued collateral auction
+	///
+	/// PendingCollateralAuctionsHead: u32
+	#[pallet::storage]
+	#[pallet::getter(fn pending_collateral_auctions_head)]
+	pub type PendingCollateralAuctionsHead<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The index of the next collateral auction to queue
+	///
+	/// PendingCollateralAuctionsTail: u32
+	#[pallet::storage]
+	#[pallet::getter(fn pending_collateral_auctions_tail)]
+	pub type PendingCollateralAuctionsTail<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Create the queued collateral auctions, at most
+		/// `MaxAuctionsPerBlock` in a block. After emergency shutdown the
+		/// queued collateral auctions are cancelled instead.
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			let pending_count = Self::pending_collateral_auctions_count();
+			if pending_count.is_zero() {
+				return T::WeightInfo::on_initialize(0);
+			}
+
+			let max_auctions_per_block = T::MaxAuctionsPerBlock::get();
+			let count = if max_auctions_per_block.is_zero() {
+				pending_count
+			} else {
+				pending_count.min(max_auctions_per_block)
+			};
+			let head = Self::pending_collateral_auctions_head();
+			PendingCollateralAuctionsHead::<T>::put(head.wrapping_add(count));
+
+			if T::EmergencyShutdown::is_shutdown() {
+				for index in (0..count).map(|i| head.wrapping_add(i)) {
+					if let Some(pending_auction) = PendingCollateralAuctions::<T>::take(index) {
+						if let Err(e) = Self::cancel_pending_collateral_auction(&pending_auction) {
+							log::warn!(
+								target: "auction-manager",
+								"cancel queued collateral auction failed: {:?}, retry it later.",
+								e
+							);
+							Self::queue_pending_collateral_auction(pending_auction);
+						}
+					}
+				}
+
+				return T::WeightInfo::cancel_collateral_auction().saturating_mul(count as Weight);
+			}
+
+			for index in (0..count).map(|i| head.wrapping_add(i)) {
+				if let Some(pending_auction) = PendingCollateralAuctions::<T>::take(index) {
+					if let Err(e) = Self::create_collateral_auction(
+						&pending_auction.refund_recipient,
+						pending_auction.currency_id,
+						pending_auction.amount,
+						pending_auction.target,
+						pending_auction.reserve_price,
+					) {
+						log::warn!(
+							target: "auction-manager",
+							"create queued collateral auction failed: {:?}. \
+							This is unexpected, release the collateral of the auction.",
+							e
+						);
+						TotalCollateralInAuction::<T>::mutate(pending_auction.currency_id, |total| {
+							*total = total.saturating_sub(pending_auction.amount)
+						});
+						TotalTargetInAuction::<T>::mutate(|total| {
+							*total = total.saturating_sub(pending_auction.target)
+						});
+					}
+				}
+			}
+
+			T::WeightInfo::on_initialize(count)
+		}
+
 		/// Start offchain worker in order to submit unsigned tx to cancel
 		/// active auction after system shutdown.
 		fn offchain_worker(now: T::BlockNumber) {
@@ -273,6 +454,38 @@ pub mod module {
 			Self::deposit_event(Event::CancelAuction(id));
 			Ok(())
 		}
+
+		/// Cancel all live collateral auctions which are not in reverse stage,
+		/// refund the remaining collateral to the refund recipients and the
+		/// bids to the bidders.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `auction_count`: the witness of the number of live collateral
+		///   auctions, must not be less than the actual number.
+		#[pallet::weight(T::WeightInfo::cancel_all_collateral_auctions(*auction_count))]
+		#[transactional]
+		pub fn cancel_all_collateral_auctions(origin: OriginFor<T>, auction_count: u32) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let collateral_auctions = <CollateralAuctions<T>>::iter().collect::<Vec<_>>();
+			ensure!(
+				collateral_auctions.len() <= auction_count as usize,
+				Error::<T>::InvalidAuctionCountWitness
+			);
+
+			for (id, collateral_auction) in collateral_auctions {
+				// skip the collateral auction which has already been in reverse stage
+				if let Some((_, bid_price)) = Self::get_last_bid(id) {
+					if collateral_auction.in_reverse_stage(bid_price) {
+						continue;
+					}
+				}
+				<Self as AuctionManager<T::AccountId>>::cancel_auction(id)?;
+				Self::deposit_event(Event::CancelAuction(id));
+			}
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -464,6 +677,36 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Return the effective minimum increment size of the bid at `now`.
+	/// Within the time to close before the auction `end`, the minimum
+	/// increment size is scaled up linearly with the elapsed part of the time
+	/// to close, and reaches `1 + MinimumIncrementCloseMultiplier` times at
+	/// the close.
+	fn get_effective_minimum_increment_size(
+		now: T::BlockNumber,
+		start_block: T::BlockNumber,
+		end: Option<T::BlockNumber>,
+	) -> Rate {
+		let minimum_increment_size = Self::get_minimum_increment_size(now, start_block);
+		let time_to_close = Self::get_auction_time_to_close(now, start_block);
+
+		match end {
+			Some(end) if now <= end && end.saturating_sub(now) < time_to_close => {
+				let elapsed: u128 = time_to_close
+					.saturating_sub(end.saturating_sub(now))
+					.unique_saturated_into();
+				let elapsed_ratio =
+					Rate::checked_from_rational(elapsed, time_to_close.unique_saturated_into()).unwrap_or_default();
+				minimum_increment_size.saturating_add(
+					minimum_increment_size
+						.saturating_mul(T::MinimumIncrementCloseMultiplier::get())
+						.saturating_mul(elapsed_ratio),
+				)
+			}
+			_ => minimum_increment_size,
+		}
+	}
+
 	fn get_auction_time_to_close(now: T::BlockNumber, start_block: T::BlockNumber) -> T::BlockNumber {
 		if now >= start_block + T::AuctionDurationSoftCap::get() {
 			// halve the extended time of bid when reach soft cap
@@ -475,6 +718,30 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Return the end time of the auction after the bid at `now`. If the bid
+	/// is within `AuctionExtensionWindow` of the current end and the auction
+	/// hasn't reached `MaxAuctionExtensions`, the current end is extended by
+	/// `AuctionExtensionWindow` when it's later than `new_end_time`.
+	fn end_time_after_bid(id: AuctionId, now: T::BlockNumber, new_end_time: T::BlockNumber) -> T::BlockNumber {
+		let end = match T::Auction::auction_info(id).and_then(|auction_info| auction_info.end) {
+			Some(end) => end,
+			None => return new_end_time,
+		};
+		let window = T::AuctionExtensionWindow::get();
+		let extended_end_time = end.saturating_add(window);
+		if now >= end
+			|| now.saturating_add(window) < end
+			|| extended_end_time <= new_end_time
+			|| Self::auction_extensions(id) >= T::MaxAuctionExtensions::get()
+		{
+			return new_end_time;
+		}
+
+		AuctionExtensions::<T>::mutate(id, |extensions| *extensions = extensions.saturating_add(1));
+		Self::deposit_event(Event::CollateralAuctionExtended(id, extended_end_time));
+		extended_end_time
+	}
+
 	/// Handles collateral auction new bid. Returns
 	/// `Ok(new_auction_end_time)` if bid accepted.
 	///
@@ -488,6 +755,9 @@ impl<T: Config> Pallet<T> {
 	) -> sp_std::result::Result<T::BlockNumber, DispatchError> {
 		let (new_bidder, new_bid_price) = new_bid;
 		ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+		if let Some(reserve_price) = Self::collateral_auction_reserve_prices(id) {
+			ensure!(new_bid_price >= reserve_price, Error::<T>::BidBelowReservePrice);
+		}
 
 		<CollateralAuctions<T>>::try_mutate_exists(
 			id,
@@ -501,7 +771,11 @@ impl<T: Config> Pallet<T> {
 						new_bid_price,
 						last_bid_price,
 						collateral_auction.target,
-						Self::get_minimum_increment_size(now, collateral_auction.start_time),
+						Self::get_effective_minimum_increment_size(
+							now,
+							collateral_auction.start_time,
+							T::Auction::auction_info(id).and_then(|auction_info| auction_info.end),
+						),
 					),
 					Error::<T>::InvalidBidPrice
 				);
@@ -511,9 +785,11 @@ impl<T: Config> Pallet<T> {
 				let mut payment = collateral_auction.payment_amount(new_bid_price);
 
 				// if there's bid before, return stablecoin from new bidder to last bidder
+				// before the remain payment of new bidder is deposited
 				if let Some(last_bidder) = last_bidder {
 					let refund = collateral_auction.payment_amount(last_bid_price);
 					T::Currency::transfer(T::GetStableCurrencyId::get(), &new_bidder, last_bidder, refund)?;
+					Self::deposit_event(Event::BidRefunded(id, last_bidder.clone(), refund));
 
 					payment = payment
 						.checked_sub(refund)
@@ -548,7 +824,8 @@ impl<T: Config> Pallet<T> {
 
 				Self::swap_bidders(&new_bidder, last_bidder);
 
-				Ok(now + Self::get_auction_time_to_close(now, collateral_auction.start_time))
+				let new_end_time = now + Self::get_auction_time_to_close(now, collateral_auction.start_time);
+				Ok(Self::end_time_after_bid(id, now, new_end_time))
 			},
 		)
 	}
@@ -557,6 +834,7 @@ impl<T: Config> Pallet<T> {
 		auction_id: AuctionId,
 		collateral_auction: CollateralAuctionItem<T::AccountId, T::BlockNumber>,
 		winner: Option<(T::AccountId, Balance)>,
+		reserve_price: Option<Balance>,
 	) {
 		let (maybe_bidder, bid_price) = if let Some((bidder, bid_price)) = winner {
 			(Some(bidder), bid_price)
@@ -564,6 +842,7 @@ impl<T: Config> Pallet<T> {
 			(None, Zero::zero())
 		};
 		let mut should_deal = maybe_bidder.is_some();
+		let mut dex_taken = false;
 
 		// if there's no bid or bid_price doesn't reach target and trading with DEX will get better result
 		if maybe_bidder.is_none()
@@ -575,16 +854,18 @@ impl<T: Config> Pallet<T> {
 					)
 					.unwrap_or_default())
 		{
-			// try swap collateral in auction with DEX to get stable
+			// try swap collateral in auction with DEX to get stable, the swap must not be
+			// below the reserve price
 			if let Ok(stable_amount) = T::CDPTreasury::swap_exact_collateral_to_stable(
 				collateral_auction.currency_id,
 				collateral_auction.amount,
-				Zero::zero(),
+				reserve_price.unwrap_or_else(Zero::zero),
 				None,
 				true,
 			) {
 				// swap successfully, will not deal
 				should_deal = false;
+				dex_taken = true;
 
 				// refund stable currency to the last bidder, it shouldn't fail and affect the
 				// process. but even it failed, just the winner did not get the bid price. it
@@ -657,6 +938,14 @@ impl<T: Config> Pallet<T> {
 				bidder,
 				payment_amount,
 			));
+		} else if !dex_taken {
+			// no qualifying bid and DEX can't take it, the collateral is retained by CDP
+			// treasury and can be auctioned again.
+			Self::deposit_event(Event::CollateralAuctionRetained(
+				auction_id,
+				collateral_auction.currency_id,
+				collateral_auction.amount,
+			));
 		}
 
 		// decrement recipient account reference
@@ -688,6 +977,109 @@ impl<T: Config> Pallet<T> {
 			frame_system::Pallet::<T>::dec_consumers(who);
 		}
 	}
+
+	/// The number of collateral auctions created in the current block.
+	pub fn auctions_created_in_block() -> u32 {
+		let (block_number, count) = AuctionsCreatedInBlock::<T>::get();
+		if block_number == <frame_system::Pallet<T>>::block_number() {
+			count
+		} else {
+			0
+		}
+	}
+
+	/// The number of queued collateral auctions.
+	pub fn pending_collateral_auctions_count() -> u32 {
+		Self::pending_collateral_auctions_tail().wrapping_sub(Self::pending_collateral_auctions_head())
+	}
+
+	/// Append a collateral auction to the queue.
+	fn queue_pending_collateral_auction(pending_auction: PendingCollateralAuction<T::AccountId>) {
+		let tail = Self::pending_collateral_auctions_tail();
+		PendingCollateralAuctions::<T>::insert(tail, pending_auction);
+		PendingCollateralAuctionsTail::<T>::put(tail.wrapping_add(1));
+	}
+
+	/// Cancel a queued collateral auction after emergency shutdown, refund
+	/// the collateral exceeding the target at settle price to the refund
+	/// recipient from CDP treasury.
+	fn cancel_pending_collateral_auction(pending_auction: &PendingCollateralAuction<T::AccountId>) -> DispatchResult {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let settle_price = T::PriceSource::get_relative_price(stable_currency_id, pending_auction.currency_id)
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let confiscate_collateral_amount = if pending_auction.target.is_zero() {
+			pending_auction.amount
+		} else {
+			sp_std::cmp::min(
+				settle_price.saturating_mul_int(pending_auction.target),
+				pending_auction.amount,
+			)
+		};
+		let refund_collateral_amount = pending_auction.amount.saturating_sub(confiscate_collateral_amount);
+
+		T::CDPTreasury::withdraw_collateral(
+			&pending_auction.refund_recipient,
+			pending_auction.currency_id,
+			refund_collateral_amount,
+		)?;
+
+		TotalCollateralInAuction::<T>::mutate(pending_auction.currency_id, |balance| {
+			*balance = balance.saturating_sub(pending_auction.amount)
+		});
+		TotalTargetInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(pending_auction.target));
+
+		Self::deposit_event(Event::CancelQueuedCollateralAuction(
+			pending_auction.currency_id,
+			pending_auction.amount,
+			pending_auction.target,
+		));
+		Ok(())
+	}
+
+	fn create_collateral_auction(
+		refund_recipient: &T::AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target: Balance,
+		reserve_price: Option<Balance>,
+	) -> DispatchResult {
+		let start_time = <frame_system::Pallet<T>>::block_number();
+		// use start_time + AuctionDurationSoftCap as the initial end-time of collateral auction.
+		let end_time = start_time.saturating_add(T::AuctionDurationSoftCap::get());
+		let auction_id = T::Auction::new_auction(start_time, Some(end_time))?;
+
+		<CollateralAuctions<T>>::insert(
+			auction_id,
+			CollateralAuctionItem {
+				refund_recipient: refund_recipient.clone(),
+				currency_id,
+				initial_amount: amount,
+				amount,
+				target,
+				start_time,
+			},
+		);
+		if let Some(reserve_price) = reserve_price {
+			CollateralAuctionReservePrices::<T>::insert(auction_id, reserve_price);
+		}
+
+		// increment recipient account reference
+		if frame_system::Pallet::<T>::inc_consumers(refund_recipient).is_err() {
+			// No providers for the locks. This is impossible under normal circumstances
+			// since the funds that are under the lock will themselves be stored in the
+			// account and therefore will need a reference.
+			log::warn!(
+				target: "auction-manager",
+				"Attempt to `inc_consumers` for {:?} failed. \
+				This is unexpected but should be safe.",
+				refund_recipient.clone()
+			);
+		}
+
+		AuctionsCreatedInBlock::<T>::put((start_time, Self::auctions_created_in_block().saturating_add(1)));
+		Self::deposit_event(Event::NewCollateralAuction(auction_id, currency_id, amount, target));
+		Ok(())
+	}
 }
 
 impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId> for Pallet<T> {
@@ -712,8 +1104,10 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId>
 	}
 
 	fn on_auction_ended(id: AuctionId, winner: Option<(T::AccountId, Balance)>) {
+		AuctionExtensions::<T>::remove(id);
+		let reserve_price = CollateralAuctionReservePrices::<T>::take(id);
 		if let Some(collateral_auction) = <CollateralAuctions<T>>::take(id) {
-			Self::collateral_auction_end_handler(id, collateral_auction, winner.clone());
+			Self::collateral_auction_end_handler(id, collateral_auction, winner.clone(), reserve_price);
 		}
 
 		if let Some((bidder, _)) = &winner {
@@ -733,8 +1127,13 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 		currency_id: Self::CurrencyId,
 		amount: Self::Balance,
 		target: Self::Balance,
+		reserve_price: Option<Self::Balance>,
 	) -> DispatchResult {
 		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		ensure!(
+			target.is_zero() || reserve_price.map_or(true, |reserve_price| reserve_price <= target),
+			Error::<T>::InvalidReservePrice
+		);
 		TotalCollateralInAuction::<T>::try_mutate(currency_id, |total| -> DispatchResult {
 			*total = total.checked_add(amount).ok_or(Error::<T>::InvalidAmount)?;
 			Ok(())
@@ -748,43 +1147,27 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 			})?;
 		}
 
-		let start_time = <frame_system::Pallet<T>>::block_number();
-		// use start_time + AuctionDurationSoftCap as the initial end-time of collateral auction.
-		let end_time = start_time.saturating_add(T::AuctionDurationSoftCap::get());
-		let auction_id = T::Auction::new_auction(start_time, Some(end_time))?;
-
-		<CollateralAuctions<T>>::insert(
-			auction_id,
-			CollateralAuctionItem {
+		let max_auctions_per_block = T::MaxAuctionsPerBlock::get();
+		if !max_auctions_per_block.is_zero() && Self::auctions_created_in_block() >= max_auctions_per_block {
+			Self::queue_pending_collateral_auction(PendingCollateralAuction {
 				refund_recipient: refund_recipient.clone(),
 				currency_id,
-				initial_amount: amount,
 				amount,
 				target,
-				start_time,
-			},
-		);
-
-		// increment recipient account reference
-		if frame_system::Pallet::<T>::inc_consumers(refund_recipient).is_err() {
-			// No providers for the locks. This is impossible under normal circumstances
-			// since the funds that are under the lock will themselves be stored in the
-			// account and therefore will need a reference.
-			log::warn!(
-				target: "auction-manager",
-				"Attempt to `inc_consumers` for {:?} failed. \
-				This is unexpected but should be safe.",
-				refund_recipient.clone()
-			);
+				reserve_price,
+			});
+			Self::deposit_event(Event::CollateralAuctionQueued(currency_id, amount, target));
+			return Ok(());
 		}
 
-		Self::deposit_event(Event::NewCollateralAuction(auction_id, currency_id, amount, target));
-		Ok(())
+		Self::create_collateral_auction(refund_recipient, currency_id, amount, target, reserve_price)
 	}
 
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult {
 		let collateral_auction = <CollateralAuctions<T>>::take(id).ok_or(Error::<T>::AuctionNotExists)?;
 		Self::cancel_collateral_auction(id, collateral_auction)?;
+		AuctionExtensions::<T>::remove(id);
+		CollateralAuctionReservePrices::<T>::remove(id);
 		T::Auction::remove_auction(id);
 		Ok(())
 	}
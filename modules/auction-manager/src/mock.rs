@@ -124,6 +124,7 @@ impl cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type EmergencyShutdown = MockEmergencyShutdown;
 	type WeightInfo = ();
 }
 
@@ -151,6 +152,7 @@ parameter_types! {
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxBatchSwaps: u32 = 3;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![TradingPair::from_currency_ids(AUSD, BTC).unwrap()];
 }
 
@@ -159,9 +161,14 @@ impl module_dex::Config for Runtime {
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPathLimit = TradingPathLimit;
+	type MaxBatchSwaps = MaxBatchSwaps;
+	type MinimumLiquidity = ();
 	type PalletId = DEXPalletId;
+	type MaxTwapWindow = ();
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
+	type PriceSource = ();
 	type WeightInfo = ();
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
 }
@@ -179,12 +186,20 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	fn is_shutdown() -> bool {
 		IS_SHUTDOWN.with(|v| *v.borrow_mut())
 	}
+
+	fn is_collateral_shutdown(_currency_id: CurrencyId) -> bool {
+		false
+	}
 }
 
 parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(1, 20);
+	pub MinimumIncrementCloseMultiplier: Rate = Rate::saturating_from_integer(1);
 	pub const AuctionTimeToClose: u64 = 100;
 	pub const AuctionDurationSoftCap: u64 = 2000;
+	pub const AuctionExtensionWindow: u64 = 80;
+	pub const MaxAuctionExtensions: u32 = 2;
+	pub const MaxAuctionsPerBlock: u32 = 3;
 	pub const UnsignedPriority: u64 = 1 << 20;
 }
 
@@ -193,14 +208,19 @@ impl Config for Runtime {
 	type Currency = Tokens;
 	type Auction = AuctionModule;
 	type MinimumIncrementSize = MinimumIncrementSize;
+	type MinimumIncrementCloseMultiplier = MinimumIncrementCloseMultiplier;
 	type AuctionTimeToClose = AuctionTimeToClose;
 	type AuctionDurationSoftCap = AuctionDurationSoftCap;
+	type AuctionExtensionWindow = AuctionExtensionWindow;
+	type MaxAuctionExtensions = MaxAuctionExtensions;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CDPTreasuryModule;
 	type DEX = DEXModule;
 	type PriceSource = MockPriceSource;
 	type UnsignedPriority = UnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type WeightInfo = ();
 }
 
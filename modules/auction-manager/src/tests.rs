@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{Event, *};
-use sp_runtime::traits::One;
+use sp_runtime::traits::{BadOrigin, One};
 
 #[test]
 fn get_auction_time_to_close_work() {
@@ -33,10 +33,89 @@ fn get_auction_time_to_close_work() {
 	});
 }
 
+#[test]
+fn get_effective_minimum_increment_size_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// flat minimum increment size when far from close
+		assert_eq!(
+			AuctionManagerModule::get_effective_minimum_increment_size(1, 1, None),
+			Rate::saturating_from_rational(5, 100)
+		);
+		assert_eq!(
+			AuctionManagerModule::get_effective_minimum_increment_size(1, 1, Some(2001)),
+			Rate::saturating_from_rational(5, 100)
+		);
+		assert_eq!(
+			AuctionManagerModule::get_effective_minimum_increment_size(1, 1, Some(101)),
+			Rate::saturating_from_rational(5, 100)
+		);
+
+		// scaled up within the time to close
+		assert_eq!(
+			AuctionManagerModule::get_effective_minimum_increment_size(51, 1, Some(101)),
+			Rate::saturating_from_rational(75, 1000)
+		);
+		assert_eq!(
+			AuctionManagerModule::get_effective_minimum_increment_size(100, 1, Some(101)),
+			Rate::saturating_from_rational(995, 10000)
+		);
+		assert_eq!(
+			AuctionManagerModule::get_effective_minimum_increment_size(101, 1, Some(101)),
+			Rate::saturating_from_rational(10, 100)
+		);
+
+		// just before the soft cap
+		assert_eq!(
+			AuctionManagerModule::get_effective_minimum_increment_size(2000, 1, Some(2050)),
+			Rate::saturating_from_rational(75, 1000)
+		);
+
+		// reach the soft cap, the minimum increment size is doubled and the time to
+		// close is halved
+		assert_eq!(
+			AuctionManagerModule::get_effective_minimum_increment_size(2001, 1, Some(2051)),
+			Rate::saturating_from_rational(10, 100)
+		);
+		assert_eq!(
+			AuctionManagerModule::get_effective_minimum_increment_size(2001, 1, Some(2026)),
+			Rate::saturating_from_rational(15, 100)
+		);
+		assert_eq!(
+			AuctionManagerModule::get_effective_minimum_increment_size(2026, 1, Some(2026)),
+			Rate::saturating_from_rational(20, 100)
+		);
+	});
+}
+
+#[test]
+fn bid_near_close_requires_scaled_minimum_increment() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&ALICE, BTC, 10));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 10));
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(101));
+
+		// bid just above the flat minimum increment is accepted early
+		System::set_block_number(2);
+		assert_ok!(AuctionModule::bid(Origin::signed(CAROL), 0, 16));
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(102));
+
+		// bid just above the flat minimum increment is rejected near close
+		System::set_block_number(101);
+		assert_noop!(
+			AuctionManagerModule::collateral_auction_bid_handler(101, 0, (BOB, 22), Some((CAROL, 16))),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert!(AuctionModule::bid(Origin::signed(BOB), 0, 22).is_err());
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 26));
+	});
+}
+
 #[test]
 fn collateral_auction_methods() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
 		assert_eq!(
 			AuctionModule::auctions(0),
 			Some(orml_traits::AuctionInfo {
@@ -56,7 +135,7 @@ fn collateral_auction_methods() {
 		assert_eq!(collateral_auction_with_positive_target.collateral_amount(80, 100), 10);
 		assert_eq!(collateral_auction_with_positive_target.collateral_amount(100, 200), 5);
 
-		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 0));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 0, None));
 		let collateral_auction_with_zero_target = AuctionManagerModule::collateral_auctions(1).unwrap();
 		assert_eq!(collateral_auction_with_zero_target.always_forward(), true);
 		assert_eq!(collateral_auction_with_zero_target.in_reverse_stage(0), false);
@@ -73,11 +152,11 @@ fn new_collateral_auction_work() {
 		System::set_block_number(1);
 		let ref_count_0 = System::consumers(&ALICE);
 		assert_noop!(
-			AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 0, 100),
+			AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 0, 100, None),
 			Error::<Runtime>::InvalidAmount,
 		);
 
-		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
 		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NewCollateralAuction(
 			0, BTC, 10, 100,
 		)));
@@ -88,12 +167,141 @@ fn new_collateral_auction_work() {
 		assert_eq!(System::consumers(&ALICE), ref_count_0 + 1);
 
 		assert_noop!(
-			AuctionManagerModule::new_collateral_auction(&ALICE, BTC, Balance::max_value(), Balance::max_value()),
+			AuctionManagerModule::new_collateral_auction(&ALICE, BTC, Balance::max_value(), Balance::max_value(), None),
 			Error::<Runtime>::InvalidAmount,
 		);
 	});
 }
 
+#[test]
+fn new_collateral_auction_limited_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		for _ in 0..5 {
+			assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
+		}
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionQueued(
+			BTC, 10, 100,
+		)));
+
+		// at most MaxAuctionsPerBlock auctions are created, the rest are queued
+		assert_eq!(AuctionModule::auctions_index(), 3);
+		assert_eq!(AuctionManagerModule::auctions_created_in_block(), 3);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 2);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 50);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 500);
+
+		// the queue is drained in the next block
+		System::set_block_number(2);
+		AuctionManagerModule::on_initialize(2);
+		assert_eq!(AuctionModule::auctions_index(), 5);
+		assert_eq!(AuctionManagerModule::auctions_created_in_block(), 2);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 0);
+		assert_eq!(AuctionManagerModule::collateral_auctions(4).unwrap().start_time, 2);
+
+		// the created queued auctions take up the limit of the block
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
+		assert_eq!(AuctionModule::auctions_index(), 6);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 1);
+	});
+}
+
+#[test]
+fn pending_collateral_auctions_drain_over_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		for _ in 0..10 {
+			assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
+		}
+		assert_eq!(AuctionModule::auctions_index(), 3);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 7);
+
+		System::set_block_number(2);
+		AuctionManagerModule::on_initialize(2);
+		assert_eq!(AuctionModule::auctions_index(), 6);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 4);
+
+		System::set_block_number(3);
+		AuctionManagerModule::on_initialize(3);
+		assert_eq!(AuctionModule::auctions_index(), 9);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 1);
+
+		System::set_block_number(4);
+		AuctionManagerModule::on_initialize(4);
+		assert_eq!(AuctionModule::auctions_index(), 10);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 0);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_head(), 7);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_tail(), 7);
+		assert_eq!(PendingCollateralAuctions::<Runtime>::iter().count(), 0);
+		assert_eq!(AuctionManagerModule::auctions_created_in_block(), 1);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 100);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 1000);
+	});
+}
+
+#[test]
+fn pending_collateral_auctions_cancelled_after_shutdown() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 50));
+		for _ in 0..4 {
+			assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
+		}
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&BOB, BTC, 10, 5, None));
+		assert_eq!(AuctionModule::auctions_index(), 3);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 2);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 50);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 405);
+		let bob_collateral = Tokens::free_balance(BTC, &BOB);
+
+		// no queued auction is created after shutdown, they are cancelled
+		mock_shutdown();
+		System::set_block_number(2);
+		AuctionManagerModule::on_initialize(2);
+		System::assert_has_event(Event::AuctionManagerModule(
+			crate::Event::CancelQueuedCollateralAuction(BTC, 10, 100),
+		));
+		System::assert_last_event(Event::AuctionManagerModule(
+			crate::Event::CancelQueuedCollateralAuction(BTC, 10, 5),
+		));
+		assert_eq!(AuctionModule::auctions_index(), 3);
+		assert_eq!(AuctionManagerModule::auctions_created_in_block(), 0);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 0);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 30);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 300);
+
+		// the collateral exceeding the target is refunded
+		assert_eq!(Tokens::free_balance(BTC, &BOB), bob_collateral + 5);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 45);
+	});
+}
+
+#[test]
+fn pending_collateral_auctions_kept_if_cancel_failed_after_shutdown() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 40));
+		for _ in 0..4 {
+			assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
+		}
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 1);
+
+		// the queued auction is cancelled once the price is available
+		mock_shutdown();
+		MockPriceSource::set_relative_price(None);
+		AuctionManagerModule::on_initialize(2);
+		assert_eq!(AuctionModule::auctions_index(), 3);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 1);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 40);
+
+		MockPriceSource::set_relative_price(Some(Price::one()));
+		AuctionManagerModule::on_initialize(3);
+		assert_eq!(AuctionManagerModule::pending_collateral_auctions_count(), 0);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 30);
+	});
+}
+
 #[test]
 fn collateral_auction_bid_handler_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -103,7 +311,7 @@ fn collateral_auction_bid_handler_work() {
 		);
 
 		assert_ok!(CDPTreasuryModule::deposit_collateral(&ALICE, BTC, 10));
-		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
 		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
 		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
 
@@ -154,10 +362,42 @@ fn collateral_auction_bid_handler_work() {
 	});
 }
 
+#[test]
+fn collateral_auction_bid_refund_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&ALICE, BTC, 10));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
+
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 80));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 920);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 80);
+
+		// the new bidder can not afford the remain payment after refunding the last
+		// bidder, the whole bid is reverted
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(
+			AUSD, &CAROL, &ALICE, 915
+		));
+		assert_noop!(
+			AuctionManagerModule::collateral_auction_bid_handler(2, 0, (CAROL, 90), Some((BOB, 80))),
+			orml_tokens::Error::<Runtime>::BalanceTooLow,
+		);
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(
+			AUSD, &ALICE, &CAROL, 915
+		));
+
+		assert_ok!(AuctionModule::bid(Origin::signed(CAROL), 0, 90));
+		System::assert_has_event(Event::AuctionManagerModule(crate::Event::BidRefunded(0, BOB, 80)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(Tokens::free_balance(AUSD, &CAROL), 910);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 90);
+	});
+}
+
 #[test]
 fn bid_when_soft_cap_for_collateral_auction_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
 		assert_eq!(
 			AuctionManagerModule::on_new_bid(1, 0, (BOB, 100), None).auction_end_change,
 			Change::NewValue(Some(101))
@@ -173,6 +413,158 @@ fn bid_when_soft_cap_for_collateral_auction_work() {
 	});
 }
 
+#[test]
+fn late_bid_extends_collateral_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&ALICE, BTC, 10));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
+
+		// the first bid does not extend the auction
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 10));
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(101));
+		assert_eq!(AuctionManagerModule::auction_extensions(0), 0);
+
+		// bid before the extension window of close does not extend the auction
+		System::set_block_number(10);
+		assert_ok!(AuctionModule::bid(Origin::signed(CAROL), 0, 20));
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(110));
+		assert_eq!(AuctionManagerModule::auction_extensions(0), 0);
+
+		// bid within the extension window of close extends the auction
+		System::set_block_number(50);
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 30));
+		System::assert_has_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionExtended(
+			0, 190,
+		)));
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(190));
+		assert_eq!(AuctionManagerModule::auction_extensions(0), 1);
+
+		System::set_block_number(140);
+		assert_ok!(AuctionModule::bid(Origin::signed(CAROL), 0, 40));
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(270));
+		assert_eq!(AuctionManagerModule::auction_extensions(0), 2);
+
+		// the number of extensions is capped by `MaxAuctionExtensions`
+		System::set_block_number(220);
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 50));
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(320));
+		assert_eq!(AuctionManagerModule::auction_extensions(0), 2);
+
+		// bid still must meet the minimum increment
+		assert!(AuctionModule::bid(Origin::signed(CAROL), 0, 51).is_err());
+
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 50)));
+		assert_eq!(AuctionManagerModule::auction_extensions(0), 0);
+	});
+}
+
+#[test]
+fn collateral_auction_bid_below_reserve_price_rejected() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionManagerModule::new_collateral_auction(
+			&ALICE,
+			BTC,
+			10,
+			100,
+			Some(50)
+		));
+		assert_eq!(AuctionManagerModule::collateral_auction_reserve_prices(0), Some(50));
+
+		assert_noop!(
+			AuctionManagerModule::collateral_auction_bid_handler(1, 0, (BOB, 40), None),
+			Error::<Runtime>::BidBelowReservePrice,
+		);
+		assert_eq!(
+			AuctionManagerModule::on_new_bid(1, 0, (BOB, 40), None).accept_bid,
+			false
+		);
+		assert_eq!(AuctionManagerModule::on_new_bid(1, 0, (BOB, 50), None).accept_bid, true);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 950);
+	});
+}
+
+#[test]
+fn new_collateral_auction_with_reserve_price_above_target_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		// the bids pay at most `target`, the reserve price above it can never be met
+		assert_noop!(
+			AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, Some(101)),
+			Error::<Runtime>::InvalidReservePrice,
+		);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 0);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 0);
+
+		assert_ok!(AuctionManagerModule::new_collateral_auction(
+			&ALICE,
+			BTC,
+			10,
+			100,
+			Some(100)
+		));
+		assert_eq!(AuctionManagerModule::collateral_auction_reserve_prices(0), Some(100));
+
+		// the auction without target is always in forward stage, the bids are not capped
+		assert_ok!(AuctionManagerModule::new_collateral_auction(
+			&ALICE,
+			BTC,
+			10,
+			0,
+			Some(101)
+		));
+		assert_eq!(AuctionManagerModule::collateral_auction_reserve_prices(1), Some(101));
+	});
+}
+
+#[test]
+fn collateral_auction_end_handler_without_qualifying_bid_retains_collateral() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			1000,
+			0,
+			false
+		));
+		assert_eq!(DEXModule::get_swap_target_amount(&[BTC, AUSD], 100).unwrap(), 500);
+
+		// reserve price is higher than the DEX can offer
+		assert_ok!(AuctionManagerModule::new_collateral_auction(
+			&ALICE,
+			BTC,
+			100,
+			800,
+			Some(600)
+		));
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 100);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 100);
+		let alice_ref_count_0 = System::consumers(&ALICE);
+
+		AuctionManagerModule::on_auction_ended(0, None);
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionRetained(
+			0, BTC, 100,
+		)));
+
+		// collateral is retained by CDP treasury and available for re-auction
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 100);
+		assert_eq!(CDPTreasuryModule::total_collaterals_not_in_auction(BTC), 100);
+		assert_eq!(AuctionManagerModule::collateral_auctions(0), None);
+		assert_eq!(AuctionManagerModule::collateral_auction_reserve_prices(0), None);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 0);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 0);
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 1000));
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 1000);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1000);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
+	});
+}
+
 #[test]
 fn collateral_auction_end_handler_without_bid() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -189,7 +581,9 @@ fn collateral_auction_end_handler_without_bid() {
 		));
 		assert_eq!(DEXModule::get_swap_target_amount(&[BTC, AUSD], 100).unwrap(), 500);
 
-		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 100, 200));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(
+			&ALICE, BTC, 100, 200, None
+		));
 		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 100);
 		assert_eq!(AuctionManagerModule::total_target_in_auction(), 200);
 		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 100);
@@ -223,7 +617,9 @@ fn collateral_auction_end_handler_in_reverse_stage() {
 	ExtBuilder::default().build().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
-		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 100, 200));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(
+			&ALICE, BTC, 100, 200, None
+		));
 		assert_eq!(
 			AuctionManagerModule::collateral_auction_bid_handler(2, 0, (BOB, 400), None).is_ok(),
 			true
@@ -264,7 +660,9 @@ fn collateral_auction_end_handler_by_dealing_which_target_not_zero() {
 	ExtBuilder::default().build().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
-		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 100, 200));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(
+			&ALICE, BTC, 100, 200, None
+		));
 		assert_eq!(
 			AuctionManagerModule::collateral_auction_bid_handler(1, 0, (BOB, 100), None).is_ok(),
 			true
@@ -304,7 +702,9 @@ fn collateral_auction_end_handler_by_dex_which_target_not_zero() {
 	ExtBuilder::default().build().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
-		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 100, 200));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(
+			&ALICE, BTC, 100, 200, None
+		));
 		assert_eq!(
 			AuctionManagerModule::collateral_auction_bid_handler(1, 0, (BOB, 20), None).is_ok(),
 			true
@@ -386,7 +786,7 @@ fn swap_bidders_works() {
 fn cancel_collateral_auction_failed() {
 	ExtBuilder::default().build().execute_with(|| {
 		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 10));
-		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
 		MockPriceSource::set_relative_price(None);
 		assert_noop!(
 			AuctionManagerModule::cancel_collateral_auction(0, AuctionManagerModule::collateral_auctions(0).unwrap()),
@@ -412,7 +812,7 @@ fn cancel_collateral_auction_work() {
 		System::set_block_number(1);
 		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 10));
 		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 10);
-		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
 		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 10);
 		assert_eq!(AuctionManagerModule::total_target_in_auction(), 100);
 		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
@@ -446,3 +846,50 @@ fn cancel_collateral_auction_work() {
 		assert_eq!(bob_ref_count_1, bob_ref_count_0 - 1);
 	});
 }
+
+#[test]
+fn cancel_all_collateral_auctions_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 30));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100, None));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&CAROL, BTC, 10, 5, None));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 20, None));
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 80));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 2, 20));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 920);
+		assert_eq!(Tokens::free_balance(BTC, &CAROL), 970);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 30);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 125);
+
+		assert_noop!(
+			AuctionManagerModule::cancel_all_collateral_auctions(Origin::signed(BOB), 3),
+			BadOrigin,
+		);
+		assert_noop!(
+			AuctionManagerModule::cancel_all_collateral_auctions(Origin::signed(ALICE), 2),
+			Error::<Runtime>::InvalidAuctionCountWitness,
+		);
+
+		assert_ok!(AuctionManagerModule::cancel_all_collateral_auctions(
+			Origin::signed(ALICE),
+			3
+		));
+		System::assert_has_event(Event::AuctionManagerModule(crate::Event::CancelAuction(0)));
+		System::assert_has_event(Event::AuctionManagerModule(crate::Event::CancelAuction(1)));
+
+		// bids are refunded and remaining collateral is returned
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(Tokens::free_balance(BTC, &CAROL), 975);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 25);
+		assert_eq!(AuctionManagerModule::collateral_auctions(0).is_some(), false);
+		assert_eq!(AuctionManagerModule::collateral_auctions(1).is_some(), false);
+		assert_eq!(AuctionModule::auction_info(0).is_some(), false);
+		assert_eq!(AuctionModule::auction_info(1).is_some(), false);
+
+		// the collateral auction in reverse stage is kept
+		assert_eq!(AuctionManagerModule::collateral_auctions(2).is_some(), true);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 10);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 20);
+	});
+}
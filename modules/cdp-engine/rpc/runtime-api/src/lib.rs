@@ -0,0 +1,62 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for cdp engine module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use primitives::{Amount, CurrencyId};
+use sp_runtime::FixedU128;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(3)]
+	pub trait CdpEngineApi<AccountId, RiskManagementParams> where
+		AccountId: Codec,
+		RiskManagementParams: Codec,
+	{
+		/// Returns at most `max` owners of unsafe CDPs of `currency_id`, whose
+		/// collateral ratio is below the liquidation ratio at the current
+		/// oracle price.
+		fn unsafe_cdps(currency_id: CurrencyId, max: u32) -> Vec<AccountId>;
+
+		/// Returns the collateral ratio of the CDP of `who` after applying
+		/// the hypothetical adjustments at the current oracle price.
+		fn simulate_collateral_ratio(
+			currency_id: CurrencyId,
+			collateral_adjustment: Amount,
+			debit_adjustment: Amount,
+			who: AccountId,
+		) -> Option<FixedU128>;
+
+		/// Returns the current debit exchange rate of `currency_id`, the
+		/// interest accrued factor of its debits, or the default rate if it
+		/// has not accrued yet.
+		///
+		/// Available since version 2.
+		fn debit_exchange_rate(currency_id: CurrencyId) -> FixedU128;
+
+		/// Returns the risk management params of every collateral type in
+		/// `CollateralCurrencyIds`.
+		///
+		/// Available since version 3.
+		fn all_collateral_params() -> Vec<(CurrencyId, RiskManagementParams)>;
+	}
+}
@@ -53,7 +53,7 @@ use sp_runtime::{
 	},
 	DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
-use sp_std::prelude::*;
+use sp_std::{convert::TryInto, prelude::*};
 use support::{
 	CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, ExchangeRate, Price, PriceProvider, Rate, Ratio, RiskManager,
 };
@@ -117,6 +117,21 @@ pub enum LiquidationStrategy {
 	Exchange,
 }
 
+/// The reason why a liquidation strategy was chosen
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub enum LiquidationStrategyReason {
+	/// Swap with DEX succeeded
+	DexSwapSucceeded,
+	/// Collateral amount exceeds the DEX liquidation size threshold
+	ExceedDexLiquidationSizeThreshold,
+	/// No available swap path in DEX
+	DexPathUnavailable,
+	/// Swap with DEX exceeds `MaxSwapSlippageCompareToOracle`
+	ExceedMaxSwapSlippage,
+	/// Swap with DEX failed for other reasons
+	DexSwapFailed,
+}
+
 /// Status of CDP
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
 pub enum CDPStatus {
@@ -157,6 +172,10 @@ pub mod module {
 		#[pallet::constant]
 		type MinimumDebitValue: Get<Balance>;
 
+		/// The minimum collateral value to avoid collateral dust
+		#[pallet::constant]
+		type MinimumCollateralValue: Get<Balance>;
+
 		/// Stablecoin currency id
 		#[pallet::constant]
 		type GetStableCurrencyId: Get<CurrencyId>;
@@ -165,6 +184,11 @@ pub mod module {
 		#[pallet::constant]
 		type MaxSwapSlippageCompareToOracle: Get<Ratio>;
 
+		/// The share of the liquidation penalty paid to the keeper who
+		/// triggers the liquidation by signed extrinsic. Zero means no bounty.
+		#[pallet::constant]
+		type KeeperBountyRate: Get<Rate>;
+
 		/// The CDP treasury to maintain bad debts and surplus generated by CDPs
 		type CDPTreasury: CDPTreasuryExtended<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
 
@@ -208,6 +232,8 @@ pub mod module {
 		InvalidCollateralType,
 		/// Remain debit value in CDP below the dust amount
 		RemainDebitValueTooSmall,
+		/// Remain collateral value in CDP below the dust amount
+		RemainCollateralValueTooSmall,
 		/// Feed price is invalid
 		InvalidFeedPrice,
 		/// No debit value in CDP so that it cannot be settled
@@ -216,11 +242,22 @@ pub mod module {
 		AlreadyShutdown,
 		/// Must after system shutdown
 		MustAfterShutdown,
+		/// The collateral type has been shutdown
+		CollateralShutdown,
+		/// Closing the CDP requires selling more collateral than the max
+		/// collateral amount
+		ExceedMaxCollateralAmount,
+		/// The total debit value of all collateral types will exceed the
+		/// global debit ceiling
+		ExceedGlobalDebitCeiling,
+		/// The swap path must start with the collateral type and end with the
+		/// stable currency
+		InvalidSwapPath,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId", Option<Rate> = "OptionRate", Option<Ratio> = "OptionRatio")]
+	#[pallet::metadata(T::AccountId = "AccountId", Option<Rate> = "OptionRate", Option<Ratio> = "OptionRatio", Option<Balance> = "OptionBalance")]
 	pub enum Event<T: Config> {
 		/// Liquidate the unsafe CDP. \[collateral_type, owner,
 		/// collateral_amount, bad_debt_value, liquidation_strategy\]
@@ -231,6 +268,9 @@ pub mod module {
 		/// \[collateral_type, owner, sold_collateral_amount,
 		/// refund_collateral_amount, debit_value\]
 		CloseCDPInDebitByDEX(CurrencyId, T::AccountId, Balance, Balance, Balance),
+		/// The liquidation strategy chosen for the unsafe CDP and the reason.
+		/// \[collateral_type, owner, liquidation_strategy, reason\]
+		LiquidationStrategyChosen(CurrencyId, T::AccountId, LiquidationStrategy, LiquidationStrategyReason),
 		/// The interest rate per sec for specific collateral type updated.
 		/// \[collateral_type, new_interest_rate_per_sec\]
 		InterestRatePerSecUpdated(CurrencyId, Option<Rate>),
@@ -249,6 +289,15 @@ pub mod module {
 		/// The global interest rate per sec for all types of collateral
 		/// updated. \[new_global_interest_rate_per_sec\]
 		GlobalInterestRatePerSecUpdated(Rate),
+		/// The global debit ceiling for all types of collateral updated.
+		/// \[new_global_debit_ceiling\]
+		GlobalDebitCeilingUpdated(Option<Balance>),
+		/// The swap path used in liquidation for specific collateral type
+		/// updated. \[collateral_type, new_swap_path\]
+		LiquidationSwapPathUpdated(CurrencyId, Option<Vec<CurrencyId>>),
+		/// Paid bounty to the keeper who triggered the liquidation.
+		/// \[keeper, collateral_type, bounty_amount\]
+		KeeperBountyPaid(T::AccountId, CurrencyId, Balance),
 	}
 
 	/// Mapping from collateral type to its exchange rate of debit units and
@@ -266,6 +315,14 @@ pub mod module {
 	#[pallet::getter(fn global_interest_rate_per_sec)]
 	pub type GlobalInterestRatePerSec<T: Config> = StorageValue<_, Rate, ValueQuery>;
 
+	/// The ceiling of total debit value of all types of collateral, `None`
+	/// means no ceiling
+	///
+	/// GlobalDebitCeiling: Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn global_debit_ceiling)]
+	pub type GlobalDebitCeiling<T: Config> = StorageValue<_, Balance, OptionQuery>;
+
 	/// Mapping from collateral type to its risk management params
 	///
 	/// CollateralParams: CurrencyId => RiskManagementParams
@@ -273,6 +330,24 @@ pub mod module {
 	#[pallet::getter(fn collateral_params)]
 	pub type CollateralParams<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, RiskManagementParams, ValueQuery>;
 
+	/// Mapping from collateral type to the swap path through intermediate
+	/// currencies, which is tried in liquidation if swapping the collateral
+	/// to stable currency directly fails
+	///
+	/// LiquidationSwapPaths: CurrencyId => Option<Vec<CurrencyId>>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_swap_paths)]
+	pub type LiquidationSwapPaths<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Vec<CurrencyId>, OptionQuery>;
+
+	/// Mapping from collateral type to the raw storage key of the CDP at
+	/// which the last `settle_cdps_batch` stopped, the next batch continues
+	/// the scan from it
+	///
+	/// SettleCdpsCursor: CurrencyId => Option<Vec<u8>>
+	#[pallet::storage]
+	#[pallet::getter(fn settle_cdps_cursor)]
+	pub type SettleCdpsCursor<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Vec<u8>, OptionQuery>;
+
 	/// Timestamp in seconds of the last interest accumulation
 	///
 	/// LastAccumulationSecs: u64
@@ -396,6 +471,43 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Liquidate unsafe CDP by keeper, the keeper will receive a bounty
+		/// from the liquidation penalty recovered in stable currency. No
+		/// bounty is paid if the CDP is liquidated by collateral auction.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `currency_id`: CDP's collateral type.
+		/// - `who`: CDP's owner.
+		#[pallet::weight(<T as Config>::WeightInfo::liquidate_by_keeper())]
+		#[transactional]
+		pub fn liquidate_by_keeper(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let keeper = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+
+			let Position { debit, .. } = <LoansOf<T>>::positions(currency_id, &who);
+			let bad_debt_value = Self::get_debit_value(currency_id, debit);
+			let surplus_before = <T as Config>::CDPTreasury::get_surplus_pool();
+
+			Self::liquidate_unsafe_cdp(who, currency_id)?;
+
+			// the stable currency recovered by the liquidation beyond the bad debt
+			let recovered_penalty = <T as Config>::CDPTreasury::get_surplus_pool()
+				.saturating_sub(surplus_before)
+				.saturating_sub(bad_debt_value);
+			let bounty = T::KeeperBountyRate::get().saturating_mul_int(recovered_penalty);
+			if !bounty.is_zero() {
+				<T as Config>::CDPTreasury::withdraw_surplus(&keeper, bounty)?;
+				Self::deposit_event(Event::KeeperBountyPaid(keeper, currency_id, bounty));
+			}
+			Ok(())
+		}
+
 		/// Settle CDP has debit after system shutdown
 		///
 		/// The dispatch origin of this call must be _None_.
@@ -416,6 +528,55 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Settle up to `max` CDPs that have debit of a collateral type after
+		/// system shutdown. At most `max_iterations` CDPs are scanned, starting
+		/// from where the last batch of the collateral type stopped.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `currency_id`: CDP's collateral type.
+		/// - `max`: the max number of CDPs to settle.
+		/// - `max_iterations`: the max number of CDPs to scan.
+		#[pallet::weight(<T as Config>::WeightInfo::settle_cdps_batch(*max)
+			.saturating_add(T::DbWeight::get().reads_writes((*max_iterations as Weight).saturating_add(1), 1)))]
+		#[transactional]
+		pub fn settle_cdps_batch(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			max: u32,
+			max_iterations: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(T::EmergencyShutdown::is_shutdown(), Error::<T>::MustAfterShutdown);
+
+			let mut map_iterator = <loans::Positions<T> as IterableStorageDoubleMapExtended<_, _, _>>::iter_prefix(
+				currency_id,
+				Some(max_iterations),
+				Self::settle_cdps_cursor(currency_id),
+			);
+			let mut owners: Vec<T::AccountId> = vec![];
+			while owners.len() < max as usize {
+				match map_iterator.next() {
+					Some((who, Position { debit, .. })) => {
+						if !debit.is_zero() {
+							owners.push(who);
+						}
+					}
+					None => break,
+				}
+			}
+			if map_iterator.finished {
+				SettleCdpsCursor::<T>::remove(currency_id);
+			} else {
+				SettleCdpsCursor::<T>::insert(currency_id, map_iterator.map_iterator.previous_key);
+			}
+
+			for who in owners {
+				Self::settle_cdp_has_debit(who, currency_id)?;
+			}
+			Ok(())
+		}
+
 		/// Update global parameters related to risk management of CDP
 		///
 		/// The dispatch origin of this call must be `UpdateOrigin`.
@@ -430,6 +591,54 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Update the ceiling of total debit value of all types of collateral
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `global_debit_ceiling`: global debit ceiling, `None` means no ceiling.
+		#[pallet::weight((<T as Config>::WeightInfo::set_global_debit_ceiling(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_global_debit_ceiling(origin: OriginFor<T>, global_debit_ceiling: Option<Balance>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			GlobalDebitCeiling::<T>::set(global_debit_ceiling);
+			Self::deposit_event(Event::GlobalDebitCeilingUpdated(global_debit_ceiling));
+			Ok(())
+		}
+
+		/// Update the swap path used in liquidation when the collateral can't
+		/// be swapped to stable currency directly
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `swap_path`: swap path from the collateral type to stable currency, `None` means
+		///   only swap directly.
+		#[pallet::weight((<T as Config>::WeightInfo::set_liquidation_swap_path(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_liquidation_swap_path(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			swap_path: Option<Vec<CurrencyId>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id),
+				Error::<T>::InvalidCollateralType,
+			);
+			if let Some(path) = &swap_path {
+				ensure!(
+					path.len() >= 2
+						&& path.first() == Some(&currency_id)
+						&& path.last() == Some(&T::GetStableCurrencyId::get()),
+					Error::<T>::InvalidSwapPath
+				);
+			}
+
+			LiquidationSwapPaths::<T>::set(currency_id, swap_path.clone());
+			Self::deposit_event(Event::LiquidationSwapPathUpdated(currency_id, swap_path));
+			Ok(())
+		}
+
 		/// Update parameters related to risk management of CDP under specific
 		/// collateral type
 		///
@@ -714,6 +923,29 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Get the risk management params of all collateral types.
+	pub fn all_collateral_params() -> Vec<(CurrencyId, RiskManagementParams)> {
+		T::CollateralCurrencyIds::get()
+			.into_iter()
+			.map(|currency_id| (currency_id, Self::collateral_params(currency_id)))
+			.collect()
+	}
+
+	/// Get the owners of unsafe CDPs of `currency_id` at the current oracle
+	/// price, returns at most `max` accounts.
+	pub fn unsafe_cdps(currency_id: CurrencyId, max: u32) -> Vec<T::AccountId> {
+		<loans::Positions<T>>::iter_prefix(currency_id)
+			.filter(|(_, Position { collateral, debit })| {
+				matches!(
+					Self::check_cdp_status(currency_id, *collateral, *debit),
+					CDPStatus::Unsafe
+				)
+			})
+			.map(|(who, _)| who)
+			.take(max as usize)
+			.collect()
+	}
+
 	pub fn maximum_total_debit_value(currency_id: CurrencyId) -> Balance {
 		Self::collateral_params(currency_id).maximum_total_debit_value
 	}
@@ -748,6 +980,8 @@ impl<T: Config> Pallet<T> {
 			.unwrap_or_else(T::DefaultLiquidationPenalty::get)
 	}
 
+	/// The current debit exchange rate of `currency_id`, the stored rate or
+	/// `DefaultDebitExchangeRate` if no interest has been accrued yet.
 	pub fn get_debit_exchange_rate(currency_id: CurrencyId) -> ExchangeRate {
 		Self::debit_exchange_rate(currency_id).unwrap_or_else(T::DefaultDebitExchangeRate::get)
 	}
@@ -768,6 +1002,38 @@ impl<T: Config> Pallet<T> {
 		Ratio::checked_from_rational(locked_collateral_value, debit_value).unwrap_or_else(Ratio::max_value)
 	}
 
+	/// Simulate the collateral ratio of the CDP of `who` after applying
+	/// `collateral_adjustment` and `debit_adjustment` at the current oracle
+	/// price, without mutating state. Returns `None` if the adjustments are
+	/// invalid or the oracle price is unavailable.
+	pub fn simulate_collateral_ratio(
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+		who: &T::AccountId,
+	) -> Option<Ratio> {
+		let apply_adjustment = |balance: Balance, adjustment: Amount| -> Option<Balance> {
+			let adjustment_abs: Balance = adjustment.saturating_abs().try_into().ok()?;
+			if adjustment.is_positive() {
+				balance.checked_add(adjustment_abs)
+			} else {
+				balance.checked_sub(adjustment_abs)
+			}
+		};
+
+		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, who);
+		let collateral = apply_adjustment(collateral, collateral_adjustment)?;
+		let debit = apply_adjustment(debit, debit_adjustment)?;
+		let feed_price = T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())?;
+
+		Some(Self::calculate_collateral_ratio(
+			currency_id,
+			collateral,
+			debit,
+			feed_price,
+		))
+	}
+
 	pub fn adjust_position(
 		who: &T::AccountId,
 		currency_id: CurrencyId,
@@ -778,6 +1044,11 @@ impl<T: Config> Pallet<T> {
 			T::CollateralCurrencyIds::get().contains(&currency_id),
 			Error::<T>::InvalidCollateralType,
 		);
+		// reject new debit for the collateral type which has been shutdown
+		ensure!(
+			debit_adjustment <= 0 || !T::EmergencyShutdown::is_collateral_shutdown(currency_id),
+			Error::<T>::CollateralShutdown,
+		);
 		<LoansOf<T>>::adjust_position(who, currency_id, collateral_adjustment, debit_adjustment)?;
 		Ok(())
 	}
@@ -817,11 +1088,21 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::MustBeSafe
 		);
 
+		// reject if clearing the debit requires selling more collateral than the cap
+		let debit_value = Self::get_debit_value(currency_id, debit);
+		if let Some(supply_amount) =
+			<T as Config>::CDPTreasury::get_swap_supply_collateral_amount(currency_id, debit_value, maybe_path)
+		{
+			ensure!(
+				supply_amount <= max_collateral_amount,
+				Error::<T>::ExceedMaxCollateralAmount
+			);
+		}
+
 		// confiscate all collateral and debit of unsafe cdp to cdp treasury
 		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral, debit)?;
 
 		// swap exact stable with DEX in limit of price impact
-		let debit_value = Self::get_debit_value(currency_id, debit);
 		let actual_supply_collateral = <T as Config>::CDPTreasury::swap_collateral_to_exact_stable(
 			currency_id,
 			collateral.min(max_collateral_amount),
@@ -864,7 +1145,7 @@ impl<T: Config> Pallet<T> {
 
 		let bad_debt_value = Self::get_debit_value(currency_id, debit);
 		let target_stable_amount = Self::get_liquidation_penalty(currency_id).saturating_mul_acc_int(bad_debt_value);
-		let liquidation_strategy = (|| -> Result<LiquidationStrategy, DispatchError> {
+		let (liquidation_strategy, reason) = (|| -> Result<_, DispatchError> {
 			// calculate the supply limit by slippage limit for the price of oracle,
 			let max_supply_limit = Ratio::one()
 				.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
@@ -876,14 +1157,39 @@ impl<T: Config> Pallet<T> {
 						.saturating_mul_int(target_stable_amount),
 				);
 
-			// try use collateral to swap enough stable token in DEX.
-			if let Ok(actual_supply_collateral) = <T as Config>::CDPTreasury::swap_collateral_to_exact_stable(
-				currency_id,
-				collateral.min(max_supply_limit),
-				target_stable_amount,
-				None,
-				false,
-			) {
+			// only liquidations within the threshold are allowed to swap in DEX.
+			let within_dex_threshold = <T as Config>::CDPTreasury::get_dex_liquidation_size_threshold(currency_id)
+				.map_or(true, |threshold| collateral <= threshold);
+
+			// try use collateral to swap enough stable token in DEX, directly first and then
+			// by the configured swap path.
+			let maybe_swap_path = Self::liquidation_swap_paths(currency_id);
+			let maybe_actual_supply_collateral = if within_dex_threshold {
+				<T as Config>::CDPTreasury::swap_collateral_to_exact_stable(
+					currency_id,
+					collateral.min(max_supply_limit),
+					target_stable_amount,
+					None,
+					false,
+				)
+				.ok()
+				.or_else(|| {
+					maybe_swap_path.as_ref().and_then(|path| {
+						<T as Config>::CDPTreasury::swap_collateral_to_exact_stable(
+							currency_id,
+							collateral.min(max_supply_limit),
+							target_stable_amount,
+							Some(path),
+							false,
+						)
+						.ok()
+					})
+				})
+			} else {
+				None
+			};
+
+			if let Some(actual_supply_collateral) = maybe_actual_supply_collateral {
 				// refund remain collateral to CDP owner
 				let refund_collateral_amount = collateral
 					.checked_sub(actual_supply_collateral)
@@ -891,21 +1197,56 @@ impl<T: Config> Pallet<T> {
 
 				<T as Config>::CDPTreasury::withdraw_collateral(&who, currency_id, refund_collateral_amount)?;
 
-				Ok(LiquidationStrategy::Exchange)
+				Ok((
+					LiquidationStrategy::Exchange,
+					LiquidationStrategyReason::DexSwapSucceeded,
+				))
 			} else {
+				let reason = if !within_dex_threshold {
+					LiquidationStrategyReason::ExceedDexLiquidationSizeThreshold
+				} else {
+					match <T as Config>::CDPTreasury::get_swap_supply_collateral_amount(
+						currency_id,
+						target_stable_amount,
+						None,
+					)
+					.or_else(|| {
+						maybe_swap_path.as_ref().and_then(|path| {
+							<T as Config>::CDPTreasury::get_swap_supply_collateral_amount(
+								currency_id,
+								target_stable_amount,
+								Some(path),
+							)
+						})
+					}) {
+						None => LiquidationStrategyReason::DexPathUnavailable,
+						Some(supply_amount) if supply_amount > max_supply_limit => {
+							LiquidationStrategyReason::ExceedMaxSwapSlippage
+						}
+						Some(_) => LiquidationStrategyReason::DexSwapFailed,
+					}
+				};
+
 				// if swap failed, create collateral auctions by cdp treasury
 				<T as Config>::CDPTreasury::create_collateral_auctions(
 					currency_id,
 					collateral,
 					target_stable_amount,
+					None,
 					who.clone(),
 					true,
 				)?;
 
-				Ok(LiquidationStrategy::Auction)
+				Ok((LiquidationStrategy::Auction, reason))
 			}
 		})()?;
 
+		Self::deposit_event(Event::LiquidationStrategyChosen(
+			currency_id,
+			who.clone(),
+			liquidation_strategy.clone(),
+			reason,
+		));
 		Self::deposit_event(Event::LiquidateUnsafeCDP(
 			currency_id,
 			who,
@@ -958,6 +1299,21 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 			);
 		}
 
+		// check the minimum_collateral_value. The price is required above if there's debit,
+		// the check is skipped for the position without debit if the price is unavailable,
+		// so that the collateral can still be withdrawn during oracle outage.
+		if !collateral_balance.is_zero() {
+			if let Some(feed_price) =
+				<T as Config>::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())
+			{
+				let collateral_value = feed_price.saturating_mul_int(collateral_balance);
+				ensure!(
+					collateral_value >= T::MinimumCollateralValue::get(),
+					Error::<T>::RemainCollateralValueTooSmall,
+				);
+			}
+		}
+
 		Ok(())
 	}
 
@@ -967,6 +1323,19 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 
 		ensure!(total_debit_value <= hard_cap, Error::<T>::ExceedDebitValueHardCap,);
 
+		if let Some(global_debit_ceiling) = Self::global_debit_ceiling() {
+			let global_total_debit_value = T::CollateralCurrencyIds::get()
+				.into_iter()
+				.filter(|id| *id != currency_id)
+				.fold(total_debit_value, |total, id| {
+					total.saturating_add(Self::get_debit_value(id, <LoansOf<T>>::total_positions(id).debit))
+				});
+			ensure!(
+				global_total_debit_value <= global_debit_ceiling,
+				Error::<T>::ExceedGlobalDebitCeiling
+			);
+		}
+
 		Ok(())
 	}
 }
@@ -155,6 +155,8 @@ impl PriceProvider<CurrencyId> for MockPriceSource {
 		match (base, quote) {
 			(AUSD, BTC) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
 			(BTC, AUSD) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
+			(AUSD, DOT) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
+			(DOT, AUSD) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
 			_ => None,
 		}
 	}
@@ -175,6 +177,7 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		_currency_id: Self::CurrencyId,
 		_amount: Self::Balance,
 		_target: Self::Balance,
+		_reserve_price: Option<Self::Balance>,
 	) -> DispatchResult {
 		Ok(())
 	}
@@ -209,6 +212,7 @@ impl cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type EmergencyShutdown = MockEmergencyShutdown;
 	type WeightInfo = ();
 }
 
@@ -216,9 +220,11 @@ parameter_types! {
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxBatchSwaps: u32 = 3;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
+		TradingPair::from_currency_ids(BTC, DOT).unwrap(),
 	];
 }
 
@@ -227,9 +233,14 @@ impl dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPathLimit = TradingPathLimit;
+	type MaxBatchSwaps = MaxBatchSwaps;
+	type MinimumLiquidity = ();
 	type PalletId = DEXPalletId;
+	type MaxTwapWindow = ();
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
+	type PriceSource = ();
 	type WeightInfo = ();
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
 }
@@ -246,17 +257,26 @@ impl pallet_timestamp::Config for Runtime {
 
 thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
+	static SHUTDOWN_COLLATERALS: RefCell<Vec<CurrencyId>> = RefCell::new(vec![]);
 }
 
 pub fn mock_shutdown() {
 	IS_SHUTDOWN.with(|v| *v.borrow_mut() = true)
 }
 
+pub fn mock_collateral_shutdown(currency_id: CurrencyId) {
+	SHUTDOWN_COLLATERALS.with(|v| v.borrow_mut().push(currency_id))
+}
+
 pub struct MockEmergencyShutdown;
 impl EmergencyShutdown for MockEmergencyShutdown {
 	fn is_shutdown() -> bool {
 		IS_SHUTDOWN.with(|v| *v.borrow_mut())
 	}
+
+	fn is_collateral_shutdown(currency_id: CurrencyId) -> bool {
+		SHUTDOWN_COLLATERALS.with(|v| v.borrow().contains(&currency_id))
+	}
 }
 
 ord_parameter_types! {
@@ -268,7 +288,9 @@ parameter_types! {
 	pub DefaultDebitExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(10, 100);
 	pub const MinimumDebitValue: Balance = 2;
+	pub const MinimumCollateralValue: Balance = 10;
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(50, 100);
+	pub KeeperBountyRate: Rate = Rate::saturating_from_rational(10, 100);
 	pub const UnsignedPriority: u64 = 1 << 20;
 	pub CollateralCurrencyIds: Vec<CurrencyId> = vec![BTC, DOT];
 }
@@ -281,10 +303,12 @@ impl Config for Runtime {
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
 	type DefaultLiquidationPenalty = DefaultLiquidationPenalty;
 	type MinimumDebitValue = MinimumDebitValue;
+	type MinimumCollateralValue = MinimumCollateralValue;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CDPTreasuryModule;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
+	type KeeperBountyRate = KeeperBountyRate;
 	type UnsignedPriority = UnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type UnixTime = Timestamp;
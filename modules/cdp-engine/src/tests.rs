@@ -272,6 +272,71 @@ fn check_debit_cap_work() {
 	});
 }
 
+#[test]
+fn set_global_debit_ceiling_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_global_debit_ceiling(Origin::signed(5), Some(100)),
+			BadOrigin
+		);
+		assert_ok!(CDPEngineModule::set_global_debit_ceiling(Origin::signed(1), Some(100)));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::GlobalDebitCeilingUpdated(Some(
+			100,
+		))));
+		assert_eq!(CDPEngineModule::global_debit_ceiling(), Some(100));
+
+		assert_ok!(CDPEngineModule::set_global_debit_ceiling(Origin::signed(1), None));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::GlobalDebitCeilingUpdated(None)));
+		assert_eq!(CDPEngineModule::global_debit_ceiling(), None);
+	});
+}
+
+#[test]
+fn check_global_debit_ceiling_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		for currency_id in &[BTC, DOT] {
+			assert_ok!(CDPEngineModule::set_collateral_params(
+				Origin::signed(1),
+				*currency_id,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+				Change::NewValue(10000),
+			));
+		}
+		assert_ok!(CDPEngineModule::set_global_debit_ceiling(Origin::signed(1), Some(80)));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		// within the cap of DOT, but the total debit value of BTC and DOT exceeds
+		// the global debit ceiling
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, DOT, 100, 400),
+			Error::<Runtime>::ExceedGlobalDebitCeiling,
+		);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 100, 300));
+		assert_eq!(LoansModule::positions(DOT, ALICE).debit, 300);
+
+		assert_noop!(
+			CDPEngineModule::check_debit_cap(BTC, 510),
+			Error::<Runtime>::ExceedGlobalDebitCeiling,
+		);
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 0, 10),
+			Error::<Runtime>::ExceedGlobalDebitCeiling,
+		);
+
+		// decreasing debit is always allowed
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -100));
+
+		// no global debit ceiling
+		assert_ok!(CDPEngineModule::set_global_debit_ceiling(Origin::signed(1), None));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 300));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 700);
+	});
+}
+
 #[test]
 fn check_position_valid_failed_when_invalid_feed_price() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -388,6 +453,80 @@ fn adjust_position_work() {
 	});
 }
 
+#[test]
+fn simulate_collateral_ratio_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+
+		let simulated_ratio = CDPEngineModule::simulate_collateral_ratio(BTC, 100, 500, &ALICE);
+		assert_eq!(simulated_ratio, Some(Ratio::saturating_from_rational(2, 1)));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_eq!(
+			simulated_ratio,
+			Some(CDPEngineModule::calculate_collateral_ratio(BTC, 100, 500, Price::one()))
+		);
+
+		let simulated_ratio = CDPEngineModule::simulate_collateral_ratio(BTC, 50, -200, &ALICE);
+		assert_eq!(simulated_ratio, Some(Ratio::saturating_from_rational(5, 1)));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 50, -200));
+		assert_eq!(
+			simulated_ratio,
+			Some(CDPEngineModule::calculate_collateral_ratio(BTC, 150, 300, Price::one()))
+		);
+
+		// invalid adjustments
+		assert_eq!(CDPEngineModule::simulate_collateral_ratio(BTC, -200, 0, &ALICE), None);
+		assert_eq!(CDPEngineModule::simulate_collateral_ratio(BTC, 0, -400, &ALICE), None);
+
+		// no oracle price
+		MockPriceSource::set_relative_price(None);
+		assert_eq!(CDPEngineModule::simulate_collateral_ratio(BTC, 0, 0, &ALICE), None);
+	});
+}
+
+#[test]
+fn adjust_position_reject_new_debit_after_collateral_shutdown() {
+	ExtBuilder::default().build().execute_with(|| {
+		for currency_id in &[BTC, DOT] {
+			assert_ok!(CDPEngineModule::set_collateral_params(
+				Origin::signed(1),
+				*currency_id,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+				Change::NewValue(10000),
+			));
+		}
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		mock_collateral_shutdown(BTC);
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 100, 100),
+			Error::<Runtime>::CollateralShutdown,
+		);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -200));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 300);
+
+		// other collateral types still work
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 100, 500));
+		assert_eq!(LoansModule::positions(DOT, ALICE).debit, 500);
+		assert_eq!(LoansModule::positions(DOT, ALICE).collateral, 100);
+	});
+}
+
 #[test]
 fn remain_debit_value_too_small_check() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -407,7 +546,385 @@ fn remain_debit_value_too_small_check() {
 }
 
 #[test]
-fn liquidate_unsafe_cdp_by_collateral_auction() {
+fn remain_collateral_value_too_small_check() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 0));
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, -91, 0),
+			Error::<Runtime>::RemainCollateralValueTooSmall,
+		);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, -90, 0));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 10);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, -10, 0));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 9, 0),
+			Error::<Runtime>::RemainCollateralValueTooSmall,
+		);
+	});
+}
+
+#[test]
+fn all_collateral_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			CDPEngineModule::all_collateral_params(),
+			vec![(BTC, Default::default()), (DOT, Default::default())]
+		);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			DOT,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_eq!(
+			CDPEngineModule::all_collateral_params(),
+			vec![
+				(BTC, Default::default()),
+				(
+					DOT,
+					RiskManagementParams {
+						maximum_total_debit_value: 10000,
+						interest_rate_per_sec: Some(Rate::saturating_from_rational(1, 100000)),
+						liquidation_ratio: Some(Ratio::saturating_from_rational(3, 2)),
+						liquidation_penalty: Some(Rate::saturating_from_rational(2, 10)),
+						required_collateral_ratio: Some(Ratio::saturating_from_rational(9, 5)),
+					}
+				)
+			]
+		);
+	});
+}
+
+#[test]
+fn remain_collateral_value_check_skipped_without_debit_during_oracle_outage() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 50));
+
+		// the position with debit can't be adjusted without price
+		MockPriceSource::set_relative_price(None);
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, -10, 0),
+			Error::<Runtime>::InvalidFeedPrice,
+		);
+
+		// the collateral of the position without debit can still be withdrawn
+		MockPriceSource::set_relative_price(Some(Price::one()));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -50));
+		MockPriceSource::set_relative_price(None);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, -95, 0));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 5);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, -5, 0));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+	});
+}
+
+#[test]
+fn unsafe_cdps_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		// collateral ratio of ALICE is 200%, BOB is 181.8%, CAROL is 333.3%
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 550));
+		assert_ok!(CDPEngineModule::adjust_position(&CAROL, BTC, 100, 300));
+		assert_eq!(CDPEngineModule::unsafe_cdps(BTC, 10), vec![]);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(19, 10))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_eq!(CDPEngineModule::unsafe_cdps(BTC, 10), vec![BOB]);
+		assert_eq!(CDPEngineModule::unsafe_cdps(DOT, 10), vec![]);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(5, 2))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		let mut unsafe_cdps = CDPEngineModule::unsafe_cdps(BTC, 10);
+		unsafe_cdps.sort_unstable();
+		assert_eq!(unsafe_cdps, vec![ALICE, BOB]);
+
+		let limited_unsafe_cdps = CDPEngineModule::unsafe_cdps(BTC, 1);
+		assert_eq!(limited_unsafe_cdps.len(), 1);
+		assert!(limited_unsafe_cdps[0] == ALICE || limited_unsafe_cdps[0] == BOB);
+	});
+}
+
+#[test]
+fn liquidate_unsafe_cdp_by_collateral_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+		assert_noop!(
+			CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC),
+			Error::<Runtime>::MustBeUnsafe,
+		);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Auction,
+		)));
+		let strategy_chosen_event = Event::CDPEngineModule(crate::Event::LiquidationStrategyChosen(
+			BTC,
+			ALICE,
+			LiquidationStrategy::Auction,
+			LiquidationStrategyReason::DexPathUnavailable,
+		));
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == strategy_chosen_event));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+
+		mock_shutdown();
+		assert_noop!(
+			CDPEngineModule::liquidate(Origin::none(), BTC, ALICE),
+			Error::<Runtime>::AlreadyShutdown
+		);
+	});
+}
+
+#[test]
+fn liquidate_by_keeper_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_noop!(
+			CDPEngineModule::liquidate_by_keeper(Origin::none(), BTC, ALICE),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::liquidate_by_keeper(Origin::signed(BOB), BTC, ALICE),
+			Error::<Runtime>::MustBeUnsafe,
+		);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 0);
+		assert_ok!(CDPEngineModule::liquidate_by_keeper(Origin::signed(BOB), BTC, ALICE));
+		// the penalty of collateral auctions is not recovered yet, no bounty is paid
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Auction,
+		)));
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 0);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+
+		mock_shutdown();
+		assert_noop!(
+			CDPEngineModule::liquidate_by_keeper(Origin::signed(BOB), BTC, ALICE),
+			Error::<Runtime>::AlreadyShutdown
+		);
+	});
+}
+
+#[test]
+fn liquidate_by_keeper_pays_bounty_from_recovered_penalty() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			121,
+			0,
+			false
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::max_value())),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 0);
+		assert_ok!(CDPEngineModule::liquidate_by_keeper(Origin::signed(BOB), BTC, ALICE));
+		// 60 stable recovered for 50 bad debt, penalty = 10, bounty = 10 * 10% = 1
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::KeeperBountyPaid(BOB, BTC, 1)));
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (199, 61));
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 1);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 59);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+	});
+}
+
+#[test]
+fn liquidate_unsafe_cdp_by_collateral_auction_when_limited_by_slippage() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			121,
+			0,
+			false
+		));
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
+
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::max_value())),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		// pool is enough, but slippage limit the swap
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(1, 2)));
+		assert_eq!(DEXModule::get_swap_supply_amount(&[BTC, AUSD], 60), Some(99));
+		assert_eq!(DEXModule::get_swap_target_amount(&[BTC, AUSD], 100), Some(60));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Auction,
+		)));
+		let strategy_chosen_event = Event::CDPEngineModule(crate::Event::LiquidationStrategyChosen(
+			BTC,
+			ALICE,
+			LiquidationStrategy::Auction,
+			LiquidationStrategyReason::ExceedMaxSwapSlippage,
+		));
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == strategy_chosen_event));
+
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+	});
+}
+
+#[test]
+fn liquidate_unsafe_cdp_by_swap() {
 	ExtBuilder::default().build().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(CDPEngineModule::set_collateral_params(
@@ -419,79 +936,143 @@ fn liquidate_unsafe_cdp_by_collateral_auction() {
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
 		));
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			121,
+			0,
+			false
+		));
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
+
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
 		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
 		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
 		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
 		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
-		assert_noop!(
-			CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC),
-			Error::<Runtime>::MustBeUnsafe,
-		);
+
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
 			BTC,
 			Change::NoChange,
-			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NewValue(Some(Ratio::max_value())),
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
 		));
+
 		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
 		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
 			BTC,
 			ALICE,
 			100,
 			50,
-			LiquidationStrategy::Auction,
+			LiquidationStrategy::Exchange,
 		)));
+		let strategy_chosen_event = Event::CDPEngineModule(crate::Event::LiquidationStrategyChosen(
+			BTC,
+			ALICE,
+			LiquidationStrategy::Exchange,
+			LiquidationStrategyReason::DexSwapSucceeded,
+		));
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == strategy_chosen_event));
+
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (199, 61));
 		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
-		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 901);
 		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
 		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
 		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+	});
+}
 
-		mock_shutdown();
+#[test]
+fn set_liquidation_swap_path_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
 		assert_noop!(
-			CDPEngineModule::liquidate(Origin::none(), BTC, ALICE),
-			Error::<Runtime>::AlreadyShutdown
+			CDPEngineModule::set_liquidation_swap_path(Origin::signed(5), DOT, Some(vec![DOT, BTC, AUSD])),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_swap_path(Origin::signed(1), LDOT, Some(vec![LDOT, DOT, AUSD])),
+			Error::<Runtime>::InvalidCollateralType
 		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_swap_path(Origin::signed(1), DOT, Some(vec![BTC, AUSD])),
+			Error::<Runtime>::InvalidSwapPath
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_swap_path(Origin::signed(1), DOT, Some(vec![DOT, BTC])),
+			Error::<Runtime>::InvalidSwapPath
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_swap_path(Origin::signed(1), DOT, Some(vec![DOT])),
+			Error::<Runtime>::InvalidSwapPath
+		);
+
+		assert_ok!(CDPEngineModule::set_liquidation_swap_path(
+			Origin::signed(1),
+			DOT,
+			Some(vec![DOT, BTC, AUSD])
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidationSwapPathUpdated(
+			DOT,
+			Some(vec![DOT, BTC, AUSD]),
+		)));
+		assert_eq!(CDPEngineModule::liquidation_swap_paths(DOT), Some(vec![DOT, BTC, AUSD]));
+
+		assert_ok!(CDPEngineModule::set_liquidation_swap_path(Origin::signed(1), DOT, None));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidationSwapPathUpdated(
+			DOT, None,
+		)));
+		assert_eq!(CDPEngineModule::liquidation_swap_paths(DOT), None);
 	});
 }
 
 #[test]
-fn liquidate_unsafe_cdp_by_collateral_auction_when_limited_by_slippage() {
+fn liquidate_unsafe_cdp_by_swap_with_liquidation_swap_path() {
 	ExtBuilder::default().build().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
-			BTC,
+			DOT,
 			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
 		));
+		// DOT only has an indirect route to AUSD through BTC
 		assert_ok!(DEXModule::add_liquidity(
 			Origin::signed(CAROL),
 			BTC,
 			AUSD,
 			100,
-			121,
+			1000,
 			0,
 			false
 		));
-		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
-
-		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
-		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
-		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
-		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
-		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(BOB),
+			DOT,
+			BTC,
+			500,
+			500,
+			0,
+			false
+		));
+		assert_eq!(DEXModule::get_liquidity_pool(DOT, AUSD), (0, 0));
 
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, DOT, 100, 500));
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
-			BTC,
+			DOT,
 			Change::NoChange,
 			Change::NewValue(Some(Ratio::max_value())),
 			Change::NoChange,
@@ -499,30 +1080,58 @@ fn liquidate_unsafe_cdp_by_collateral_auction_when_limited_by_slippage() {
 			Change::NoChange,
 		));
 
-		// pool is enough, but slippage limit the swap
-		MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(1, 2)));
-		assert_eq!(DEXModule::get_swap_supply_amount(&[BTC, AUSD], 60), Some(99));
-		assert_eq!(DEXModule::get_swap_target_amount(&[BTC, AUSD], 100), Some(60));
-		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		// no configured swap path, fall back to auction
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(BOB, DOT));
 		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
-			BTC,
-			ALICE,
+			DOT,
+			BOB,
 			100,
 			50,
 			LiquidationStrategy::Auction,
 		)));
+		let strategy_chosen_event = Event::CDPEngineModule(crate::Event::LiquidationStrategyChosen(
+			DOT,
+			BOB,
+			LiquidationStrategy::Auction,
+			LiquidationStrategyReason::DexPathUnavailable,
+		));
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == strategy_chosen_event));
 
-		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
-		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
-		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
-		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
-		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
-		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+		// swap by the configured swap path
+		assert_ok!(CDPEngineModule::set_liquidation_swap_path(
+			Origin::signed(1),
+			DOT,
+			Some(vec![DOT, BTC, AUSD])
+		));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, DOT));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			DOT,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Exchange,
+		)));
+		let strategy_chosen_event = Event::CDPEngineModule(crate::Event::LiquidationStrategyChosen(
+			DOT,
+			ALICE,
+			LiquidationStrategy::Exchange,
+			LiquidationStrategyReason::DexSwapSucceeded,
+		));
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == strategy_chosen_event));
+
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD).1, 940);
+		assert!(Currencies::free_balance(DOT, &ALICE) > 900);
+		assert_eq!(LoansModule::positions(DOT, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(DOT, ALICE).collateral, 0);
 	});
 }
 
 #[test]
-fn liquidate_unsafe_cdp_by_swap() {
+fn liquidate_unsafe_cdp_respects_dex_liquidation_size_threshold() {
 	ExtBuilder::default().build().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(CDPEngineModule::set_collateral_params(
@@ -543,14 +1152,14 @@ fn liquidate_unsafe_cdp_by_swap() {
 			0,
 			false
 		));
-		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
 
+		// collateral is just over the threshold, use auction
+		assert_ok!(CDPTreasuryModule::set_dex_liquidation_size_threshold(
+			Origin::signed(1),
+			BTC,
+			Some(99)
+		));
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
-		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
-		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
-		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
-		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
-
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
 			BTC,
@@ -560,7 +1169,50 @@ fn liquidate_unsafe_cdp_by_swap() {
 			Change::NoChange,
 			Change::NoChange,
 		));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Auction,
+		)));
+		let strategy_chosen_event = Event::CDPEngineModule(crate::Event::LiquidationStrategyChosen(
+			BTC,
+			ALICE,
+			LiquidationStrategy::Auction,
+			LiquidationStrategyReason::ExceedDexLiquidationSizeThreshold,
+		));
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == strategy_chosen_event));
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
 
+		// collateral is just under the threshold, use DEX
+		assert_ok!(CDPTreasuryModule::set_dex_liquidation_size_threshold(
+			Origin::signed(1),
+			BTC,
+			Some(101)
+		));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::max_value())),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
 		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
 		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
 			BTC,
@@ -569,13 +1221,16 @@ fn liquidate_unsafe_cdp_by_swap() {
 			50,
 			LiquidationStrategy::Exchange,
 		)));
-
+		let strategy_chosen_event = Event::CDPEngineModule(crate::Event::LiquidationStrategyChosen(
+			BTC,
+			ALICE,
+			LiquidationStrategy::Exchange,
+			LiquidationStrategyReason::DexSwapSucceeded,
+		));
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == strategy_chosen_event));
 		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (199, 61));
-		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
-		assert_eq!(Currencies::free_balance(BTC, &ALICE), 901);
-		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
-		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
-		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
 	});
 }
 
@@ -764,6 +1419,82 @@ fn settle_cdp_has_debit_work() {
 	});
 }
 
+#[test]
+fn settle_cdps_batch_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&CAROL, BTC, 100, 0));
+
+		assert_noop!(
+			CDPEngineModule::settle_cdps_batch(Origin::signed(CAROL), BTC, 10, 10),
+			Error::<Runtime>::MustAfterShutdown
+		);
+
+		mock_shutdown();
+		assert_ok!(CDPEngineModule::settle_cdps_batch(Origin::signed(CAROL), BTC, 1, 10));
+		assert_eq!(
+			LoansModule::positions(BTC, ALICE).debit + LoansModule::positions(BTC, BOB).debit,
+			500
+		);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 50);
+
+		assert_ok!(CDPEngineModule::settle_cdps_batch(Origin::signed(CAROL), BTC, 10, 10));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 50);
+		assert_eq!(LoansModule::positions(BTC, BOB).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, BOB).collateral, 50);
+		assert_eq!(LoansModule::positions(BTC, CAROL).collateral, 100);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 100);
+		assert_eq!(CDPEngineModule::settle_cdps_cursor(BTC), None);
+	});
+}
+
+#[test]
+fn settle_cdps_batch_continue_from_cursor() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&CAROL, BTC, 100, 0));
+
+		mock_shutdown();
+		// scan only one CDP per batch
+		assert_ok!(CDPEngineModule::settle_cdps_batch(Origin::signed(CAROL), BTC, 10, 1));
+		assert!(CDPEngineModule::settle_cdps_cursor(BTC).is_some());
+		assert!(LoansModule::positions(BTC, ALICE).debit + LoansModule::positions(BTC, BOB).debit >= 500);
+
+		assert_ok!(CDPEngineModule::settle_cdps_batch(Origin::signed(CAROL), BTC, 10, 1));
+		assert_ok!(CDPEngineModule::settle_cdps_batch(Origin::signed(CAROL), BTC, 10, 1));
+		assert_ok!(CDPEngineModule::settle_cdps_batch(Origin::signed(CAROL), BTC, 10, 1));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, BOB).debit, 0);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(CDPEngineModule::settle_cdps_cursor(BTC), None);
+	});
+}
+
 #[test]
 fn close_cdp_has_debit_by_dex_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -833,7 +1564,7 @@ fn close_cdp_has_debit_by_dex_work() {
 		// max collateral amount limit swap
 		assert_noop!(
 			CDPEngineModule::close_cdp_has_debit_by_dex(ALICE, BTC, 5, None),
-			dex::Error::<Runtime>::ExcessiveSupplyAmount
+			Error::<Runtime>::ExceedMaxCollateralAmount
 		);
 
 		assert_ok!(CDPEngineModule::close_cdp_has_debit_by_dex(ALICE, BTC, 6, None));
@@ -848,3 +1579,49 @@ fn close_cdp_has_debit_by_dex_work() {
 		assert_eq!(CDPTreasuryModule::get_debit_pool(), 50);
 	});
 }
+
+#[test]
+fn close_cdp_has_debit_by_dex_with_max_collateral_amount_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			1000,
+			0,
+			false
+		));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		// the cap is too tight to clear the debit through the custom path
+		assert_noop!(
+			CDPEngineModule::close_cdp_has_debit_by_dex(ALICE, BTC, 5, Some(&[BTC, AUSD][..])),
+			Error::<Runtime>::ExceedMaxCollateralAmount
+		);
+
+		// the cap of full collateral only sells the required collateral
+		assert_ok!(CDPEngineModule::close_cdp_has_debit_by_dex(
+			ALICE,
+			BTC,
+			100,
+			Some(&[BTC, AUSD][..])
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::CloseCDPInDebitByDEX(
+			BTC, ALICE, 6, 94, 50,
+		)));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 994);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+	});
+}
@@ -50,9 +50,13 @@ pub trait WeightInfo {
 	fn on_initialize(c: u32) -> Weight;
 	fn set_collateral_params() -> Weight;
 	fn set_global_params() -> Weight;
+	fn set_global_debit_ceiling() -> Weight;
+	fn set_liquidation_swap_path() -> Weight;
 	fn liquidate_by_auction() -> Weight;
 	fn liquidate_by_dex() -> Weight;
+	fn liquidate_by_keeper() -> Weight;
 	fn settle() -> Weight;
+	fn settle_cdps_batch(c: u32) -> Weight;
 }
 
 /// Weights for module_cdp_engine using the Acala node and recommended hardware.
@@ -73,21 +77,41 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(11_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_global_debit_ceiling() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_liquidation_swap_path() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn liquidate_by_auction() -> Weight {
 		(203_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(28 as Weight))
+			.saturating_add(T::DbWeight::get().reads(29 as Weight))
 			.saturating_add(T::DbWeight::get().writes(17 as Weight))
 	}
 	fn liquidate_by_dex() -> Weight {
 		(252_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(29 as Weight))
+			.saturating_add(T::DbWeight::get().reads(30 as Weight))
 			.saturating_add(T::DbWeight::get().writes(15 as Weight))
 	}
+	fn liquidate_by_keeper() -> Weight {
+		(252_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(31 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
 	fn settle() -> Weight {
 		(97_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(11 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	fn settle_cdps_batch(c: u32) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((97_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((11 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((8 as Weight).saturating_mul(c as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -107,19 +131,39 @@ impl WeightInfo for () {
 		(11_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_global_debit_ceiling() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_liquidation_swap_path() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 	fn liquidate_by_auction() -> Weight {
 		(203_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(28 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(29 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
 	}
 	fn liquidate_by_dex() -> Weight {
 		(252_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(29 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(30 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(15 as Weight))
 	}
+	fn liquidate_by_keeper() -> Weight {
+		(252_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(31 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
+	}
 	fn settle() -> Weight {
 		(97_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
+	fn settle_cdps_batch(c: u32) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((97_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((11 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((8 as Weight).saturating_mul(c as Weight)))
+	}
 }
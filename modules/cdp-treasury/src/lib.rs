@@ -34,9 +34,9 @@ use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId};
 use sp_runtime::{
 	traits::{AccountIdConversion, One, Zero},
-	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber,
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, Permill,
 };
-use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, Ratio};
+use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, Ratio};
 
 mod mock;
 mod tests;
@@ -85,6 +85,9 @@ pub mod module {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// Emergency shutdown.
+		type EmergencyShutdown: EmergencyShutdown;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -99,6 +102,10 @@ pub mod module {
 		DebitPoolNotEnough,
 		/// The swap path is invalid
 		InvalidSwapPath,
+		/// The collateral dust is below the dust threshold
+		CollateralDustTooSmall,
+		/// The reserve price is higher than the target of the collateral auctions
+		InvalidReservePrice,
 	}
 
 	#[pallet::event]
@@ -107,6 +114,22 @@ pub mod module {
 		/// The expected amount size for per lot collateral auction of specific
 		/// collateral type updated. \[collateral_type, new_size\]
 		ExpectedCollateralAuctionSizeUpdated(CurrencyId, Balance),
+		/// The maximum collateral amount of specific collateral type that
+		/// liquidation will try to swap through DEX updated.
+		/// \[collateral_type, new_threshold\]
+		DexLiquidationSizeThresholdUpdated(CurrencyId, Option<Balance>),
+		/// The dust threshold of specific collateral type updated.
+		/// \[collateral_type, new_threshold\]
+		CollateralDustThresholdUpdated(CurrencyId, Balance),
+		/// Collateral dust consolidated into a single collateral auction.
+		/// \[collateral_type, amount\]
+		CollateralConsolidated(CurrencyId, Balance),
+		/// The share of stability fees routed to treasury updated.
+		/// \[new_share\]
+		SurplusTreasuryShareUpdated(Permill),
+		/// The share of stability fees transferred to treasury.
+		/// \[amount\]
+		SurplusRoutedToTreasury(Balance),
 	}
 
 	/// The expected amount size for per lot collateral auction of specific
@@ -117,6 +140,23 @@ pub mod module {
 	#[pallet::getter(fn expected_collateral_auction_size)]
 	pub type ExpectedCollateralAuctionSize<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Balance, ValueQuery>;
 
+	/// The maximum collateral amount of specific collateral type that
+	/// liquidation will try to swap through DEX, larger liquidation will be
+	/// handled by collateral auction directly. No limit if not set.
+	///
+	/// DexLiquidationSizeThreshold: map CurrencyId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn dex_liquidation_size_threshold)]
+	pub type DexLiquidationSizeThreshold<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Balance, OptionQuery>;
+
+	/// The collateral amount of specific collateral type below which is
+	/// considered as dust, too small to be auctioned economically on its own.
+	///
+	/// CollateralDustThreshold: map CurrencyId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_dust_threshold)]
+	pub type CollateralDustThreshold<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Balance, ValueQuery>;
+
 	/// Current total debit value of system. It's not same as debit in CDP
 	/// engine, it is the bad debt of the system.
 	///
@@ -125,6 +165,22 @@ pub mod module {
 	#[pallet::getter(fn debit_pool)]
 	pub type DebitPool<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// The share of stability fees which is routed to treasury as protocol
+	/// revenue, the remainder is retained in surplus pool.
+	///
+	/// SurplusTreasuryShare: Permill
+	#[pallet::storage]
+	#[pallet::getter(fn surplus_treasury_share)]
+	pub type SurplusTreasuryShare<T: Config> = StorageValue<_, Permill, ValueQuery>;
+
+	/// The amount of stability fees in surplus pool pending to be transferred
+	/// to treasury.
+	///
+	/// PendingTreasurySurplus: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn pending_treasury_surplus)]
+	pub type PendingTreasurySurplus<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig {
 		pub expected_collateral_auction_size: Vec<(CurrencyId, Balance)>,
@@ -157,6 +213,9 @@ pub mod module {
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		/// Handle excessive surplus or debits of system when block end
 		fn on_finalize(_now: T::BlockNumber) {
+			// transfer the treasury share of stability fees to treasury
+			Self::route_surplus_to_treasury();
+
 			// offset the same amount between debit pool and surplus pool
 			Self::offset_surplus_and_debit();
 		}
@@ -184,6 +243,7 @@ pub mod module {
 			currency_id: CurrencyId,
 			amount: Balance,
 			target: Balance,
+			reserve_price: Option<Balance>,
 			splited: bool,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
@@ -191,6 +251,7 @@ pub mod module {
 				currency_id,
 				amount,
 				target,
+				reserve_price,
 				Self::account_id(),
 				splited,
 			)?;
@@ -216,6 +277,88 @@ pub mod module {
 			Self::deposit_event(Event::ExpectedCollateralAuctionSizeUpdated(currency_id, size));
 			Ok(())
 		}
+
+		/// Update the maximum collateral amount of specific collateral type
+		/// that liquidation will try to swap through DEX.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type
+		/// - `threshold`: the new threshold, `None` means no limit
+		#[pallet::weight((T::WeightInfo::set_dex_liquidation_size_threshold(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_dex_liquidation_size_threshold(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			threshold: Option<Balance>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			DexLiquidationSizeThreshold::<T>::mutate_exists(currency_id, |maybe_threshold| {
+				*maybe_threshold = threshold;
+			});
+			Self::deposit_event(Event::DexLiquidationSizeThresholdUpdated(currency_id, threshold));
+			Ok(())
+		}
+
+		/// Update the dust threshold of specific collateral type.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type
+		/// - `threshold`: the new dust threshold
+		#[pallet::weight((T::WeightInfo::set_collateral_dust_threshold(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_collateral_dust_threshold(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			threshold: Balance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			CollateralDustThreshold::<T>::insert(currency_id, threshold);
+			Self::deposit_event(Event::CollateralDustThresholdUpdated(currency_id, threshold));
+			Ok(())
+		}
+
+		/// Update the share of stability fees routed to treasury.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `share`: the new share
+		#[pallet::weight((T::WeightInfo::set_surplus_treasury_share(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_surplus_treasury_share(origin: OriginFor<T>, share: Permill) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			SurplusTreasuryShare::<T>::put(share);
+			Self::deposit_event(Event::SurplusTreasuryShareUpdated(share));
+			Ok(())
+		}
+
+		/// Sweep the collateral remnants of specific collateral type held by
+		/// CDP treasury and not in auction into a single collateral auction.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type
+		#[pallet::weight(T::WeightInfo::consolidate_collateral())]
+		#[transactional]
+		pub fn consolidate_collateral(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let amount = Self::total_collaterals_not_in_auction(currency_id);
+			ensure!(
+				!amount.is_zero() && amount >= Self::collateral_dust_threshold(currency_id),
+				Error::<T>::CollateralDustTooSmall
+			);
+
+			T::AuctionManagerHandler::new_collateral_auction(
+				&Self::account_id(),
+				currency_id,
+				amount,
+				Zero::zero(),
+				None,
+			)?;
+			Self::deposit_event(Event::CollateralConsolidated(currency_id, amount));
+			Ok(())
+		}
 	}
 }
 
@@ -241,6 +384,37 @@ impl<T: Config> Pallet<T> {
 			.saturating_sub(T::AuctionManagerHandler::get_total_collateral_in_auction(currency_id))
 	}
 
+	fn route_surplus_to_treasury() {
+		// the surplus pool is kept to settle the system after emergency shutdown
+		if T::EmergencyShutdown::is_shutdown() {
+			return;
+		}
+
+		let amount = sp_std::cmp::min(Self::pending_treasury_surplus(), Self::surplus_pool());
+		if amount.is_zero() {
+			return;
+		}
+
+		match T::Currency::transfer(
+			T::GetStableCurrencyId::get(),
+			&Self::account_id(),
+			&T::TreasuryAccount::get(),
+			amount,
+		) {
+			Ok(_) => {
+				PendingTreasurySurplus::<T>::mutate(|pending| *pending = pending.saturating_sub(amount));
+				Self::deposit_event(Event::SurplusRoutedToTreasury(amount));
+			}
+			Err(e) => {
+				log::warn!(
+					target: "cdp-treasury",
+					"route_surplus_to_treasury: Attempt to transfer surplus {:?} to treasury failed: {:?}, this is unexpected but should be safe",
+					amount, e
+				);
+			}
+		}
+	}
+
 	fn offset_surplus_and_debit() {
 		let offset_amount = sp_std::cmp::min(Self::debit_pool(), Self::surplus_pool());
 
@@ -296,7 +470,14 @@ impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
 	}
 
 	fn on_system_surplus(amount: Self::Balance) -> DispatchResult {
-		Self::issue_debit(&Self::account_id(), amount, true)
+		Self::issue_debit(&Self::account_id(), amount, true)?;
+
+		// the treasury share is transferred on block end
+		let treasury_share = Self::surplus_treasury_share().mul_floor(amount);
+		if !treasury_share.is_zero() {
+			PendingTreasurySurplus::<T>::mutate(|pending| *pending = pending.saturating_add(treasury_share));
+		}
+		Ok(())
 	}
 
 	fn issue_debit(who: &T::AccountId, debit: Self::Balance, backed: bool) -> DispatchResult {
@@ -317,6 +498,10 @@ impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
 		T::Currency::transfer(T::GetStableCurrencyId::get(), from, &Self::account_id(), surplus)
 	}
 
+	fn withdraw_surplus(to: &T::AccountId, surplus: Self::Balance) -> DispatchResult {
+		T::Currency::transfer(T::GetStableCurrencyId::get(), &Self::account_id(), to, surplus)
+	}
+
 	fn deposit_collateral(from: &T::AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult {
 		T::Currency::transfer(currency_id, from, &Self::account_id(), amount)
 	}
@@ -327,6 +512,31 @@ impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
 }
 
 impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
+	fn get_dex_liquidation_size_threshold(currency_id: CurrencyId) -> Option<Balance> {
+		Self::dex_liquidation_size_threshold(currency_id)
+	}
+
+	fn get_swap_supply_collateral_amount(
+		currency_id: CurrencyId,
+		target_amount: Balance,
+		maybe_path: Option<&[CurrencyId]>,
+	) -> Option<Balance> {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let default_swap_path = &[currency_id, stable_currency_id];
+		let swap_path = match maybe_path {
+			None => default_swap_path,
+			Some(path) => {
+				let path_length = path.len();
+				if path_length < 2 || path[0] != currency_id || path[path_length - 1] != stable_currency_id {
+					return None;
+				}
+				path
+			}
+		};
+
+		T::DEX::get_swap_supply_amount(swap_path, target_amount)
+	}
+
 	/// Swap exact amount of collateral stable,
 	/// return actual target stable amount
 	fn swap_exact_collateral_to_stable(
@@ -409,6 +619,7 @@ impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
 		currency_id: CurrencyId,
 		amount: Balance,
 		target: Balance,
+		reserve_price: Option<Balance>,
 		refund_receiver: T::AccountId,
 		splited: bool,
 	) -> DispatchResult {
@@ -416,9 +627,14 @@ impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
 			Self::total_collaterals_not_in_auction(currency_id) >= amount,
 			Error::<T>::CollateralNotEnough,
 		);
+		ensure!(
+			target.is_zero() || reserve_price.map_or(true, |reserve_price| reserve_price <= target),
+			Error::<T>::InvalidReservePrice,
+		);
 
 		let mut unhandled_collateral_amount = amount;
 		let mut unhandled_target = target;
+		let mut unhandled_reserve_price = reserve_price;
 		let expected_collateral_auction_size = Self::expected_collateral_auction_size(currency_id);
 		let max_auctions_count: Balance = T::MaxAuctionsCount::get().into();
 		let lots_count = if !splited
@@ -442,15 +658,27 @@ impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
 		};
 		let average_amount_per_lot = amount.checked_div(lots_count).expect("lots count is at least 1; qed");
 		let average_target_per_lot = target.checked_div(lots_count).expect("lots count is at least 1; qed");
+		let average_reserve_price_per_lot =
+			reserve_price.map(|price| price.checked_div(lots_count).expect("lots count is at least 1; qed"));
 		let mut created_lots: Balance = Zero::zero();
 
 		while !unhandled_collateral_amount.is_zero() {
 			created_lots = created_lots.saturating_add(One::one());
-			let (lot_collateral_amount, lot_target) = if created_lots == lots_count {
+			let (lot_collateral_amount, lot_target, lot_reserve_price) = if created_lots == lots_count {
 				// the last lot may be have some remnant than average
-				(unhandled_collateral_amount, unhandled_target)
+				(unhandled_collateral_amount, unhandled_target, unhandled_reserve_price)
+			} else {
+				(
+					average_amount_per_lot,
+					average_target_per_lot,
+					average_reserve_price_per_lot,
+				)
+			};
+			// the rounding of the last lot must not push its reserve price above its target
+			let lot_reserve_price = if target.is_zero() {
+				lot_reserve_price
 			} else {
-				(average_amount_per_lot, average_target_per_lot)
+				lot_reserve_price.map(|price| price.min(lot_target))
 			};
 
 			T::AuctionManagerHandler::new_collateral_auction(
@@ -458,10 +686,13 @@ impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
 				currency_id,
 				lot_collateral_amount,
 				lot_target,
+				lot_reserve_price,
 			)?;
 
 			unhandled_collateral_amount = unhandled_collateral_amount.saturating_sub(lot_collateral_amount);
 			unhandled_target = unhandled_target.saturating_sub(lot_target);
+			unhandled_reserve_price = unhandled_reserve_price
+				.map(|price| price.saturating_sub(lot_reserve_price.unwrap_or_else(Zero::zero)));
 		}
 		Ok(())
 	}
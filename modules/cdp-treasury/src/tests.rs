@@ -71,6 +71,106 @@ fn on_system_surplus_work() {
 	});
 }
 
+#[test]
+fn set_surplus_treasury_share_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPTreasuryModule::set_surplus_treasury_share(Origin::signed(5), Permill::from_percent(20)),
+			BadOrigin
+		);
+		assert_ok!(CDPTreasuryModule::set_surplus_treasury_share(
+			Origin::signed(1),
+			Permill::from_percent(20)
+		));
+		System::assert_last_event(Event::CDPTreasuryModule(crate::Event::SurplusTreasuryShareUpdated(
+			Permill::from_percent(20),
+		)));
+		assert_eq!(CDPTreasuryModule::surplus_treasury_share(), Permill::from_percent(20));
+	});
+}
+
+#[test]
+fn route_surplus_to_treasury_on_finalize_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::set_surplus_treasury_share(
+			Origin::signed(1),
+			Permill::from_percent(20)
+		));
+		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 0);
+
+		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 1000);
+		assert_eq!(CDPTreasuryModule::pending_treasury_surplus(), 200);
+		CDPTreasuryModule::on_finalize(1);
+		System::assert_last_event(Event::CDPTreasuryModule(crate::Event::SurplusRoutedToTreasury(200)));
+		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 200);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 800);
+		assert_eq!(CDPTreasuryModule::pending_treasury_surplus(), 0);
+
+		// the treasury receives its share as surplus accrues, the remainder is
+		// retained for debit offset
+		assert_ok!(CDPTreasuryModule::on_system_surplus(500));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(500));
+		assert_ok!(CDPTreasuryModule::on_system_debit(300));
+		CDPTreasuryModule::on_finalize(2);
+		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 400);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 1300);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+
+		// surplus not from stability fees is not routed
+		assert_ok!(CDPTreasuryModule::deposit_surplus(&ALICE, 100));
+		CDPTreasuryModule::on_finalize(3);
+		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 400);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 1400);
+	});
+}
+
+#[test]
+fn route_surplus_to_treasury_keeps_unrouted_pending_surplus() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::set_surplus_treasury_share(
+			Origin::signed(1),
+			Permill::from_percent(20)
+		));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		assert_ok!(CDPTreasuryModule::extract_surplus_to_treasury(Origin::signed(1), 900));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(CDPTreasuryModule::pending_treasury_surplus(), 200);
+
+		CDPTreasuryModule::on_finalize(1);
+		System::assert_last_event(Event::CDPTreasuryModule(crate::Event::SurplusRoutedToTreasury(100)));
+		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 1000);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
+		assert_eq!(CDPTreasuryModule::pending_treasury_surplus(), 100);
+
+		assert_ok!(CDPTreasuryModule::on_system_surplus(500));
+		assert_eq!(CDPTreasuryModule::pending_treasury_surplus(), 200);
+		CDPTreasuryModule::on_finalize(2);
+		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 1200);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 300);
+		assert_eq!(CDPTreasuryModule::pending_treasury_surplus(), 0);
+	});
+}
+
+#[test]
+fn route_surplus_to_treasury_skipped_after_emergency_shutdown() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::set_surplus_treasury_share(
+			Origin::signed(1),
+			Permill::from_percent(20)
+		));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		mock_shutdown();
+		CDPTreasuryModule::on_finalize(1);
+		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 0);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 1000);
+		assert_eq!(CDPTreasuryModule::pending_treasury_surplus(), 200);
+	});
+}
+
 #[test]
 fn offset_surplus_and_debit_on_finalize_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -295,7 +395,7 @@ fn swap_exact_collateral_to_stable_work() {
 		);
 
 		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
-			BTC, 200, 1000, ALICE, true
+			BTC, 200, 1000, None, ALICE, true
 		));
 		assert_eq!(TOTAL_COLLATERAL_IN_AUCTION.with(|v| *v.borrow_mut()), 200);
 
@@ -339,13 +439,17 @@ fn create_collateral_auctions_work() {
 		assert_ok!(Currencies::deposit(BTC, &CDPTreasuryModule::account_id(), 10000));
 		assert_eq!(CDPTreasuryModule::expected_collateral_auction_size(BTC), 0);
 		assert_noop!(
-			CDPTreasuryModule::create_collateral_auctions(BTC, 10001, 1000, ALICE, true),
+			CDPTreasuryModule::create_collateral_auctions(BTC, 10001, 1000, None, ALICE, true),
 			Error::<Runtime>::CollateralNotEnough,
 		);
+		assert_noop!(
+			CDPTreasuryModule::create_collateral_auctions(BTC, 1000, 1000, Some(1001), ALICE, true),
+			Error::<Runtime>::InvalidReservePrice,
+		);
 
 		// without collateral auction maximum size
 		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
-			BTC, 1000, 1000, ALICE, true
+			BTC, 1000, 1000, None, ALICE, true
 		));
 		assert_eq!(TOTAL_COLLATERAL_AUCTION.with(|v| *v.borrow_mut()), 1);
 		assert_eq!(TOTAL_COLLATERAL_IN_AUCTION.with(|v| *v.borrow_mut()), 1000);
@@ -360,7 +464,7 @@ fn create_collateral_auctions_work() {
 		// amount < collateral auction maximum size
 		// auction + 1
 		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
-			BTC, 200, 1000, ALICE, true
+			BTC, 200, 1000, None, ALICE, true
 		));
 		assert_eq!(TOTAL_COLLATERAL_AUCTION.with(|v| *v.borrow_mut()), 2);
 		assert_eq!(TOTAL_COLLATERAL_IN_AUCTION.with(|v| *v.borrow_mut()), 1200);
@@ -368,7 +472,7 @@ fn create_collateral_auctions_work() {
 		// not exceed lots count cap
 		// auction + 4
 		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
-			BTC, 1000, 1000, ALICE, true
+			BTC, 1000, 1000, None, ALICE, true
 		));
 		assert_eq!(TOTAL_COLLATERAL_AUCTION.with(|v| *v.borrow_mut()), 6);
 		assert_eq!(TOTAL_COLLATERAL_IN_AUCTION.with(|v| *v.borrow_mut()), 2200);
@@ -376,7 +480,7 @@ fn create_collateral_auctions_work() {
 		// exceed lots count cap
 		// auction + 5
 		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
-			BTC, 2000, 1000, ALICE, true
+			BTC, 2000, 1000, None, ALICE, true
 		));
 		assert_eq!(TOTAL_COLLATERAL_AUCTION.with(|v| *v.borrow_mut()), 11);
 		assert_eq!(TOTAL_COLLATERAL_IN_AUCTION.with(|v| *v.borrow_mut()), 4200);
@@ -403,6 +507,92 @@ fn set_expected_collateral_auction_size_work() {
 	});
 }
 
+#[test]
+fn set_dex_liquidation_size_threshold_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(CDPTreasuryModule::dex_liquidation_size_threshold(BTC), None);
+		assert_noop!(
+			CDPTreasuryModule::set_dex_liquidation_size_threshold(Origin::signed(5), BTC, Some(200)),
+			BadOrigin
+		);
+		assert_ok!(CDPTreasuryModule::set_dex_liquidation_size_threshold(
+			Origin::signed(1),
+			BTC,
+			Some(200)
+		));
+		System::assert_last_event(Event::CDPTreasuryModule(
+			crate::Event::DexLiquidationSizeThresholdUpdated(BTC, Some(200)),
+		));
+		assert_eq!(CDPTreasuryModule::get_dex_liquidation_size_threshold(BTC), Some(200));
+
+		assert_ok!(CDPTreasuryModule::set_dex_liquidation_size_threshold(
+			Origin::signed(1),
+			BTC,
+			None
+		));
+		assert_eq!(CDPTreasuryModule::dex_liquidation_size_threshold(BTC), None);
+	});
+}
+
+#[test]
+fn set_collateral_dust_threshold_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(CDPTreasuryModule::collateral_dust_threshold(BTC), 0);
+		assert_noop!(
+			CDPTreasuryModule::set_collateral_dust_threshold(Origin::signed(5), BTC, 100),
+			BadOrigin
+		);
+		assert_ok!(CDPTreasuryModule::set_collateral_dust_threshold(
+			Origin::signed(1),
+			BTC,
+			100
+		));
+		System::assert_last_event(Event::CDPTreasuryModule(crate::Event::CollateralDustThresholdUpdated(
+			BTC, 100,
+		)));
+		assert_eq!(CDPTreasuryModule::collateral_dust_threshold(BTC), 100);
+	});
+}
+
+#[test]
+fn consolidate_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::set_collateral_dust_threshold(
+			Origin::signed(1),
+			BTC,
+			100
+		));
+		assert_noop!(
+			CDPTreasuryModule::consolidate_collateral(Origin::signed(5), BTC),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPTreasuryModule::consolidate_collateral(Origin::signed(1), BTC),
+			Error::<Runtime>::CollateralDustTooSmall
+		);
+
+		// several dust remnants
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&BOB, BTC, 30));
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&BOB, BTC, 40));
+		assert_noop!(
+			CDPTreasuryModule::consolidate_collateral(Origin::signed(1), BTC),
+			Error::<Runtime>::CollateralDustTooSmall
+		);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&BOB, BTC, 50));
+		assert_eq!(CDPTreasuryModule::total_collaterals_not_in_auction(BTC), 120);
+
+		assert_ok!(CDPTreasuryModule::consolidate_collateral(Origin::signed(1), BTC));
+		System::assert_last_event(Event::CDPTreasuryModule(crate::Event::CollateralConsolidated(BTC, 120)));
+		assert_eq!(TOTAL_COLLATERAL_AUCTION.with(|v| *v.borrow_mut()), 1);
+		assert_eq!(TOTAL_COLLATERAL_IN_AUCTION.with(|v| *v.borrow_mut()), 120);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 120);
+		assert_eq!(CDPTreasuryModule::total_collaterals_not_in_auction(BTC), 0);
+	});
+}
+
 #[test]
 fn extract_surplus_to_treasury_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -46,15 +46,10 @@
 //!
 //! ### Rewards
 //!
-//! The Collator Selection pallet maintains an on-chain account (the "Pot"). In each block, the
-//! collator who authored it receives:
-//!
-//! - Half the value of the Pot.
-//! - Half the value of the transaction fees within the block. The other half of the transaction
-//!   fees are deposited into the Pot.
-//!
-//! Note: Eventually the Pot distribution may be modified as discussed in
-//! [this issue](https://github.com/paritytech/statemint/issues/21#issuecomment-810481073).
+//! The Collator Selection pallet maintains an on-chain account (the "Pot"). Each collator earns
+//! `POINT_PER_BLOCK` points for every block it authors in a session. At the end of the session,
+//! the Pot (minus the existential deposit) is distributed to the collators in proportion to their
+//! authored-block points.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -87,8 +82,8 @@ pub mod pallet {
 	};
 	use frame_support::{
 		sp_runtime::{
-			traits::{AccountIdConversion, CheckedSub, Zero},
-			Permill,
+			traits::{AccountIdConversion, CheckedSub, Saturating, Zero},
+			Perbill, Permill,
 		},
 		weights::DispatchClass,
 	};
@@ -97,7 +92,7 @@ pub mod pallet {
 	use pallet_session::SessionManager;
 	use primitives::ReserveIdentifier;
 	use sp_staking::SessionIndex;
-	use sp_std::{convert::TryInto, ops::Div, vec};
+	use sp_std::{convert::TryInto, vec};
 
 	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::CollatorSelection;
 	pub const POINT_PER_BLOCK: u32 = 10;
@@ -158,6 +153,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type CollatorKickThreshold: Get<Permill>;
 
+		/// The left or kicked candidate cannot withdraw bond or register
+		/// candidate again until `UnbondCooldown` blocks have passed since
+		/// leaving or being kicked.
+		#[pallet::constant]
+		type UnbondCooldown: Get<Self::BlockNumber>;
+
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -195,7 +196,8 @@ pub mod pallet {
 	#[pallet::getter(fn candidacy_bond)]
 	pub type CandidacyBond<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
-	/// Session points for each candidate.
+	/// Session points for each collator, used to distribute the Pot and kick the candidates at
+	/// the end of the session.
 	///
 	/// SessionPoints: map AccountId => u32
 	#[pallet::storage]
@@ -209,6 +211,14 @@ pub mod pallet {
 	#[pallet::getter(fn non_candidates)]
 	pub type NonCandidates<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, SessionIndex, ValueQuery>;
 
+	/// Mapping from the left or kicked candidate to the block number their bond
+	/// unlocks and they can register candidate again.
+	///
+	/// PendingUnbonds: map AccountId => BlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn pending_unbonds)]
+	pub type PendingUnbonds<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub invulnerables: Vec<T::AccountId>,
@@ -267,6 +277,8 @@ pub mod pallet {
 		CandidateAdded(T::AccountId, BalanceOf<T>),
 		/// A candidate was removed. \[who\]
 		CandidateRemoved(T::AccountId),
+		/// A collator was rewarded from the Pot for the authored blocks. \[who, reward\]
+		CollatorRewarded(T::AccountId, BalanceOf<T>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -329,8 +341,15 @@ pub mod pallet {
 				if let Some(index) = maybe_index.take() {
 					ensure!(T::ValidatorSet::session_index() >= index, Error::<T>::StillLocked);
 				}
+				if let Some(unlock_at) = <PendingUnbonds<T>>::get(&who) {
+					ensure!(
+						<frame_system::Pallet<T>>::block_number() >= unlock_at,
+						Error::<T>::StillLocked
+					);
+				}
 				Ok(())
 			})?;
+			<PendingUnbonds<T>>::remove(&who);
 
 			let deposit = Self::candidacy_bond();
 			let bounded_candidates_len = Self::do_register_candidate(&who, deposit)?;
@@ -354,7 +373,11 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let current_count = Self::try_remove_candidate(&who)?;
-			<NonCandidates<T>>::insert(who, T::ValidatorSet::session_index().saturating_add(SESSION_DELAY));
+			<NonCandidates<T>>::insert(&who, T::ValidatorSet::session_index().saturating_add(SESSION_DELAY));
+			<PendingUnbonds<T>>::insert(
+				who,
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::UnbondCooldown::get()),
+			);
 
 			Ok(Some(T::WeightInfo::leave_intent(current_count as u32)).into())
 		}
@@ -366,6 +389,13 @@ pub mod pallet {
 			<NonCandidates<T>>::try_mutate_exists(&who, |maybe_index| -> DispatchResult {
 				if let Some(index) = maybe_index.take() {
 					ensure!(T::ValidatorSet::session_index() >= index, Error::<T>::StillLocked);
+					if let Some(unlock_at) = <PendingUnbonds<T>>::get(&who) {
+						ensure!(
+							<frame_system::Pallet<T>>::block_number() >= unlock_at,
+							Error::<T>::StillLocked
+						);
+						<PendingUnbonds<T>>::remove(&who);
+					}
 					T::Currency::unreserve_all_named(&RESERVE_ID, &who);
 					Ok(())
 				} else {
@@ -406,6 +436,43 @@ pub mod pallet {
 			collators
 		}
 
+		/// Distribute the Pot (minus ED) to the collators in proportion to their session points.
+		fn distribute_pot(session_points: &[(T::AccountId, u32)]) {
+			let total_point: u32 = session_points
+				.iter()
+				.fold(0u32, |sum, (_, point)| sum.saturating_add(*point));
+			if total_point.is_zero() {
+				return;
+			}
+
+			let pot = Self::account_id();
+			// assumes an ED will be sent to pot.
+			let total_reward = T::Currency::free_balance(&pot)
+				.checked_sub(&T::Currency::minimum_balance())
+				.unwrap_or_default();
+			if total_reward.is_zero() {
+				return;
+			}
+
+			for (who, point) in session_points {
+				let reward = Perbill::from_rational(*point, total_point).mul_floor(total_reward);
+				if reward.is_zero() {
+					continue;
+				}
+				// the sum of `reward` is at most the pot account minus ED, this only fails if the
+				// reward can't create the collator account.
+				match T::Currency::transfer(&pot, who, reward, KeepAlive) {
+					Ok(_) => Self::deposit_event(Event::CollatorRewarded(who.clone(), reward)),
+					Err(e) => log::warn!(
+						target: "collator-selection",
+						"failed to reward collator {:?}: {:?}",
+						who,
+						e,
+					),
+				}
+			}
+		}
+
 		pub fn do_register_candidate(who: &T::AccountId, deposit: BalanceOf<T>) -> Result<usize, DispatchError> {
 			// ensure we are below limit.
 			let length = <Candidates<T>>::decode_len().unwrap_or_default();
@@ -440,16 +507,6 @@ pub mod pallet {
 				author,
 				<frame_system::Pallet<T>>::block_number(),
 			);
-			let pot = Self::account_id();
-			// assumes an ED will be sent to pot.
-			let reward = T::Currency::free_balance(&pot)
-				.checked_sub(&T::Currency::minimum_balance())
-				.unwrap_or_default()
-				.div(2u32.into());
-			// `reward` is half of pot account minus ED, this should never fail.
-			let _success = T::Currency::transfer(&pot, &author, reward, KeepAlive);
-			debug_assert!(_success.is_ok());
-
 			if <SessionPoints<T>>::contains_key(&author) {
 				<SessionPoints<T>>::mutate(author, |point| *point += POINT_PER_BLOCK);
 			}
@@ -487,13 +544,14 @@ pub mod pallet {
 
 		fn start_session(index: SessionIndex) {
 			let validators = T::ValidatorSet::validators();
+			let invulnerables = Self::invulnerables();
 			let candidates = Self::candidates();
 			let mut collators = vec![];
 
-			candidates.iter().for_each(|candidate| {
-				if validators.contains(&candidate) {
-					collators.push(candidate);
-					<SessionPoints<T>>::insert(&candidate, 0);
+			invulnerables.iter().chain(candidates.iter()).for_each(|collator| {
+				if validators.contains(&collator) {
+					collators.push(collator);
+					<SessionPoints<T>>::insert(&collator, 0);
 				}
 			});
 
@@ -512,8 +570,16 @@ pub mod pallet {
 		}
 
 		fn end_session(index: SessionIndex) {
-			let mut removed_len = 0;
 			let session_points = <SessionPoints<T>>::drain().collect::<Vec<_>>();
+			Self::distribute_pot(&session_points);
+
+			// only the candidates can be kicked.
+			let invulnerables = Self::invulnerables();
+			let mut removed_len = 0;
+			let session_points = session_points
+				.into_iter()
+				.filter(|(who, _)| !invulnerables.contains(who))
+				.collect::<Vec<_>>();
 			let candidates_len: u32 = session_points.len() as u32;
 
 			let total_session_point: u32 = session_points.iter().fold(0, |mut sum, (_, point)| {
@@ -544,9 +610,13 @@ pub mod pallet {
 						debug_assert!(false, "failed to remove candidate {:?}", why);
 					} else {
 						<NonCandidates<T>>::insert(
-							who,
+							&who,
 							T::ValidatorSet::session_index().saturating_add(T::KickPenaltySessionLength::get()),
 						);
+						<PendingUnbonds<T>>::insert(
+							who,
+							<frame_system::Pallet<T>>::block_number().saturating_add(T::UnbondCooldown::get()),
+						);
 					}
 				}
 			}
@@ -196,6 +196,7 @@ parameter_types! {
 	pub const MaxInvulnerables: u32 = 4;
 	pub const KickPenaltySessionLength: u32 = 8;
 	pub const CollatorKickThreshold: Permill = Permill::from_percent(100);
+	pub storage UnbondCooldown: u64 = 15;
 }
 
 impl Config for Test {
@@ -209,6 +210,7 @@ impl Config for Test {
 	type MaxInvulnerables = MaxInvulnerables;
 	type KickPenaltySessionLength = KickPenaltySessionLength;
 	type CollatorKickThreshold = CollatorKickThreshold;
+	type UnbondCooldown = UnbondCooldown;
 	type WeightInfo = ();
 }
 
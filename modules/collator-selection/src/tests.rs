@@ -17,13 +17,15 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate as collator_selection;
-use crate::{mock::*, Error, NonCandidates, RESERVE_ID};
+use crate::{mock::*, Error, NonCandidates, PendingUnbonds, POINT_PER_BLOCK, RESERVE_ID};
 use frame_support::{
 	assert_noop, assert_ok,
 	storage::bounded_btree_set::BoundedBTreeSet,
 	traits::{Currency, GenesisBuild, NamedReservableCurrency, OnInitialize},
 };
+use pallet_authorship::EventHandler;
 use pallet_balances::Error as BalancesError;
+use pallet_session::SessionManager;
 use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin};
 
 #[test]
@@ -385,6 +387,66 @@ fn withdraw_bond() {
 	});
 }
 
+#[test]
+fn withdraw_bond_after_unbond_cooldown() {
+	new_test_ext().execute_with(|| {
+		UnbondCooldown::set(&35);
+		for who in 3..=5 {
+			assert_ok!(Session::set_keys(
+				Origin::signed(who),
+				MockSessionKeys {
+					aura: UintAuthorityId(who)
+				},
+				vec![]
+			));
+		}
+		assert_ok!(CollatorSelection::set_desired_candidates(
+			Origin::signed(RootAccount::get()),
+			4
+		));
+		assert_ok!(CollatorSelection::register_as_candidate(Origin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(Origin::signed(4)));
+		assert_ok!(CollatorSelection::register_as_candidate(Origin::signed(5)));
+
+		// bond is not spendable after leaving
+		assert_ok!(CollatorSelection::leave_intent(Origin::signed(3)));
+		let unlock_at = System::block_number() + UnbondCooldown::get();
+		assert_eq!(CollatorSelection::pending_unbonds(3), Some(unlock_at));
+		assert_eq!(Balances::free_balance(3), 90);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &3), 10);
+
+		// session lock has passed, but the cooldown has not
+		initialize_to_block(2 * Period::get());
+		assert_noop!(
+			CollatorSelection::withdraw_bond(Origin::signed(3)),
+			Error::<Test>::StillLocked
+		);
+
+		// 4 is the default author, so `required_point` > 0 (the `ensure_can_kick_collator`
+		// invariant) and 5 gets kicked at the end of session 2. The kick starts the cooldown of 5
+		// and must not touch the pending unbond of 3.
+		initialize_to_block(unlock_at - 1);
+		assert_eq!(CollatorSelection::candidates().contains(&5), false);
+		assert_eq!(
+			CollatorSelection::pending_unbonds(5),
+			Some(3 * Period::get() + UnbondCooldown::get())
+		);
+		assert_noop!(
+			CollatorSelection::withdraw_bond(Origin::signed(3)),
+			Error::<Test>::StillLocked
+		);
+		assert_eq!(Balances::free_balance(3), 90);
+
+		// bond is returned after the cooldown
+		initialize_to_block(unlock_at);
+		assert_ok!(CollatorSelection::withdraw_bond(Origin::signed(3)));
+		assert_eq!(Balances::free_balance(3), 100);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &3), 0);
+		assert_eq!(PendingUnbonds::<Test>::contains_key(3), false);
+		assert_eq!(NonCandidates::<Test>::contains_key(3), false);
+	});
+}
+
 #[test]
 fn fees_edgecases() {
 	new_test_ext().execute_with(|| {
@@ -419,6 +481,38 @@ fn fees_edgecases() {
 	});
 }
 
+#[test]
+fn pot_distributed_by_authored_points() {
+	new_test_ext().execute_with(|| {
+		let pot = CollatorSelection::account_id();
+		let ed = Balances::minimum_balance();
+		Balances::make_free_balance_be(&pot, ed + 400);
+
+		// 1 authored one block and 2 authored three blocks in the session.
+		CollatorSelection::start_session(0);
+		CollatorSelection::note_author(1);
+		CollatorSelection::note_author(2);
+		CollatorSelection::note_author(2);
+		CollatorSelection::note_author(2);
+		assert_eq!(CollatorSelection::session_points(1), POINT_PER_BLOCK);
+		assert_eq!(CollatorSelection::session_points(2), 3 * POINT_PER_BLOCK);
+
+		// Nothing is paid before the session ends.
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Balances::free_balance(2), 100);
+
+		CollatorSelection::end_session(0);
+
+		assert_eq!(Balances::free_balance(1), 200);
+		assert_eq!(Balances::free_balance(2), 400);
+		assert_eq!(Balances::free_balance(pot), ed);
+		assert_eq!(CollatorSelection::session_points(1), 0);
+		assert_eq!(CollatorSelection::session_points(2), 0);
+		// the invulnerables are not kicked
+		assert_eq!(CollatorSelection::invulnerables(), vec![1, 2]);
+	});
+}
+
 #[test]
 fn session_management_works() {
 	new_test_ext().execute_with(|| {
@@ -512,6 +606,49 @@ fn kick_mechanism() {
 	});
 }
 
+#[test]
+fn kicked_collator_register_after_unbond_cooldown() {
+	new_test_ext().execute_with(|| {
+		UnbondCooldown::set(&200);
+		for who in 3..=4 {
+			assert_ok!(Session::set_keys(
+				Origin::signed(who),
+				MockSessionKeys {
+					aura: UintAuthorityId(who)
+				},
+				vec![]
+			));
+		}
+		assert_ok!(CollatorSelection::register_as_candidate(Origin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(Origin::signed(4)));
+
+		// 3 gets kicked at the end of session 2
+		initialize_to_block(31);
+		assert_eq!(CollatorSelection::candidates().contains(&3), false);
+		let unlock_at = 30 + UnbondCooldown::get();
+		assert_eq!(CollatorSelection::pending_unbonds(3), Some(unlock_at));
+
+		// kick penalty has passed, but the cooldown has not
+		initialize_to_block(unlock_at - 1);
+		assert!(Session::current_index() >= NonCandidates::<Test>::get(3));
+		assert_noop!(
+			CollatorSelection::register_as_candidate(Origin::signed(3)),
+			Error::<Test>::StillLocked
+		);
+		assert_noop!(
+			CollatorSelection::withdraw_bond(Origin::signed(3)),
+			Error::<Test>::StillLocked
+		);
+
+		initialize_to_block(unlock_at);
+		assert_ok!(CollatorSelection::register_as_candidate(Origin::signed(3)));
+		assert_eq!(CollatorSelection::candidates().contains(&3), true);
+		assert_eq!(PendingUnbonds::<Test>::contains_key(3), false);
+		assert_eq!(NonCandidates::<Test>::contains_key(3), false);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &3), 10);
+	});
+}
+
 #[test]
 fn exceeding_max_invulnerables_should_fail() {
 	new_test_ext().execute_with(|| {
@@ -0,0 +1,39 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for cross-chain transfer of currencies by xtokens.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use frame_support::weights::Weight;
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+
+sp_api::decl_runtime_apis! {
+	pub trait XTokensApi<CurrencyId, Balance> where
+		CurrencyId: Codec,
+		Balance: Codec + MaybeDisplay + MaybeFromStr,
+	{
+		/// Estimate the execution fee in `currency_id` charged on the
+		/// destination for a cross-chain transfer of `currency_id`, where
+		/// `dest_weight` is the weight bought on the destination. Returns
+		/// `None` if `currency_id` is not a cross-chain asset.
+		fn estimate_transfer_fee(currency_id: CurrencyId, dest_weight: Weight) -> Option<Balance>;
+	}
+}
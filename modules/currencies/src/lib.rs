@@ -38,18 +38,20 @@ use orml_traits::{
 	BalanceStatus, BasicCurrency, BasicCurrencyExtended, BasicLockableCurrency, BasicReservableCurrency,
 	LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency,
 };
+use orml_utilities::with_transaction_result;
 use primitives::{evm::EvmAddress, CurrencyId};
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{CheckedSub, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
+	traits::{Bounded, CheckedSub, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
 	DispatchError, DispatchResult,
 };
 use sp_std::{
 	convert::{TryFrom, TryInto},
 	fmt::Debug,
 	marker, result,
+	vec::Vec,
 };
-use support::{AddressMapping, EVMBridge, InvokeContext};
+use support::{AddressMapping, EVMBridge, InvokeContext, TransferFilter};
 
 mod mock;
 mod tests;
@@ -58,6 +60,9 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The lock identifier of the frozen balances.
+pub const FREEZE_LOCK_ID: LockIdentifier = *b"aca/frzn";
+
 type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
 type CurrencyIdOf<T> =
 	<<T as Config>::MultiCurrency as MultiCurrency<<T as frame_system::Config>::AccountId>>::CurrencyId;
@@ -90,6 +95,20 @@ pub mod module {
 		/// Mapping from address to account id.
 		type AddressMapping: AddressMapping<Self::AccountId>;
 		type EVMBridge: EVMBridge<Self::AccountId, BalanceOf<Self>>;
+
+		/// The origin which may sweep dust balances.
+		type SweepOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The account that receives swept dust balances.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The origin which may freeze and thaw accounts.
+		type FreezeOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The filter consulted before `transfer` and
+		/// `transfer_native_currency`, which may veto the transfer.
+		type PreTransferFilter: TransferFilter<Self::AccountId, CurrencyId, BalanceOf<Self>>;
 	}
 
 	#[pallet::error]
@@ -102,6 +121,12 @@ pub mod module {
 		Erc20InvalidOperation,
 		/// EVM account not found
 		EvmAccountNotFound,
+		/// The account is already frozen
+		AlreadyFrozen,
+		/// The account is not frozen
+		NotFrozen,
+		/// The transfer is rejected by the pre-transfer filter
+		TransferFiltered,
 	}
 
 	#[pallet::event]
@@ -116,7 +141,23 @@ pub mod module {
 		Deposited(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
 		/// Withdraw success. \[currency_id, who, amount\]
 		Withdrawn(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
-	}
+		/// Dust swept to treasury. \[currency_id, who, amount\]
+		DustSwept(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
+		/// Failed to sweep dust to treasury. \[currency_id, who, amount, error\]
+		DustSweepFailed(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>, DispatchError),
+		/// Account frozen. \[currency_id, who\]
+		AccountFrozen(CurrencyIdOf<T>, T::AccountId),
+		/// Account thawed. \[currency_id, who\]
+		AccountThawed(CurrencyIdOf<T>, T::AccountId),
+	}
+
+	/// The accounts whose balance under the currency is frozen.
+	///
+	/// FrozenAccounts: double_map CurrencyId, AccountId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_accounts)]
+	pub type FrozenAccounts<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyIdOf<T>, Twox64Concat, T::AccountId, bool, ValueQuery>;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -139,6 +180,7 @@ pub mod module {
 		) -> DispatchResult {
 			let from = ensure_signed(origin)?;
 			let to = T::Lookup::lookup(dest)?;
+			Self::ensure_transfer_allowed(&from, &to, currency_id, amount)?;
 			<Self as MultiCurrency<T::AccountId>>::transfer(currency_id, &from, &to, amount)?;
 			Ok(())
 		}
@@ -155,12 +197,141 @@ pub mod module {
 		) -> DispatchResult {
 			let from = ensure_signed(origin)?;
 			let to = T::Lookup::lookup(dest)?;
+			Self::ensure_transfer_allowed(&from, &to, T::GetNativeCurrencyId::get(), amount)?;
 			T::NativeCurrency::transfer(&from, &to, amount)?;
 
 			Self::deposit_event(Event::Transferred(T::GetNativeCurrencyId::get(), from, to, amount));
 			Ok(())
 		}
 
+		/// Transfer all free balance under `currency_id` to another account.
+		///
+		/// The dispatch origin for this call must be `Signed` by the
+		/// transactor.
+		///
+		/// - `keep_alive`: leave the existential deposit in the sender's
+		///   account so that it is not reaped.
+		#[pallet::weight(T::WeightInfo::transfer_all_native_currency()
+			.max(T::WeightInfo::transfer_all_non_native_currency()))]
+		pub fn transfer_all(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyIdOf<T>,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			let free_balance = <Self as MultiCurrency<T::AccountId>>::free_balance(currency_id, &from);
+			let amount = if keep_alive {
+				free_balance.saturating_sub(<Self as MultiCurrency<T::AccountId>>::minimum_balance(currency_id))
+			} else {
+				free_balance
+			};
+			Self::ensure_transfer_allowed(&from, &to, currency_id, amount)?;
+			<Self as MultiCurrency<T::AccountId>>::transfer(currency_id, &from, &to, amount)?;
+			Ok(())
+		}
+
+		/// Sweep the free balance of accounts whose total balance is below
+		/// the existential deposit into the treasury. The accounts failed to
+		/// sweep are skipped, the result of each account is emitted in event.
+		///
+		/// The dispatch origin of this call must be `SweepOrigin`.
+		///
+		/// - `currency_ids`: the currencies to check.
+		/// - `accounts`: the accounts to check.
+		#[pallet::weight(T::WeightInfo::sweep_dust((currency_ids.len() * accounts.len()) as u32))]
+		#[transactional]
+		pub fn sweep_dust(
+			origin: OriginFor<T>,
+			currency_ids: Vec<CurrencyIdOf<T>>,
+			accounts: Vec<T::AccountId>,
+		) -> DispatchResult {
+			T::SweepOrigin::ensure_origin(origin)?;
+			let treasury = T::TreasuryAccount::get();
+
+			for currency_id in currency_ids {
+				ensure!(
+					!matches!(currency_id, CurrencyId::Erc20(_)),
+					Error::<T>::Erc20InvalidOperation
+				);
+				let existential_deposit = <Self as MultiCurrency<T::AccountId>>::minimum_balance(currency_id);
+
+				for who in accounts.iter().filter(|who| **who != treasury) {
+					let free_balance = <Self as MultiCurrency<T::AccountId>>::free_balance(currency_id, who);
+					if free_balance.is_zero()
+						|| <Self as MultiCurrency<T::AccountId>>::total_balance(currency_id, who) >= existential_deposit
+					{
+						continue;
+					}
+
+					match with_transaction_result(|| {
+						<Self as MultiCurrency<T::AccountId>>::transfer(currency_id, who, &treasury, free_balance)
+					}) {
+						Ok(_) => Self::deposit_event(Event::DustSwept(currency_id, who.clone(), free_balance)),
+						Err(e) => {
+							Self::deposit_event(Event::DustSweepFailed(currency_id, who.clone(), free_balance, e))
+						}
+					}
+				}
+			}
+			Ok(())
+		}
+
+		/// Freeze the balance of account `who` under `currency_id`, it can't
+		/// be transferred out until the account is thawed. The collaterals
+		/// of `who` held by loans are not affected and can still be
+		/// liquidated.
+		///
+		/// The dispatch origin of this call must be `FreezeOrigin`.
+		#[pallet::weight(T::WeightInfo::freeze_account())]
+		#[transactional]
+		pub fn freeze_account(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(
+				!matches!(currency_id, CurrencyId::Erc20(_)),
+				Error::<T>::Erc20InvalidOperation
+			);
+			ensure!(!Self::frozen_accounts(currency_id, &who), Error::<T>::AlreadyFrozen);
+
+			<Self as MultiLockableCurrency<T::AccountId>>::set_lock(
+				FREEZE_LOCK_ID,
+				currency_id,
+				&who,
+				BalanceOf::<T>::max_value(),
+			)?;
+			FrozenAccounts::<T>::insert(currency_id, &who, true);
+
+			Self::deposit_event(Event::AccountFrozen(currency_id, who));
+			Ok(())
+		}
+
+		/// Thaw the frozen balance of account `who` under `currency_id`.
+		///
+		/// The dispatch origin of this call must be `FreezeOrigin`.
+		#[pallet::weight(T::WeightInfo::thaw_account())]
+		#[transactional]
+		pub fn thaw_account(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(Self::frozen_accounts(currency_id, &who), Error::<T>::NotFrozen);
+
+			<Self as MultiLockableCurrency<T::AccountId>>::remove_lock(FREEZE_LOCK_ID, currency_id, &who)?;
+			FrozenAccounts::<T>::remove(currency_id, &who);
+
+			Self::deposit_event(Event::AccountThawed(currency_id, who));
+			Ok(())
+		}
+
 		/// update amount of account `who` under `currency_id`.
 		///
 		/// The dispatch origin of this call must be _Root_.
@@ -179,6 +350,21 @@ pub mod module {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	fn ensure_transfer_allowed(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		currency_id: CurrencyIdOf<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(
+			T::PreTransferFilter::is_allowed(from, to, currency_id, amount),
+			Error::<T>::TransferFiltered
+		);
+		Ok(())
+	}
+}
+
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	type CurrencyId = CurrencyIdOf<T>;
 	type Balance = BalanceOf<T>;
@@ -835,6 +1021,11 @@ where
 }
 
 impl<T: Config> TransferAll<T::AccountId> for Pallet<T> {
+	/// Transfer native and `MultiCurrency` balances from `source` to `dest`.
+	///
+	/// Erc20 balances are held by the EVM address rather than the account, so
+	/// they follow the address when it is mapped to `dest` and are not moved
+	/// here.
 	#[transactional]
 	fn transfer_all(source: &T::AccountId, dest: &T::AccountId) -> DispatchResult {
 		// transfer non-native free to dest
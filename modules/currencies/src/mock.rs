@@ -34,7 +34,7 @@ use support::{mocks::MockAddressMapping, AddressMapping};
 use super::*;
 use frame_system::EnsureSignedBy;
 use sp_core::{bytes::from_hex, H160};
-use sp_std::str::FromStr;
+use sp_std::{cell::RefCell, str::FromStr};
 
 pub use crate as currencies;
 
@@ -74,9 +74,17 @@ impl frame_system::Config for Runtime {
 
 type Balance = u128;
 
+thread_local! {
+	static TOKEN_EXISTENTIAL_DEPOSIT: RefCell<Balance> = RefCell::new(0);
+}
+
+pub fn set_token_existential_deposit(amount: Balance) {
+	TOKEN_EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = amount);
+}
+
 parameter_type_with_key! {
 	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
-		Default::default()
+		TOKEN_EXISTENTIAL_DEPOSIT.with(|v| *v.borrow())
 	};
 }
 
@@ -145,6 +153,7 @@ ord_parameter_types! {
 	pub const MaxCodeSize: u32 = 60 * 1024;
 	pub const DeveloperDeposit: u64 = 1000;
 	pub const DeploymentFee: u64 = 200;
+	pub const BlockedAccount: AccountId32 = AccountId32::from([3u8; 32]);
 }
 
 impl module_evm::Config for Runtime {
@@ -175,6 +184,13 @@ impl module_evm_bridge::Config for Runtime {
 	type EVM = EVM;
 }
 
+pub struct MockTransferFilter;
+impl TransferFilter<AccountId, CurrencyId, Balance> for MockTransferFilter {
+	fn is_allowed(_from: &AccountId, to: &AccountId, _currency_id: CurrencyId, _amount: Balance) -> bool {
+		*to != BlockedAccount::get()
+	}
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
@@ -183,6 +199,10 @@ impl Config for Runtime {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = EVMBridge;
+	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type TreasuryAccount = TreasuryAccount;
+	type FreezeOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type PreTransferFilter = MockTransferFilter;
 }
 
 pub type NativeCurrency = Currency<Runtime, GetNativeCurrencyId>;
@@ -23,8 +23,9 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	alice, bob, deploy_contracts, erc20_address, eva, AccountId, AdaptedBasicCurrency, Currencies, Event, ExtBuilder,
-	NativeCurrency, Origin, PalletBalances, Runtime, System, Tokens, EVM, ID_1, NATIVE_CURRENCY_ID, X_TOKEN_ID,
+	alice, bob, deploy_contracts, erc20_address, eva, set_token_existential_deposit, AccountId, AdaptedBasicCurrency,
+	BlockedAccount, CouncilAccount, Currencies, Event, ExtBuilder, NativeCurrency, Origin, PalletBalances, Runtime,
+	System, Tokens, TreasuryAccount, EVM, ID_1, NATIVE_CURRENCY_ID, X_TOKEN_ID,
 };
 use sp_core::H160;
 use sp_runtime::traits::BadOrigin;
@@ -318,6 +319,262 @@ fn call_event_should_work() {
 		});
 }
 
+#[test]
+fn transfer_all_call_should_work() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY_ID, 100),
+			(bob(), NATIVE_CURRENCY_ID, 100),
+			(eva(), NATIVE_CURRENCY_ID, 100),
+			(alice(), X_TOKEN_ID, 100),
+			(eva(), X_TOKEN_ID, 100),
+		])
+		.build()
+		.execute_with(|| {
+			// native currency, keep alive
+			assert_ok!(Currencies::transfer_all(
+				Some(bob()).into(),
+				alice(),
+				NATIVE_CURRENCY_ID,
+				true
+			));
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &bob()), 1);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &alice()), 199);
+			assert!(System::account_exists(&bob()));
+			System::assert_last_event(Event::Currencies(crate::Event::Transferred(
+				NATIVE_CURRENCY_ID,
+				bob(),
+				alice(),
+				99,
+			)));
+
+			// native currency, allow death
+			assert_ok!(Currencies::transfer_all(
+				Some(bob()).into(),
+				alice(),
+				NATIVE_CURRENCY_ID,
+				false
+			));
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &bob()), 0);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &alice()), 200);
+			assert!(!System::account_exists(&bob()));
+
+			// token, keep alive
+			assert_ok!(Currencies::transfer_all(Some(alice()).into(), bob(), X_TOKEN_ID, true));
+			assert_eq!(
+				Currencies::free_balance(X_TOKEN_ID, &alice()),
+				<Currencies as MultiCurrency<AccountId>>::minimum_balance(X_TOKEN_ID)
+			);
+			assert_eq!(
+				Currencies::free_balance(X_TOKEN_ID, &bob()),
+				100 - <Currencies as MultiCurrency<AccountId>>::minimum_balance(X_TOKEN_ID)
+			);
+			assert!(System::account_exists(&alice()));
+
+			// token, allow death
+			assert_ok!(Currencies::transfer_all(Some(eva()).into(), bob(), X_TOKEN_ID, false));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &eva()), 0);
+			assert_eq!(
+				Currencies::free_balance(X_TOKEN_ID, &bob()),
+				200 - <Currencies as MultiCurrency<AccountId>>::minimum_balance(X_TOKEN_ID)
+			);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &eva()), 100);
+			assert!(System::account_exists(&eva()));
+		});
+}
+
+#[test]
+fn sweep_dust_call_should_work() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), X_TOKEN_ID, 5),
+			(bob(), X_TOKEN_ID, 100),
+			(eva(), X_TOKEN_ID, 9),
+			(TreasuryAccount::get(), X_TOKEN_ID, 100),
+		])
+		.build()
+		.execute_with(|| {
+			// ED is raised after the balances are created
+			set_token_existential_deposit(10);
+
+			assert_noop!(
+				Currencies::sweep_dust(Origin::signed(alice()), vec![X_TOKEN_ID], vec![alice()]),
+				BadOrigin
+			);
+			assert_noop!(
+				Currencies::sweep_dust(
+					Origin::signed(CouncilAccount::get()),
+					vec![CurrencyId::Erc20(erc20_address())],
+					vec![alice()]
+				),
+				Error::<Runtime>::Erc20InvalidOperation
+			);
+
+			// the locked dust of alice can't be swept, it's skipped
+			assert_ok!(Currencies::set_lock(ID_1, X_TOKEN_ID, &alice(), 5));
+			assert_ok!(Currencies::sweep_dust(
+				Origin::signed(CouncilAccount::get()),
+				vec![X_TOKEN_ID],
+				vec![alice(), bob(), eva(), TreasuryAccount::get()]
+			));
+			System::assert_has_event(Event::Currencies(crate::Event::DustSweepFailed(
+				X_TOKEN_ID,
+				alice(),
+				5,
+				tokens::Error::<Runtime>::LiquidityRestrictions.into(),
+			)));
+			System::assert_last_event(Event::Currencies(crate::Event::DustSwept(X_TOKEN_ID, eva(), 9)));
+
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 5);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &eva()), 0);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 100);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &TreasuryAccount::get()), 109);
+
+			assert_ok!(Currencies::remove_lock(ID_1, X_TOKEN_ID, &alice()));
+			assert_ok!(Currencies::sweep_dust(
+				Origin::signed(CouncilAccount::get()),
+				vec![X_TOKEN_ID],
+				vec![alice()]
+			));
+			System::assert_last_event(Event::Currencies(crate::Event::DustSwept(X_TOKEN_ID, alice(), 5)));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 0);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &TreasuryAccount::get()), 114);
+		});
+}
+
+#[test]
+fn freeze_account_call_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Currencies::freeze_account(Origin::signed(alice()), X_TOKEN_ID, alice()),
+				BadOrigin
+			);
+			assert_noop!(
+				Currencies::freeze_account(
+					Origin::signed(CouncilAccount::get()),
+					CurrencyId::Erc20(erc20_address()),
+					alice()
+				),
+				Error::<Runtime>::Erc20InvalidOperation
+			);
+
+			assert_ok!(Currencies::freeze_account(
+				Origin::signed(CouncilAccount::get()),
+				X_TOKEN_ID,
+				alice()
+			));
+			System::assert_last_event(Event::Currencies(crate::Event::AccountFrozen(X_TOKEN_ID, alice())));
+			assert!(Currencies::frozen_accounts(X_TOKEN_ID, alice()));
+			assert_noop!(
+				Currencies::freeze_account(Origin::signed(CouncilAccount::get()), X_TOKEN_ID, alice()),
+				Error::<Runtime>::AlreadyFrozen
+			);
+			assert_noop!(
+				Currencies::transfer(Some(alice()).into(), bob(), X_TOKEN_ID, 50),
+				tokens::Error::<Runtime>::LiquidityRestrictions
+			);
+
+			assert_ok!(Currencies::freeze_account(
+				Origin::signed(CouncilAccount::get()),
+				NATIVE_CURRENCY_ID,
+				alice()
+			));
+			assert_noop!(
+				Currencies::transfer(Some(alice()).into(), bob(), NATIVE_CURRENCY_ID, 50),
+				pallet_balances::Error::<Runtime>::LiquidityRestrictions
+			);
+
+			// other accounts are not affected
+			assert_ok!(Currencies::transfer(Some(bob()).into(), alice(), X_TOKEN_ID, 50));
+		});
+}
+
+#[test]
+fn thaw_account_call_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Currencies::thaw_account(Origin::signed(CouncilAccount::get()), X_TOKEN_ID, alice()),
+				Error::<Runtime>::NotFrozen
+			);
+			assert_ok!(Currencies::freeze_account(
+				Origin::signed(CouncilAccount::get()),
+				X_TOKEN_ID,
+				alice()
+			));
+			assert_ok!(Currencies::freeze_account(
+				Origin::signed(CouncilAccount::get()),
+				NATIVE_CURRENCY_ID,
+				alice()
+			));
+
+			assert_noop!(
+				Currencies::thaw_account(Origin::signed(alice()), X_TOKEN_ID, alice()),
+				BadOrigin
+			);
+			assert_ok!(Currencies::thaw_account(
+				Origin::signed(CouncilAccount::get()),
+				X_TOKEN_ID,
+				alice()
+			));
+			System::assert_last_event(Event::Currencies(crate::Event::AccountThawed(X_TOKEN_ID, alice())));
+			assert!(!Currencies::frozen_accounts(X_TOKEN_ID, alice()));
+			assert_eq!(Tokens::locks(&alice(), X_TOKEN_ID).len(), 0);
+			assert_ok!(Currencies::transfer(Some(alice()).into(), bob(), X_TOKEN_ID, 50));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 150);
+
+			assert_ok!(Currencies::thaw_account(
+				Origin::signed(CouncilAccount::get()),
+				NATIVE_CURRENCY_ID,
+				alice()
+			));
+			assert_eq!(PalletBalances::locks(&alice()).len(), 0);
+			assert_ok!(Currencies::transfer(
+				Some(alice()).into(),
+				bob(),
+				NATIVE_CURRENCY_ID,
+				50
+			));
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &bob()), 150);
+		});
+}
+
+#[test]
+fn pre_transfer_filter_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Currencies::transfer(Some(alice()).into(), BlockedAccount::get(), X_TOKEN_ID, 50),
+				Error::<Runtime>::TransferFiltered
+			);
+			assert_noop!(
+				Currencies::transfer(Some(alice()).into(), BlockedAccount::get(), NATIVE_CURRENCY_ID, 50),
+				Error::<Runtime>::TransferFiltered
+			);
+			assert_noop!(
+				Currencies::transfer_native_currency(Some(alice()).into(), BlockedAccount::get(), 50),
+				Error::<Runtime>::TransferFiltered
+			);
+			assert_noop!(
+				Currencies::transfer_all(Some(alice()).into(), BlockedAccount::get(), X_TOKEN_ID, false),
+				Error::<Runtime>::TransferFiltered
+			);
+
+			// other recipients are not affected
+			assert_ok!(Currencies::transfer(Some(alice()).into(), bob(), X_TOKEN_ID, 50));
+			assert_ok!(Currencies::transfer_native_currency(Some(alice()).into(), bob(), 50));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 150);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &bob()), 150);
+		});
+}
+
 #[test]
 fn erc20_total_issuance_should_work() {
 	ExtBuilder::default()
@@ -50,9 +50,14 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn transfer_non_native_currency() -> Weight;
 	fn transfer_native_currency() -> Weight;
+	fn transfer_all_native_currency() -> Weight;
+	fn transfer_all_non_native_currency() -> Weight;
 	fn update_balance_non_native_currency() -> Weight;
 	fn update_balance_native_currency_creating() -> Weight;
 	fn update_balance_native_currency_killing() -> Weight;
+	fn sweep_dust(c: u32, ) -> Weight;
+	fn freeze_account() -> Weight;
+	fn thaw_account() -> Weight;
 }
 
 /// Weights for module_currencies using the Acala node and recommended hardware.
@@ -66,6 +71,14 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	fn transfer_native_currency() -> Weight {
 		(11_000_000 as Weight)
 	}
+	fn transfer_all_native_currency() -> Weight {
+		(11_000_000 as Weight)
+	}
+	fn transfer_all_non_native_currency() -> Weight {
+		(65_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 	fn update_balance_non_native_currency() -> Weight {
 		(31_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
@@ -77,6 +90,23 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	fn update_balance_native_currency_killing() -> Weight {
 		(13_000_000 as Weight)
 	}
+	fn sweep_dust(c: u32, ) -> Weight {
+		(4_380_000 as Weight)
+			// Standard Error: 14_000
+			.saturating_add((66_274_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(c as Weight)))
+	}
+	fn freeze_account() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn thaw_account() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -89,6 +119,14 @@ impl WeightInfo for () {
 	fn transfer_native_currency() -> Weight {
 		(11_000_000 as Weight)
 	}
+	fn transfer_all_native_currency() -> Weight {
+		(11_000_000 as Weight)
+	}
+	fn transfer_all_non_native_currency() -> Weight {
+		(65_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
 	fn update_balance_non_native_currency() -> Weight {
 		(31_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
@@ -100,4 +138,21 @@ impl WeightInfo for () {
 	fn update_balance_native_currency_killing() -> Weight {
 		(13_000_000 as Weight)
 	}
+	fn sweep_dust(c: u32, ) -> Weight {
+		(4_380_000 as Weight)
+			// Standard Error: 14_000
+			.saturating_add((66_274_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(c as Weight)))
+	}
+	fn freeze_account() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn thaw_account() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
 }
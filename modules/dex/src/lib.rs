@@ -39,11 +39,11 @@ use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId, TradingPair};
 use sp_core::{H160, U256};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
-	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug, SaturatedConversion,
+	traits::{AccountIdConversion, CheckedDiv, CheckedMul, CheckedSub, One, Saturating, Zero},
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, Permill, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::{convert::TryInto, prelude::*, vec};
-use support::{CurrencyIdMapping, DEXIncentives, DEXManager, ExchangeRate, Ratio};
+use support::{CurrencyIdMapping, DEXIncentives, DEXManager, ExchangeRate, Price, PriceProvider, Ratio};
 
 mod mock;
 mod tests;
@@ -52,6 +52,10 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The default slippage tolerance applied to the expected target amount when
+/// quoting a swap, 0.5%.
+pub const DEFAULT_SWAP_SLIPPAGE_TOLERANCE: Permill = Permill::from_parts(5_000);
+
 /// Parameters of TradingPair in Provisioning status
 #[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
 pub struct ProvisioningParameters<Balance, BlockNumber> {
@@ -65,6 +69,17 @@ pub struct ProvisioningParameters<Balance, BlockNumber> {
 	not_before: BlockNumber,
 }
 
+/// The swap fees accounting of the dex shares of a liquidity provider
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct LiquidityProviderFeeInfo {
+	/// The dex shares added through DEX and not removed yet.
+	pub shares: Balance,
+	/// The cumulative fees per share when the accrued fees were last settled.
+	pub fees_per_share_checkpoint: (ExchangeRate, ExchangeRate),
+	/// The fees accrued until the last settlement.
+	pub accrued_fees: (Balance, Balance),
+}
+
 /// Status for TradingPair
 #[derive(Clone, Copy, Encode, Decode, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
 pub enum TradingPairStatus<Balance, BlockNumber> {
@@ -108,14 +123,39 @@ pub mod module {
 		#[pallet::constant]
 		type TradingPathLimit: Get<u32>;
 
+		/// The limit for length of trading path of the accounts allowed to
+		/// use extended trading path
+		#[pallet::constant]
+		type MaxTradingPathLimit: Get<u32>;
+
+		/// The maximum number of swaps in a batch
+		#[pallet::constant]
+		type MaxBatchSwaps: Get<u32>;
+
+		/// The amount of dex share permanently locked to the module account
+		/// when a liquidity pool issues shares for the first time, to prevent
+		/// share inflation by the first liquidity provider.
+		#[pallet::constant]
+		type MinimumLiquidity: Get<Balance>;
+
 		/// The DEX's module id, keep all assets in DEX.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The max number of blocks a time-weighted average price can be
+		/// queried over, the cumulative price snapshots are only kept this
+		/// long. The price accumulator is disabled if it is zero.
+		#[pallet::constant]
+		type MaxTwapWindow: Get<Self::BlockNumber>;
+
 		/// Mapping between CurrencyId and ERC20 address so user can use Erc20
 		/// address as LP token.
 		type CurrencyIdMapping: CurrencyIdMapping;
 
+		/// The price source to provide oracle prices of currencies, used to
+		/// guard swaps against price manipulation.
+		type PriceSource: PriceProvider<CurrencyId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 
@@ -166,6 +206,14 @@ pub mod module {
 		UnqualifiedProvision,
 		/// Trading pair is still provisioning
 		StillProvisioning,
+		/// The number of swaps in the batch is invalid
+		InvalidBatchSwapsLength,
+		/// The swap moves the pool price too far from the oracle price
+		ExceedMaxOracleDeviation,
+		/// The swap price deviates from the spot price more than max_slippage
+		ExceedMaxSlippage,
+		/// The swap is executed after its deadline
+		SwapExpired,
 	}
 
 	#[pallet::event]
@@ -194,6 +242,15 @@ pub mod module {
 		/// Provisioning trading pair convert to Enabled. \[trading_pair,
 		/// pool_0_amount, pool_1_amount, total_share_amount\]
 		ProvisioningToEnabled(TradingPair, Balance, Balance, Balance),
+		/// A swap in the batch failed and was skipped. \[trader, index,
+		/// error\]
+		BatchSwapItemFailed(T::AccountId, u32, DispatchError),
+		/// The permission of an account to use extended trading path updated.
+		/// \[who, allowed\]
+		ExtendedTradingPathAccountUpdated(T::AccountId, bool),
+		/// The max deviation of pool price from oracle price updated.
+		/// \[max_deviation\]
+		MaxDexOracleDeviationUpdated(Option<Ratio>),
 	}
 
 	/// Liquidity pool for TradingPair.
@@ -228,6 +285,67 @@ pub mod module {
 	pub type InitialShareExchangeRates<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, (ExchangeRate, ExchangeRate), ValueQuery>;
 
+	/// The accounts allowed to swap with trading path longer than
+	/// `TradingPathLimit`, up to `MaxTradingPathLimit`.
+	///
+	/// ExtendedTradingPathAccounts: map AccountId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn extended_trading_path_accounts)]
+	pub type ExtendedTradingPathAccounts<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, bool, ValueQuery>;
+
+	/// The max deviation of the pool price after swap from the oracle price,
+	/// swaps exceeding it are rejected unless they reduce the deviation. The
+	/// guard is disabled if it is none.
+	///
+	/// MaxDexOracleDeviation: Option<Ratio>
+	#[pallet::storage]
+	#[pallet::getter(fn max_dex_oracle_deviation)]
+	pub type MaxDexOracleDeviation<T: Config> = StorageValue<_, Ratio, OptionQuery>;
+
+	/// The cumulative price of the first currency of TradingPair in the
+	/// second one, sum of the price at the start of each block multiplied by
+	/// the blocks it lasted, and the block it was last updated at.
+	///
+	/// CumulativePrices: map TradingPair => Option<(U256, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn cumulative_prices)]
+	pub type CumulativePrices<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, (U256, T::BlockNumber), OptionQuery>;
+
+	/// The cumulative swap fees per dex share of TradingPair, in the first
+	/// and the second currency.
+	///
+	/// CumulativeFeesPerShare: map TradingPair => (ExchangeRate, ExchangeRate)
+	#[pallet::storage]
+	#[pallet::getter(fn cumulative_fees_per_share)]
+	pub type CumulativeFeesPerShare<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, (ExchangeRate, ExchangeRate), ValueQuery>;
+
+	/// The swap fees accounting of the dex shares added by AccountId.
+	///
+	/// LiquidityProviderFees: double_map TradingPair, AccountId =>
+	/// LiquidityProviderFeeInfo
+	#[pallet::storage]
+	#[pallet::getter(fn liquidity_provider_fees)]
+	pub type LiquidityProviderFees<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TradingPair,
+		Twox64Concat,
+		T::AccountId,
+		LiquidityProviderFeeInfo,
+		ValueQuery,
+	>;
+
+	/// Snapshots of the cumulative price of TradingPair taken in
+	/// `on_initialize`, kept for `MaxTwapWindow` blocks.
+	///
+	/// CumulativePriceSnapshots: double_map TradingPair, BlockNumber => Option<U256>
+	#[pallet::storage]
+	#[pallet::getter(fn cumulative_price_snapshots)]
+	pub type CumulativePriceSnapshots<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, TradingPair, Twox64Concat, T::BlockNumber, U256, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub initial_listing_trading_pairs: Vec<(TradingPair, (Balance, Balance), (Balance, Balance), T::BlockNumber)>,
@@ -296,7 +414,27 @@ pub mod module {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let max_twap_window = T::MaxTwapWindow::get();
+			if max_twap_window.is_zero() {
+				return 0;
+			}
+
+			// accumulate the price at the start of the block, which can't be moved by swaps in this block
+			let mut count: u32 = 0;
+			for (trading_pair, (pool_0, pool_1)) in LiquidityPool::<T>::iter() {
+				count += 1;
+				Self::accumulate_price(trading_pair, pool_0, pool_1, now);
+				CumulativePriceSnapshots::<T>::remove(
+					trading_pair,
+					now.saturating_sub(max_twap_window).saturating_sub(One::one()),
+				);
+			}
+
+			T::WeightInfo::on_initialize(count)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -318,11 +456,72 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Trading with DEX, swap with exact supply amount, the swap is
+		/// rejected if it's executed after the deadline block.
+		///
+		/// - `path`: trading path.
+		/// - `supply_amount`: exact supply amount.
+		/// - `min_target_amount`: acceptable minimum target amount.
+		/// - `deadline`: the last block number at which the swap can be
+		///   executed.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_supply(path.len() as u32))]
+		#[transactional]
+		pub fn swap_with_exact_supply_and_deadline(
+			origin: OriginFor<T>,
+			path: Vec<CurrencyId>,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= deadline,
+				Error::<T>::SwapExpired
+			);
+			Self::do_swap_with_exact_supply(&who, &path, supply_amount, min_target_amount)?;
+			Ok(())
+		}
+
+		/// Trading with DEX, execute a batch of swaps with exact supply
+		/// amounts in sequence.
+		///
+		/// - `swaps`: list of (trading path, exact supply amount, acceptable
+		///   minimum target amount).
+		/// - `allow_partial`: if false, the whole batch is reverted when any
+		///   swap fails, otherwise failed swaps are skipped.
+		#[pallet::weight(swaps.iter().fold(0, |acc: Weight, (path, _, _)| {
+			acc.saturating_add(<T as Config>::WeightInfo::swap_with_exact_supply(path.len() as u32))
+		}))]
+		#[transactional]
+		pub fn batch_swap(
+			origin: OriginFor<T>,
+			swaps: Vec<(Vec<CurrencyId>, Balance, Balance)>,
+			allow_partial: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!swaps.is_empty() && swaps.len() <= T::MaxBatchSwaps::get() as usize,
+				Error::<T>::InvalidBatchSwapsLength
+			);
+
+			for (index, (path, supply_amount, min_target_amount)) in swaps.into_iter().enumerate() {
+				if let Err(e) = Self::do_swap_with_exact_supply(&who, &path, supply_amount, min_target_amount) {
+					ensure!(allow_partial, e);
+					Self::deposit_event(Event::BatchSwapItemFailed(who.clone(), index as u32, e));
+				}
+			}
+			Ok(())
+		}
+
 		/// Trading with DEX, swap with exact target amount
 		///
 		/// - `path`: trading path.
 		/// - `target_amount`: exact target amount.
 		/// - `max_supply_amount`: acceptable maximum supply amount.
+		/// - `max_slippage`: acceptable maximum deviation of the swap price from
+		///   the spot price of the path before swap, `None` means no limit. The
+		///   spot price includes the exchange fee of each hop, so this only limits
+		///   the price impact of the swap.
 		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_target(path.len() as u32))]
 		#[transactional]
 		pub fn swap_with_exact_target(
@@ -330,9 +529,10 @@ pub mod module {
 			path: Vec<CurrencyId>,
 			#[pallet::compact] target_amount: Balance,
 			#[pallet::compact] max_supply_amount: Balance,
+			max_slippage: Option<Ratio>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			Self::do_swap_with_exact_target(&who, &path, target_amount, max_supply_amount)?;
+			Self::do_swap_with_exact_target(&who, &path, target_amount, max_supply_amount, max_slippage)?;
 			Ok(())
 		}
 
@@ -377,6 +577,45 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Add liquidity to Enabled trading pair with a single currency. The
+		/// optimal portion of `amount_a` is swapped for `currency_id_b`
+		/// against the pool itself, then the remainder is added as balanced
+		/// liquidity.
+		///
+		/// - `currency_id_a`: currency id A, the supplied currency.
+		/// - `currency_id_b`: currency id B.
+		/// - `amount_a`: total amount of currency_id_a to supply.
+		/// - `min_share_increment`: minimum acceptable share amount.
+		/// - `stake_increment_share`: indicates whether to stake increased dex share to earn
+		///   incentives
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_supply(2).saturating_add(
+			if *stake_increment_share {
+				<T as Config>::WeightInfo::add_liquidity_and_stake()
+			} else {
+				<T as Config>::WeightInfo::add_liquidity()
+			}
+		))]
+		#[transactional]
+		pub fn swap_and_add_liquidity(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			#[pallet::compact] amount_a: Balance,
+			#[pallet::compact] min_share_increment: Balance,
+			stake_increment_share: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_swap_and_add_liquidity(
+				&who,
+				currency_id_a,
+				currency_id_b,
+				amount_a,
+				min_share_increment,
+				stake_increment_share,
+			)?;
+			Ok(())
+		}
+
 		/// Add provision to Provisioning trading pair.
 		/// If succecced, will record the provision, but shares issuing will happen after the
 		/// trading pair convert to Enabled status.
@@ -605,6 +844,30 @@ pub mod module {
 						.checked_add(shares_from_provision_1)
 						.ok_or(ArithmeticError::Overflow)?;
 
+					// the minimum liquidity is locked from the shares of the provisioners if the
+					// pool has never issued shares, the same as the first `add_liquidity`
+					let locked_shares = if T::Currency::total_issuance(trading_pair.dex_share_currency_id()).is_zero() {
+						T::MinimumLiquidity::get()
+					} else {
+						Zero::zero()
+					};
+					ensure!(total_shares_to_issue > locked_shares, Error::<T>::UnqualifiedProvision);
+
+					// scale down the exchange rates the provisioners claim their shares by
+					let claimable_rate = ExchangeRate::checked_from_rational(
+						total_shares_to_issue.saturating_sub(locked_shares),
+						total_shares_to_issue,
+					)
+					.ok_or(ArithmeticError::Overflow)?;
+					let (claim_exchange_rate_0, claim_exchange_rate_1) = (
+						share_exchange_rate_0
+							.checked_mul(&claimable_rate)
+							.ok_or(ArithmeticError::Overflow)?,
+						share_exchange_rate_1
+							.checked_mul(&claimable_rate)
+							.ok_or(ArithmeticError::Overflow)?,
+					);
+
 					// issue total shares to module account
 					T::Currency::deposit(
 						trading_pair.dex_share_currency_id(),
@@ -625,7 +888,7 @@ pub mod module {
 					// record initial exchange rate so that founders can use it to calculate their own shares
 					InitialShareExchangeRates::<T>::insert(
 						trading_pair,
-						(share_exchange_rate_0, share_exchange_rate_1),
+						(claim_exchange_rate_0, claim_exchange_rate_1),
 					);
 
 					Self::deposit_event(Event::ProvisioningToEnabled(
@@ -694,10 +957,132 @@ pub mod module {
 			Self::deposit_event(Event::DisableTradingPair(trading_pair));
 			Ok(())
 		}
+
+		/// Allow or disallow an account to swap with trading path longer than
+		/// `TradingPathLimit`, up to `MaxTradingPathLimit`.
+		///
+		/// - `who`: the account.
+		/// - `allowed`: whether the account can use extended trading path.
+		#[pallet::weight((<T as Config>::WeightInfo::set_extended_trading_path_account(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_extended_trading_path_account(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			allowed: bool,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			if allowed {
+				ExtendedTradingPathAccounts::<T>::insert(&who, true);
+			} else {
+				ExtendedTradingPathAccounts::<T>::remove(&who);
+			}
+			Self::deposit_event(Event::ExtendedTradingPathAccountUpdated(who, allowed));
+			Ok(())
+		}
+
+		/// Update the max deviation of the pool price after swap from the
+		/// oracle price.
+		///
+		/// - `max_deviation`: the max deviation, `None` disables the guard.
+		#[pallet::weight((<T as Config>::WeightInfo::set_max_dex_oracle_deviation(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_max_dex_oracle_deviation(origin: OriginFor<T>, max_deviation: Option<Ratio>) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			MaxDexOracleDeviation::<T>::set(max_deviation);
+			Self::deposit_event(Event::MaxDexOracleDeviationUpdated(max_deviation));
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// The time-weighted average price of the first currency of
+	/// `trading_pair` in the second one over the last `window` blocks.
+	///
+	/// Note: this returns the price for 1 basic unit
+	pub fn get_twap(trading_pair: TradingPair, window: T::BlockNumber) -> Option<Price> {
+		if window.is_zero() || window > T::MaxTwapWindow::get() {
+			return None;
+		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let end = Self::cumulative_price_snapshots(trading_pair, now)?;
+		let start = Self::cumulative_price_snapshots(trading_pair, now.checked_sub(&window)?)?;
+		let elapsed: u128 = window.saturated_into();
+
+		end.checked_sub(start)
+			.and_then(|n| n.checked_div(U256::from(elapsed)))
+			.and_then(|r| TryInto::<u128>::try_into(r).ok())
+			.map(Price::from_inner)
+	}
+
+	/// The estimated swap fees earned by the dex shares `who` added to
+	/// `trading_pair` since they were minted, in the first and the second
+	/// currency. The shares transferred out of DEX are not tracked.
+	pub fn lp_accrued_fees(who: &T::AccountId, trading_pair: TradingPair) -> (Balance, Balance) {
+		let info = Self::liquidity_provider_fees(trading_pair, who);
+		let (fees_per_share_0, fees_per_share_1) = Self::cumulative_fees_per_share(trading_pair);
+		let pending_fee_0 = fees_per_share_0
+			.saturating_sub(info.fees_per_share_checkpoint.0)
+			.saturating_mul_int(info.shares);
+		let pending_fee_1 = fees_per_share_1
+			.saturating_sub(info.fees_per_share_checkpoint.1)
+			.saturating_mul_int(info.shares);
+
+		(
+			info.accrued_fees.0.saturating_add(pending_fee_0),
+			info.accrued_fees.1.saturating_add(pending_fee_1),
+		)
+	}
+
+	/// Settle the swap fees accrued by the dex shares of `who` before
+	/// updating the amount of the shares.
+	fn update_lp_fee_shares(who: &T::AccountId, trading_pair: TradingPair, f: impl FnOnce(Balance) -> Balance) {
+		let accrued_fees = Self::lp_accrued_fees(who, trading_pair);
+		let fees_per_share = Self::cumulative_fees_per_share(trading_pair);
+		LiquidityProviderFees::<T>::mutate(trading_pair, who, |info| {
+			info.shares = f(info.shares);
+			info.fees_per_share_checkpoint = fees_per_share;
+			info.accrued_fees = accrued_fees;
+		});
+	}
+
+	fn accumulate_fee(trading_pair: TradingPair, supply_currency_id: CurrencyId, supply_amount: Balance) {
+		let total_shares = T::Currency::total_issuance(trading_pair.dex_share_currency_id());
+		let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+		let fee = U256::from(supply_amount)
+			.saturating_mul(U256::from(fee_numerator))
+			.checked_div(U256::from(fee_denominator))
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero);
+
+		if let Some(fee_per_share) = ExchangeRate::checked_from_rational(fee, total_shares) {
+			CumulativeFeesPerShare::<T>::mutate(trading_pair, |(fees_per_share_0, fees_per_share_1)| {
+				if supply_currency_id == trading_pair.first() {
+					*fees_per_share_0 = fees_per_share_0.saturating_add(fee_per_share);
+				} else {
+					*fees_per_share_1 = fees_per_share_1.saturating_add(fee_per_share);
+				}
+			});
+		}
+	}
+
+	fn accumulate_price(trading_pair: TradingPair, pool_0: Balance, pool_1: Balance, now: T::BlockNumber) {
+		// skip the empty pool, the elapsed blocks are accumulated with the next available price
+		if let Some(price) = Price::checked_from_rational(pool_1, pool_0) {
+			let cumulative_price = match Self::cumulative_prices(trading_pair) {
+				Some((cumulative_price, last_updated)) => {
+					let elapsed: u128 = now.saturating_sub(last_updated).saturated_into();
+					cumulative_price.saturating_add(U256::from(price.into_inner()).saturating_mul(U256::from(elapsed)))
+				}
+				None => U256::zero(),
+			};
+
+			CumulativePrices::<T>::insert(trading_pair, (cumulative_price, now));
+			CumulativePriceSnapshots::<T>::insert(trading_pair, now, cumulative_price);
+		}
+	}
+
 	fn account_id() -> T::AccountId {
 		T::PalletId::get().into_account()
 	}
@@ -733,6 +1118,8 @@ impl<T: Config> Pallet<T> {
 					shares_to_claim,
 				)?;
 
+				Self::update_lp_fee_shares(who, trading_pair, |shares| shares.saturating_add(shares_to_claim));
+
 				// decrease ref count
 				frame_system::Pallet::<T>::dec_consumers(who);
 			}
@@ -874,7 +1261,12 @@ impl<T: Config> Pallet<T> {
 					.checked_add(shares_from_token_1)
 					.ok_or(ArithmeticError::Overflow)?;
 
-				(max_amount_0, max_amount_1, initial_shares)
+				// the minimum liquidity is locked from the initial shares
+				(
+					max_amount_0,
+					max_amount_1,
+					initial_shares.saturating_sub(T::MinimumLiquidity::get()),
+				)
 			} else {
 				let exchange_rate_0_1 =
 					ExchangeRate::checked_from_rational(*pool_1, *pool_0).ok_or(ArithmeticError::Overflow)?;
@@ -916,7 +1308,12 @@ impl<T: Config> Pallet<T> {
 			let module_account_id = Self::account_id();
 			T::Currency::transfer(trading_pair.first(), who, &module_account_id, pool_0_increment)?;
 			T::Currency::transfer(trading_pair.second(), who, &module_account_id, pool_1_increment)?;
+			if total_shares.is_zero() {
+				// lock the minimum liquidity to the module account permanently
+				T::Currency::deposit(dex_share_currency_id, &module_account_id, T::MinimumLiquidity::get())?;
+			}
 			T::Currency::deposit(dex_share_currency_id, who, share_increment)?;
+			Self::update_lp_fee_shares(who, trading_pair, |shares| shares.saturating_add(share_increment));
 
 			*pool_0 = pool_0.checked_add(pool_0_increment).ok_or(ArithmeticError::Overflow)?;
 			*pool_1 = pool_1.checked_add(pool_1_increment).ok_or(ArithmeticError::Overflow)?;
@@ -937,6 +1334,31 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	#[transactional]
+	fn do_swap_and_add_liquidity(
+		who: &T::AccountId,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		amount_a: Balance,
+		min_share_increment: Balance,
+		stake_increment_share: bool,
+	) -> DispatchResult {
+		let (pool_a, _) = Self::get_liquidity(currency_id_a, currency_id_b);
+		let swap_amount = Self::get_optimal_swap_amount(pool_a, amount_a);
+		let target_amount =
+			Self::do_swap_with_exact_supply(who, &[currency_id_a, currency_id_b], swap_amount, Zero::zero())?;
+
+		Self::do_add_liquidity(
+			who,
+			currency_id_a,
+			currency_id_b,
+			amount_a.saturating_sub(swap_amount),
+			target_amount,
+			min_share_increment,
+			stake_increment_share,
+		)
+	}
+
 	#[transactional]
 	fn do_remove_liquidity(
 		who: &T::AccountId,
@@ -976,6 +1398,7 @@ impl<T: Config> Pallet<T> {
 				T::DEXIncentives::do_withdraw_dex_share(who, dex_share_currency_id, remove_share)?;
 			}
 			T::Currency::withdraw(dex_share_currency_id, &who, remove_share)?;
+			Self::update_lp_fee_shares(who, trading_pair, |shares| shares.saturating_sub(remove_share));
 			T::Currency::transfer(trading_pair.first(), &module_account_id, &who, pool_0_decrement)?;
 			T::Currency::transfer(trading_pair.second(), &module_account_id, &who, pool_1_decrement)?;
 
@@ -1007,6 +1430,20 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The amount of the supply currency per one target currency to swap
+	/// through `path` at the current pool prices, including the exchange fee
+	/// of each hop.
+	fn get_spot_price(path: &[CurrencyId]) -> Option<ExchangeRate> {
+		let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+		let fee_rate =
+			ExchangeRate::checked_from_rational(fee_denominator, fee_denominator.saturating_sub(fee_numerator))?;
+		path.windows(2).try_fold(ExchangeRate::one(), |price, pair| {
+			let (supply_pool, target_pool) = Self::get_liquidity(pair[0], pair[1]);
+			ExchangeRate::checked_from_rational(supply_pool, target_pool)
+				.map(|rate| price.saturating_mul(rate).saturating_mul(fee_rate))
+		})
+	}
+
 	/// Get how much target amount will be got for specific supply amount.
 	fn get_target_amount(supply_pool: Balance, target_pool: Balance, supply_amount: Balance) -> Balance {
 		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
@@ -1027,6 +1464,36 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Get how much of `amount` should be swapped against a pool with
+	/// `supply_pool` reserve of the same currency so that the remainder and
+	/// the swapped target amount match the pool ratio after the swap.
+	///
+	/// With fee rate `f = n / d`, the amount `s` solves
+	/// `s = (sqrt(r^2 * (2d - n)^2 + 4 * d * (d - n) * r * a) - r * (2d - n)) / (2 * (d - n))`.
+	fn get_optimal_swap_amount(supply_pool: Balance, amount: Balance) -> Balance {
+		if amount.is_zero() || supply_pool.is_zero() {
+			Zero::zero()
+		} else {
+			let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+			let base: U256 = U256::from(fee_denominator.saturating_mul(2).saturating_sub(fee_numerator))
+				.saturating_mul(U256::from(supply_pool));
+			let discriminant: U256 = base.saturating_mul(base).saturating_add(
+				U256::from(4u8)
+					.saturating_mul(U256::from(fee_denominator))
+					.saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)))
+					.saturating_mul(U256::from(supply_pool))
+					.saturating_mul(U256::from(amount)),
+			);
+
+			discriminant
+				.integer_sqrt()
+				.saturating_sub(base)
+				.checked_div(U256::from(fee_denominator.saturating_sub(fee_numerator)).saturating_mul(U256::from(2u8)))
+				.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+				.unwrap_or_else(Zero::zero)
+		}
+	}
+
 	/// Get how much supply amount will be paid for specific target amount.
 	fn get_supply_amount(supply_pool: Balance, target_pool: Balance, target_amount: Balance) -> Balance {
 		if target_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
@@ -1048,13 +1515,82 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The deviations of the pool prices of the trading pairs along `path`
+	/// from the oracle prices, `None` for pairs without oracle prices. Empty
+	/// if the `MaxDexOracleDeviation` guard is disabled.
+	fn oracle_price_deviations(path: &[CurrencyId]) -> sp_std::result::Result<Vec<Option<Ratio>>, DispatchError> {
+		if Self::max_dex_oracle_deviation().is_none() {
+			return Ok(vec![]);
+		}
+
+		path.windows(2)
+			.map(|pair| {
+				T::PriceSource::get_relative_price(pair[0], pair[1])
+					.map(|oracle_price| -> sp_std::result::Result<Ratio, DispatchError> {
+						let (supply_pool, target_pool) = Self::get_liquidity(pair[0], pair[1]);
+						let relative_price = Price::checked_from_rational(target_pool, supply_pool)
+							.and_then(|dex_price| dex_price.checked_div(&oracle_price))
+							.ok_or(ArithmeticError::Overflow)?;
+						Ok(if relative_price > Price::one() {
+							relative_price.saturating_sub(Price::one())
+						} else {
+							Price::one().saturating_sub(relative_price)
+						})
+					})
+					.transpose()
+			})
+			.collect()
+	}
+
+	/// Ensure the swap along `path` doesn't leave the pool price of any
+	/// trading pair deviating from the oracle price more than
+	/// `MaxDexOracleDeviation`, unless the swap reduced the deviation.
+	/// `deviations_before` are the deviations before the swap. Pairs without
+	/// oracle prices are skipped.
+	fn ensure_oracle_price_deviation(path: &[CurrencyId], deviations_before: &[Option<Ratio>]) -> DispatchResult {
+		if let Some(max_deviation) = Self::max_dex_oracle_deviation() {
+			let deviations_after = Self::oracle_price_deviations(path)?;
+			for (index, deviation) in deviations_after.into_iter().enumerate() {
+				if let Some(deviation) = deviation {
+					let deviation_before = deviations_before.get(index).copied().flatten();
+					ensure!(
+						deviation <= max_deviation || deviation_before.map_or(false, |before| deviation < before),
+						Error::<T>::ExceedMaxOracleDeviation
+					);
+				}
+			}
+		}
+		Ok(())
+	}
+
+	/// The limit for length of trading path of `who`.
+	fn trading_path_limit(who: &T::AccountId) -> u32 {
+		if Self::extended_trading_path_accounts(who) {
+			T::MaxTradingPathLimit::get()
+		} else {
+			T::TradingPathLimit::get()
+		}
+	}
+
+	/// Quote a swap with exact `supply_amount` along `path`. Returns the
+	/// expected target amount and the minimum target amount after
+	/// `DEFAULT_SWAP_SLIPPAGE_TOLERANCE`, `None` if the swap is not possible.
+	pub fn quote_swap(path: &[CurrencyId], supply_amount: Balance) -> Option<(Balance, Balance)> {
+		let target_amount = Self::get_target_amounts(path, supply_amount, T::TradingPathLimit::get())
+			.ok()
+			.and_then(|amounts| amounts.last().copied())?;
+		let min_target_amount = target_amount.saturating_sub(DEFAULT_SWAP_SLIPPAGE_TOLERANCE * target_amount);
+		Some((target_amount, min_target_amount))
+	}
+
 	fn get_target_amounts(
 		path: &[CurrencyId],
 		supply_amount: Balance,
+		path_limit: u32,
 	) -> sp_std::result::Result<Vec<Balance>, DispatchError> {
 		let path_length = path.len();
 		ensure!(
-			path_length >= 2 && path_length <= T::TradingPathLimit::get().saturated_into(),
+			path_length >= 2 && path_length <= path_limit.saturated_into(),
 			Error::<T>::InvalidTradingPathLength
 		);
 		let mut target_amounts: Vec<Balance> = vec![Zero::zero(); path_length];
@@ -1089,10 +1625,11 @@ impl<T: Config> Pallet<T> {
 	fn get_supply_amounts(
 		path: &[CurrencyId],
 		target_amount: Balance,
+		path_limit: u32,
 	) -> sp_std::result::Result<Vec<Balance>, DispatchError> {
 		let path_length = path.len();
 		ensure!(
-			path_length >= 2 && path_length <= T::TradingPathLimit::get().saturated_into(),
+			path_length >= 2 && path_length <= path_limit.saturated_into(),
 			Error::<T>::InvalidTradingPathLength
 		);
 		let mut supply_amounts: Vec<Balance> = vec![Zero::zero(); path_length];
@@ -1150,6 +1687,8 @@ impl<T: Config> Pallet<T> {
 				);
 				Ok(())
 			})?;
+
+			Self::accumulate_fee(trading_pair, supply_currency_id, supply_increment);
 		}
 		Ok(())
 	}
@@ -1178,16 +1717,18 @@ impl<T: Config> Pallet<T> {
 		supply_amount: Balance,
 		min_target_amount: Balance,
 	) -> sp_std::result::Result<Balance, DispatchError> {
-		let amounts = Self::get_target_amounts(&path, supply_amount)?;
+		let amounts = Self::get_target_amounts(&path, supply_amount, Self::trading_path_limit(who))?;
 		ensure!(
 			amounts[amounts.len() - 1] >= min_target_amount,
 			Error::<T>::InsufficientTargetAmount
 		);
 		let module_account_id = Self::account_id();
 		let actual_target_amount = amounts[amounts.len() - 1];
+		let deviations_before = Self::oracle_price_deviations(&path)?;
 
 		T::Currency::transfer(path[0], who, &module_account_id, supply_amount)?;
 		Self::_swap_by_path(&path, &amounts)?;
+		Self::ensure_oracle_price_deviation(&path, &deviations_before)?;
 		T::Currency::transfer(path[path.len() - 1], &module_account_id, who, actual_target_amount)?;
 
 		Self::deposit_event(Event::Swap(
@@ -1206,14 +1747,26 @@ impl<T: Config> Pallet<T> {
 		path: &[CurrencyId],
 		target_amount: Balance,
 		max_supply_amount: Balance,
+		max_slippage: Option<Ratio>,
 	) -> sp_std::result::Result<Balance, DispatchError> {
-		let amounts = Self::get_supply_amounts(&path, target_amount)?;
+		let amounts = Self::get_supply_amounts(&path, target_amount, Self::trading_path_limit(who))?;
 		ensure!(amounts[0] <= max_supply_amount, Error::<T>::ExcessiveSupplyAmount);
+		if let Some(max_slippage) = max_slippage {
+			let spot_price = Self::get_spot_price(&path).ok_or(Error::<T>::InsufficientLiquidity)?;
+			let swap_price =
+				ExchangeRate::checked_from_rational(amounts[0], target_amount).ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				swap_price <= spot_price.saturating_mul(Ratio::one().saturating_add(max_slippage)),
+				Error::<T>::ExceedMaxSlippage
+			);
+		}
 		let module_account_id = Self::account_id();
 		let actual_supply_amount = amounts[0];
+		let deviations_before = Self::oracle_price_deviations(&path)?;
 
 		T::Currency::transfer(path[0], who, &module_account_id, actual_supply_amount)?;
 		Self::_swap_by_path(&path, &amounts)?;
+		Self::ensure_oracle_price_deviation(&path, &deviations_before)?;
 		T::Currency::transfer(path[path.len() - 1], &module_account_id, who, target_amount)?;
 
 		Self::deposit_event(Event::Swap(
@@ -1237,17 +1790,31 @@ impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 	}
 
 	fn get_swap_target_amount(path: &[CurrencyId], supply_amount: Balance) -> Option<Balance> {
-		Self::get_target_amounts(&path, supply_amount)
+		Self::get_target_amounts(&path, supply_amount, T::TradingPathLimit::get())
 			.ok()
 			.map(|amounts| amounts[amounts.len() - 1])
 	}
 
 	fn get_swap_supply_amount(path: &[CurrencyId], target_amount: Balance) -> Option<Balance> {
-		Self::get_supply_amounts(&path, target_amount)
+		Self::get_supply_amounts(&path, target_amount, T::TradingPathLimit::get())
 			.ok()
 			.map(|amounts| amounts[0])
 	}
 
+	fn get_time_weighted_average_price(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: u32,
+	) -> Option<Price> {
+		let trading_pair = TradingPair::from_currency_ids(currency_id_a, currency_id_b)?;
+		let twap = Self::get_twap(trading_pair, window.into())?;
+		if currency_id_a == trading_pair.first() {
+			Some(twap)
+		} else {
+			twap.reciprocal()
+		}
+	}
+
 	fn swap_with_exact_supply(
 		who: &T::AccountId,
 		path: &[CurrencyId],
@@ -1263,7 +1830,7 @@ impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 		target_amount: Balance,
 		max_supply_amount: Balance,
 	) -> sp_std::result::Result<Balance, DispatchError> {
-		Self::do_swap_with_exact_target(who, path, target_amount, max_supply_amount)
+		Self::do_swap_with_exact_target(who, path, target_amount, max_supply_amount, None)
 	}
 
 	// `do_add_liquidity` is used in genesis_build,
@@ -1291,6 +1858,24 @@ impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 		)
 	}
 
+	fn swap_and_add_liquidity(
+		who: &T::AccountId,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		amount_a: Balance,
+		min_share_increment: Balance,
+		stake_increment_share: bool,
+	) -> DispatchResult {
+		Self::do_swap_and_add_liquidity(
+			who,
+			currency_id_a,
+			currency_id_b,
+			amount_a,
+			min_share_increment,
+			stake_increment_share,
+		)
+	}
+
 	fn remove_liquidity(
 		who: &T::AccountId,
 		currency_id_a: CurrencyId,
@@ -27,6 +27,7 @@ use orml_traits::{parameter_type_with_key, MultiReservableCurrency};
 use primitives::{Amount, TokenSymbol};
 use sp_core::H256;
 use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_std::cell::RefCell;
 
 pub type BlockNumber = u64;
 pub type AccountId = u128;
@@ -107,6 +108,26 @@ impl DEXIncentives<AccountId, CurrencyId, Balance> for MockDEXIncentives {
 	}
 }
 
+thread_local! {
+	static DOT_PRICE: RefCell<Option<Price>> = RefCell::new(None);
+}
+
+pub struct MockPriceSource;
+impl MockPriceSource {
+	pub fn set_dot_price(price: Option<Price>) {
+		DOT_PRICE.with(|v| *v.borrow_mut() = price);
+	}
+}
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			AUSD => Some(Price::one()),
+			DOT => DOT_PRICE.with(|v| *v.borrow()),
+			_ => None,
+		}
+	}
+}
+
 ord_parameter_types! {
 	pub const ListingOrigin: AccountId = 3;
 }
@@ -114,6 +135,10 @@ ord_parameter_types! {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 100);
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxTradingPathLimit: u32 = 4;
+	pub const MaxTwapWindow: BlockNumber = 10;
+	pub const MaxBatchSwaps: u32 = 3;
+	pub const MinimumLiquidity: Balance = 1_000;
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 }
 
@@ -122,8 +147,13 @@ impl Config for Runtime {
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPathLimit = MaxTradingPathLimit;
+	type MaxBatchSwaps = MaxBatchSwaps;
+	type MinimumLiquidity = MinimumLiquidity;
 	type PalletId = DEXPalletId;
+	type MaxTwapWindow = MaxTwapWindow;
 	type CurrencyIdMapping = ();
+	type PriceSource = MockPriceSource;
 	type WeightInfo = ();
 	type DEXIncentives = MockDEXIncentives;
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
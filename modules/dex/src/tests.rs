@@ -23,8 +23,8 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	AUSDBTCPair, AUSDDOTPair, DexModule, Event, ExtBuilder, ListingOrigin, Origin, Runtime, System, Tokens, ACA, ALICE,
-	AUSD, BOB, BTC, DOT,
+	AUSDBTCPair, AUSDDOTPair, DexModule, Event, ExtBuilder, ListingOrigin, MaxTradingPathLimit, MockPriceSource,
+	Origin, Runtime, System, Tokens, TradingPathLimit, ACA, ALICE, AUSD, BOB, BTC, DOT,
 };
 use orml_traits::MultiReservableCurrency;
 use sp_runtime::traits::BadOrigin;
@@ -356,7 +356,10 @@ fn end_provisioning_trading_work() {
 		);
 		assert_eq!(
 			DexModule::initial_share_exchange_rates(AUSDBTCPair::get()),
-			(ExchangeRate::one(), ExchangeRate::checked_from_rational(1, 2).unwrap())
+			(
+				ExchangeRate::checked_from_rational(1_999_999_999_000u128, 2_000_000_000_000u128).unwrap(),
+				ExchangeRate::checked_from_rational(1_999_999_999_000u128, 4_000_000_000_000u128).unwrap()
+			)
 		);
 		assert_eq!(
 			DexModule::liquidity_pool(AUSDBTCPair::get()),
@@ -569,7 +572,10 @@ fn claim_dex_share_work() {
 		);
 		assert_eq!(
 			DexModule::initial_share_exchange_rates(AUSDDOTPair::get()),
-			(ExchangeRate::one(), ExchangeRate::saturating_from_rational(5, 1))
+			(
+				ExchangeRate::saturating_from_rational(9_999_999_999_999_000u128, 10_000_000_000_000_000u128),
+				ExchangeRate::saturating_from_rational(9_999_999_999_999_000u128, 2_000_000_000_000_000u128)
+			)
 		);
 		assert_eq!(
 			Tokens::free_balance(lp_currency_id, &DexModule::account_id()),
@@ -592,10 +598,11 @@ fn claim_dex_share_work() {
 		assert_ok!(DexModule::claim_dex_share(Origin::signed(ALICE), ALICE, AUSD, DOT));
 		assert_eq!(
 			Tokens::free_balance(lp_currency_id, &DexModule::account_id()),
-			8_000_000_000_000_000u128
+			8_000_000_000_000_200u128
 		);
 		assert_eq!(DexModule::provisioning_pool(AUSDDOTPair::get(), ALICE), (0, 0));
-		assert_eq!(Tokens::free_balance(lp_currency_id, &ALICE), 2_000_000_000_000_000u128);
+		// the minimum liquidity is locked from the shares of the provisioners
+		assert_eq!(Tokens::free_balance(lp_currency_id, &ALICE), 1_999_999_999_999_800u128);
 		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
 		assert_eq!(
 			InitialShareExchangeRates::<Runtime>::contains_key(AUSDDOTPair::get()),
@@ -608,9 +615,9 @@ fn claim_dex_share_work() {
 			DOT
 		));
 		assert_ok!(DexModule::claim_dex_share(Origin::signed(BOB), BOB, AUSD, DOT));
-		assert_eq!(Tokens::free_balance(lp_currency_id, &DexModule::account_id()), 0);
+		assert_eq!(Tokens::free_balance(lp_currency_id, &DexModule::account_id()), 1_000);
 		assert_eq!(DexModule::provisioning_pool(AUSDDOTPair::get(), BOB), (0, 0));
-		assert_eq!(Tokens::free_balance(lp_currency_id, &BOB), 8_000_000_000_000_000u128);
+		assert_eq!(Tokens::free_balance(lp_currency_id, &BOB), 7_999_999_999_999_200u128);
 		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
 		assert_eq!(
 			InitialShareExchangeRates::<Runtime>::contains_key(AUSDDOTPair::get()),
@@ -664,36 +671,62 @@ fn get_target_amounts_work() {
 			LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (50000, 10000));
 			LiquidityPool::<Runtime>::insert(AUSDBTCPair::get(), (100000, 10));
 			assert_noop!(
-				DexModule::get_target_amounts(&vec![DOT], 10000),
+				DexModule::get_target_amounts(&vec![DOT], 10000, TradingPathLimit::get()),
 				Error::<Runtime>::InvalidTradingPathLength,
 			);
 			assert_noop!(
-				DexModule::get_target_amounts(&vec![DOT, AUSD, BTC, DOT], 10000),
+				DexModule::get_target_amounts(&vec![DOT, AUSD, BTC, DOT], 10000, TradingPathLimit::get()),
 				Error::<Runtime>::InvalidTradingPathLength,
 			);
 			assert_noop!(
-				DexModule::get_target_amounts(&vec![DOT, AUSD, ACA], 10000),
+				DexModule::get_target_amounts(&vec![DOT, AUSD, ACA], 10000, TradingPathLimit::get()),
 				Error::<Runtime>::MustBeEnabled,
 			);
 			assert_eq!(
-				DexModule::get_target_amounts(&vec![DOT, AUSD], 10000),
+				DexModule::get_target_amounts(&vec![DOT, AUSD], 10000, TradingPathLimit::get()),
 				Ok(vec![10000, 24874])
 			);
 			assert_eq!(
-				DexModule::get_target_amounts(&vec![DOT, AUSD, BTC], 10000),
+				DexModule::get_target_amounts(&vec![DOT, AUSD, BTC], 10000, TradingPathLimit::get()),
 				Ok(vec![10000, 24874, 1])
 			);
 			assert_noop!(
-				DexModule::get_target_amounts(&vec![DOT, AUSD, BTC], 100),
+				DexModule::get_target_amounts(&vec![DOT, AUSD, BTC], 100, TradingPathLimit::get()),
 				Error::<Runtime>::ZeroTargetAmount,
 			);
 			assert_noop!(
-				DexModule::get_target_amounts(&vec![DOT, BTC], 100),
+				DexModule::get_target_amounts(&vec![DOT, BTC], 100, TradingPathLimit::get()),
 				Error::<Runtime>::InsufficientLiquidity,
 			);
 		});
 }
 
+#[test]
+fn quote_swap_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (50000, 10000));
+			LiquidityPool::<Runtime>::insert(AUSDBTCPair::get(), (100000, 10));
+			assert_eq!(DexModule::quote_swap(&vec![DOT], 10000), None);
+			assert_eq!(DexModule::quote_swap(&vec![DOT, AUSD, ACA], 10000), None);
+			assert_eq!(DexModule::quote_swap(&vec![DOT, BTC], 100), None);
+
+			// the expected target matches `get_target_amounts` with exchange fee
+			assert_eq!(
+				DexModule::get_target_amounts(&vec![DOT, AUSD], 10000, TradingPathLimit::get()),
+				Ok(vec![10000, 24874])
+			);
+			assert_eq!(DexModule::quote_swap(&vec![DOT, AUSD], 10000), Some((24874, 24750)));
+			assert_eq!(
+				DexModule::get_target_amounts(&vec![DOT, AUSD, BTC], 10000, TradingPathLimit::get()),
+				Ok(vec![10000, 24874, 1])
+			);
+			assert_eq!(DexModule::quote_swap(&vec![DOT, AUSD, BTC], 10000), Some((1, 1)));
+		});
+}
+
 #[test]
 fn calculate_amount_for_big_number_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -729,31 +762,31 @@ fn get_supply_amounts_work() {
 			LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (50000, 10000));
 			LiquidityPool::<Runtime>::insert(AUSDBTCPair::get(), (100000, 10));
 			assert_noop!(
-				DexModule::get_supply_amounts(&vec![DOT], 10000),
+				DexModule::get_supply_amounts(&vec![DOT], 10000, TradingPathLimit::get()),
 				Error::<Runtime>::InvalidTradingPathLength,
 			);
 			assert_noop!(
-				DexModule::get_supply_amounts(&vec![DOT, AUSD, BTC, DOT], 10000),
+				DexModule::get_supply_amounts(&vec![DOT, AUSD, BTC, DOT], 10000, TradingPathLimit::get()),
 				Error::<Runtime>::InvalidTradingPathLength,
 			);
 			assert_noop!(
-				DexModule::get_supply_amounts(&vec![DOT, AUSD, ACA], 10000),
+				DexModule::get_supply_amounts(&vec![DOT, AUSD, ACA], 10000, TradingPathLimit::get()),
 				Error::<Runtime>::MustBeEnabled,
 			);
 			assert_eq!(
-				DexModule::get_supply_amounts(&vec![DOT, AUSD], 24874),
+				DexModule::get_supply_amounts(&vec![DOT, AUSD], 24874, TradingPathLimit::get()),
 				Ok(vec![10000, 24874])
 			);
 			assert_eq!(
-				DexModule::get_supply_amounts(&vec![DOT, AUSD], 25000),
+				DexModule::get_supply_amounts(&vec![DOT, AUSD], 25000, TradingPathLimit::get()),
 				Ok(vec![10102, 25000])
 			);
 			assert_noop!(
-				DexModule::get_supply_amounts(&vec![DOT, AUSD, BTC], 10000),
+				DexModule::get_supply_amounts(&vec![DOT, AUSD, BTC], 10000, TradingPathLimit::get()),
 				Error::<Runtime>::ZeroSupplyAmount,
 			);
 			assert_noop!(
-				DexModule::get_supply_amounts(&vec![DOT, BTC], 10000),
+				DexModule::get_supply_amounts(&vec![DOT, BTC], 10000, TradingPathLimit::get()),
 				Error::<Runtime>::InsufficientLiquidity,
 			);
 		});
@@ -844,7 +877,7 @@ fn add_liquidity_work() {
 				5_000_000_000_000,
 				DOT,
 				1_000_000_000_000,
-				10_000_000_000_000,
+				9_999_999_999_000,
 			)));
 			assert_eq!(
 				DexModule::get_liquidity(AUSD, DOT),
@@ -854,6 +887,14 @@ fn add_liquidity_work() {
 			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 1_000_000_000_000);
 			assert_eq!(
 				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
+				9_999_999_999_000
+			);
+			assert_eq!(
+				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &DexModule::account_id()),
+				1_000
+			);
+			assert_eq!(
+				Tokens::total_issuance(AUSDDOTPair::get().dex_share_currency_id()),
 				10_000_000_000_000
 			);
 			assert_eq!(
@@ -924,9 +965,156 @@ fn add_liquidity_work() {
 			);
 			assert_eq!(Tokens::free_balance(AUSD, &BOB), 999_960_000_000_000_000);
 			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_992_000_000_000_000);
+
+			// the first provider can't withdraw the locked minimum liquidity
+			assert_ok!(DexModule::remove_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				9_999_999_999_000,
+				0,
+				0,
+				false,
+			));
+			assert_noop!(
+				DexModule::remove_liquidity(Origin::signed(ALICE), AUSD, DOT, 1_000, 0, 0, false),
+				orml_tokens::Error::<Runtime>::BalanceTooLow
+			);
+			assert_eq!(
+				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &DexModule::account_id()),
+				1_000
+			);
+			assert_eq!(
+				Tokens::total_issuance(AUSDDOTPair::get().dex_share_currency_id()),
+				80_000_000_001_000
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_lock_minimum_liquidity_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			// initial shares must be more than the minimum liquidity
+			assert_noop!(
+				DexModule::add_liquidity(Origin::signed(ALICE), AUSD, DOT, 500, 100, 0, false),
+				Error::<Runtime>::InvalidLiquidityIncrement
+			);
+			assert_noop!(
+				DexModule::add_liquidity(Origin::signed(ALICE), AUSD, DOT, 5_000, 1_000, 9_001, false),
+				Error::<Runtime>::UnacceptableShareIncrement
+			);
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				5_000,
+				1_000,
+				9_000,
+				false
+			));
+			assert_eq!(
+				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
+				9_000
+			);
+			assert_eq!(
+				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &DexModule::account_id()),
+				1_000
+			);
 		});
 }
 
+#[test]
+fn swap_and_add_liquidity_work() {
+	let initialize_pool = || {
+		System::set_block_number(1);
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			500_000_000_000_000,
+			100_000_000_000_000,
+			0,
+			false,
+		));
+	};
+	let snapshot = || {
+		(
+			Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &BOB),
+			DexModule::get_liquidity(AUSD, DOT),
+			Tokens::free_balance(AUSD, &BOB),
+			Tokens::free_balance(DOT, &BOB),
+		)
+	};
+
+	let swap_and_add = ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			initialize_pool();
+
+			assert_noop!(
+				DexModule::swap_and_add_liquidity(Origin::signed(BOB), AUSD, ACA, 10_000_000_000_000, 0, false),
+				Error::<Runtime>::MustBeEnabled
+			);
+			assert_noop!(
+				DexModule::swap_and_add_liquidity(
+					Origin::signed(BOB),
+					AUSD,
+					DOT,
+					10_000_000_000_000,
+					10_000_000_000_000,
+					false
+				),
+				Error::<Runtime>::UnacceptableShareIncrement
+			);
+
+			assert_ok!(DexModule::swap_and_add_liquidity(
+				Origin::signed(BOB),
+				AUSD,
+				DOT,
+				10_000_000_000_000,
+				0,
+				false
+			));
+			snapshot()
+		});
+
+	let swap_then_add = ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			initialize_pool();
+
+			let swap_amount = DexModule::get_optimal_swap_amount(500_000_000_000_000, 10_000_000_000_000);
+			assert_eq!(swap_amount, 5_000_248_780_912);
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![AUSD, DOT],
+				swap_amount,
+				0
+			));
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_980_343_376_291);
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(BOB),
+				AUSD,
+				DOT,
+				10_000_000_000_000 - swap_amount,
+				980_343_376_291,
+				0,
+				false
+			));
+			snapshot()
+		});
+
+	assert!(!swap_and_add.0.is_zero());
+	assert_eq!(swap_and_add, swap_then_add);
+}
+
 #[test]
 fn remove_liquidity_work() {
 	ExtBuilder::default()
@@ -965,7 +1153,7 @@ fn remove_liquidity_work() {
 			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 1_000_000_000_000);
 			assert_eq!(
 				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
-				10_000_000_000_000
+				9_999_999_999_000
 			);
 			assert_eq!(Tokens::free_balance(AUSD, &ALICE), 999_995_000_000_000_000);
 			assert_eq!(Tokens::free_balance(DOT, &ALICE), 999_999_000_000_000_000);
@@ -1019,7 +1207,7 @@ fn remove_liquidity_work() {
 			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 200_000_000_000);
 			assert_eq!(
 				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
-				2_000_000_000_000
+				1_999_999_999_000
 			);
 			assert_eq!(Tokens::free_balance(AUSD, &ALICE), 999_999_000_000_000_000);
 			assert_eq!(Tokens::free_balance(DOT, &ALICE), 999_999_800_000_000_000);
@@ -1028,7 +1216,7 @@ fn remove_liquidity_work() {
 				Origin::signed(ALICE),
 				AUSD,
 				DOT,
-				2_000_000_000_000,
+				1_999_999_999_000,
 				0,
 				0,
 				false,
@@ -1036,20 +1224,21 @@ fn remove_liquidity_work() {
 			System::assert_last_event(Event::DexModule(crate::Event::RemoveLiquidity(
 				ALICE,
 				AUSD,
-				1_000_000_000_000,
+				999_999_999_500,
 				DOT,
-				200_000_000_000,
-				2_000_000_000_000,
+				199_999_999_900,
+				1_999_999_999_000,
 			)));
-			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (0, 0));
-			assert_eq!(Tokens::free_balance(AUSD, &DexModule::account_id()), 0);
-			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 0);
+			// the locked minimum liquidity remains in the pool
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (500, 100));
+			assert_eq!(Tokens::free_balance(AUSD, &DexModule::account_id()), 500);
+			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 100);
 			assert_eq!(
 				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
 				0
 			);
-			assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1_000_000_000_000_000_000);
-			assert_eq!(Tokens::free_balance(DOT, &ALICE), 1_000_000_000_000_000_000);
+			assert_eq!(Tokens::free_balance(AUSD, &ALICE), 999_999_999_999_999_500);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 999_999_999_999_999_900);
 
 			assert_ok!(DexModule::add_liquidity(
 				Origin::signed(BOB),
@@ -1209,7 +1398,7 @@ fn do_swap_with_exact_supply_work() {
 }
 
 #[test]
-fn do_swap_with_exact_target_work() {
+fn batch_swap_work() {
 	ExtBuilder::default()
 		.initialize_enabled_trading_pairs()
 		.build()
@@ -1235,119 +1424,759 @@ fn do_swap_with_exact_target_work() {
 				false,
 			));
 
-			assert_eq!(
-				DexModule::get_liquidity(AUSD, DOT),
-				(500_000_000_000_000, 100_000_000_000_000)
-			);
-			assert_eq!(
-				DexModule::get_liquidity(AUSD, BTC),
-				(100_000_000_000_000, 10_000_000_000)
-			);
-			assert_eq!(
-				Tokens::free_balance(AUSD, &DexModule::account_id()),
-				600_000_000_000_000
-			);
-			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 100_000_000_000_000);
-			assert_eq!(Tokens::free_balance(BTC, &DexModule::account_id()), 10_000_000_000);
-			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_000_000_000_000_000);
-			assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_000_000);
-			assert_eq!(Tokens::free_balance(BTC, &BOB), 1_000_000_000_000_000_000);
-
 			assert_noop!(
-				DexModule::do_swap_with_exact_target(&BOB, &[DOT, AUSD], 250_000_000_000_000, 100_000_000_000_000,),
-				Error::<Runtime>::ExcessiveSupplyAmount
+				DexModule::batch_swap(Origin::signed(BOB), vec![], false),
+				Error::<Runtime>::InvalidBatchSwapsLength
 			);
 			assert_noop!(
-				DexModule::do_swap_with_exact_target(
-					&BOB,
-					&[DOT, AUSD, BTC, DOT],
-					250_000_000_000_000,
-					200_000_000_000_000,
+				DexModule::batch_swap(
+					Origin::signed(BOB),
+					vec![(vec![DOT, AUSD], 1_000_000_000_000, 0); 4],
+					false
 				),
-				Error::<Runtime>::InvalidTradingPathLength,
+				Error::<Runtime>::InvalidBatchSwapsLength
 			);
+
+			// the second swap misses its bound, the whole batch is reverted
 			assert_noop!(
-				DexModule::do_swap_with_exact_target(&BOB, &[DOT, ACA], 250_000_000_000_000, 200_000_000_000_000),
-				Error::<Runtime>::MustBeEnabled,
+				DexModule::batch_swap(
+					Origin::signed(BOB),
+					vec![
+						(vec![DOT, AUSD], 100_000_000_000_000, 200_000_000_000_000),
+						(vec![DOT, AUSD, BTC], 200_000_000_000_000, 10_000_000_000),
+					],
+					false
+				),
+				Error::<Runtime>::InsufficientTargetAmount
 			);
 
-			assert_ok!(DexModule::do_swap_with_exact_target(
-				&BOB,
-				&[DOT, AUSD],
-				250_000_000_000_000,
-				200_000_000_000_000,
+			assert_ok!(DexModule::batch_swap(
+				Origin::signed(BOB),
+				vec![
+					(vec![DOT, AUSD], 100_000_000_000_000, 200_000_000_000_000),
+					(vec![DOT, AUSD, BTC], 200_000_000_000_000, 1),
+				],
+				false
 			));
-			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+			System::assert_has_event(Event::DexModule(crate::Event::Swap(
 				BOB,
 				vec![DOT, AUSD],
-				101_010_101_010_102,
-				250_000_000_000_000,
+				100_000_000_000_000,
+				248_743_718_592_964,
+			)));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![DOT, AUSD, BTC],
+				200_000_000_000_000,
+				5_530_663_837,
 			)));
 			assert_eq!(
 				DexModule::get_liquidity(AUSD, DOT),
-				(250_000_000_000_000, 201_010_101_010_102)
+				(126_259_437_892_983, 400_000_000_000_000)
 			);
 			assert_eq!(
 				DexModule::get_liquidity(AUSD, BTC),
-				(100_000_000_000_000, 10_000_000_000)
+				(224_996_843_514_053, 4_469_336_163)
 			);
-			assert_eq!(
-				Tokens::free_balance(AUSD, &DexModule::account_id()),
-				350_000_000_000_000
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_248_743_718_592_964);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_700_000_000_000_000);
+			assert_eq!(Tokens::free_balance(BTC, &BOB), 1_000_000_005_530_663_837);
+		});
+}
+
+#[test]
+fn swap_with_exact_supply_and_deadline_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(10);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+
+			assert_noop!(
+				DexModule::swap_with_exact_supply_and_deadline(
+					Origin::signed(BOB),
+					vec![DOT, AUSD],
+					100_000_000_000_000,
+					0,
+					9
+				),
+				Error::<Runtime>::SwapExpired
 			);
-			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 201_010_101_010_102);
-			assert_eq!(Tokens::free_balance(BTC, &DexModule::account_id()), 10_000_000_000);
-			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_250_000_000_000_000);
-			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_898_989_898_989_898);
-			assert_eq!(Tokens::free_balance(BTC, &BOB), 1_000_000_000_000_000_000);
 
-			assert_ok!(DexModule::do_swap_with_exact_target(
-				&BOB,
-				&[DOT, AUSD, BTC],
-				5_000_000_000,
-				2_000_000_000_000_000,
+			assert_ok!(DexModule::swap_with_exact_supply_and_deadline(
+				Origin::signed(BOB),
+				vec![DOT, AUSD],
+				100_000_000_000_000,
+				200_000_000_000_000,
+				10
 			));
 			System::assert_last_event(Event::DexModule(crate::Event::Swap(
 				BOB,
-				vec![DOT, AUSD, BTC],
-				137_654_580_386_993,
-				5_000_000_000,
+				vec![DOT, AUSD],
+				100_000_000_000_000,
+				248_743_718_592_964,
 			)));
-			assert_eq!(
-				DexModule::get_liquidity(AUSD, DOT),
-				(148_989_898_989_898, 338_664_681_397_095)
-			);
-			assert_eq!(
-				DexModule::get_liquidity(AUSD, BTC),
-				(201_010_101_010_102, 5_000_000_000)
-			);
-			assert_eq!(
-				Tokens::free_balance(AUSD, &DexModule::account_id()),
-				350_000_000_000_000
-			);
-			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 338_664_681_397_095);
-			assert_eq!(Tokens::free_balance(BTC, &DexModule::account_id()), 5_000_000_000);
-			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_250_000_000_000_000);
-			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_761_335_318_602_905);
-			assert_eq!(Tokens::free_balance(BTC, &BOB), 1_000_000_005_000_000_000);
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_248_743_718_592_964);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_900_000_000_000_000);
 		});
 }
 
 #[test]
-fn initialize_added_liquidity_pools_genesis_work() {
+fn batch_swap_allow_partial_work() {
 	ExtBuilder::default()
 		.initialize_enabled_trading_pairs()
-		.initialize_added_liquidity_pools(ALICE)
 		.build()
 		.execute_with(|| {
 			System::set_block_number(1);
 
-			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1000000, 2000000));
-			assert_eq!(Tokens::free_balance(AUSD, &DexModule::account_id()), 2000000);
-			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 3000000);
-			assert_eq!(
-				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
-				2000000
-			);
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+
+			assert_ok!(DexModule::batch_swap(
+				Origin::signed(BOB),
+				vec![
+					(vec![DOT, AUSD], 100_000_000_000_000, 250_000_000_000_000),
+					(vec![DOT, AUSD], 100_000_000_000_000, 200_000_000_000_000),
+				],
+				true
+			));
+			System::assert_has_event(Event::DexModule(crate::Event::BatchSwapItemFailed(
+				BOB,
+				0,
+				Error::<Runtime>::InsufficientTargetAmount.into(),
+			)));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![DOT, AUSD],
+				100_000_000_000_000,
+				248_743_718_592_964,
+			)));
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(251_256_281_407_036, 200_000_000_000_000)
+			);
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_248_743_718_592_964);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_900_000_000_000_000);
+		});
+}
+
+#[test]
+fn swap_with_extended_trading_path_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				100_000_000_000_000,
+				10_000_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				100_000_000_000_000,
+				10_000_000_000,
+				0,
+				false,
+			));
+
+			let path = vec![DOT, AUSD, BTC, DOT];
+			assert_noop!(
+				DexModule::swap_with_exact_supply(Origin::signed(BOB), path.clone(), 1_000_000_000_000, 0),
+				Error::<Runtime>::InvalidTradingPathLength
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_target(
+					Origin::signed(BOB),
+					path.clone(),
+					1_000_000_000,
+					1_000_000_000_000,
+					None
+				),
+				Error::<Runtime>::InvalidTradingPathLength
+			);
+
+			assert_noop!(
+				DexModule::set_extended_trading_path_account(Origin::signed(BOB), BOB, true),
+				BadOrigin
+			);
+			assert_ok!(DexModule::set_extended_trading_path_account(
+				Origin::signed(ListingOrigin::get()),
+				BOB,
+				true
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::ExtendedTradingPathAccountUpdated(
+				BOB, true,
+			)));
+			assert!(DexModule::extended_trading_path_accounts(BOB));
+
+			let target_amount =
+				DexModule::get_target_amounts(&path, 1_000_000_000_000, MaxTradingPathLimit::get()).unwrap()[3];
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				path.clone(),
+				1_000_000_000_000,
+				0
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				path.clone(),
+				1_000_000_000_000,
+				target_amount,
+			)));
+
+			// still bounded by MaxTradingPathLimit
+			assert_noop!(
+				DexModule::swap_with_exact_supply(
+					Origin::signed(BOB),
+					vec![DOT, AUSD, BTC, DOT, AUSD],
+					1_000_000_000_000,
+					0
+				),
+				Error::<Runtime>::InvalidTradingPathLength
+			);
+
+			// quote for other callers is still bounded by TradingPathLimit
+			assert_eq!(DexModule::quote_swap(&path, 1_000_000_000_000), None);
+
+			assert_ok!(DexModule::set_extended_trading_path_account(
+				Origin::signed(ListingOrigin::get()),
+				BOB,
+				false
+			));
+			assert!(!DexModule::extended_trading_path_accounts(BOB));
+			assert_noop!(
+				DexModule::swap_with_exact_supply(Origin::signed(BOB), path, 1_000_000_000_000, 0),
+				Error::<Runtime>::InvalidTradingPathLength
+			);
+		});
+}
+
+#[test]
+fn swap_with_oracle_price_deviation_guard_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+			MockPriceSource::set_dot_price(Some(Price::saturating_from_integer(5)));
+
+			assert_noop!(
+				DexModule::set_max_dex_oracle_deviation(
+					Origin::signed(BOB),
+					Some(Ratio::saturating_from_rational(10, 100))
+				),
+				BadOrigin
+			);
+			assert_ok!(DexModule::set_max_dex_oracle_deviation(
+				Origin::signed(ListingOrigin::get()),
+				Some(Ratio::saturating_from_rational(10, 100))
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::MaxDexOracleDeviationUpdated(Some(
+				Ratio::saturating_from_rational(10, 100),
+			))));
+
+			// manipulative swaps move the pool price too far from the oracle price
+			assert_noop!(
+				DexModule::swap_with_exact_supply(Origin::signed(BOB), vec![DOT, AUSD], 20_000_000_000_000, 0),
+				Error::<Runtime>::ExceedMaxOracleDeviation
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_target(
+					Origin::signed(BOB),
+					vec![AUSD, DOT],
+					20_000_000_000_000,
+					200_000_000_000_000,
+					None
+				),
+				Error::<Runtime>::ExceedMaxOracleDeviation
+			);
+
+			// normal swap passes
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![DOT, AUSD],
+				1_000_000_000_000,
+				0
+			));
+
+			// pairs without oracle price are not guarded
+			MockPriceSource::set_dot_price(None);
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![DOT, AUSD],
+				20_000_000_000_000,
+				0
+			));
+
+			// the pool price now deviates more than the max, swaps increasing the deviation
+			// are rejected while swaps reducing it pass
+			MockPriceSource::set_dot_price(Some(Price::saturating_from_integer(5)));
+			assert_noop!(
+				DexModule::swap_with_exact_supply(Origin::signed(BOB), vec![DOT, AUSD], 1_000_000_000_000, 0),
+				Error::<Runtime>::ExceedMaxOracleDeviation
+			);
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![AUSD, DOT],
+				10_000_000_000_000,
+				0
+			));
+			assert_ok!(DexModule::swap_with_exact_target(
+				Origin::signed(BOB),
+				vec![AUSD, DOT],
+				1_000_000_000_000,
+				100_000_000_000_000,
+				None
+			));
+
+			// the guard is disabled when the deviation is none
+			assert_ok!(DexModule::set_max_dex_oracle_deviation(
+				Origin::signed(ListingOrigin::get()),
+				None
+			));
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![DOT, AUSD],
+				20_000_000_000_000,
+				0
+			));
+		});
+}
+
+#[test]
+fn do_swap_with_exact_target_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				100_000_000_000_000,
+				10_000_000_000,
+				0,
+				false,
+			));
+
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(500_000_000_000_000, 100_000_000_000_000)
+			);
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, BTC),
+				(100_000_000_000_000, 10_000_000_000)
+			);
+			assert_eq!(
+				Tokens::free_balance(AUSD, &DexModule::account_id()),
+				600_000_000_000_000
+			);
+			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 100_000_000_000_000);
+			assert_eq!(Tokens::free_balance(BTC, &DexModule::account_id()), 10_000_000_000);
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_000_000_000_000_000);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_000_000);
+			assert_eq!(Tokens::free_balance(BTC, &BOB), 1_000_000_000_000_000_000);
+
+			assert_noop!(
+				DexModule::do_swap_with_exact_target(
+					&BOB,
+					&[DOT, AUSD],
+					250_000_000_000_000,
+					100_000_000_000_000,
+					None
+				),
+				Error::<Runtime>::ExcessiveSupplyAmount
+			);
+			assert_noop!(
+				DexModule::do_swap_with_exact_target(
+					&BOB,
+					&[DOT, AUSD, BTC, DOT],
+					250_000_000_000_000,
+					200_000_000_000_000,
+					None,
+				),
+				Error::<Runtime>::InvalidTradingPathLength,
+			);
+			assert_noop!(
+				DexModule::do_swap_with_exact_target(&BOB, &[DOT, ACA], 250_000_000_000_000, 200_000_000_000_000, None),
+				Error::<Runtime>::MustBeEnabled,
+			);
+
+			assert_ok!(DexModule::do_swap_with_exact_target(
+				&BOB,
+				&[DOT, AUSD],
+				250_000_000_000_000,
+				200_000_000_000_000,
+				None,
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![DOT, AUSD],
+				101_010_101_010_102,
+				250_000_000_000_000,
+			)));
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(250_000_000_000_000, 201_010_101_010_102)
+			);
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, BTC),
+				(100_000_000_000_000, 10_000_000_000)
+			);
+			assert_eq!(
+				Tokens::free_balance(AUSD, &DexModule::account_id()),
+				350_000_000_000_000
+			);
+			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 201_010_101_010_102);
+			assert_eq!(Tokens::free_balance(BTC, &DexModule::account_id()), 10_000_000_000);
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_250_000_000_000_000);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_898_989_898_989_898);
+			assert_eq!(Tokens::free_balance(BTC, &BOB), 1_000_000_000_000_000_000);
+
+			assert_ok!(DexModule::do_swap_with_exact_target(
+				&BOB,
+				&[DOT, AUSD, BTC],
+				5_000_000_000,
+				2_000_000_000_000_000,
+				None,
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![DOT, AUSD, BTC],
+				137_654_580_386_993,
+				5_000_000_000,
+			)));
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(148_989_898_989_898, 338_664_681_397_095)
+			);
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, BTC),
+				(201_010_101_010_102, 5_000_000_000)
+			);
+			assert_eq!(
+				Tokens::free_balance(AUSD, &DexModule::account_id()),
+				350_000_000_000_000
+			);
+			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 338_664_681_397_095);
+			assert_eq!(Tokens::free_balance(BTC, &DexModule::account_id()), 5_000_000_000);
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_250_000_000_000_000);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_761_335_318_602_905);
+			assert_eq!(Tokens::free_balance(BTC, &BOB), 1_000_000_005_000_000_000);
+		});
+}
+
+#[test]
+fn swap_with_exact_target_max_slippage_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000_000,
+				1_000_000_000,
+				0,
+				false,
+			));
+
+			// the spot price includes the 1% fee, the price impact is about 1%
+			assert_noop!(
+				DexModule::swap_with_exact_target(
+					Origin::signed(BOB),
+					vec![AUSD, DOT],
+					10_000_000,
+					20_000_000,
+					Some(Ratio::saturating_from_rational(1, 100))
+				),
+				Error::<Runtime>::ExceedMaxSlippage
+			);
+			assert_ok!(DexModule::swap_with_exact_target(
+				Origin::signed(BOB),
+				vec![AUSD, DOT],
+				10_000_000,
+				20_000_000,
+				Some(Ratio::saturating_from_rational(3, 100))
+			));
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_010_000_000);
+
+			// the absolute supply cap still applies
+			assert_noop!(
+				DexModule::swap_with_exact_target(
+					Origin::signed(BOB),
+					vec![AUSD, DOT],
+					10_000_000,
+					10_000_000,
+					Some(Ratio::saturating_from_rational(10, 100))
+				),
+				Error::<Runtime>::ExcessiveSupplyAmount
+			);
+		});
+}
+
+#[test]
+fn swap_with_exact_target_max_slippage_below_fees_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000_000,
+				1_000_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				1_000_000_000,
+				1_000_000_000,
+				0,
+				false,
+			));
+			assert_eq!(
+				DexModule::get_spot_price(&[AUSD, DOT, BTC]),
+				Some(
+					ExchangeRate::saturating_from_rational(100, 99)
+						.saturating_mul(ExchangeRate::saturating_from_rational(100, 99))
+				)
+			);
+
+			// the price impact is about 0.2%, above the max slippage
+			assert_noop!(
+				DexModule::swap_with_exact_target(
+					Origin::signed(BOB),
+					vec![AUSD, DOT, BTC],
+					1_000_000,
+					2_000_000,
+					Some(Ratio::saturating_from_rational(1, 1000))
+				),
+				Error::<Runtime>::ExceedMaxSlippage
+			);
+
+			// the cumulative fees of the two hops are about 2%, the max slippage
+			// below them passes as it only limits the price impact
+			assert_ok!(DexModule::swap_with_exact_target(
+				Origin::signed(BOB),
+				vec![AUSD, DOT, BTC],
+				1_000_000,
+				2_000_000,
+				Some(Ratio::saturating_from_rational(1, 100))
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![AUSD, DOT, BTC],
+				1_022_360,
+				1_000_000,
+			)));
+		});
+}
+
+#[test]
+fn initialize_added_liquidity_pools_genesis_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.initialize_added_liquidity_pools(ALICE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1000000, 2000000));
+			assert_eq!(Tokens::free_balance(AUSD, &DexModule::account_id()), 2000000);
+			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 3000000);
+			assert_eq!(
+				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
+				1999000
+			);
+		});
+}
+
+#[test]
+fn get_twap_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.initialize_added_liquidity_pools(ALICE)
+		.build()
+		.execute_with(|| {
+			let trading_pair = AUSDDOTPair::get();
+			for n in 1..=10 {
+				System::set_block_number(n);
+				DexModule::on_initialize(n);
+			}
+
+			assert_eq!(
+				DexModule::get_twap(trading_pair, 9),
+				Some(Price::saturating_from_integer(2))
+			);
+			assert_eq!(DexModule::get_twap(trading_pair, 0), None);
+			// no snapshot before the first accumulation
+			assert_eq!(DexModule::get_twap(trading_pair, 10), None);
+			// exceed MaxTwapWindow
+			assert_eq!(DexModule::get_twap(trading_pair, 11), None);
+
+			// the average price of either currency of the trading pair
+			assert_eq!(
+				DexModule::get_time_weighted_average_price(AUSD, DOT, 9),
+				Some(Price::saturating_from_integer(2))
+			);
+			assert_eq!(
+				DexModule::get_time_weighted_average_price(DOT, AUSD, 9),
+				Some(Price::saturating_from_rational(1, 2))
+			);
+			assert_eq!(DexModule::get_time_weighted_average_price(AUSD, BTC, 9), None);
+
+			// a large swap spikes the price for one block
+			assert_ok!(DexModule::do_swap_with_exact_supply(&BOB, &[DOT, AUSD], 2_000_000, 0));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (502_513, 4_000_000));
+			System::set_block_number(11);
+			DexModule::on_initialize(11);
+
+			let spot_price = Price::checked_from_rational(4_000_000, 502_513).unwrap();
+			assert!(spot_price > Price::saturating_from_integer(7));
+			assert_eq!(DexModule::get_twap(trading_pair, 1), Some(spot_price));
+			let twap = DexModule::get_twap(trading_pair, 10).unwrap();
+			assert!(twap > Price::saturating_from_integer(2));
+			assert!(twap < Price::saturating_from_rational(26, 10));
+
+			// snapshots older than MaxTwapWindow are pruned
+			assert_eq!(
+				DexModule::cumulative_price_snapshots(trading_pair, 1),
+				Some(U256::zero())
+			);
+			System::set_block_number(12);
+			DexModule::on_initialize(12);
+			assert_eq!(DexModule::cumulative_price_snapshots(trading_pair, 1), None);
+		});
+}
+
+#[test]
+fn lp_accrued_fees_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let trading_pair = AUSDDOTPair::get();
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				2_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(BOB),
+				AUSD,
+				DOT,
+				1_000_000,
+				2_000_000,
+				0,
+				false,
+			));
+			assert_eq!(
+				DexModule::liquidity_provider_fees(trading_pair, ALICE).shares,
+				1_999_000
+			);
+			assert_eq!(DexModule::liquidity_provider_fees(trading_pair, BOB).shares, 2_000_000);
+			assert_eq!(DexModule::lp_accrued_fees(&ALICE, trading_pair), (0, 0));
+			assert_eq!(DexModule::lp_accrued_fees(&BOB, trading_pair), (0, 0));
+
+			// 1% fee of 100_000 AUSD is shared by 4_000_000 shares
+			assert_ok!(DexModule::do_swap_with_exact_supply(&ALICE, &[AUSD, DOT], 100_000, 0));
+			assert_eq!(DexModule::lp_accrued_fees(&ALICE, trading_pair), (499, 0));
+			assert_eq!(DexModule::lp_accrued_fees(&BOB, trading_pair), (500, 0));
+
+			assert_ok!(DexModule::do_swap_with_exact_supply(&BOB, &[DOT, AUSD], 200_000, 0));
+			assert_eq!(DexModule::lp_accrued_fees(&ALICE, trading_pair), (499, 999));
+			assert_eq!(DexModule::lp_accrued_fees(&BOB, trading_pair), (500, 1_000));
+
+			assert_ok!(DexModule::do_swap_with_exact_supply(&ALICE, &[AUSD, DOT], 100_000, 0));
+			assert_eq!(DexModule::lp_accrued_fees(&ALICE, trading_pair), (999, 999));
+			assert_eq!(DexModule::lp_accrued_fees(&BOB, trading_pair), (1_000, 1_000));
+
+			// the accrued fees are kept when the shares are removed
+			assert_ok!(DexModule::remove_liquidity(
+				Origin::signed(BOB),
+				AUSD,
+				DOT,
+				1_000_000,
+				0,
+				0,
+				false,
+			));
+			assert_eq!(DexModule::liquidity_provider_fees(trading_pair, BOB).shares, 1_000_000);
+			assert_eq!(DexModule::lp_accrued_fees(&BOB, trading_pair), (1_000, 1_000));
+
+			assert_ok!(DexModule::do_swap_with_exact_supply(&ALICE, &[AUSD, DOT], 100_000, 0));
+			assert_eq!(DexModule::lp_accrued_fees(&ALICE, trading_pair), (1_665, 999));
+			assert_eq!(DexModule::lp_accrued_fees(&BOB, trading_pair), (1_333, 1_000));
 		});
 }
@@ -47,8 +47,11 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for module_dex.
 pub trait WeightInfo {
+	fn on_initialize(c: u32, ) -> Weight;
 	fn enable_trading_pair() -> Weight;
 	fn disable_trading_pair() -> Weight;
+	fn set_extended_trading_path_account() -> Weight;
+	fn set_max_dex_oracle_deviation() -> Weight;
 	fn list_provisioning() -> Weight;
 	fn update_provisioning_parameters() -> Weight;
 	fn end_provisioning() -> Weight;
@@ -65,6 +68,13 @@ pub trait WeightInfo {
 /// Weights for module_dex using the Acala node and recommended hardware.
 pub struct AcalaWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	fn on_initialize(c: u32, ) -> Weight {
+		(2_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
 	fn enable_trading_pair() -> Weight {
 		(28_975_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
@@ -75,6 +85,14 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_extended_trading_path_account() -> Weight {
+		(28_920_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_max_dex_oracle_deviation() -> Weight {
+		(28_920_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn list_provisioning() -> Weight {
 		(36_413_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
@@ -88,17 +106,17 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	fn end_provisioning() -> Weight {
 		(36_413_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn add_liquidity() -> Weight {
 		(197_944_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
-			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
 	fn add_liquidity_and_stake() -> Weight {
 		(296_383_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(13 as Weight))
-			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
 	}
 	fn add_provision() -> Weight {
 		(197_944_000 as Weight)
@@ -107,37 +125,44 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	}
 	fn claim_dex_share() -> Weight {
 		(197_944_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
-			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
 	fn remove_liquidity() -> Weight {
 		(205_562_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(7 as Weight))
-			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
 	fn remove_liquidity_by_unstake() -> Weight {
 		(339_614_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(12 as Weight))
-			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
 	}
 	fn swap_with_exact_supply(u: u32, ) -> Weight {
 		(156_409_000 as Weight)
 			// Standard Error: 185_000
 			.saturating_add((488_000 as Weight).saturating_mul(u as Weight))
-			.saturating_add(T::DbWeight::get().reads(10 as Weight))
-			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
 	}
 	fn swap_with_exact_target(u: u32, ) -> Weight {
 		(155_993_000 as Weight)
 			// Standard Error: 138_000
 			.saturating_add((654_000 as Weight).saturating_mul(u as Weight))
-			.saturating_add(T::DbWeight::get().reads(10 as Weight))
-			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	fn on_initialize(c: u32, ) -> Weight {
+		(2_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
 	fn enable_trading_pair() -> Weight {
 		(28_975_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
@@ -148,6 +173,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_extended_trading_path_account() -> Weight {
+		(28_920_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_max_dex_oracle_deviation() -> Weight {
+		(28_920_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 	fn list_provisioning() -> Weight {
 		(36_413_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
@@ -161,17 +194,17 @@ impl WeightInfo for () {
 	fn end_provisioning() -> Weight {
 		(36_413_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
 	fn add_liquidity() -> Weight {
 		(197_944_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
 	}
 	fn add_liquidity_and_stake() -> Weight {
 		(296_383_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
 	}
 	fn add_provision() -> Weight {
 		(197_944_000 as Weight)
@@ -180,31 +213,31 @@ impl WeightInfo for () {
 	}
 	fn claim_dex_share() -> Weight {
 		(197_944_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
 	fn remove_liquidity() -> Weight {
 		(205_562_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
 	fn remove_liquidity_by_unstake() -> Weight {
 		(339_614_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
 	}
 	fn swap_with_exact_supply(u: u32, ) -> Weight {
 		(156_409_000 as Weight)
 			// Standard Error: 185_000
 			.saturating_add((488_000 as Weight).saturating_mul(u as Weight))
-			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
 	}
 	fn swap_with_exact_target(u: u32, ) -> Weight {
 		(155_993_000 as Weight)
 			// Standard Error: 138_000
 			.saturating_add((654_000 as Weight).saturating_mul(u as Weight))
-			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
 	}
 }
@@ -35,7 +35,10 @@
 use frame_support::{pallet_prelude::*, transactional};
 use frame_system::{ensure_signed, pallet_prelude::*};
 use primitives::{Balance, CurrencyId};
-use sp_runtime::{traits::Zero, FixedPointNumber};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	FixedPointNumber,
+};
 use sp_std::prelude::*;
 use support::{AuctionManager, CDPTreasury, EmergencyShutdown, LockablePrice, Ratio};
 
@@ -68,6 +71,11 @@ pub mod module {
 		/// redemption
 		type AuctionManagerHandler: AuctionManager<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
 
+		/// The number of blocks that must elapse after emergency shutdown
+		/// before the final redemption can be opened
+		#[pallet::constant]
+		type RefundDelayPeriod: Get<Self::BlockNumber>;
+
 		/// The origin which may trigger emergency shutdown. Root can always do
 		/// this.
 		type ShutdownOrigin: EnsureOrigin<Self::Origin>;
@@ -88,6 +96,12 @@ pub mod module {
 		ExistPotentialSurplus,
 		/// Exist unhandled debit, means settlement has not been completed
 		ExistUnhandledDebit,
+		/// Invalid collateral type
+		InvalidCollateralType,
+		/// The collateral type has already been shutdown
+		CollateralAlreadyShutdown,
+		/// Refund delay period after shutdown has not elapsed
+		RefundDelayNotElapsed,
 	}
 
 	#[pallet::event]
@@ -100,6 +114,10 @@ pub mod module {
 		OpenRefund(T::BlockNumber),
 		/// Refund info. \[caller, stable_coin_amount, refund_list\]
 		Refund(T::AccountId, Balance, Vec<(CurrencyId, Balance)>),
+		/// Shutdown occurs for a single collateral type. \[collateral_type\]
+		CollateralShutdown(CurrencyId),
+		/// Progress of the final redemption. \[processed, total\]
+		ShutdownProgress(Balance, Balance),
 	}
 
 	/// Emergency shutdown flag
@@ -109,6 +127,20 @@ pub mod module {
 	#[pallet::getter(fn is_shutdown)]
 	pub type IsShutdown<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// The block number at which emergency shutdown occurred
+	///
+	/// ShutdownAt: T::BlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn shutdown_at)]
+	pub type ShutdownAt<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// Shutdown flag of specific collateral type
+	///
+	/// IsCollateralShutdown: map CurrencyId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn is_collateral_shutdown)]
+	pub type IsCollateralShutdown<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, bool, ValueQuery>;
+
 	/// Open final redemption flag
 	///
 	/// CanRefund: bool
@@ -116,6 +148,14 @@ pub mod module {
 	#[pallet::getter(fn can_refund)]
 	pub type CanRefund<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// The sum of collaterals held by CDP treasury when final redemption
+	/// opened
+	///
+	/// TotalRefundCollaterals: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_refund_collaterals)]
+	pub type TotalRefundCollaterals<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -142,8 +182,38 @@ pub mod module {
 				let _ = <T as Config>::PriceSource::lock_price(currency_id);
 			}
 
+			let now = <frame_system::Pallet<T>>::block_number();
 			IsShutdown::<T>::put(true);
-			Self::deposit_event(Event::Shutdown(<frame_system::Pallet<T>>::block_number()));
+			ShutdownAt::<T>::put(now);
+			Self::deposit_event(Event::Shutdown(now));
+			Ok(())
+		}
+
+		/// Shutdown a single collateral type, lock its price and stop issuing
+		/// new debit against it, while the rest of the system keeps running.
+		///
+		/// The dispatch origin of this call must be `ShutdownOrigin`.
+		///
+		/// - `currency_id`: collateral type to shutdown.
+		#[pallet::weight((T::WeightInfo::shutdown_collateral(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn shutdown_collateral(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::ShutdownOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_shutdown(), Error::<T>::AlreadyShutdown);
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id),
+				Error::<T>::InvalidCollateralType,
+			);
+			ensure!(
+				!Self::is_collateral_shutdown(currency_id),
+				Error::<T>::CollateralAlreadyShutdown
+			);
+
+			// TODO: check the results
+			let _ = <T as Config>::PriceSource::lock_price(currency_id);
+
+			IsCollateralShutdown::<T>::insert(currency_id, true);
+			Self::deposit_event(Event::CollateralShutdown(currency_id));
 			Ok(())
 		}
 
@@ -155,6 +225,11 @@ pub mod module {
 		pub fn open_collateral_refund(origin: OriginFor<T>) -> DispatchResult {
 			T::ShutdownOrigin::ensure_origin(origin)?;
 			ensure!(Self::is_shutdown(), Error::<T>::MustAfterShutdown); // must after shutdown
+			ensure!(
+				<frame_system::Pallet<T>>::block_number()
+					>= Self::shutdown_at().saturating_add(T::RefundDelayPeriod::get()),
+				Error::<T>::RefundDelayNotElapsed,
+			);
 
 			// Ensure all debits of CDPs have been settled, and all collateral auction has
 			// been done or canceled. Settle all collaterals type CDPs which have debit,
@@ -175,6 +250,7 @@ pub mod module {
 			}
 
 			// Open refund stage
+			TotalRefundCollaterals::<T>::put(Self::remaining_collaterals());
 			CanRefund::<T>::put(true);
 			Self::deposit_event(Event::OpenRefund(<frame_system::Pallet<T>>::block_number()));
 			Ok(())
@@ -208,13 +284,40 @@ pub mod module {
 			}
 
 			Self::deposit_event(Event::Refund(who, amount, refund_assets));
+			let (processed, total) = Self::shutdown_progress();
+			Self::deposit_event(Event::ShutdownProgress(processed, total));
 			Ok(())
 		}
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Get the progress of the final redemption as `(processed, total)`.
+	///
+	/// Amounts are the sum of all collateral types held by CDP treasury.
+	/// Every refund withdraws the same proportion of each collateral, so
+	/// `processed / total` is the share of collaterals already refunded.
+	pub fn shutdown_progress() -> (Balance, Balance) {
+		let total = Self::total_refund_collaterals();
+		let processed = total.saturating_sub(Self::remaining_collaterals());
+		(processed, total)
+	}
+
+	fn remaining_collaterals() -> Balance {
+		T::CollateralCurrencyIds::get()
+			.into_iter()
+			.fold(Zero::zero(), |acc: Balance, currency_id| {
+				acc.saturating_add(<T as Config>::CDPTreasury::get_total_collaterals(currency_id))
+			})
+	}
+}
+
 impl<T: Config> EmergencyShutdown for Pallet<T> {
 	fn is_shutdown() -> bool {
 		Self::is_shutdown()
 	}
+
+	fn is_collateral_shutdown(currency_id: CurrencyId) -> bool {
+		Self::is_collateral_shutdown(currency_id)
+	}
 }
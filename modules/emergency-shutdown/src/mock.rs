@@ -168,6 +168,7 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		_currency_id: Self::CurrencyId,
 		_amount: Self::Balance,
 		_target: Self::Balance,
+		_reserve_price: Option<Self::Balance>,
 	) -> DispatchResult {
 		unimplemented!()
 	}
@@ -206,6 +207,7 @@ impl cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type EmergencyShutdown = EmergencyShutdownModule;
 	type WeightInfo = ();
 }
 
@@ -213,12 +215,17 @@ ord_parameter_types! {
 	pub const CollateralCurrencyIds: Vec<CurrencyId> = vec![BTC, DOT];
 }
 
+parameter_types! {
+	pub const RefundDelayPeriod: u64 = 10;
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type PriceSource = MockLockablePrice;
 	type CDPTreasury = CDPTreasuryModule;
 	type AuctionManagerHandler = MockAuctionManager;
+	type RefundDelayPeriod = RefundDelayPeriod;
 	type ShutdownOrigin = EnsureSignedBy<One, AccountId>;
 	type WeightInfo = ();
 }
@@ -44,6 +44,37 @@ fn emergency_shutdown_work() {
 	});
 }
 
+#[test]
+fn shutdown_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(EmergencyShutdownModule::is_collateral_shutdown(BTC), false);
+		assert_noop!(
+			EmergencyShutdownModule::shutdown_collateral(Origin::signed(5), BTC),
+			BadOrigin,
+		);
+		assert_noop!(
+			EmergencyShutdownModule::shutdown_collateral(Origin::signed(1), ACA),
+			Error::<Runtime>::InvalidCollateralType,
+		);
+		assert_ok!(EmergencyShutdownModule::shutdown_collateral(Origin::signed(1), BTC));
+		System::assert_last_event(Event::EmergencyShutdownModule(crate::Event::CollateralShutdown(BTC)));
+		assert_eq!(EmergencyShutdownModule::is_collateral_shutdown(BTC), true);
+		assert_eq!(EmergencyShutdownModule::is_collateral_shutdown(DOT), false);
+		assert_eq!(EmergencyShutdownModule::is_shutdown(), false);
+		assert_noop!(
+			EmergencyShutdownModule::shutdown_collateral(Origin::signed(1), BTC),
+			Error::<Runtime>::CollateralAlreadyShutdown,
+		);
+
+		assert_ok!(EmergencyShutdownModule::emergency_shutdown(Origin::signed(1)));
+		assert_noop!(
+			EmergencyShutdownModule::shutdown_collateral(Origin::signed(1), DOT),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
+
 #[test]
 fn open_collateral_refund_fail() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -61,12 +92,23 @@ fn open_collateral_refund_work() {
 		System::set_block_number(1);
 		assert_eq!(EmergencyShutdownModule::can_refund(), false);
 		assert_ok!(EmergencyShutdownModule::emergency_shutdown(Origin::signed(1)));
+		assert_eq!(EmergencyShutdownModule::shutdown_at(), 1);
 		assert_noop!(
 			EmergencyShutdownModule::open_collateral_refund(Origin::signed(5)),
 			BadOrigin,
 		);
+		assert_noop!(
+			EmergencyShutdownModule::open_collateral_refund(Origin::signed(1)),
+			Error::<Runtime>::RefundDelayNotElapsed,
+		);
+		System::set_block_number(10);
+		assert_noop!(
+			EmergencyShutdownModule::open_collateral_refund(Origin::signed(1)),
+			Error::<Runtime>::RefundDelayNotElapsed,
+		);
+		System::set_block_number(11);
 		assert_ok!(EmergencyShutdownModule::open_collateral_refund(Origin::signed(1)));
-		System::assert_last_event(Event::EmergencyShutdownModule(crate::Event::OpenRefund(1)));
+		System::assert_last_event(Event::EmergencyShutdownModule(crate::Event::OpenRefund(11)));
 		assert_eq!(EmergencyShutdownModule::can_refund(), true);
 	});
 }
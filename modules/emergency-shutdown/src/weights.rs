@@ -49,6 +49,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for module_emergency_shutdown.
 pub trait WeightInfo {
 	fn emergency_shutdown(c: u32, ) -> Weight;
+	fn shutdown_collateral() -> Weight;
 	fn open_collateral_refund() -> Weight;
 	fn refund_collaterals(c: u32, ) -> Weight;
 }
@@ -61,13 +62,18 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			// Standard Error: 565_000
 			.saturating_add((20_539_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().reads(60 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
 	}
+	fn shutdown_collateral() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	fn open_collateral_refund() -> Weight {
 		(62_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(17 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn refund_collaterals(c: u32, ) -> Weight {
 		(122_271_000 as Weight)
@@ -87,13 +93,18 @@ impl WeightInfo for () {
 			// Standard Error: 565_000
 			.saturating_add((20_539_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(RocksDbWeight::get().reads(60 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
 	}
+	fn shutdown_collateral() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 	fn open_collateral_refund() -> Weight {
 		(62_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
 	fn refund_collaterals(c: u32, ) -> Weight {
 		(122_271_000 as Weight)
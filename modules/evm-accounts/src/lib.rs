@@ -181,6 +181,37 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Claim account mapping between Substrate accounts and the default EVM
+		/// address derived from those accounts, without an external signature.
+		/// Ensure the default eth_address has not been mapped.
+		#[pallet::weight(T::WeightInfo::claim_default_evm_address())]
+		#[transactional]
+		pub fn claim_default_evm_address(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// ensure account_id and the default eth_address has not been mapped
+			ensure!(!EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountIdHasMapped);
+			let eth_address = T::AddressMapping::get_default_evm_address(&who);
+			ensure!(
+				!Accounts::<T>::contains_key(eth_address),
+				Error::<T>::EthAddressHasMapped
+			);
+
+			// check if the evm padded address already exists
+			let account_id = T::AddressMapping::get_account_id(&eth_address);
+			if frame_system::Pallet::<T>::account_exists(&account_id) {
+				// merge balance from `evm padded address` to `origin`
+				T::TransferAll::transfer_all(&account_id, &who)?;
+			}
+
+			Accounts::<T>::insert(eth_address, &who);
+			EvmAddresses::<T>::insert(&who, eth_address);
+
+			Self::deposit_event(Event::ClaimAccount(who, eth_address));
+
+			Ok(())
+		}
 	}
 }
 
@@ -234,6 +265,17 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T>
+where
+	T::AccountId: IsType<AccountId32>,
+{
+	/// Returns the EVM address of `who`: the claimed address if one exists,
+	/// else the default address derived from the account.
+	pub fn evm_address_of(who: &T::AccountId) -> Option<EvmAddress> {
+		EvmAddressMapping::<T>::get_evm_address(who).or_else(|| Some(account_to_default_evm_address(who)))
+	}
+}
+
 // Creates a an EvmAddress from an AccountId by appending the bytes "evm:" to
 // the account_id and hashing it.
 fn account_to_default_evm_address(account_id: &impl Encode) -> EvmAddress {
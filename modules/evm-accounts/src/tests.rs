@@ -22,7 +22,7 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
-use mock::{alice, bob, Event, EvmAccountsModule, ExtBuilder, Origin, Runtime, System, ALICE, BOB};
+use mock::{alice, bob, bob_account_id, Event, EvmAccountsModule, ExtBuilder, Origin, Runtime, System, ALICE, BOB};
 use std::str::FromStr;
 
 #[test]
@@ -95,6 +95,72 @@ fn claim_account_should_not_work() {
 	});
 }
 
+#[test]
+fn claim_default_evm_address_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let default_evm_address = EvmAddressMapping::<Runtime>::get_default_evm_address(&ALICE);
+		assert_ok!(EvmAccountsModule::claim_default_evm_address(Origin::signed(ALICE)));
+		System::assert_last_event(Event::EvmAccountsModule(crate::Event::ClaimAccount(
+			ALICE,
+			default_evm_address,
+		)));
+		assert_eq!(
+			EvmAddressMapping::<Runtime>::get_account_id(&default_evm_address),
+			ALICE
+		);
+		assert_eq!(
+			EvmAddressMapping::<Runtime>::get_evm_address(&ALICE),
+			Some(default_evm_address)
+		);
+	});
+}
+
+#[test]
+fn claim_default_evm_address_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			EvmAccountsModule::eth_address(&alice()),
+			EvmAccountsModule::eth_sign(&alice(), &ALICE.encode(), &[][..])
+		));
+		assert_noop!(
+			EvmAccountsModule::claim_default_evm_address(Origin::signed(ALICE)),
+			Error::<Runtime>::AccountIdHasMapped
+		);
+
+		Accounts::<Runtime>::insert(EvmAddressMapping::<Runtime>::get_default_evm_address(&BOB), ALICE);
+		assert_noop!(
+			EvmAccountsModule::claim_default_evm_address(Origin::signed(BOB)),
+			Error::<Runtime>::EthAddressHasMapped
+		);
+	});
+}
+
+#[test]
+fn evm_address_of_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let default_evm_account = EvmAddress::from_str("f0bd9ffde7f9f4394d8cc1d86bf24d87e5d5a9a9").unwrap();
+		let alice_evm_account = EvmAccountsModule::eth_address(&alice());
+
+		// unclaimed account returns the default address
+		assert_eq!(EvmAccountsModule::evm_address_of(&ALICE), Some(default_evm_account));
+		// evm padded account returns the underlying address
+		assert_eq!(
+			EvmAccountsModule::evm_address_of(&bob_account_id()),
+			Some(EvmAccountsModule::eth_address(&bob()))
+		);
+
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			alice_evm_account,
+			EvmAccountsModule::eth_sign(&alice(), &ALICE.encode(), &[][..])
+		));
+
+		// claimed account returns the claimed address
+		assert_eq!(EvmAccountsModule::evm_address_of(&ALICE), Some(alice_evm_account));
+	});
+}
+
 #[test]
 fn evm_get_account_id() {
 	ExtBuilder::default().build().execute_with(|| {
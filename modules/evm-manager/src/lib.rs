@@ -22,11 +22,15 @@
 //!
 //! Evm manager module provides common support features for Evm, including:
 //! - A two way mapping between `u32` and `Erc20 address` so user can use Erc20 address as LP token.
+//! - A decimals registry set by governance, which overrides the decimals the MultiCurrency precompile reports.
+//! - Registration of Erc20 contracts as `CurrencyId::Erc20` by governance, with the metadata read
+//!   from the contract.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{ensure, pallet_prelude::*, require_transactional, traits::Currency};
+use frame_support::{ensure, pallet_prelude::*, require_transactional, traits::Currency, transactional};
+use frame_system::pallet_prelude::*;
 use module_support::{CurrencyIdMapping, EVMBridge, InvokeContext};
 use primitives::{
 	currency::TokenInfo,
@@ -40,8 +44,10 @@ use sp_std::{
 
 mod mock;
 mod tests;
+pub mod weights;
 
 pub use module::*;
+pub use weights::WeightInfo;
 
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -51,8 +57,15 @@ pub mod module {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		type Currency: Currency<Self::AccountId>;
 		type EVMBridge: EVMBridge<Self::AccountId, BalanceOf<Self>>;
+
+		/// The origin which may set the decimals of currencies.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// Error for evm accounts module.
@@ -60,6 +73,17 @@ pub mod module {
 	pub enum Error<T> {
 		/// CurrencyId existed
 		CurrencyIdExisted,
+		/// The Erc20 contract has been registered
+		Erc20AlreadyRegistered,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The decimals of currency updated. \[currency_id, decimals\]
+		CurrencyDecimalsUpdated(CurrencyId, Option<u8>),
+		/// Erc20 contract registered as currency. \[contract\]
+		Erc20Registered(EvmAddress),
 	}
 
 	/// Mapping between u32 and Erc20 address.
@@ -71,6 +95,15 @@ pub mod module {
 	#[pallet::getter(fn currency_id_map)]
 	pub type CurrencyIdMap<T: Config> = StorageMap<_, Twox64Concat, u32, Erc20Info, OptionQuery>;
 
+	/// The decimals of currencies registered by governance, which override
+	/// the decimals the MultiCurrency precompile reports. They are never used
+	/// to convert amounts or prices.
+	///
+	/// map CurrencyId => Option<u8>
+	#[pallet::storage]
+	#[pallet::getter(fn currency_decimals)]
+	pub type CurrencyDecimals<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, u8, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -78,7 +111,46 @@ pub mod module {
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Set the decimals of currency.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `decimals`: the decimals, `None` means remove it from the registry.
+		#[pallet::weight((T::WeightInfo::set_currency_decimals(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_currency_decimals(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			decimals: Option<u8>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			CurrencyDecimals::<T>::set(currency_id, decimals);
+			Self::deposit_event(Event::CurrencyDecimalsUpdated(currency_id, decimals));
+			Ok(())
+		}
+
+		/// Register the Erc20 contract as `CurrencyId::Erc20`, the name, symbol
+		/// and decimals are read from the contract, so it must respond to
+		/// these Erc20 calls.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `contract`: the address of Erc20 contract.
+		#[pallet::weight((T::WeightInfo::register_erc20(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn register_erc20(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				EvmCurrencyIdMapping::<T>::get_evm_address(DexShare::Erc20(contract).into()) != Some(contract),
+				Error::<T>::Erc20AlreadyRegistered
+			);
+			EvmCurrencyIdMapping::<T>::set_erc20_mapping(contract)?;
+			Self::deposit_event(Event::Erc20Registered(contract));
+			Ok(())
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {}
@@ -213,10 +285,8 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 				// initial dex share amount is calculated based on currency_id_0,
 				// use the decimals of currency_id_0 as the decimals of lp token.
 				match symbol_0 {
-					DexShare::Token(symbol) => CurrencyId::Token(symbol).decimals(),
-					DexShare::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(symbol_0))
-						.filter(|v| v.address == address)
-						.map(|v| v.decimals),
+					DexShare::Token(symbol) => Self::decimals(CurrencyId::Token(symbol)),
+					DexShare::Erc20(address) => Self::decimals(CurrencyId::Erc20(address)),
 				}
 			}
 			CurrencyId::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(DexShare::Erc20(address)))
@@ -226,6 +296,12 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 		}
 	}
 
+	// Returns the decimals the MultiCurrency precompile reports.
+	// The decimals registered in `CurrencyDecimals` take precedence.
+	fn display_decimals(currency_id: CurrencyId) -> Option<u8> {
+		CurrencyDecimals::<T>::get(currency_id).or_else(|| Self::decimals(currency_id))
+	}
+
 	// Encode the CurrencyId to EvmAddress.
 	// If is CurrencyId::DexShare and contain DexShare::Erc20,
 	// will use the u32 to get the DexShare::Erc20 from the mapping.
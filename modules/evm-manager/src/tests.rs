@@ -22,10 +22,14 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
-use mock::{alice, deploy_contracts, erc20_address, erc20_address_not_exists, ExtBuilder, Runtime};
+use mock::{
+	alice, deploy_contracts, erc20_address, erc20_address_not_exists, CouncilAccount, Event, EvmManager, ExtBuilder,
+	Origin, Runtime, System,
+};
 use orml_utilities::with_transaction_result;
 use primitives::TokenSymbol;
 use sp_core::H160;
+use sp_runtime::traits::BadOrigin;
 use std::str::FromStr;
 
 #[test]
@@ -264,6 +268,127 @@ fn decimals_works() {
 		});
 }
 
+#[test]
+fn set_currency_decimals_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let dot = CurrencyId::Token(TokenSymbol::DOT);
+		assert_noop!(
+			EvmManager::set_currency_decimals(Origin::signed(alice()), dot, Some(18)),
+			BadOrigin
+		);
+
+		assert_ok!(EvmManager::set_currency_decimals(
+			Origin::signed(CouncilAccount::get()),
+			dot,
+			Some(18)
+		));
+		System::assert_last_event(Event::EvmManager(crate::Event::CurrencyDecimalsUpdated(dot, Some(18))));
+		assert_eq!(EvmManager::currency_decimals(dot), Some(18));
+
+		assert_ok!(EvmManager::set_currency_decimals(
+			Origin::signed(CouncilAccount::get()),
+			dot,
+			None
+		));
+		System::assert_last_event(Event::EvmManager(crate::Event::CurrencyDecimalsUpdated(dot, None)));
+		assert_eq!(EvmManager::currency_decimals(dot), None);
+	});
+}
+
+#[test]
+fn display_decimals_prefer_registered_decimals() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			assert_ok!(with_transaction_result(|| -> DispatchResult {
+				EvmCurrencyIdMapping::<Runtime>::set_erc20_mapping(erc20_address())
+			}));
+
+			let dot = CurrencyId::Token(TokenSymbol::DOT);
+			let erc20 = CurrencyId::Erc20(erc20_address());
+			let lp_dot_ausd =
+				CurrencyId::DexShare(DexShare::Token(TokenSymbol::DOT), DexShare::Token(TokenSymbol::AUSD));
+			assert_eq!(EvmCurrencyIdMapping::<Runtime>::display_decimals(dot), Some(10));
+			assert_eq!(EvmCurrencyIdMapping::<Runtime>::display_decimals(erc20), Some(17));
+
+			assert_ok!(EvmManager::set_currency_decimals(
+				Origin::signed(CouncilAccount::get()),
+				dot,
+				Some(12)
+			));
+			assert_ok!(EvmManager::set_currency_decimals(
+				Origin::signed(CouncilAccount::get()),
+				erc20,
+				Some(18)
+			));
+			assert_eq!(EvmCurrencyIdMapping::<Runtime>::display_decimals(dot), Some(12));
+			assert_eq!(EvmCurrencyIdMapping::<Runtime>::display_decimals(erc20), Some(18));
+			assert_eq!(EvmCurrencyIdMapping::<Runtime>::display_decimals(lp_dot_ausd), Some(10));
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::display_decimals(CurrencyId::Token(TokenSymbol::ACA)),
+				Some(12)
+			);
+
+			// the decimals to convert amounts and prices are not affected
+			assert_eq!(EvmCurrencyIdMapping::<Runtime>::decimals(dot), Some(10));
+			assert_eq!(EvmCurrencyIdMapping::<Runtime>::decimals(erc20), Some(17));
+			assert_eq!(EvmCurrencyIdMapping::<Runtime>::decimals(lp_dot_ausd), Some(10));
+		});
+}
+
+#[test]
+fn register_erc20_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			assert_noop!(
+				EvmManager::register_erc20(Origin::signed(alice()), erc20_address()),
+				BadOrigin
+			);
+			assert_noop!(
+				EvmManager::register_erc20(Origin::signed(CouncilAccount::get()), erc20_address_not_exists()),
+				module_evm_bridge::Error::<Runtime>::InvalidReturnValue
+			);
+
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(erc20_address()),
+				None
+			);
+			assert_ok!(EvmManager::register_erc20(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address()
+			));
+			System::assert_last_event(Event::EvmManager(crate::Event::Erc20Registered(erc20_address())));
+
+			let erc20 = CurrencyId::Erc20(erc20_address());
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(erc20_address()),
+				Some(erc20)
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::name(erc20),
+				Some(
+					b"long string name, long string name, long string name, long string name, long string name"[..32]
+						.to_vec()
+				)
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::symbol(erc20),
+				Some(b"TestToken".to_vec())
+			);
+			assert_eq!(EvmCurrencyIdMapping::<Runtime>::decimals(erc20), Some(17));
+
+			assert_noop!(
+				EvmManager::register_erc20(Origin::signed(CouncilAccount::get()), erc20_address()),
+				Error::<Runtime>::Erc20AlreadyRegistered
+			);
+		});
+}
+
 #[test]
 fn encode_evm_address_works() {
 	ExtBuilder::default()
@@ -19,8 +19,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::all)]
 
-use ethereum_types::H160;
-use primitives::evm::{CallInfo, CreateInfo, EstimateResourcesRequest};
+use ethereum_types::{H160, U256};
+use primitives::evm::{AccessListItem, CallInfo, CreateInfo, EstimateResourcesRequest};
 use sp_runtime::{
 	codec::Codec,
 	traits::{MaybeDisplay, MaybeFromStr},
@@ -28,9 +28,11 @@ use sp_runtime::{
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait EVMRuntimeRPCApi<Balance> where
 		Balance: Codec + MaybeDisplay + MaybeFromStr,
 	{
+		#[changed_in(2)]
 		fn call(
 			from: H160,
 			to: H160,
@@ -41,15 +43,50 @@ sp_api::decl_runtime_apis! {
 			estimate: bool,
 		) -> Result<CallInfo, sp_runtime::DispatchError>;
 
+		/// `access_list` is only supported for RPC calls, `None` runs with the
+		/// empty access list of the `call` extrinsic.
+		fn call(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			estimate: bool,
+		) -> Result<CallInfo, sp_runtime::DispatchError>;
+
+		#[changed_in(2)]
+		fn create(
+			from: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			estimate: bool,
+		) -> Result<CreateInfo, sp_runtime::DispatchError>;
+
+		/// `access_list` is only supported for RPC calls, `None` runs with the
+		/// empty access list of the `create` extrinsic.
 		fn create(
 			from: H160,
 			data: Vec<u8>,
 			value: Balance,
 			gas_limit: u64,
 			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
 			estimate: bool,
 		) -> Result<CreateInfo, sp_runtime::DispatchError>;
 
 		fn get_estimate_resources_request(data: Vec<u8>) -> Result<EstimateResourcesRequest, sp_runtime::DispatchError>;
+
+		/// Available since version 2.
+		fn gas_to_fee(gas_limit: u64, gas_price: u64) -> Balance;
+
+		/// Available since version 2.
+		fn base_fee_per_gas() -> U256;
+
+		/// Available since version 2.
+		fn chain_id() -> u64;
 	}
 }
@@ -18,7 +18,7 @@
 
 //! EVM rpc interface.
 
-use ethereum_types::H160;
+use ethereum_types::{H160, U256};
 use jsonrpc_core::Result;
 use jsonrpc_derive::rpc;
 use sp_core::Bytes;
@@ -42,4 +42,12 @@ pub trait EVMApi<BlockHash> {
 		unsigned_extrinsic: Bytes,
 		at: Option<BlockHash>,
 	) -> Result<EstimateResourcesResponse>;
+
+	/// Synthetic EIP-1559 base fee per gas derived from the current fee multiplier.
+	#[rpc(name = "evm_baseFeePerGas")]
+	fn base_fee_per_gas(&self, at: Option<BlockHash>) -> Result<U256>;
+
+	/// The chain id of EVM.
+	#[rpc(name = "evm_chainId")]
+	fn chain_id(&self, at: Option<BlockHash>) -> Result<u64>;
 }
@@ -24,7 +24,7 @@ use jsonrpc_core::{Error, ErrorCode, Result, Value};
 use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi;
 use rustc_hex::ToHex;
 use sc_rpc_api::DenyUnsafe;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::{Bytes, Decode};
 use sp_rpc::number::NumberOrHex;
@@ -38,6 +38,7 @@ use std::convert::{TryFrom, TryInto};
 use std::{marker::PhantomData, sync::Arc};
 
 use call_request::{CallRequest, EstimateResourcesResponse};
+use module_evm::{AccessListItem, CallInfo, CreateInfo};
 pub use module_evm::{ExitError, ExitReason};
 pub use module_evm_rpc_runtime_api::EVMRuntimeRPCApi;
 
@@ -126,6 +127,113 @@ fn to_u128(val: NumberOrHex) -> std::result::Result<u128, ()> {
 	val.into_u256().try_into().map_err(|_| ())
 }
 
+/// The version of `EVMRuntimeRPCApi` which adds the access list to `call` and
+/// `create`, and adds `gas_to_fee`, `base_fee_per_gas` and `chain_id`.
+const EVM_RUNTIME_RPC_API_V2: u32 = 2;
+
+impl<B, C, Balance> EVMApi<B, C, Balance>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: EVMRuntimeRPCApi<B, Balance>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	/// Returns whether the runtime at `at` implements `EVMRuntimeRPCApi` of
+	/// at least `version`.
+	fn has_api_version(&self, at: &BlockId<B>, version: u32) -> Result<bool> {
+		self.client
+			.runtime_api()
+			.has_api_with::<dyn EVMRuntimeRPCApi<B, Balance>, _>(at, |v| v >= version)
+			.map_err(|err| internal_err(format!("runtime error: {:?}", err)))
+	}
+
+	/// Returns an error if the runtime at `at` is older than `version`.
+	fn ensure_api_version(&self, at: &BlockId<B>, version: u32) -> Result<()> {
+		if self.has_api_version(at, version)? {
+			Ok(())
+		} else {
+			Err(internal_err(format!(
+				"EVMRuntimeRPCApi version {} is not supported by the runtime",
+				version
+			)))
+		}
+	}
+
+	/// Returns whether the runtime at `at` takes the access list in `call`
+	/// and `create`, or an error if an access list is given but the runtime
+	/// doesn't support it.
+	fn use_access_list(&self, at: &BlockId<B>, access_list: &Option<Vec<AccessListItem>>) -> Result<bool> {
+		let supported = self.has_api_version(at, EVM_RUNTIME_RPC_API_V2)?;
+		if !supported && access_list.is_some() {
+			return Err(Error {
+				code: ErrorCode::InvalidParams,
+				message: "Access list is not supported by the runtime".into(),
+				data: None,
+			});
+		}
+		Ok(supported)
+	}
+
+	#[allow(clippy::too_many_arguments, deprecated)]
+	fn runtime_call(
+		&self,
+		at: &BlockId<B>,
+		from: H160,
+		to: H160,
+		data: Vec<u8>,
+		value: Balance,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Option<Vec<AccessListItem>>,
+		estimate: bool,
+	) -> Result<CallInfo> {
+		let api = self.client.runtime_api();
+		let result = if self.use_access_list(at, &access_list)? {
+			api.call(
+				at,
+				from,
+				to,
+				data,
+				value,
+				gas_limit,
+				storage_limit,
+				access_list,
+				estimate,
+			)
+		} else {
+			api.call_before_version_2(at, from, to, data, value, gas_limit, storage_limit, estimate)
+		};
+
+		result
+			.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
+			.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))
+	}
+
+	#[allow(clippy::too_many_arguments, deprecated)]
+	fn runtime_create(
+		&self,
+		at: &BlockId<B>,
+		from: H160,
+		data: Vec<u8>,
+		value: Balance,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Option<Vec<AccessListItem>>,
+		estimate: bool,
+	) -> Result<CreateInfo> {
+		let api = self.client.runtime_api();
+		let result = if self.use_access_list(at, &access_list)? {
+			api.create(at, from, data, value, gas_limit, storage_limit, access_list, estimate)
+		} else {
+			api.create_before_version_2(at, from, data, value, gas_limit, storage_limit, estimate)
+		};
+
+		result
+			.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
+			.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))
+	}
+}
+
 impl<B, C, Balance> EVMApiT<<B as BlockT>::Hash> for EVMApi<B, C, Balance>
 where
 	B: BlockT,
@@ -146,14 +254,13 @@ where
 			storage_limit,
 			value,
 			data,
+			access_list,
 		} = request;
 
 		let gas_limit = gas_limit.unwrap_or_else(u64::max_value); // TODO: set a limit
 		let storage_limit = storage_limit.unwrap_or_else(u32::max_value); // TODO: set a limit
 		let data = data.map(|d| d.0).unwrap_or_default();
 
-		let api = self.client.runtime_api();
-
 		let balance_value = if let Some(value) = value {
 			to_u128(value).and_then(|v| TryInto::<Balance>::try_into(v).map_err(|_| ()))
 		} else {
@@ -168,37 +275,33 @@ where
 
 		match to {
 			Some(to) => {
-				let info = api
-					.call(
-						&BlockId::Hash(hash),
-						from.unwrap_or_default(),
-						to,
-						data,
-						balance_value,
-						gas_limit,
-						storage_limit,
-						false,
-					)
-					.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
-					.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))?;
+				let info = self.runtime_call(
+					&BlockId::Hash(hash),
+					from.unwrap_or_default(),
+					to,
+					data,
+					balance_value,
+					gas_limit,
+					storage_limit,
+					access_list,
+					false,
+				)?;
 
 				error_on_execution_failure(&info.exit_reason, &info.output)?;
 
 				Ok(Bytes(info.output))
 			}
 			None => {
-				let info = api
-					.create(
-						&BlockId::Hash(hash),
-						from.unwrap_or_default(),
-						data,
-						balance_value,
-						gas_limit,
-						storage_limit,
-						false,
-					)
-					.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
-					.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))?;
+				let info = self.runtime_create(
+					&BlockId::Hash(hash),
+					from.unwrap_or_default(),
+					data,
+					balance_value,
+					gas_limit,
+					storage_limit,
+					access_list,
+					false,
+				)?;
 
 				error_on_execution_failure(&info.exit_reason, &info.output)?;
 
@@ -230,6 +333,10 @@ where
 			storage_limit: request.storage_limit,
 			value: request.value.map(|v| NumberOrHex::Hex(U256::from(v))),
 			data: request.data.map(Bytes),
+			// the extrinsics run with an empty access list, estimate with the same
+			access_list: self
+				.has_api_version(&BlockId::Hash(hash), EVM_RUNTIME_RPC_API_V2)?
+				.then(Vec::new),
 		};
 
 		let calculate_gas_used = |request| -> Result<(U256, i32)> {
@@ -242,6 +349,7 @@ where
 				storage_limit,
 				value,
 				data,
+				access_list,
 			} = request;
 
 			let gas_limit = gas_limit.unwrap_or_else(u64::max_value); // TODO: set a limit
@@ -262,41 +370,33 @@ where
 
 			let (used_gas, used_storage) = match to {
 				Some(to) => {
-					let info = self
-						.client
-						.runtime_api()
-						.call(
-							&BlockId::Hash(hash),
-							from.unwrap_or_default(),
-							to,
-							data,
-							balance_value,
-							gas_limit,
-							storage_limit,
-							true,
-						)
-						.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
-						.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))?;
+					let info = self.runtime_call(
+						&BlockId::Hash(hash),
+						from.unwrap_or_default(),
+						to,
+						data,
+						balance_value,
+						gas_limit,
+						storage_limit,
+						access_list,
+						true,
+					)?;
 
 					error_on_execution_failure(&info.exit_reason, &info.output)?;
 
 					(info.used_gas, info.used_storage)
 				}
 				None => {
-					let info = self
-						.client
-						.runtime_api()
-						.create(
-							&BlockId::Hash(hash),
-							from.unwrap_or_default(),
-							data,
-							balance_value,
-							gas_limit,
-							storage_limit,
-							true,
-						)
-						.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
-						.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))?;
+					let info = self.runtime_create(
+						&BlockId::Hash(hash),
+						from.unwrap_or_default(),
+						data,
+						balance_value,
+						gas_limit,
+						storage_limit,
+						access_list,
+						true,
+					)?;
 
 					error_on_execution_failure(&info.exit_reason, &[])?;
 
@@ -424,6 +524,24 @@ where
 			})
 		}
 	}
+
+	fn base_fee_per_gas(&self, at: Option<<B as BlockT>::Hash>) -> Result<U256> {
+		let hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_api_version(&BlockId::Hash(hash), EVM_RUNTIME_RPC_API_V2)?;
+		self.client
+			.runtime_api()
+			.base_fee_per_gas(&BlockId::Hash(hash))
+			.map_err(|err| internal_err(format!("runtime error: {:?}", err)))
+	}
+
+	fn chain_id(&self, at: Option<<B as BlockT>::Hash>) -> Result<u64> {
+		let hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_api_version(&BlockId::Hash(hash), EVM_RUNTIME_RPC_API_V2)?;
+		self.client
+			.runtime_api()
+			.chain_id(&BlockId::Hash(hash))
+			.map_err(|err| internal_err(format!("runtime error: {:?}", err)))
+	}
 }
 
 #[test]
@@ -64,7 +64,7 @@ pub use crate::runner::Runner;
 pub use evm::{Context, ExitError, ExitFatal, ExitReason, ExitRevert, ExitSucceed};
 pub use orml_traits::currency::TransferAll;
 pub use primitives::{
-	evm::{Account, CallInfo, CreateInfo, EvmAddress, Log, Vicinity},
+	evm::{AccessListItem, Account, CallInfo, CreateInfo, EvmAddress, Log, Vicinity},
 	ReserveIdentifier, MIRRORED_NFT_ADDRESS_START,
 };
 
@@ -162,7 +162,7 @@ pub mod module {
 		/// Precompiles associated with this EVM engine.
 		type Precompiles: Precompiles;
 
-		/// Chain ID of EVM.
+		/// The default chain ID of EVM, can be changed by `set_chain_id`.
 		#[pallet::constant]
 		type ChainId: Get<u64>;
 
@@ -293,6 +293,42 @@ pub mod module {
 	#[pallet::getter(fn extrinsic_origin)]
 	pub type ExtrinsicOrigin<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// The EVM addresses that can deploy contracts without paying the deployment fee and
+	/// interact with non-deployed contracts without the developer deposit.
+	///
+	/// FreeDeployers: Vec<EvmAddress>
+	#[pallet::storage]
+	#[pallet::getter(fn free_deployers)]
+	pub type FreeDeployers<T: Config> = StorageValue<_, Vec<EvmAddress>, ValueQuery>;
+
+	/// The maximum gas a single origin can use in a block through the `call`, `create` and
+	/// `create2` extrinsics. RPC calls and internal calls are not limited. No limit if `None`.
+	///
+	/// PerAccountGasLimit: Option<u64>
+	#[pallet::storage]
+	#[pallet::getter(fn per_account_gas_limit)]
+	pub type PerAccountGasLimit<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+	/// The gas used by origins through the extrinsics in the current block, tracked only when
+	/// `PerAccountGasLimit` is set.
+	///
+	/// AccountGasUsages: map EvmAddress => u64
+	#[pallet::storage]
+	#[pallet::getter(fn account_gas_usages)]
+	pub type AccountGasUsages<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, u64, ValueQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultChainId<T: Config>() -> u64 {
+		T::ChainId::get()
+	}
+
+	/// The chain ID of EVM, defaults to the configured `ChainId`.
+	///
+	/// ChainId: u64
+	#[pallet::storage]
+	#[pallet::getter(fn chain_id)]
+	pub type ChainId<T: Config> = StorageValue<_, u64, ValueQuery, DefaultChainId<T>>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub accounts: std::collections::BTreeMap<EvmAddress, GenesisAccount<BalanceOf<T>, T::Index>>,
@@ -329,6 +365,7 @@ pub mod module {
 					let vicinity = Vicinity {
 						gas_price: U256::one(),
 						origin: source,
+						access_list: Vec::new(),
 					};
 					let storage_limit = 0;
 					let contract_address = *address;
@@ -376,7 +413,7 @@ pub mod module {
 	/// EVM events
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId")]
+	#[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance")]
 	pub enum Event<T: Config> {
 		/// Ethereum events from contracts.
 		Log(Log),
@@ -418,6 +455,19 @@ pub mod module {
 		ContractSetCode(EvmAddress),
 		/// Selfdestructed contract code. \[contract\]
 		ContractSelfdestructed(EvmAddress),
+		/// Storage deposit has been refunded from a contract whose storage
+		/// was freed. \[contract, amount\]
+		StorageDepositRefunded(EvmAddress, BalanceOf<T>),
+		/// Added a free deployer. \[address\]
+		FreeDeployerAdded(EvmAddress),
+		/// Removed a free deployer. \[address\]
+		FreeDeployerRemoved(EvmAddress),
+		/// The per account gas limit has been updated. \[new_limit\]
+		PerAccountGasLimitUpdated(Option<u64>),
+		/// Renounced the maintainer. \[contract\]
+		MaintainerRenounced(EvmAddress),
+		/// The chain id has been updated. \[new_chain_id\]
+		ChainIdUpdated(u64),
 	}
 
 	#[pallet::error]
@@ -448,18 +498,30 @@ pub mod module {
 		CannotKillContract,
 		/// Contract address conflicts with the system contract
 		ConflictContractAddress,
+		/// Address is already a free deployer
+		AlreadyFreeDeployer,
+		/// Address is not a free deployer
+		NotFreeDeployer,
+		/// The origin exceeds its gas limit for the current block
+		AccountGasLimitExceeded,
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			let count = AccountGasUsages::<T>::drain().count() as Weight;
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Issue an EVM call operation. This is similar to a message call
-		/// transaction in Ethereum.
+		/// transaction in Ethereum. Access lists are only supported by the RPC,
+		/// the call runs with an empty access list.
 		///
 		/// - `target`: the contract address to call
 		/// - `input`: the data supplied for the call
@@ -478,6 +540,8 @@ pub mod module {
 			let who = ensure_signed(origin)?;
 			let source = T::AddressMapping::get_or_create_evm_address(&who);
 
+			Pallet::<T>::ensure_account_gas_limit(&source, gas_limit)?;
+
 			let info = Runner::<T>::call(
 				source,
 				source,
@@ -486,6 +550,7 @@ pub mod module {
 				value,
 				gas_limit,
 				storage_limit,
+				vec![],
 				T::config(),
 			)?;
 
@@ -496,6 +561,7 @@ pub mod module {
 			}
 
 			let used_gas: u64 = info.used_gas.unique_saturated_into();
+			Pallet::<T>::record_account_gas_usage(&source, used_gas);
 
 			Ok(PostDispatchInfo {
 				actual_weight: Some(T::GasToWeight::convert(used_gas)),
@@ -536,7 +602,17 @@ pub mod module {
 				_payed = imbalance;
 			}
 
-			let info = Runner::<T>::call(from, from, target, input, value, gas_limit, storage_limit, T::config())?;
+			let info = Runner::<T>::call(
+				from,
+				from,
+				target,
+				input,
+				value,
+				gas_limit,
+				storage_limit,
+				vec![],
+				T::config(),
+			)?;
 
 			if info.exit_reason.is_succeed() {
 				Pallet::<T>::deposit_event(Event::<T>::Executed(target));
@@ -569,7 +645,8 @@ pub mod module {
 		}
 
 		/// Issue an EVM create operation. This is similar to a contract
-		/// creation transaction in Ethereum.
+		/// creation transaction in Ethereum. Access lists are only supported by
+		/// the RPC, the creation runs with an empty access list.
 		///
 		/// - `init`: the data supplied for the contract's constructor
 		/// - `value`: the amount sent to the contract upon creation
@@ -586,7 +663,9 @@ pub mod module {
 			let who = ensure_signed(origin)?;
 			let source = T::AddressMapping::get_or_create_evm_address(&who);
 
-			let info = Runner::<T>::create(source, init, value, gas_limit, storage_limit, T::config())?;
+			Pallet::<T>::ensure_account_gas_limit(&source, gas_limit)?;
+
+			let info = Runner::<T>::create(source, init, value, gas_limit, storage_limit, vec![], T::config())?;
 
 			if info.exit_reason.is_succeed() {
 				Pallet::<T>::deposit_event(Event::<T>::Created(info.address));
@@ -595,6 +674,7 @@ pub mod module {
 			}
 
 			let used_gas: u64 = info.used_gas.unique_saturated_into();
+			Pallet::<T>::record_account_gas_usage(&source, used_gas);
 
 			Ok(PostDispatchInfo {
 				actual_weight: Some(T::GasToWeight::convert(used_gas)),
@@ -622,6 +702,8 @@ pub mod module {
 			let who = ensure_signed(origin)?;
 			let source = T::AddressMapping::get_or_create_evm_address(&who);
 
+			Pallet::<T>::ensure_account_gas_limit(&source, gas_limit)?;
+
 			let info = Runner::<T>::create2(source, init, salt, value, gas_limit, storage_limit, T::config())?;
 
 			if info.exit_reason.is_succeed() {
@@ -631,6 +713,7 @@ pub mod module {
 			}
 
 			let used_gas: u64 = info.used_gas.unique_saturated_into();
+			Pallet::<T>::record_account_gas_usage(&source, used_gas);
 
 			Ok(PostDispatchInfo {
 				actual_weight: Some(T::GasToWeight::convert(used_gas)),
@@ -696,6 +779,22 @@ pub mod module {
 			Ok(().into())
 		}
 
+		/// Renounce Contract maintainership. The contract's maintainership can no longer be
+		/// transferred and its code can no longer be changed.
+		///
+		/// - `contract`: the contract whose maintainership is being renounced, the caller must be
+		///   the contract's maintainer
+		#[pallet::weight(<T as Config>::WeightInfo::renounce_maintainer())]
+		#[transactional]
+		pub fn renounce_maintainer(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_transfer_maintainer(who, contract, EvmAddress::zero())?;
+
+			Pallet::<T>::deposit_event(Event::<T>::MaintainerRenounced(contract));
+
+			Ok(().into())
+		}
+
 		/// Mark a given contract as deployed.
 		///
 		/// - `contract`: The contract to mark as deployed, the caller must the contract's
@@ -705,12 +804,14 @@ pub mod module {
 		pub fn deploy(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			let address = T::AddressMapping::get_evm_address(&who).ok_or(Error::<T>::AddressNotMapped)?;
-			T::Currency::transfer(
-				&who,
-				&T::TreasuryAccount::get(),
-				T::DeploymentFee::get(),
-				ExistenceRequirement::AllowDeath,
-			)?;
+			if !Self::is_free_deployer(&address) {
+				T::Currency::transfer(
+					&who,
+					&T::TreasuryAccount::get(),
+					T::DeploymentFee::get(),
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
 			Self::mark_deployed(contract, Some(address))?;
 			Pallet::<T>::deposit_event(Event::<T>::ContractDeployed(contract));
 			Ok(().into())
@@ -729,6 +830,67 @@ pub mod module {
 			Ok(().into())
 		}
 
+		/// Allow an address to deploy contracts without paying the deployment fee, and to
+		/// interact with non-deployed contracts without the developer deposit.
+		///
+		/// - `deployer`: the EVM address to add to the free deployers
+		#[pallet::weight(<T as Config>::WeightInfo::add_free_deployer())]
+		#[transactional]
+		pub fn add_free_deployer(origin: OriginFor<T>, deployer: EvmAddress) -> DispatchResultWithPostInfo {
+			T::FreeDeploymentOrigin::ensure_origin(origin)?;
+			FreeDeployers::<T>::try_mutate(|deployers| -> DispatchResult {
+				ensure!(!deployers.contains(&deployer), Error::<T>::AlreadyFreeDeployer);
+				deployers.push(deployer);
+				Ok(())
+			})?;
+			Pallet::<T>::deposit_event(Event::<T>::FreeDeployerAdded(deployer));
+			Ok(().into())
+		}
+
+		/// Remove an address from the free deployers.
+		///
+		/// - `deployer`: the EVM address to remove from the free deployers
+		#[pallet::weight(<T as Config>::WeightInfo::remove_free_deployer())]
+		#[transactional]
+		pub fn remove_free_deployer(origin: OriginFor<T>, deployer: EvmAddress) -> DispatchResultWithPostInfo {
+			T::FreeDeploymentOrigin::ensure_origin(origin)?;
+			FreeDeployers::<T>::try_mutate(|deployers| -> DispatchResult {
+				let index = deployers
+					.iter()
+					.position(|x| *x == deployer)
+					.ok_or(Error::<T>::NotFreeDeployer)?;
+				deployers.remove(index);
+				Ok(())
+			})?;
+			Pallet::<T>::deposit_event(Event::<T>::FreeDeployerRemoved(deployer));
+			Ok(().into())
+		}
+
+		/// Set the maximum gas a single origin can use through the `call`, `create` and
+		/// `create2` extrinsics in a block.
+		///
+		/// - `new_limit`: the new limit, `None` to remove the limit
+		#[pallet::weight(<T as Config>::WeightInfo::set_per_account_gas_limit())]
+		#[transactional]
+		pub fn set_per_account_gas_limit(origin: OriginFor<T>, new_limit: Option<u64>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			PerAccountGasLimit::<T>::set(new_limit);
+			Pallet::<T>::deposit_event(Event::<T>::PerAccountGasLimitUpdated(new_limit));
+			Ok(().into())
+		}
+
+		/// Set the chain id of EVM.
+		///
+		/// - `new_chain_id`: the new chain id
+		#[pallet::weight(<T as Config>::WeightInfo::set_chain_id())]
+		#[transactional]
+		pub fn set_chain_id(origin: OriginFor<T>, new_chain_id: u64) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ChainId::<T>::put(new_chain_id);
+			Pallet::<T>::deposit_event(Event::<T>::ChainIdUpdated(new_chain_id));
+			Ok(().into())
+		}
+
 		/// Mark the caller's address to allow contract development.
 		/// This allows the address to interact with non-deployed contracts.
 		#[pallet::weight(<T as Config>::WeightInfo::enable_contract_development())]
@@ -877,6 +1039,35 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Ensure `origin` can use `gas_limit` more gas in the current block.
+	pub fn ensure_account_gas_limit(origin: &EvmAddress, gas_limit: u64) -> DispatchResult {
+		if let Some(limit) = Self::per_account_gas_limit() {
+			ensure!(
+				Self::account_gas_usages(origin).saturating_add(gas_limit) <= limit,
+				Error::<T>::AccountGasLimitExceeded
+			);
+		}
+		Ok(())
+	}
+
+	/// Record the gas used by `origin` in the current block.
+	pub fn record_account_gas_usage(origin: &EvmAddress, used_gas: u64) {
+		if Self::per_account_gas_limit().is_some() {
+			AccountGasUsages::<T>::mutate(origin, |usage| *usage = usage.saturating_add(used_gas));
+		}
+	}
+
+	/// Whether the address can deploy contracts for free.
+	pub fn is_free_deployer(address: &EvmAddress) -> bool {
+		Self::free_deployers().contains(address)
+	}
+
+	/// Get the storage deposit reserved by the contract at given address.
+	pub fn contract_storage_deposit(contract: &EvmAddress) -> BalanceOf<T> {
+		let account_id = T::AddressMapping::get_account_id(contract);
+		T::Currency::reserved_balance_named(&RESERVE_ID_STORAGE_DEPOSIT, &account_id)
+	}
+
 	/// Get code hash at given address.
 	pub fn code_hash_at_address(address: &EvmAddress) -> H256 {
 		if let Some(AccountInfo {
@@ -980,7 +1171,7 @@ impl<T: Config> Pallet<T> {
 				.ok_or(Error::<T>::ContractNotFound)?;
 
 			let maintainer = T::AddressMapping::get_evm_address(&who).ok_or(Error::<T>::AddressNotMapped)?;
-			ensure!(contract_info.maintainer == maintainer, Error::<T>::NoPermission);
+			Self::ensure_maintainer(contract_info, &maintainer)?;
 
 			contract_info.maintainer = new_maintainer;
 			Ok(())
@@ -989,6 +1180,16 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Ensure `maintainer` is the maintainer of the contract. A renounced contract has no
+	/// maintainer.
+	fn ensure_maintainer(contract_info: &ContractInfo, maintainer: &EvmAddress) -> DispatchResult {
+		ensure!(
+			!contract_info.maintainer.is_zero() && contract_info.maintainer == *maintainer,
+			Error::<T>::NoPermission
+		);
+		Ok(())
+	}
+
 	/// Mark contract as deployed
 	///
 	/// If maintainer is provider then it will check maintainer
@@ -1000,7 +1201,7 @@ impl<T: Config> Pallet<T> {
 			}) = maybe_account_info.as_mut()
 			{
 				if let Some(maintainer) = maintainer {
-					ensure!(contract_info.maintainer == maintainer, Error::<T>::NoPermission);
+					Self::ensure_maintainer(contract_info, &maintainer)?;
 				}
 				ensure!(!contract_info.deployed, Error::<T>::ContractAlreadyDeployed);
 				contract_info.deployed = true;
@@ -1024,9 +1225,12 @@ impl<T: Config> Pallet<T> {
 				.as_ref()
 				.ok_or(Error::<T>::ContractNotFound)?;
 
+			// renounced contracts can't be changed
+			ensure!(!contract_info.maintainer.is_zero(), Error::<T>::NoPermission);
+
 			let source = if let Either::Right(signer) = root_or_signed {
 				let maintainer = T::AddressMapping::get_evm_address(&signer).ok_or(Error::<T>::AddressNotMapped)?;
-				ensure!(contract_info.maintainer == maintainer, Error::<T>::NoPermission);
+				Self::ensure_maintainer(contract_info, &maintainer)?;
 				ensure!(!contract_info.deployed, Error::<T>::ContractAlreadyDeployed);
 				maintainer
 			} else {
@@ -1065,7 +1269,7 @@ impl<T: Config> Pallet<T> {
 			.as_ref()
 			.ok_or(Error::<T>::ContractNotFound)?;
 
-		ensure!(contract_info.maintainer == *maintainer, Error::<T>::NoPermission);
+		Self::ensure_maintainer(contract_info, maintainer)?;
 		ensure!(!contract_info.deployed, Error::<T>::ContractAlreadyDeployed);
 
 		let storage = Self::remove_contract(&contract, &maintainer)?;
@@ -1115,6 +1319,7 @@ impl<T: Config> EVMTrait<T::AccountId> for Pallet<T> {
 				value,
 				gas_limit,
 				storage_limit,
+				vec![],
 				&config,
 			);
 
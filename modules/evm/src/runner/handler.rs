@@ -21,8 +21,8 @@
 use crate::{
 	precompiles::Precompiles,
 	runner::storage_meter::{StorageMeter, StorageMeterHandler},
-	AccountInfo, AccountStorages, Accounts, AddressMapping, Codes, Config, ContractInfo, Error, Event, Log, Pallet,
-	Vicinity, RESERVE_ID_DEVELOPER_DEPOSIT, RESERVE_ID_STORAGE_DEPOSIT,
+	AccessListItem, AccountInfo, AccountStorages, Accounts, AddressMapping, Codes, Config, ContractInfo, Error, Event,
+	Log, Pallet, Vicinity, RESERVE_ID_DEVELOPER_DEPOSIT, RESERVE_ID_STORAGE_DEPOSIT,
 };
 use evm::{Capture, Context, CreateScheme, ExitError, ExitReason, Opcode, Runtime, Stack, Transfer};
 use evm_gasometer::{self as gasometer, Gasometer};
@@ -43,6 +43,13 @@ use sp_std::{cmp::min, convert::Infallible, marker::PhantomData, prelude::*, rc:
 /// Storage key size and storage value size.
 pub const STORAGE_SIZE: u32 = 64;
 
+/// EIP-2930 intrinsic cost of each address in the access list.
+pub const ACCESS_LIST_ADDRESS_COST: u64 = 2400;
+/// EIP-2930 intrinsic cost of each storage key in the access list.
+pub const ACCESS_LIST_STORAGE_KEY_COST: u64 = 1900;
+/// EIP-2929 cost of reading a storage slot that is already warm.
+pub const WARM_STORAGE_READ_COST: u64 = 100;
+
 pub struct Handler<'vicinity, 'config, 'meter, T: Config> {
 	pub vicinity: &'vicinity Vicinity,
 	pub config: &'config EvmRuntimeConfig,
@@ -146,6 +153,22 @@ impl<'vicinity, 'config, 'meter, T: Config> Handler<'vicinity, 'config, 'meter,
 		self.storage_meter.used_storage()
 	}
 
+	/// Intrinsic gas charged for pre-warming the `access_list`.
+	pub fn access_list_cost(access_list: &[AccessListItem]) -> u64 {
+		access_list.iter().fold(0u64, |cost, item| {
+			cost.saturating_add(ACCESS_LIST_ADDRESS_COST)
+				.saturating_add(ACCESS_LIST_STORAGE_KEY_COST.saturating_mul(item.storage_keys.len() as u64))
+		})
+	}
+
+	/// Whether the storage slot was pre-warmed by the transaction access list.
+	pub fn is_warm_slot(&self, address: H160, index: H256) -> bool {
+		self.vicinity
+			.access_list
+			.iter()
+			.any(|item| item.address == address && item.storage_keys.contains(&index))
+	}
+
 	pub fn execute(
 		&mut self,
 		caller: H160,
@@ -257,6 +280,10 @@ impl<'vicinity, 'config, 'meter, T: Config> Handler<'vicinity, 'config, 'meter,
 	}
 
 	pub fn is_developer_or_contract(caller: &H160) -> bool {
+		if Pallet::<T>::is_free_deployer(caller) {
+			return true;
+		}
+
 		if let Some(AccountInfo { contract_info, .. }) = Accounts::<T>::get(caller) {
 			let account_id = T::AddressMapping::get_account_id(&caller);
 			contract_info.is_some()
@@ -400,7 +427,7 @@ impl<'vicinity, 'config, 'meter, T: Config> HandlerT for Handler<'vicinity, 'con
 	}
 
 	fn chain_id(&self) -> U256 {
-		U256::from(T::ChainId::get())
+		U256::from(Pallet::<T>::chain_id())
 	}
 
 	fn exists(&self, _address: H160) -> bool {
@@ -680,6 +707,11 @@ impl<'vicinity, 'config, 'meter, T: Config> HandlerT for Handler<'vicinity, 'con
 	}
 
 	fn pre_validate(&mut self, context: &Context, opcode: Opcode, stack: &Stack) -> Result<(), ExitError> {
+		// slots in the access list are paid for upfront, reading them is charged as warm.
+		if opcode == Opcode::SLOAD && self.is_warm_slot(context.address, stack.peek(0)?) {
+			return self.gasometer.record_cost(WARM_STORAGE_READ_COST);
+		}
+
 		if let Some(cost) = gasometer::static_opcode_cost(opcode) {
 			self.gasometer.record_cost(cost)?;
 		} else {
@@ -788,6 +820,8 @@ impl<T: Config> StorageMeterHandler for StorageMeterHandlerImpl<T> {
 				BalanceStatus::Reserved,
 			)?;
 			debug_assert!(val.is_zero());
+
+			Pallet::<T>::deposit_event(Event::<T>::StorageDepositRefunded(*contract, amount));
 		};
 
 		Ok(())
@@ -19,7 +19,7 @@
 pub mod handler;
 pub mod storage_meter;
 
-use crate::{AddressMapping, BalanceOf, CallInfo, Config, CreateInfo, Error, Pallet, Vicinity};
+use crate::{AccessListItem, AddressMapping, BalanceOf, CallInfo, Config, CreateInfo, Error, Pallet, Vicinity};
 use evm::{CreateScheme, ExitError, ExitReason};
 use evm_gasometer::{self as gasometer};
 use evm_runtime::Handler as HandlerT;
@@ -47,6 +47,7 @@ impl<T: Config> Runner<T> {
 		storage_limit: u32,
 		assigned_address: Option<H160>,
 		salt: Option<H256>,
+		access_list: Vec<AccessListItem>,
 		tag: &'static str,
 		config: &evm::Config,
 	) -> Result<CreateInfo, DispatchError> {
@@ -59,9 +60,11 @@ impl<T: Config> Runner<T> {
 			storage_limit,
 		);
 
+		let access_list_cost = Handler::<T>::access_list_cost(&access_list);
 		let vicinity = Vicinity {
 			gas_price: U256::one(),
 			origin: source,
+			access_list,
 		};
 
 		let address = if let Some(addr) = assigned_address {
@@ -95,7 +98,9 @@ impl<T: Config> Runner<T> {
 				}
 
 				let transaction_cost = gasometer::call_transaction_cost(&init);
-				if substate.gasometer.record_transaction(transaction_cost).is_err() {
+				if substate.gasometer.record_transaction(transaction_cost).is_err()
+					|| substate.gasometer.record_cost(access_list_cost).is_err()
+				{
 					return TransactionOutcome::Rollback(Err(DispatchError::Other("OutOfGas")));
 				}
 
@@ -177,6 +182,7 @@ impl<T: Config> Runner<T> {
 		value: BalanceOf<T>,
 		gas_limit: u64,
 		storage_limit: u32,
+		access_list: Vec<AccessListItem>,
 		config: &evm::Config,
 	) -> Result<CallInfo, DispatchError> {
 		log::debug!(
@@ -190,9 +196,11 @@ impl<T: Config> Runner<T> {
 			storage_limit,
 		);
 
+		let access_list_cost = Handler::<T>::access_list_cost(&access_list);
 		let vicinity = Vicinity {
 			gas_price: U256::one(),
 			origin,
+			access_list,
 		};
 
 		// if the contract not deployed, the caller must be developer or contract or maintainer.
@@ -210,7 +218,9 @@ impl<T: Config> Runner<T> {
 
 			let code = substate.code(target);
 			let transaction_cost = gasometer::call_transaction_cost(&code);
-			if substate.gasometer.record_transaction(transaction_cost).is_err() {
+			if substate.gasometer.record_transaction(transaction_cost).is_err()
+				|| substate.gasometer.record_cost(access_list_cost).is_err()
+			{
 				return TransactionOutcome::Rollback(Err(DispatchError::Other("OutOfGas")));
 			}
 
@@ -244,6 +254,7 @@ impl<T: Config> Runner<T> {
 		value: BalanceOf<T>,
 		gas_limit: u64,
 		storage_limit: u32,
+		access_list: Vec<AccessListItem>,
 		config: &evm::Config,
 	) -> Result<CreateInfo, DispatchError> {
 		Self::inner_create(
@@ -254,6 +265,7 @@ impl<T: Config> Runner<T> {
 			storage_limit,
 			None,
 			None,
+			access_list,
 			"create",
 			config,
 		)
@@ -276,6 +288,7 @@ impl<T: Config> Runner<T> {
 			storage_limit,
 			None,
 			Some(salt),
+			Vec::new(),
 			"create2",
 			config,
 		)
@@ -298,6 +311,7 @@ impl<T: Config> Runner<T> {
 			storage_limit,
 			Some(assigned_address),
 			None,
+			Vec::new(),
 			"create-system-contract",
 			config,
 		)
@@ -21,7 +21,9 @@
 use super::*;
 use mock::{Event, *};
 
-use crate::runner::handler::{Handler, STORAGE_SIZE};
+use crate::runner::handler::{
+	Handler, ACCESS_LIST_ADDRESS_COST, ACCESS_LIST_STORAGE_KEY_COST, STORAGE_SIZE, WARM_STORAGE_READ_COST,
+};
 use frame_support::{assert_err, assert_noop, assert_ok};
 use sp_core::{
 	bytes::{from_hex, to_hex},
@@ -89,6 +91,7 @@ fn should_create_and_call_contract() {
 			0,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		).unwrap();
 		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
@@ -116,6 +119,7 @@ fn should_create_and_call_contract() {
 			0,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		).unwrap();
 		assert_eq!(
@@ -159,8 +163,16 @@ fn create_reverts_with_message() {
 		"0x6080604052348015600f57600080fd5b5060006083576040517f08c379a000000000000000000000000000000000000000000000000000000000815260040180806020018281038252600d8152602001807f6572726f72206d6573736167650000000000000000000000000000000000000081525060200191505060405180910390fd5b603e8060906000396000f3fe6080604052600080fdfea265627a7a723158204741083d83bf4e3ee8099dd0b3471c81061237c2e8eccfcb513dfa4c04634b5b64736f6c63430005110032"
 	).unwrap();
 	new_test_ext().execute_with(|| {
-		let result =
-			Runner::<Test>::create(alice(), contract, 0, 12_000_000, 12_000_000, <Test as Config>::config()).unwrap();
+		let result = Runner::<Test>::create(
+			alice(),
+			contract,
+			0,
+			12_000_000,
+			12_000_000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
 		assert_eq!(result.exit_reason, ExitReason::Revert(ExitRevert::Reverted));
 		assert!(String::from_utf8_lossy(&result.output).contains("error message"));
 	});
@@ -188,6 +200,7 @@ fn call_reverts_with_message() {
 			0,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		).unwrap();
 
@@ -212,6 +225,7 @@ fn call_reverts_with_message() {
 			0,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		).unwrap();
 
@@ -254,8 +268,16 @@ fn should_deploy_payable_contract() {
 			from_hex("0x000000000000000000000000000000000000000000000000000000000000007b").unwrap();
 		contract.append(&mut stored_value.clone());
 
-		let result =
-			Runner::<Test>::create(alice(), contract, amount, 100000, 100000, <Test as Config>::config()).unwrap();
+		let result = Runner::<Test>::create(
+			alice(),
+			contract,
+			amount,
+			100000,
+			100000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
 		let contract_address = result.address;
 
 		#[cfg(not(feature = "with-ethereum-compatibility"))]
@@ -277,6 +299,7 @@ fn should_deploy_payable_contract() {
 			amount,
 			100000,
 			100000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -328,8 +351,16 @@ fn should_transfer_from_contract() {
 	new_test_ext().execute_with(|| {
 		let amount = 1000u64;
 
-		let result = Runner::<Test>::create(alice(), contract, 0, 10000000, 10000000, <Test as Config>::config())
-			.expect("create shouldn't fail");
+		let result = Runner::<Test>::create(
+			alice(),
+			contract,
+			0,
+			10000000,
+			10000000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.expect("create shouldn't fail");
 		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
 		assert_eq!(result.used_storage, 892);
 
@@ -353,6 +384,7 @@ fn should_transfer_from_contract() {
 			amount,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -373,6 +405,7 @@ fn should_transfer_from_contract() {
 			amount,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -393,6 +426,7 @@ fn should_transfer_from_contract() {
 			amount,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -427,6 +461,7 @@ fn contract_should_deploy_contracts() {
 			0,
 			1000000000,
 			1000000000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -460,6 +495,7 @@ fn contract_should_deploy_contracts() {
 			amount,
 			1000000000,
 			1000000000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -502,6 +538,7 @@ fn contract_should_deploy_contracts_without_payable() {
 			0,
 			1000000000,
 			1000000000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -527,6 +564,7 @@ fn contract_should_deploy_contracts_without_payable() {
 			0,
 			1000000000,
 			1000000000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -562,7 +600,16 @@ fn deploy_factory() {
 		"0x608060405234801561001057600080fd5b5060405161001d90610121565b604051809103906000f080158015610039573d6000803e3d6000fd5b506000806101000a81548173ffffffffffffffffffffffffffffffffffffffff021916908373ffffffffffffffffffffffffffffffffffffffff1602179055506000809054906101000a900473ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff1663c29855786040518163ffffffff1660e01b815260040160206040518083038186803b1580156100e057600080fd5b505afa1580156100f4573d6000803e3d6000fd5b505050506040513d602081101561010a57600080fd5b81019080805190602001909291905050505061012d565b60a58061017983390190565b603e8061013b6000396000f3fe6080604052600080fdfea265627a7a7231582064177030ee644a03aaf8d65027df9e0331c8bc4b161de25bfb8aa3142848e0f864736f6c634300051100326080604052348015600f57600080fd5b5060878061001e6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063c298557814602d575b600080fd5b60336049565b6040518082815260200191505060405180910390f35b6000607b90509056fea265627a7a7231582031e5a4abae00962cfe9875df1b5b0d3ce6624e220cb8c714a948794fcddb6b4f64736f6c63430005110032"
 	).unwrap();
 	new_test_ext().execute_with(|| {
-		let result = Runner::<Test>::create(alice(), contract, 0, 2_000_000, 5000, <Test as Config>::config()).unwrap();
+		let result = Runner::<Test>::create(
+			alice(),
+			contract,
+			0,
+			2_000_000,
+			5000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
 		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
 		assert_eq!(result.used_gas.as_u64(), 124_479u64);
 		assert_eq!(result.used_storage, 461);
@@ -655,8 +702,16 @@ fn should_transfer_maintainer() {
 		"0x608060405234801561001057600080fd5b5060405161001d90610121565b604051809103906000f080158015610039573d6000803e3d6000fd5b506000806101000a81548173ffffffffffffffffffffffffffffffffffffffff021916908373ffffffffffffffffffffffffffffffffffffffff1602179055506000809054906101000a900473ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff1663c29855786040518163ffffffff1660e01b815260040160206040518083038186803b1580156100e057600080fd5b505afa1580156100f4573d6000803e3d6000fd5b505050506040513d602081101561010a57600080fd5b81019080805190602001909291905050505061012d565b60a58061017983390190565b603e8061013b6000396000f3fe6080604052600080fdfea265627a7a7231582064177030ee644a03aaf8d65027df9e0331c8bc4b161de25bfb8aa3142848e0f864736f6c634300051100326080604052348015600f57600080fd5b5060878061001e6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063c298557814602d575b600080fd5b60336049565b6040518082815260200191505060405180910390f35b6000607b90509056fea265627a7a7231582031e5a4abae00962cfe9875df1b5b0d3ce6624e220cb8c714a948794fcddb6b4f64736f6c63430005110032"
 	).unwrap();
 	new_test_ext().execute_with(|| {
-		let result =
-			Runner::<Test>::create(alice(), contract, 0, 12_000_000, 12_000_000, <Test as Config>::config()).unwrap();
+		let result = Runner::<Test>::create(
+			alice(),
+			contract,
+			0,
+			12_000_000,
+			12_000_000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
 		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
 		assert_eq!(result.used_storage, 461);
 		let alice_balance = INITIAL_BALANCE - 461 * <Test as Config>::StorageDepositPerByte::get();
@@ -688,6 +743,73 @@ fn should_transfer_maintainer() {
 	});
 }
 
+#[test]
+fn should_renounce_maintainer() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Test as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Test as Config>::AddressMapping::get_account_id(&bob());
+
+		let result = Runner::<Test>::create(
+			alice(),
+			contract.clone(),
+			0,
+			21_000_000,
+			21_000_000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.address;
+
+		assert_noop!(
+			EVM::renounce_maintainer(Origin::signed(bob_account_id), contract_address),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(EVM::renounce_maintainer(
+			Origin::signed(alice_account_id.clone()),
+			contract_address
+		));
+		System::assert_last_event(Event::EVM(crate::Event::MaintainerRenounced(contract_address)));
+		assert_eq!(
+			Accounts::<Test>::get(contract_address)
+				.unwrap()
+				.contract_info
+				.unwrap()
+				.maintainer,
+			H160::zero()
+		);
+
+		assert_noop!(
+			EVM::transfer_maintainer(Origin::signed(alice_account_id.clone()), contract_address, bob()),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			EVM::renounce_maintainer(Origin::signed(alice_account_id.clone()), contract_address),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			EVM::set_code(Origin::signed(alice_account_id), contract_address, contract.clone()),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			EVM::set_code(Origin::root(), contract_address, contract),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
 #[test]
 fn should_deploy() {
 	// pragma solidity ^0.5.0;
@@ -717,13 +839,14 @@ fn should_deploy() {
 			0,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		).unwrap();
 		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
 		assert_eq!(result.used_storage, 0);
 
 		// create contract
-		let result = Runner::<Test>::create(alice(), contract, 0, 21_000_000, 21_000_000, <Test as Config>::config()).unwrap();
+		let result = Runner::<Test>::create(alice(), contract, 0, 21_000_000, 21_000_000, vec![], <Test as Config>::config()).unwrap();
 		let contract_address = result.address;
 
 		assert_eq!(result.used_storage, 284);
@@ -745,6 +868,7 @@ fn should_deploy() {
 			0,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		));
 
@@ -757,6 +881,7 @@ fn should_deploy() {
 			0,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		), Error::<Test>::NoPermission);
 
@@ -770,6 +895,7 @@ fn should_deploy() {
 			0,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		));
 
@@ -792,6 +918,7 @@ fn should_deploy() {
 			0,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		));
 
@@ -818,7 +945,7 @@ fn should_deploy_free() {
 		assert_noop!(EVM::deploy_free(Origin::signed(CouncilAccount::get()), H160::default()), Error::<Test>::ContractNotFound);
 
 		// create contract
-		let result = Runner::<Test>::create(alice(), contract, 0, 21_000_000, 21_000_000, <Test as Config>::config()).unwrap();
+		let result = Runner::<Test>::create(alice(), contract, 0, 21_000_000, 21_000_000, vec![], <Test as Config>::config()).unwrap();
 		let contract_address = result.address;
 
 		// multiply(2, 3)
@@ -835,6 +962,7 @@ fn should_deploy_free() {
 			0,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		), Error::<Test>::NoPermission);
 
@@ -849,6 +977,7 @@ fn should_deploy_free() {
 			0,
 			1000000,
 			1000000,
+			vec![],
 			<Test as Config>::config(),
 		));
 
@@ -857,6 +986,287 @@ fn should_deploy_free() {
 	});
 }
 
+#[test]
+fn free_deployers_should_work() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Test as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Test as Config>::AddressMapping::get_account_id(&bob());
+
+		assert_noop!(
+			EVM::add_free_deployer(Origin::signed(alice_account_id.clone()), alice()),
+			BadOrigin
+		);
+		assert_ok!(EVM::add_free_deployer(Origin::signed(CouncilAccount::get()), alice()));
+		System::assert_last_event(Event::EVM(crate::Event::FreeDeployerAdded(alice())));
+		assert_noop!(
+			EVM::add_free_deployer(Origin::signed(CouncilAccount::get()), alice()),
+			Error::<Test>::AlreadyFreeDeployer
+		);
+		assert_eq!(EVM::free_deployers(), vec![alice()]);
+
+		// free deployer deploys without the deployment fee
+		let result = Runner::<Test>::create(
+			alice(),
+			contract.clone(),
+			0,
+			21_000_000,
+			21_000_000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
+		let alice_balance = balance(alice());
+		assert_ok!(EVM::deploy(Origin::signed(alice_account_id.clone()), result.address));
+		assert_eq!(balance(alice()), alice_balance);
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), 0);
+
+		// others still pay the deployment fee
+		let result = Runner::<Test>::create(
+			bob(),
+			contract.clone(),
+			0,
+			21_000_000,
+			21_000_000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
+		let bob_balance = balance(bob());
+		assert_ok!(EVM::deploy(Origin::signed(bob_account_id.clone()), result.address));
+		assert_eq!(balance(bob()), bob_balance - DeploymentFee::get());
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), DeploymentFee::get());
+
+		// free deployer can call non-deployed contracts without the developer deposit
+		let result = Runner::<Test>::create(
+			alice(),
+			contract,
+			0,
+			21_000_000,
+			21_000_000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
+		assert_noop!(
+			Runner::<Test>::call(
+				bob(),
+				bob(),
+				result.address,
+				vec![],
+				0,
+				1000000,
+				1000000,
+				vec![],
+				<Test as Config>::config(),
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(EVM::add_free_deployer(Origin::signed(CouncilAccount::get()), bob()));
+		assert_ok!(Runner::<Test>::call(
+			bob(),
+			bob(),
+			result.address,
+			vec![],
+			0,
+			1000000,
+			1000000,
+			vec![],
+			<Test as Config>::config(),
+		));
+		assert_eq!(reserved_balance(bob()), 0);
+
+		assert_noop!(
+			EVM::remove_free_deployer(Origin::signed(alice_account_id), alice()),
+			BadOrigin
+		);
+		assert_ok!(EVM::remove_free_deployer(
+			Origin::signed(CouncilAccount::get()),
+			alice()
+		));
+		System::assert_last_event(Event::EVM(crate::Event::FreeDeployerRemoved(alice())));
+		assert_noop!(
+			EVM::remove_free_deployer(Origin::signed(CouncilAccount::get()), alice()),
+			Error::<Test>::NotFreeDeployer
+		);
+		assert_eq!(EVM::free_deployers(), vec![bob()]);
+	});
+}
+
+#[test]
+fn per_account_gas_limit_should_work() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+	// multiply(2, 3)
+	let multiply = from_hex(
+		"0x165c4a1600000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000003"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = Runner::<Test>::create(
+			alice(),
+			contract.clone(),
+			0,
+			21_000_000,
+			21_000_000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.address;
+
+		let alice_account_id = <Test as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Test as Config>::AddressMapping::get_account_id(&bob());
+		assert_ok!(EVM::deploy(Origin::signed(alice_account_id.clone()), contract_address));
+
+		let call = |who: &AccountId32, gas_limit: u64| {
+			EVM::call(
+				Origin::signed(who.clone()),
+				contract_address,
+				multiply.clone(),
+				0,
+				gas_limit,
+				1000000,
+			)
+		};
+		let rpc_call = |gas_limit: u64| {
+			Runner::<Test>::call(
+				alice(),
+				alice(),
+				contract_address,
+				multiply.clone(),
+				0,
+				gas_limit,
+				1000000,
+				vec![],
+				<Test as Config>::config(),
+			)
+		};
+
+		// no limit by default
+		let used_gas = rpc_call(1000000).unwrap().used_gas.as_u64();
+		assert_ok!(call(&alice_account_id, used_gas));
+		assert_eq!(EVM::account_gas_usages(alice()), 0);
+
+		assert_noop!(
+			EVM::set_per_account_gas_limit(Origin::signed(CouncilAccount::get()), Some(0)),
+			BadOrigin
+		);
+		assert_ok!(EVM::set_per_account_gas_limit(Origin::root(), Some(used_gas * 5 / 2)));
+		System::assert_last_event(Event::EVM(crate::Event::PerAccountGasLimitUpdated(Some(
+			used_gas * 5 / 2,
+		))));
+
+		// two calls within the limit
+		assert_ok!(call(&alice_account_id, used_gas));
+		System::assert_last_event(Event::EVM(crate::Event::Executed(contract_address)));
+		assert_ok!(call(&alice_account_id, used_gas));
+		System::assert_last_event(Event::EVM(crate::Event::Executed(contract_address)));
+		assert_eq!(EVM::account_gas_usages(alice()), used_gas * 2);
+
+		// the third one exceeds the limit
+		assert_noop!(
+			call(&alice_account_id, used_gas),
+			Error::<Test>::AccountGasLimitExceeded
+		);
+		assert_noop!(
+			EVM::create(
+				Origin::signed(alice_account_id.clone()),
+				contract.clone(),
+				0,
+				used_gas,
+				1000000
+			),
+			Error::<Test>::AccountGasLimitExceeded
+		);
+
+		// calls not made by the extrinsics are not limited nor tracked
+		assert_ok!(rpc_call(1000000));
+		assert_ok!(rpc_call(1000000));
+		assert_eq!(EVM::account_gas_usages(alice()), used_gas * 2);
+
+		// other origins are tracked separately
+		assert_ok!(call(&bob_account_id, used_gas));
+		assert_eq!(EVM::account_gas_usages(bob()), used_gas);
+
+		// reset in the next block
+		EVM::on_initialize(2);
+		assert_eq!(EVM::account_gas_usages(alice()), 0);
+		assert_ok!(call(&alice_account_id, used_gas));
+
+		assert_ok!(EVM::set_per_account_gas_limit(Origin::root(), None));
+		assert_ok!(call(&alice_account_id, used_gas));
+		assert_ok!(call(&alice_account_id, used_gas));
+		assert_ok!(call(&alice_account_id, used_gas));
+	});
+}
+
+#[test]
+fn set_chain_id_should_work() {
+	// runtime code: CHAINID PUSH1 0 MSTORE PUSH1 32 PUSH1 0 RETURN
+	let contract = from_hex("0x6009600c60003960096000f34660005260206000f3").unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = Runner::<Test>::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			1000000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		let contract_address = result.address;
+
+		let chain_id = || {
+			let result = Runner::<Test>::call(
+				alice(),
+				alice(),
+				contract_address,
+				vec![],
+				0,
+				1000000,
+				1000000,
+				vec![],
+				<Test as Config>::config(),
+			)
+			.unwrap();
+			assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+			U256::from(result.output.as_slice())
+		};
+
+		// defaults to the configured chain id
+		assert_eq!(EVM::chain_id(), <Test as Config>::ChainId::get());
+		assert_eq!(chain_id(), U256::from(<Test as Config>::ChainId::get()));
+
+		assert_noop!(EVM::set_chain_id(Origin::signed(CouncilAccount::get()), 686), BadOrigin);
+		assert_ok!(EVM::set_chain_id(Origin::root(), 686));
+		System::assert_last_event(Event::EVM(crate::Event::ChainIdUpdated(686)));
+		assert_eq!(EVM::chain_id(), 686);
+		assert_eq!(chain_id(), U256::from(686));
+	});
+}
+
 #[test]
 fn should_enable_contract_development() {
 	new_test_ext().execute_with(|| {
@@ -933,6 +1343,7 @@ fn should_set_code() {
 			0,
 			21_000_000,
 			21_000_000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -1003,8 +1414,16 @@ fn should_selfdestruct() {
 		contract.append(&mut stored_value.clone());
 
 		// create contract
-		let result =
-			Runner::<Test>::create(alice(), contract, amount, 100000, 100000, <Test as Config>::config()).unwrap();
+		let result = Runner::<Test>::create(
+			alice(),
+			contract,
+			amount,
+			100000,
+			100000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
 
 		let contract_address = result.address;
 		assert_eq!(result.used_storage, 287);
@@ -1066,8 +1485,16 @@ fn storage_limit_should_work() {
 	).unwrap();
 
 	new_test_ext().execute_with(|| {
-		let result =
-			Runner::<Test>::create(alice(), contract.clone(), 0, 200_000, 1000, <Test as Config>::config()).unwrap();
+		let result = Runner::<Test>::create(
+			alice(),
+			contract.clone(),
+			0,
+			200_000,
+			1000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
 		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
 		assert_eq!(result.used_storage, 516);
 		let alice_balance = INITIAL_BALANCE - 516 * <Test as Config>::StorageDepositPerByte::get();
@@ -1096,6 +1523,7 @@ fn storage_limit_should_work() {
 			amount,
 			1000000000,
 			0,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -1114,6 +1542,7 @@ fn storage_limit_should_work() {
 			amount,
 			1000000000,
 			1000000000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -1132,6 +1561,7 @@ fn storage_limit_should_work() {
 			amount,
 			1000000000,
 			127,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -1150,6 +1580,7 @@ fn storage_limit_should_work() {
 			amount,
 			1000000000,
 			1000000000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -1187,6 +1618,7 @@ fn evm_execute_mode_should_work() {
 			0,
 			1000000000,
 			1000000000,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -1348,7 +1780,8 @@ fn should_update_storage() {
 
 	new_test_ext().execute_with(|| {
 		// create contract
-		let result = Runner::<Test>::create(alice(), contract, 0, 500000, 100000, <Test as Config>::config()).unwrap();
+		let result =
+			Runner::<Test>::create(alice(), contract, 0, 500000, 100000, vec![], <Test as Config>::config()).unwrap();
 
 		let contract_address = result.address;
 
@@ -1373,6 +1806,7 @@ fn should_update_storage() {
 				0,
 				1000000,
 				0,
+				vec![],
 				<Test as Config>::config(),
 			),
 			DispatchError::Other("OutOfStorage")
@@ -1387,6 +1821,7 @@ fn should_update_storage() {
 			0,
 			1000000,
 			STORAGE_SIZE,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -1405,6 +1840,7 @@ fn should_update_storage() {
 			0,
 			1000000,
 			STORAGE_SIZE,
+			vec![],
 			<Test as Config>::config(),
 		)
 		.unwrap();
@@ -1415,3 +1851,129 @@ fn should_update_storage() {
 		assert_eq!(ContractStorageSizes::<Test>::get(&contract_address), used_storage);
 	});
 }
+
+#[test]
+fn access_list_should_prewarm_storage_slots() {
+	// runtime code reads slot 0 ten times: (PUSH1 0x00 SLOAD POP) * 10, STOP
+	let runtime_code = "60005450".repeat(10) + "00";
+	// init code copies the 41 bytes runtime code to memory and returns it
+	let contract = from_hex(&("0x602980600b6000396000f3".to_string() + &runtime_code)).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = Runner::<Test>::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			1000000,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		let contract_address = result.address;
+
+		let call_with_access_list = |access_list: Vec<AccessListItem>| {
+			let result = Runner::<Test>::call(
+				alice(),
+				alice(),
+				contract_address,
+				vec![],
+				0,
+				1000000,
+				1000000,
+				access_list,
+				<Test as Config>::config(),
+			)
+			.unwrap();
+			assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+			result.used_gas.as_u64()
+		};
+
+		let used_gas = call_with_access_list(vec![]);
+
+		// listed slot is paid upfront and read at warm cost
+		let used_gas_with_access_list = call_with_access_list(vec![AccessListItem {
+			address: contract_address,
+			storage_keys: vec![H256::zero()],
+		}]);
+		assert!(used_gas_with_access_list < used_gas);
+		assert_eq!(
+			used_gas_with_access_list,
+			used_gas + ACCESS_LIST_ADDRESS_COST + ACCESS_LIST_STORAGE_KEY_COST
+				- 10 * (<Test as Config>::config().gas_sload - WARM_STORAGE_READ_COST)
+		);
+
+		// unused entries only add the intrinsic cost
+		let used_gas_with_unused_access_list = call_with_access_list(vec![AccessListItem {
+			address: contract_address,
+			storage_keys: vec![H256::from_low_u64_be(1)],
+		}]);
+		assert_eq!(
+			used_gas_with_unused_access_list,
+			used_gas + ACCESS_LIST_ADDRESS_COST + ACCESS_LIST_STORAGE_KEY_COST
+		);
+	});
+}
+
+#[test]
+fn storage_deposit_refunded_when_storage_freed() {
+	// the `Test` contract of `should_update_storage`
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b50602a6000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002081905550610154806100646000396000f3fe608060405234801561001057600080fd5b50600436106100365760003560e01c806354fe9fd71461003b57806360fe47b114610093575b600080fd5b61007d6004803603602081101561005157600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff1690602001909291905050506100c1565b6040518082815260200191505060405180910390f35b6100bf600480360360208110156100a957600080fd5b81019080803590602001909291905050506100d9565b005b60006020528060005260406000206000915090505481565b806000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020819055505056fea265627a7a723158207ab6991e97c9c12f57d81df0c7f955435418354adeb26116b581d7f2f035ca8f64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let deposit_per_byte = <Test as Config>::StorageDepositPerByte::get();
+
+		let result =
+			Runner::<Test>::create(alice(), contract, 0, 500000, 100000, vec![], <Test as Config>::config()).unwrap();
+		let contract_address = result.address;
+		let initial_deposit = result.used_storage as u64 * deposit_per_byte;
+		assert_eq!(EVM::contract_storage_deposit(&contract_address), initial_deposit);
+
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		deploy_free(contract_address);
+
+		// call method `set(123)`, storage grows
+		let result = Runner::<Test>::call(
+			bob(),
+			alice(),
+			contract_address,
+			from_hex("0x60fe47b1000000000000000000000000000000000000000000000000000000000000007b").unwrap(),
+			0,
+			1000000,
+			STORAGE_SIZE,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.used_storage, STORAGE_SIZE as i32);
+		assert_eq!(
+			EVM::contract_storage_deposit(&contract_address),
+			initial_deposit + STORAGE_SIZE as u64 * deposit_per_byte
+		);
+
+		// call method `set(0)`, storage shrinks
+		let result = Runner::<Test>::call(
+			bob(),
+			alice(),
+			contract_address,
+			from_hex("0x60fe47b10000000000000000000000000000000000000000000000000000000000000000").unwrap(),
+			0,
+			1000000,
+			STORAGE_SIZE,
+			vec![],
+			<Test as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.used_storage, -(STORAGE_SIZE as i32));
+		assert_eq!(EVM::contract_storage_deposit(&contract_address), initial_deposit);
+
+		let refunded = Event::EVM(crate::Event::StorageDepositRefunded(
+			contract_address,
+			STORAGE_SIZE as u64 * deposit_per_byte,
+		));
+		assert!(System::events().iter().any(|record| record.event == refunded));
+	});
+}
@@ -21,7 +21,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use frame_benchmarking::{account, benchmarks};
-use frame_support::traits::Get;
+use frame_support::traits::{Get, OnInitialize};
 use frame_system::RawOrigin;
 
 pub use crate::*;
@@ -31,6 +31,19 @@ pub struct Module<T: Config>(crate::Pallet<T>);
 const SEED: u32 = 0;
 
 benchmarks! {
+	on_initialize {
+		let n in 0 .. 100;
+		let cooldown: T::BlockNumber = 10u32.into();
+		module::Pallet::<T>::set_mint_cooldown(RawOrigin::Root.into(), cooldown)?;
+		for i in 0 .. n {
+			let who: T::AccountId = account("minter", i, SEED);
+			module::LastMintBlock::<T>::insert(&who, T::BlockNumber::from(1u32));
+			module::MintCooldownExpiry::<T>::append(T::BlockNumber::from(11u32), &who);
+		}
+	}: {
+		module::Pallet::<T>::on_initialize(11u32.into());
+	}
+
 	// Benchmark mint
 	mint {
 		let amount = 1_000_000_000_000;
@@ -43,6 +56,16 @@ benchmarks! {
 
 	set_minting_cap {
 	}: _(RawOrigin::Root, 1_000_000_000_000_000_000)
+
+	set_per_era_mint_cap {
+	}: _(RawOrigin::Root, Some(1_000_000_000_000_000_000))
+
+	set_mint_cooldown {
+	}: _(RawOrigin::Root, 100u32.into())
+
+	record_staking_reward {
+		module::Pallet::<T>::set_total_staking_currency(RawOrigin::Root.into(), 1_000_000_000_000_000)?;
+	}: _(RawOrigin::Root, 1, 1_000_000_000_000)
 }
 
 #[cfg(test)]
@@ -53,7 +76,7 @@ mod benchmark_mock {
 	use crate as module_homa_lite;
 	use frame_support::{ord_parameter_types, parameter_types};
 	use frame_system::EnsureRoot;
-	use mock::{MockXcm, ACALA, KSM, LKSM, MOCK_XCM_DESTINATION, ROOT};
+	use mock::{MockRelayBlockNumberProvider, MockXcm, ACALA, KSM, LKSM, MOCK_XCM_DESTINATION, ROOT};
 	use module_support::mocks::MockAddressMapping;
 	use orml_traits::parameter_type_with_key;
 	use primitives::Amount;
@@ -131,6 +154,7 @@ mod benchmark_mock {
 		module_currencies::BasicCurrencyAdapter<Runtime, PalletBalances, Amount, BlockNumber>;
 
 	parameter_types! {
+		pub const TreasuryAccount: AccountId = AccountId::new([255u8; 32]);
 		pub const GetNativeCurrencyId: CurrencyId = ACALA;
 	}
 
@@ -142,6 +166,10 @@ mod benchmark_mock {
 		type WeightInfo = ();
 		type AddressMapping = MockAddressMapping;
 		type EVMBridge = ();
+		type SweepOrigin = frame_system::EnsureRoot<AccountId>;
+		type TreasuryAccount = TreasuryAccount;
+		type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+		type PreTransferFilter = ();
 	}
 
 	parameter_types! {
@@ -152,6 +180,7 @@ mod benchmark_mock {
 		pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 		pub MaxRewardPerEra: Permill = Permill::from_rational(411u32, 1_000_000u32);
 		pub const MintFee: Balance = 10_000_000;
+		pub const RelaychainEraLength: BlockNumber = 14_400;
 	}
 	ord_parameter_types! {
 		pub const Root: AccountId = ROOT;
@@ -170,6 +199,8 @@ mod benchmark_mock {
 		type DefaultExchangeRate = DefaultExchangeRate;
 		type MaxRewardPerEra = MaxRewardPerEra;
 		type MintFee = MintFee;
+		type RelaychainBlockNumber = MockRelayBlockNumberProvider;
+		type RelaychainEraLength = RelaychainEraLength;
 	}
 
 	type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -216,6 +247,12 @@ mod tests {
 	use benchmark_mock::*;
 	use frame_support::assert_ok;
 
+	#[test]
+	fn test_on_initialize() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_on_initialize::<Runtime>());
+		});
+	}
 	#[test]
 	fn test_mint() {
 		ExtBuilder::default().build().execute_with(|| {
@@ -234,4 +271,22 @@ mod tests {
 			assert_ok!(test_benchmark_set_minting_cap::<Runtime>());
 		});
 	}
+	#[test]
+	fn test_set_per_era_mint_cap() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_set_per_era_mint_cap::<Runtime>());
+		});
+	}
+	#[test]
+	fn test_set_mint_cooldown() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_set_mint_cooldown::<Runtime>());
+		});
+	}
+	#[test]
+	fn test_record_staking_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_record_staking_reward::<Runtime>());
+		});
+	}
 }
@@ -28,8 +28,12 @@ use frame_support::{pallet_prelude::*, transactional};
 use frame_system::{ensure_signed, pallet_prelude::*};
 use module_support::{ExchangeRate, Ratio};
 use orml_traits::{MultiCurrency, XcmTransfer};
-use primitives::{Balance, CurrencyId};
-use sp_runtime::{traits::Zero, ArithmeticError, FixedPointNumber, Permill};
+use primitives::{Balance, CurrencyId, EraIndex};
+use sp_runtime::{
+	offchain::storage_lock::BlockNumberProvider,
+	traits::{CheckedDiv, SaturatedConversion, Saturating, Zero},
+	ArithmeticError, FixedPointNumber, Permill,
+};
 use sp_std::{ops::Mul, prelude::*};
 use xcm::opaque::v0::{MultiLocation, Outcome};
 
@@ -83,6 +87,13 @@ pub mod module {
 		/// The fixed cost of transaction fee for XCM transfers.
 		#[pallet::constant]
 		type MintFee: Get<Balance>;
+
+		/// Block number provider for the relaychain.
+		type RelaychainBlockNumber: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
+
+		/// The length of an era on the relaychain, in relaychain blocks.
+		#[pallet::constant]
+		type RelaychainEraLength: Get<Self::BlockNumber>;
 	}
 
 	#[pallet::error]
@@ -95,6 +106,12 @@ pub mod module {
 		ExceededStakingCurrencyMintCap,
 		/// Error has occurred during Cross-chain transfer.
 		XcmTransferFailed,
+		/// The amount of Staking currency used in the current era has exceeded the per-era cap.
+		ExceededPerEraMintCap,
+		/// The account has already minted within the current mint cooldown window.
+		MintCooldownNotElapsed,
+		/// The staking rewards of the era, or a later era, have already been recorded.
+		StakingRewardAlreadyRecorded,
 	}
 
 	#[pallet::event]
@@ -111,6 +128,16 @@ pub mod module {
 
 		/// The mint cap for Staking currency is updated.\[new_cap\]
 		StakingCurrencyMintCapUpdated(Balance),
+
+		/// The per-era mint cap for Staking currency is updated.\[new_cap\]
+		PerEraMintCapUpdated(Option<Balance>),
+
+		/// The per-account mint cooldown is updated.\[new_cooldown_blocks\]
+		MintCooldownBlocksUpdated(T::BlockNumber),
+
+		/// The staking rewards earned on the relaychain in an era are added to the total amount
+		/// of the staking currency, capped by `T::MaxRewardPerEra`.\[era, reward_recorded\]
+		StakingRewardRecorded(EraIndex, Balance),
 	}
 
 	/// The total amount of the staking currency on the relaychain.
@@ -126,9 +153,75 @@ pub mod module {
 	#[pallet::getter(fn staking_currency_mint_cap)]
 	pub type StakingCurrencyMintCap<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// The cap on the amount of staking currency allowed to mint Liquid currency within a single
+	/// relaychain era. No per-era cap is applied if it is not set.
+	/// PerEraMintCap: value: Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn per_era_mint_cap)]
+	pub type PerEraMintCap<T: Config> = StorageValue<_, Balance, OptionQuery>;
+
+	/// The relaychain era of the latest mint, and the total amount of staking currency used to
+	/// mint Liquid currency within that era.
+	/// CurrentEraMinted: value: (era: EraIndex, amount: Balance)
+	#[pallet::storage]
+	#[pallet::getter(fn current_era_minted)]
+	pub type CurrentEraMinted<T: Config> = StorageValue<_, (EraIndex, Balance), ValueQuery>;
+
+	/// The number of blocks an account must wait after a mint before it can mint again.
+	/// No cooldown is applied if it is zero.
+	/// MintCooldownBlocks: value: T::BlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn mint_cooldown_blocks)]
+	pub type MintCooldownBlocks<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The block number of the latest mint of an account.
+	/// LastMintBlock: map AccountId => Option<T::BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn last_mint_block)]
+	pub type LastMintBlock<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
+	/// The accounts whose mint cooldown expires at a block, their `LastMintBlock` is pruned in
+	/// that block.
+	/// MintCooldownExpiry: map BlockNumber => Vec<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn mint_cooldown_expiry)]
+	pub type MintCooldownExpiry<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, Vec<T::AccountId>, ValueQuery>;
+
+	/// The latest relaychain era whose staking rewards are recorded.
+	/// LastRecordedRewardEra: value: Option<EraIndex>
+	#[pallet::storage]
+	#[pallet::getter(fn last_recorded_reward_era)]
+	pub type LastRecordedRewardEra<T: Config> = StorageValue<_, EraIndex, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Prune the `LastMintBlock` of the accounts whose mint cooldown expires in this block.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let accounts = MintCooldownExpiry::<T>::take(now);
+			if accounts.is_empty() {
+				return T::WeightInfo::on_initialize(0);
+			}
+
+			let cooldown = Self::mint_cooldown_blocks();
+			for who in accounts.iter() {
+				if let Some(last_mint_block) = Self::last_mint_block(who) {
+					let expiry = last_mint_block.saturating_add(cooldown);
+					if expiry <= now {
+						LastMintBlock::<T>::remove(who);
+					} else {
+						// the cooldown has been increased since the mint
+						MintCooldownExpiry::<T>::append(expiry, who);
+					}
+				}
+			}
+
+			T::WeightInfo::on_initialize(accounts.len() as u32)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Mint some Liquid currency, by locking up the given amount of Staking currency.
@@ -149,6 +242,18 @@ pub mod module {
 				Error::<T>::MintAmountBelowMinimumThreshold
 			);
 
+			// Ensure the account has not minted within the cooldown window.
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let cooldown = Self::mint_cooldown_blocks();
+			if !cooldown.is_zero() {
+				if let Some(last_mint_block) = Self::last_mint_block(&who) {
+					ensure!(
+						current_block >= last_mint_block.saturating_add(cooldown),
+						Error::<T>::MintCooldownNotElapsed
+					);
+				}
+			}
+
 			// Ensure the total amount staked doesn't exceed the cap.
 			let new_total_staked = Self::total_staking_currency()
 				.checked_add(amount)
@@ -158,31 +263,33 @@ pub mod module {
 				Error::<T>::ExceededStakingCurrencyMintCap
 			);
 
+			// Ensure the amount staked in the current relaychain era doesn't exceed the per-era cap.
+			// The amount minted is reset on each new era.
+			let current_era = Self::relaychain_era();
+			let (minted_era, era_minted) = Self::current_era_minted();
+			let new_era_minted = if minted_era == current_era {
+				era_minted
+			} else {
+				Zero::zero()
+			}
+			.checked_add(amount)
+			.ok_or(ArithmeticError::Overflow)?;
+			if let Some(per_era_cap) = Self::per_era_mint_cap() {
+				ensure!(new_era_minted <= per_era_cap, Error::<T>::ExceededPerEraMintCap);
+			}
+
 			let staking_currency = T::StakingCurrencyId::get();
 
 			// ensure the user has enough funds on their account.
 			T::Currency::ensure_can_withdraw(staking_currency, &who, amount)?;
 
 			// Calculate how much Liquid currency is to be minted.
-			// Gets the current exchange rate
-			let staking_total = Self::total_staking_currency();
-			let liquid_total = T::Currency::total_issuance(T::LiquidCurrencyId::get());
-			let exchange_rate =
-				Ratio::checked_from_rational(liquid_total, staking_total).unwrap_or_else(T::DefaultExchangeRate::get);
-
-			// liquid_to_mint = ( (staked_amount - MintFee) * liquid_total / staked_total ) * (1 -
-			// MaxRewardPerEra)
-			let mut liquid_to_mint = exchange_rate
-				.checked_mul_int(
-					amount
-						.checked_sub(T::MintFee::get())
-						.expect("Mint amount is ensured to be greater than T::MintFee; qed"),
-				)
-				.ok_or(ArithmeticError::Overflow)?;
-
-			liquid_to_mint = liquid_to_mint
-				.checked_sub(T::MaxRewardPerEra::get().mul(liquid_to_mint))
-				.expect("Max rewards cannot be above 100%; qed");
+			let liquid_to_mint = Self::convert_staking_to_liquid(
+				amount
+					.checked_sub(T::MintFee::get())
+					.expect("Mint amount is ensured to be greater than T::MintFee; qed"),
+			)
+			.ok_or(ArithmeticError::Overflow)?;
 
 			// All checks pass. Proceed with Xcm transfer.
 			let xcm_result = T::XcmTransfer::transfer(
@@ -201,6 +308,11 @@ pub mod module {
 			T::Currency::deposit(T::LiquidCurrencyId::get(), &who, liquid_to_mint)?;
 
 			TotalStakingCurrency::<T>::put(new_total_staked);
+			CurrentEraMinted::<T>::put((current_era, new_era_minted));
+			if !cooldown.is_zero() {
+				LastMintBlock::<T>::insert(&who, current_block);
+				MintCooldownExpiry::<T>::append(current_block.saturating_add(cooldown), &who);
+			}
 
 			Self::deposit_event(Event::<T>::Minted(who, amount, liquid_to_mint));
 
@@ -239,5 +351,96 @@ pub mod module {
 			Self::deposit_event(Event::<T>::StakingCurrencyMintCapUpdated(new_cap));
 			Ok(())
 		}
+
+		/// Updates the cap for how much Staking currency can be used to Mint liquid currency
+		/// within a single relaychain era.
+		/// Requires `T::GovernanceOrigin`
+		///
+		/// Parameters:
+		/// - `new_cap`: The new per-era cap for staking currency. `None` removes the cap.
+		#[pallet::weight(< T as Config >::WeightInfo::set_per_era_mint_cap())]
+		#[transactional]
+		pub fn set_per_era_mint_cap(origin: OriginFor<T>, new_cap: Option<Balance>) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			PerEraMintCap::<T>::set(new_cap);
+			Self::deposit_event(Event::<T>::PerEraMintCapUpdated(new_cap));
+			Ok(())
+		}
+
+		/// Updates the number of blocks an account must wait between two mints.
+		/// Requires `T::GovernanceOrigin`
+		///
+		/// Parameters:
+		/// - `cooldown_blocks`: The new mint cooldown, in blocks. Zero removes the cooldown.
+		#[pallet::weight(< T as Config >::WeightInfo::set_mint_cooldown())]
+		#[transactional]
+		pub fn set_mint_cooldown(origin: OriginFor<T>, cooldown_blocks: T::BlockNumber) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			MintCooldownBlocks::<T>::put(cooldown_blocks);
+			Self::deposit_event(Event::<T>::MintCooldownBlocksUpdated(cooldown_blocks));
+			Ok(())
+		}
+
+		/// Records the staking rewards earned on the relaychain in an era, by adding them to the
+		/// total amount of the Staking currency. The reward is capped at `T::MaxRewardPerEra` of
+		/// the current total. The rewards of each era can be recorded only once, and the eras must
+		/// be recorded in increasing order.
+		/// Requires `T::GovernanceOrigin`
+		///
+		/// Parameters:
+		/// - `era`: The relaychain era the rewards are earned in.
+		/// - `amount`: The amount of Staking currency earned as rewards.
+		#[pallet::weight(< T as Config >::WeightInfo::record_staking_reward())]
+		#[transactional]
+		pub fn record_staking_reward(origin: OriginFor<T>, era: EraIndex, amount: Balance) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				Self::last_recorded_reward_era().map_or(true, |last_era| era > last_era),
+				Error::<T>::StakingRewardAlreadyRecorded
+			);
+			LastRecordedRewardEra::<T>::put(era);
+
+			let staking_total = Self::total_staking_currency();
+			let reward = amount.min(T::MaxRewardPerEra::get().mul(staking_total));
+			TotalStakingCurrency::<T>::put(staking_total.saturating_add(reward));
+			Self::deposit_event(Event::<T>::StakingRewardRecorded(era, reward));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The current era on the relaychain, derived from the relaychain block number.
+	pub fn relaychain_era() -> EraIndex {
+		T::RelaychainBlockNumber::current_block_number()
+			.checked_div(&T::RelaychainEraLength::get())
+			.unwrap_or_else(Zero::zero)
+			.saturated_into()
+	}
+
+	/// Estimates the amount of Liquid currency minted by `mint`ing `staking_amount` of the Staking
+	/// currency, after `T::MintFee` is deducted, at the current exchange rate.
+	pub fn estimate_mint_output(staking_amount: Balance) -> Balance {
+		Self::convert_staking_to_liquid(staking_amount.saturating_sub(T::MintFee::get())).unwrap_or_default()
+	}
+
+	/// Converts the given amount of Staking currency into Liquid currency at the current exchange
+	/// rate, with a portion deducted as defined by `T::MaxRewardPerEra`.
+	fn convert_staking_to_liquid(staking_amount: Balance) -> Option<Balance> {
+		// Gets the current exchange rate
+		let staking_total = Self::total_staking_currency();
+		let liquid_total = T::Currency::total_issuance(T::LiquidCurrencyId::get());
+		let exchange_rate =
+			Ratio::checked_from_rational(liquid_total, staking_total).unwrap_or_else(T::DefaultExchangeRate::get);
+
+		// liquid_amount = ( staking_amount * liquid_total / staked_total ) * (1 - MaxRewardPerEra)
+		let liquid_amount = exchange_rate.checked_mul_int(staking_amount)?;
+		Some(
+			liquid_amount
+				.checked_sub(T::MaxRewardPerEra::get().mul(liquid_amount))
+				.expect("Max rewards cannot be above 100%; qed"),
+		)
 	}
 }
@@ -27,7 +27,7 @@ use module_support::mocks::MockAddressMapping;
 use orml_traits::{parameter_type_with_key, XcmExecutionResult, XcmTransfer};
 use primitives::{Amount, TokenSymbol};
 use sp_core::H256;
-use sp_runtime::{testing::Header, traits::IdentityLookup, AccountId32};
+use sp_runtime::{offchain::storage_lock::BlockNumberProvider, testing::Header, traits::IdentityLookup, AccountId32};
 use xcm::opaque::v0::{Junction, MultiAsset, MultiLocation, NetworkId, Outcome};
 
 pub type AccountId = AccountId32;
@@ -155,6 +155,7 @@ impl pallet_balances::Config for Runtime {
 pub type AdaptedBasicCurrency = module_currencies::BasicCurrencyAdapter<Runtime, PalletBalances, Amount, BlockNumber>;
 
 parameter_types! {
+	pub const TreasuryAccount: AccountId = AccountId::new([255u8; 32]);
 	pub const GetNativeCurrencyId: CurrencyId = ACALA;
 }
 
@@ -166,6 +167,10 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
+	type SweepOrigin = frame_system::EnsureRoot<AccountId>;
+	type TreasuryAccount = TreasuryAccount;
+	type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+	type PreTransferFilter = ();
 }
 
 parameter_types! {
@@ -176,7 +181,18 @@ parameter_types! {
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(10, 1);
 	pub const MaxRewardPerEra: Permill = Permill::from_percent(1);
 	pub MintFee: Balance = millicent(1000);
+	pub const RelaychainEraLength: BlockNumber = 10;
+	pub static MockRelayBlockNumberProvider: BlockNumber = 0;
 }
+
+impl BlockNumberProvider for MockRelayBlockNumberProvider {
+	type BlockNumber = BlockNumber;
+
+	fn current_block_number() -> Self::BlockNumber {
+		Self::get()
+	}
+}
+
 ord_parameter_types! {
 	pub const Root: AccountId = ROOT;
 }
@@ -194,6 +210,8 @@ impl Config for Runtime {
 	type DefaultExchangeRate = DefaultExchangeRate;
 	type MaxRewardPerEra = MaxRewardPerEra;
 	type MintFee = MintFee;
+	type RelaychainBlockNumber = MockRelayBlockNumberProvider;
+	type RelaychainEraLength = RelaychainEraLength;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -23,8 +23,8 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	dollar, Currencies, Event, ExtBuilder, HomaLite, Origin, Runtime, System, ACALA, ALICE, BOB, INITIAL_BALANCE,
-	INVALID_CALLER, KSM, LKSM, ROOT,
+	dollar, millicent, Currencies, Event, ExtBuilder, HomaLite, MockRelayBlockNumberProvider, Origin, Runtime, System,
+	ACALA, ALICE, BOB, INITIAL_BALANCE, INVALID_CALLER, KSM, LKSM, ROOT,
 };
 use sp_runtime::traits::BadOrigin;
 
@@ -178,6 +178,119 @@ fn mint_fails_when_cap_is_exceeded() {
 	});
 }
 
+#[test]
+fn mint_fails_when_per_era_cap_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(Origin::signed(ROOT), dollar(10_000)));
+		assert_ok!(HomaLite::set_per_era_mint_cap(
+			Origin::signed(ROOT),
+			Some(dollar(1_000))
+		));
+
+		// Relaychain era 0
+		MockRelayBlockNumberProvider::set(9);
+		assert_noop!(
+			HomaLite::mint(Origin::signed(ALICE), dollar(1_001), 0),
+			Error::<Runtime>::ExceededPerEraMintCap
+		);
+
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(600), 0));
+		assert_ok!(HomaLite::mint(Origin::signed(BOB), dollar(400), 0));
+		assert_eq!(HomaLite::current_era_minted(), (0, dollar(1_000)));
+
+		assert_noop!(
+			HomaLite::mint(Origin::signed(ALICE), dollar(1), 0),
+			Error::<Runtime>::ExceededPerEraMintCap
+		);
+
+		// The per-era amount is reset on the next relaychain era.
+		MockRelayBlockNumberProvider::set(10);
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(1_000), 0));
+		assert_eq!(HomaLite::current_era_minted(), (1, dollar(1_000)));
+		assert_eq!(TotalStakingCurrency::<Runtime>::get(), dollar(2_000));
+
+		assert_noop!(
+			HomaLite::mint(Origin::signed(BOB), dollar(1), 0),
+			Error::<Runtime>::ExceededPerEraMintCap
+		);
+
+		// Removing the per-era cap only leaves the total cap in place.
+		assert_ok!(HomaLite::set_per_era_mint_cap(Origin::signed(ROOT), None));
+		assert_ok!(HomaLite::mint(Origin::signed(BOB), dollar(1_000), 0));
+		assert_eq!(HomaLite::current_era_minted(), (1, dollar(2_000)));
+	});
+}
+
+#[test]
+fn mint_fails_within_cooldown() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(Origin::signed(ROOT), dollar(10_000)));
+		assert_ok!(HomaLite::set_mint_cooldown(Origin::signed(ROOT), 10));
+
+		System::set_block_number(1);
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(100), 0));
+		assert_eq!(HomaLite::last_mint_block(&ALICE), Some(1));
+
+		// The minimum threshold is still checked first.
+		assert_noop!(
+			HomaLite::mint(Origin::signed(ALICE), dollar(0), 0),
+			Error::<Runtime>::MintAmountBelowMinimumThreshold
+		);
+
+		// A second mint within the cooldown window fails.
+		System::set_block_number(10);
+		assert_noop!(
+			HomaLite::mint(Origin::signed(ALICE), dollar(100), 0),
+			Error::<Runtime>::MintCooldownNotElapsed
+		);
+
+		// Other accounts are not affected.
+		assert_ok!(HomaLite::mint(Origin::signed(BOB), dollar(100), 0));
+
+		// The mint succeeds once the window has passed.
+		System::set_block_number(11);
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(100), 0));
+		assert_eq!(HomaLite::last_mint_block(&ALICE), Some(11));
+
+		// Removing the cooldown allows minting in the same block.
+		assert_ok!(HomaLite::set_mint_cooldown(Origin::signed(ROOT), 0));
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(100), 0));
+	});
+}
+
+#[test]
+fn last_mint_block_is_pruned_after_cooldown() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(Origin::signed(ROOT), dollar(10_000)));
+		assert_ok!(HomaLite::set_mint_cooldown(Origin::signed(ROOT), 10));
+
+		System::set_block_number(1);
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(100), 0));
+		assert_eq!(HomaLite::mint_cooldown_expiry(11), vec![ALICE]);
+
+		HomaLite::on_initialize(11);
+		assert_eq!(HomaLite::last_mint_block(&ALICE), None);
+		assert!(HomaLite::mint_cooldown_expiry(11).is_empty());
+
+		// The entry is kept until the increased cooldown has expired.
+		System::set_block_number(11);
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(100), 0));
+		assert_ok!(HomaLite::set_mint_cooldown(Origin::signed(ROOT), 20));
+		HomaLite::on_initialize(21);
+		assert_eq!(HomaLite::last_mint_block(&ALICE), Some(11));
+		assert_eq!(HomaLite::mint_cooldown_expiry(31), vec![ALICE]);
+		HomaLite::on_initialize(31);
+		assert_eq!(HomaLite::last_mint_block(&ALICE), None);
+
+		// Nothing is recorded without cooldown.
+		assert_ok!(HomaLite::set_mint_cooldown(Origin::signed(ROOT), 0));
+		System::set_block_number(40);
+		assert_ok!(HomaLite::mint(Origin::signed(BOB), dollar(100), 0));
+		assert_eq!(HomaLite::last_mint_block(&BOB), None);
+		assert!(HomaLite::mint_cooldown_expiry(40).is_empty());
+	});
+}
+
 #[test]
 fn failed_xcm_transfer_is_handled() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -241,3 +354,168 @@ fn can_set_mint_cap() {
 		);
 	});
 }
+
+#[test]
+fn can_set_per_era_mint_cap() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Current per-era cap is not set
+		assert_eq!(PerEraMintCap::<Runtime>::get(), None);
+
+		// Requires Root previlege.
+		assert_noop!(
+			HomaLite::set_per_era_mint_cap(Origin::signed(ALICE), Some(dollar(1_000))),
+			BadOrigin
+		);
+
+		assert_ok!(HomaLite::set_per_era_mint_cap(
+			Origin::signed(ROOT),
+			Some(dollar(1_000))
+		));
+		assert_eq!(PerEraMintCap::<Runtime>::get(), Some(dollar(1_000)));
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::PerEraMintCapUpdated(Some(dollar(1_000))))
+		);
+
+		assert_ok!(HomaLite::set_per_era_mint_cap(Origin::signed(ROOT), None));
+		assert_eq!(PerEraMintCap::<Runtime>::get(), None);
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::PerEraMintCapUpdated(None))
+		);
+	});
+}
+
+#[test]
+fn can_set_mint_cooldown() {
+	ExtBuilder::default().build().execute_with(|| {
+		// No cooldown by default
+		assert_eq!(MintCooldownBlocks::<Runtime>::get(), 0);
+
+		// Requires Root previlege.
+		assert_noop!(HomaLite::set_mint_cooldown(Origin::signed(ALICE), 10), BadOrigin);
+
+		assert_ok!(HomaLite::set_mint_cooldown(Origin::signed(ROOT), 10));
+		assert_eq!(MintCooldownBlocks::<Runtime>::get(), 10);
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::MintCooldownBlocksUpdated(10))
+		);
+	});
+}
+
+#[test]
+fn record_staking_reward_increases_exchange_rate() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(
+			Origin::signed(ROOT),
+			dollar(1_000)
+		));
+		let liquid_total = Currencies::total_issuance(LKSM);
+		let staking_per_liquid =
+			|| Ratio::checked_from_rational(HomaLite::total_staking_currency(), liquid_total).unwrap();
+		let rate_before = staking_per_liquid();
+
+		// Requires Root previlege.
+		assert_noop!(
+			HomaLite::record_staking_reward(Origin::signed(ALICE), 1, dollar(5)),
+			BadOrigin
+		);
+
+		// The reward is within the 1% cap.
+		assert_ok!(HomaLite::record_staking_reward(Origin::signed(ROOT), 1, dollar(5)));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_005));
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::StakingRewardRecorded(1, dollar(5)))
+		);
+		assert!(staking_per_liquid() > rate_before);
+	});
+}
+
+#[test]
+fn record_staking_reward_is_capped() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(
+			Origin::signed(ROOT),
+			dollar(1_000)
+		));
+
+		// The reward is clamped to 1% of the total staking currency.
+		assert_ok!(HomaLite::record_staking_reward(Origin::signed(ROOT), 1, dollar(100)));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_010));
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::StakingRewardRecorded(1, dollar(10)))
+		);
+
+		// The cap is based on the updated total.
+		assert_ok!(HomaLite::record_staking_reward(Origin::signed(ROOT), 2, dollar(100)));
+		assert_eq!(HomaLite::total_staking_currency(), 1_020_100_000_000_000);
+	});
+}
+
+#[test]
+fn record_staking_reward_rejects_recorded_era() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(
+			Origin::signed(ROOT),
+			dollar(1_000)
+		));
+		assert_eq!(HomaLite::last_recorded_reward_era(), None);
+
+		assert_ok!(HomaLite::record_staking_reward(Origin::signed(ROOT), 2, dollar(5)));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_005));
+		assert_eq!(HomaLite::last_recorded_reward_era(), Some(2));
+
+		// The same era cannot be recorded twice.
+		assert_noop!(
+			HomaLite::record_staking_reward(Origin::signed(ROOT), 2, dollar(5)),
+			Error::<Runtime>::StakingRewardAlreadyRecorded
+		);
+
+		// An earlier era cannot be recorded either.
+		assert_noop!(
+			HomaLite::record_staking_reward(Origin::signed(ROOT), 1, dollar(5)),
+			Error::<Runtime>::StakingRewardAlreadyRecorded
+		);
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_005));
+
+		assert_ok!(HomaLite::record_staking_reward(Origin::signed(ROOT), 3, dollar(5)));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_010));
+		assert_eq!(HomaLite::last_recorded_reward_era(), Some(3));
+	});
+}
+
+#[test]
+fn estimate_mint_output_matches_minted_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(
+			Origin::signed(ROOT),
+			5 * dollar(INITIAL_BALANCE)
+		));
+
+		// Amounts below the mint fee yield nothing.
+		assert_eq!(HomaLite::estimate_mint_output(millicent(1)), 0);
+
+		// Uses the default 1:10 exchange rate: (1000 - 0.01) * 10 * 0.99
+		assert_eq!(HomaLite::estimate_mint_output(dollar(1_000)), 9_899_901_000_000_000);
+
+		for &amount in &[dollar(1), dollar(1_000), dollar(12_345), dollar(100_000)] {
+			let estimate = HomaLite::estimate_mint_output(amount);
+			let before = Currencies::free_balance(LKSM, &ALICE);
+			assert_ok!(HomaLite::mint(Origin::signed(ALICE), amount, 0));
+			assert_eq!(Currencies::free_balance(LKSM, &ALICE) - before, estimate);
+		}
+
+		// The estimate follows the updated exchange rate.
+		assert_ok!(HomaLite::set_total_staking_currency(
+			Origin::signed(ROOT),
+			Currencies::total_issuance(LKSM) / 5
+		));
+		let estimate = HomaLite::estimate_mint_output(dollar(1_000));
+		let before = Currencies::free_balance(LKSM, &BOB);
+		assert_ok!(HomaLite::mint(Origin::signed(BOB), dollar(1_000), 0));
+		assert_eq!(Currencies::free_balance(LKSM, &BOB) - before, estimate);
+	});
+}
@@ -153,7 +153,7 @@ pub mod module {
 		///
 		/// - `currency_id`: collateral currency id.
 		/// - `max_collateral_amount`: the max collateral amount which is used to swap enough
-		/// 	stable token to clear debit.
+		/// 	stable token to clear debit, the call fails if more collateral is required.
 		/// - `maybe_path`: the custom swap path.
 		#[pallet::weight(<T as Config>::WeightInfo::close_loan_has_debit_by_dex(
 			maybe_path.clone().map(|p| p.len() as u32).unwrap_or(2)
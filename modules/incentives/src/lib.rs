@@ -47,7 +47,10 @@
 //!
 //! Reward accumulation:
 //! 1. LoansIncentive/DexIncentive/HomaIncentive/DexSaving: the fixed blocks is
-//! period(AccumulatePeriod), and on the beginning of each period will accumulate reward.
+//! period(AccumulatePeriod), and on the beginning of each period will accumulate reward. At most
+//! MaxPoolsPerBlock pools are accumulated in a block, the rest are accumulated in the following blocks.
+//! If the round of the previous period is still in progress at the beginning of a period, the round
+//! of this period is postponed and started right after the one in progress finishes.
 //! 2. HomaValidatorAllowance: transfer rewards into the vault account.
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -59,12 +62,13 @@ use frame_system::pallet_prelude::*;
 use orml_traits::{Happened, MultiCurrency, RewardHandler};
 use primitives::{Amount, Balance, CurrencyId};
 use sp_runtime::{
-	traits::{AccountIdConversion, MaybeDisplay, One, UniqueSaturatedInto, Zero},
+	traits::{AccountIdConversion, CheckedDiv, MaybeDisplay, One, Saturating, UniqueSaturatedInto, Zero},
 	DispatchResult, FixedPointNumber, RuntimeDebug,
 };
-use sp_std::{fmt::Debug, vec::Vec};
-use support::{CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, Rate};
+use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, vec::Vec};
+use support::{CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, Price, PriceProvider, Rate, Ratio};
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -94,6 +98,19 @@ pub enum PoolId<AccountId> {
 	HomaValidatorAllowance(AccountId),
 }
 
+/// Storage version of the incentives module.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+	V1_0_0,
+	V2_0_0,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V1_0_0
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -112,6 +129,16 @@ pub mod module {
 		#[pallet::constant]
 		type AccumulatePeriod: Get<Self::BlockNumber>;
 
+		/// The maximum number of pools to accumulate rewards for in a single
+		/// block. The pools are processed round-robin across blocks.
+		#[pallet::constant]
+		type MaxPoolsPerBlock: Get<u32>;
+
+		/// The expected maximum number of pools, a round of accumulation
+		/// should process all of them within `AccumulatePeriod`.
+		#[pallet::constant]
+		type ExpectedMaxPools: Get<u32>;
+
 		/// The reward type for incentive.
 		#[pallet::constant]
 		type NativeCurrencyId: Get<CurrencyId>;
@@ -128,6 +155,11 @@ pub mod module {
 		#[pallet::constant]
 		type NativeRewardsSource: Get<Self::AccountId>;
 
+		/// The source account for incentive rewards of pools which are
+		/// configured to pay in other currency than native currency.
+		#[pallet::constant]
+		type AlternativeRewardsSource: Get<Self::AccountId>;
+
 		/// The origin which may update incentive related params
 		type UpdateOrigin: EnsureOrigin<Self::Origin>;
 
@@ -143,6 +175,9 @@ pub mod module {
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
+		/// The price source to provide oracle prices of currencies
+		type PriceSource: PriceProvider<CurrencyId>;
+
 		/// The module id, keep DexShare LP.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -161,6 +196,15 @@ pub mod module {
 		InvalidPoolId,
 		/// Invalid rate
 		InvalidRate,
+		/// Invalid reward schedule, start block must be before end block
+		InvalidRewardSchedule,
+		/// There's no shortfall rewards of the pool
+		NoShortfallRewards,
+		/// There's no rewards to compound
+		NoRewardsToCompound,
+		/// The pool still has shares, pending or shortfall rewards in the
+		/// current reward currency
+		PoolHasOutstandingRewards,
 	}
 
 	#[pallet::event]
@@ -185,6 +229,22 @@ pub mod module {
 		SavingRewardRateUpdated(PoolId<T::RelaychainAccountId>, Rate),
 		/// Payout deduction rate updated. \[pool_id, deduction_rate\]
 		PayoutDeductionRateUpdated(PoolId<T::RelaychainAccountId>, Rate),
+		/// The bounds of dex incentive reward multiplier updated. \[new_bounds\]
+		DexIncentiveBoostBoundsUpdated(Option<(Rate, Rate)>),
+		/// Reward start block updated. \[pool_id, start_block\]
+		RewardStartBlockUpdated(PoolId<T::RelaychainAccountId>, Option<T::BlockNumber>),
+		/// Shortfall rewards of pool settled. \[pool_id, amount\]
+		ShortfallRewardsSettled(PoolId<T::RelaychainAccountId>, Balance),
+		/// Reward schedule updated. \[pool_id, schedule\]
+		RewardScheduleUpdated(PoolId<T::RelaychainAccountId>, Option<(T::BlockNumber, T::BlockNumber)>),
+		/// The peg deviation boost of dex saving reward rate updated. \[new_boost\]
+		PegDeviationBoostUpdated(Option<(Ratio, Rate)>),
+		/// Dex incentive rewards compounded into liquidity. \[who,
+		/// lp_currency_id, reward_amount, share_increment\]
+		DexRewardsCompounded(T::AccountId, CurrencyId, Balance, Balance),
+		/// Reward currency of incentive pool updated. \[pool_id,
+		/// reward_currency_id\]
+		PoolRewardCurrencyUpdated(PoolId<T::RelaychainAccountId>, Option<CurrencyId>),
 	}
 
 	/// Mapping from pool to its fixed reward amount per period.
@@ -211,6 +271,67 @@ pub mod module {
 	pub type PayoutDeductionRates<T: Config> =
 		StorageMap<_, Twox64Concat, PoolId<T::RelaychainAccountId>, Rate, ValueQuery>;
 
+	/// The (minimum, maximum) bounds of the reward multiplier for `DexIncentive`
+	/// pools. If set, the reward amount of each `DexIncentive` pool scales
+	/// inversely with its share of total liquidity of all `DexIncentive`
+	/// pools, to direct more incentive to thin pools. The maximum is at most
+	/// one, so the pools thicker than the average are cut and the total payout
+	/// never exceeds the configured reward amounts.
+	///
+	/// DexIncentiveBoostBounds: Option<(Rate, Rate)>
+	#[pallet::storage]
+	#[pallet::getter(fn dex_incentive_boost_bounds)]
+	pub type DexIncentiveBoostBounds<T: Config> = StorageValue<_, (Rate, Rate), OptionQuery>;
+
+	/// The (threshold, multiplier) of the reward rate boost for `DexSaving`
+	/// pools. If set, the reward rate of a `DexSaving` pool is multiplied by
+	/// the multiplier when the price in the pool deviates from the oracle
+	/// price by more than the threshold, to direct incentive to the pools
+	/// which restore the peg of stable currency.
+	///
+	/// PegDeviationBoost: Option<(Ratio, Rate)>
+	#[pallet::storage]
+	#[pallet::getter(fn peg_deviation_boost)]
+	pub type PegDeviationBoost<T: Config> = StorageValue<_, (Ratio, Rate), OptionQuery>;
+
+	/// Mapping from pool to the block from which its rewards start to
+	/// accumulate. Pools without a start block accumulate immediately.
+	///
+	/// RewardStartBlock: map PoolId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn reward_start_block)]
+	pub type RewardStartBlock<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId<T::RelaychainAccountId>, T::BlockNumber, OptionQuery>;
+
+	/// Mapping from pool to the (start, end) block window in which its rewards
+	/// accumulate. The pool stops rewarding from the end block. Pools
+	/// without a schedule accumulate perpetually.
+	///
+	/// RewardSchedule: map PoolId => Option<(BlockNumber, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn reward_schedule)]
+	pub type RewardSchedule<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId<T::RelaychainAccountId>, (T::BlockNumber, T::BlockNumber), OptionQuery>;
+
+	/// Mapping from pool to the incentive rewards amount which failed to be
+	/// transferred from `NativeRewardsSource`, can be settled later.
+	///
+	/// ShortfallRewards: map PoolId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn shortfall_rewards)]
+	pub type ShortfallRewards<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId<T::RelaychainAccountId>, Balance, ValueQuery>;
+
+	/// Mapping from incentive pool to the currency its rewards are paid in,
+	/// sourced from `AlternativeRewardsSource`. Pools without it are paid in
+	/// native currency from `NativeRewardsSource`.
+	///
+	/// PoolRewardCurrency: map PoolId => Option<CurrencyId>
+	#[pallet::storage]
+	#[pallet::getter(fn pool_reward_currency)]
+	pub type PoolRewardCurrency<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId<T::RelaychainAccountId>, CurrencyId, OptionQuery>;
+
 	/// The pending rewards amount, actual available rewards amount may be deducted
 	///
 	/// PendingRewards: double_map PoolId, AccountId => Balance
@@ -226,108 +347,250 @@ pub mod module {
 		ValueQuery,
 	>;
 
+	/// The cursor of the reward accumulation in progress, `None` if no
+	/// accumulation is in progress. The inner value is the last accumulated
+	/// pool, `None` if no pool has been accumulated yet in this round.
+	///
+	/// AccumulationCursor: Option<PoolId>
+	#[pallet::storage]
+	#[pallet::getter(fn accumulation_cursor)]
+	pub type AccumulationCursor<T: Config> = StorageValue<_, Option<PoolId<T::RelaychainAccountId>>, OptionQuery>;
+
+	/// The number of accumulation rounds postponed because the round of the
+	/// previous period was still in progress at the beginning of their
+	/// period.
+	///
+	/// PendingAccumulationRounds: u32
+	#[pallet::storage]
+	#[pallet::getter(fn pending_accumulation_rounds)]
+	pub type PendingAccumulationRounds<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The reward multipliers of `DexIncentive` pools for the accumulation
+	/// round in progress, computed at the start of the round.
+	///
+	/// RoundDexIncentiveMultipliers: BTreeMap<CurrencyId, Rate>
+	#[pallet::storage]
+	#[pallet::getter(fn round_dex_incentive_multipliers)]
+	pub type RoundDexIncentiveMultipliers<T: Config> = StorageValue<_, BTreeMap<CurrencyId, Rate>, ValueQuery>;
+
+	/// Storage version of the pallet, used to run the storage migrations of
+	/// `PoolId` keyed storages on runtime upgrade.
+	///
+	/// StorageVersion: Releases
+	#[pallet::storage]
+	pub(crate) type StorageVersion<T: Config> = StorageValue<_, Releases, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig {}
+
+	#[cfg(feature = "std")]
+	impl Default for GenesisConfig {
+		fn default() -> Self {
+			GenesisConfig {}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			StorageVersion::<T>::put(Releases::V2_0_0);
+		}
+	}
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		fn on_initialize(now: T::BlockNumber) -> Weight {
-			// accumulate reward periodically
-			if !T::EmergencyShutdown::is_shutdown() && now % T::AccumulatePeriod::get() == Zero::zero() {
-				let mut count: u32 = 0;
-				let native_currency_id = T::NativeCurrencyId::get();
-				let stable_currency_id = T::StableCurrencyId::get();
-
-				for (pool_id, pool_info) in orml_rewards::Pools::<T>::iter() {
-					if !pool_info.total_shares.is_zero() {
-						match pool_id {
-							PoolId::LoansIncentive(_) | PoolId::DexIncentive(_) | PoolId::HomaIncentive => {
-								count += 1;
-								let incentive_reward_amount = Self::incentive_reward_amount(pool_id.clone());
-
-								if !incentive_reward_amount.is_zero() {
-									let res = T::Currency::transfer(
-										native_currency_id,
-										&T::NativeRewardsSource::get(),
+			if T::EmergencyShutdown::is_shutdown() {
+				return 0;
+			}
+
+			// accumulate reward periodically, a round is started at the beginning of each
+			// period and continues across blocks until all pools are processed. The round of
+			// a period is postponed if the previous round is still in progress.
+			let is_period_start = now % T::AccumulatePeriod::get() == Zero::zero();
+			let mut weight: Weight = 0;
+			let (cursor, is_round_start) = match Self::accumulation_cursor() {
+				Some(cursor) => {
+					if is_period_start {
+						PendingAccumulationRounds::<T>::mutate(|rounds| *rounds = rounds.saturating_add(1));
+						weight = T::DbWeight::get().reads_writes(1, 1);
+						log::warn!(
+							target: "incentives",
+							"accumulate rewards: the previous round is not finished at {:?}, postpone the round of this period",
+							now
+						);
+					}
+					(cursor, false)
+				}
+				None if is_period_start => (None, true),
+				None if !Self::pending_accumulation_rounds().is_zero() => {
+					PendingAccumulationRounds::<T>::mutate(|rounds| *rounds = rounds.saturating_sub(1));
+					weight = T::DbWeight::get().reads_writes(1, 1);
+					(None, true)
+				}
+				None => return T::DbWeight::get().reads(2),
+			};
+
+			// the multipliers of `DexIncentive` pools are computed at the start of a round
+			// and kept for the following blocks of the round.
+			let dex_incentive_multipliers = if is_round_start {
+				let (multipliers, scanned) = Self::calculate_dex_incentive_multipliers();
+				weight = weight.saturating_add(T::DbWeight::get().reads((scanned as Weight).saturating_mul(3)));
+				multipliers
+			} else {
+				Self::round_dex_incentive_multipliers()
+			};
+			let peg_deviation_boost = Self::peg_deviation_boost();
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			let mut pools = match cursor {
+				Some(last_pool_id) => {
+					orml_rewards::Pools::<T>::iter_from(orml_rewards::Pools::<T>::hashed_key_for(&last_pool_id))
+				}
+				None => orml_rewards::Pools::<T>::iter(),
+			};
+			let mut count: u32 = 0;
+			let mut last_pool: Option<PoolId<T::RelaychainAccountId>> = None;
+
+			for (pool_id, pool_info) in pools.by_ref().take(T::MaxPoolsPerBlock::get().max(1) as usize) {
+				count += 1;
+				last_pool = Some(pool_id.clone());
+
+				if !pool_info.total_shares.is_zero()
+					&& Self::is_reward_started(&pool_id, now)
+					&& Self::is_in_reward_schedule(&pool_id, now)
+				{
+					match pool_id {
+						PoolId::LoansIncentive(_) | PoolId::DexIncentive(_) | PoolId::HomaIncentive => {
+							let mut incentive_reward_amount = Self::incentive_reward_amount(pool_id.clone());
+							if let PoolId::DexIncentive(lp_currency_id) = pool_id {
+								if let Some(multiplier) = dex_incentive_multipliers.get(&lp_currency_id) {
+									incentive_reward_amount = multiplier.saturating_mul_int(incentive_reward_amount);
+								}
+							}
+
+							if !incentive_reward_amount.is_zero() {
+								let (reward_currency_id, rewards_source) = Self::incentive_reward_source(&pool_id);
+								let res = T::Currency::transfer(
+									reward_currency_id,
+									&rewards_source,
+									&Self::account_id(),
+									incentive_reward_amount,
+								);
+								match res {
+									Ok(_) => {
+										<orml_rewards::Pallet<T>>::accumulate_reward(&pool_id, incentive_reward_amount);
+									}
+									Err(e) => {
+										ShortfallRewards::<T>::mutate(&pool_id, |shortfall| {
+											*shortfall = shortfall.saturating_add(incentive_reward_amount)
+										});
+										log::warn!(
+											target: "incentives",
+											"transfer: failed to transfer {:?} {:?} from {:?} to {:?}: {:?}. \
+											This is unexpected but should be safe",
+											incentive_reward_amount, reward_currency_id, rewards_source, Self::account_id(), e
+										);
+									}
+								}
+							}
+						}
+
+						PoolId::DexSaving(lp_currency_id) => {
+							let mut dex_saving_reward_rate = Self::dex_saving_reward_rate(pool_id.clone());
+							if let Some((threshold, multiplier)) = peg_deviation_boost {
+								// reads the liquidity pool and the oracle prices of the pair
+								let deviation = Self::peg_deviation(lp_currency_id).unwrap_or_default();
+								weight = weight.saturating_add(T::DbWeight::get().reads(3));
+								if deviation > threshold {
+									dex_saving_reward_rate = dex_saving_reward_rate.saturating_mul(multiplier);
+								}
+							}
+
+							if !dex_saving_reward_rate.is_zero() {
+								// accumulate saving reward only for liquidity pool of stable currency id
+								let dex_saving_reward_base = Self::stable_liquidity(lp_currency_id);
+								let dex_saving_reward_amount =
+									dex_saving_reward_rate.saturating_mul_int(dex_saving_reward_base);
+
+								// issue stable coin without backing.
+								if !dex_saving_reward_amount.is_zero() {
+									let res = T::CDPTreasury::issue_debit(
 										&Self::account_id(),
-										incentive_reward_amount,
+										dex_saving_reward_amount,
+										false,
 									);
 									match res {
 										Ok(_) => {
 											<orml_rewards::Pallet<T>>::accumulate_reward(
 												&pool_id,
-												incentive_reward_amount,
+												dex_saving_reward_amount,
 											);
 										}
 										Err(e) => {
 											log::warn!(
 												target: "incentives",
-												"transfer: failed to transfer {:?} {:?} from {:?} to {:?}: {:?}. \
+												"issue_debit: failed to issue {:?} unbacked stable to {:?}: {:?}. \
 												This is unexpected but should be safe",
-												incentive_reward_amount, native_currency_id, T::NativeRewardsSource::get(), Self::account_id(), e
-											);
-										}
-									}
-								}
-							}
-
-							PoolId::DexSaving(lp_currency_id) => {
-								count += 1;
-								let dex_saving_reward_rate = Self::dex_saving_reward_rate(pool_id.clone());
-
-								if !dex_saving_reward_rate.is_zero() {
-									if let Some((currency_id_a, currency_id_b)) =
-										lp_currency_id.split_dex_share_currency_id()
-									{
-										// accumulate saving reward only for liquidity pool of stable currency id
-										let dex_saving_reward_base = if currency_id_a == stable_currency_id {
-											T::DEX::get_liquidity_pool(stable_currency_id, currency_id_b).0
-										} else if currency_id_b == stable_currency_id {
-											T::DEX::get_liquidity_pool(stable_currency_id, currency_id_a).0
-										} else {
-											Zero::zero()
-										};
-										let dex_saving_reward_amount =
-											dex_saving_reward_rate.saturating_mul_int(dex_saving_reward_base);
-
-										// issue stable coin without backing.
-										if !dex_saving_reward_amount.is_zero() {
-											let res = T::CDPTreasury::issue_debit(
-												&Self::account_id(),
-												dex_saving_reward_amount,
-												false,
+												dex_saving_reward_amount, Self::account_id(), e
 											);
-											match res {
-												Ok(_) => {
-													<orml_rewards::Pallet<T>>::accumulate_reward(
-														&pool_id,
-														dex_saving_reward_amount,
-													);
-												}
-												Err(e) => {
-													log::warn!(
-														target: "incentives",
-														"issue_debit: failed to issue {:?} unbacked stable to {:?}: {:?}. \
-														This is unexpected but should be safe",
-														dex_saving_reward_amount, Self::account_id(), e
-													);
-												}
-											}
 										}
 									}
 								}
 							}
-
-							_ => {}
 						}
+
+						_ => {}
 					}
 				}
+			}
+
+			if pools.next().is_some() {
+				AccumulationCursor::<T>::put(last_pool);
+				if is_round_start && !dex_incentive_multipliers.is_empty() {
+					RoundDexIncentiveMultipliers::<T>::put(&dex_incentive_multipliers);
+					weight = weight.saturating_add(T::DbWeight::get().writes(1));
+				}
+			} else {
+				AccumulationCursor::<T>::kill();
+				if !is_round_start && !dex_incentive_multipliers.is_empty() {
+					RoundDexIncentiveMultipliers::<T>::kill();
+					weight = weight.saturating_add(T::DbWeight::get().writes(1));
+				}
+			}
+
+			T::WeightInfo::on_initialize(count).saturating_add(weight)
+		}
+
+		#[cfg(feature = "std")]
+		fn integrity_test() {
+			assert!(T::MaxPoolsPerBlock::get() > 0);
+			let period: u32 = T::AccumulatePeriod::get().unique_saturated_into();
+			assert!(
+				period.saturating_mul(T::MaxPoolsPerBlock::get()) >= T::ExpectedMaxPools::get(),
+				"a round of accumulation cannot process the expected pools within `AccumulatePeriod`"
+			);
+		}
 
-				T::WeightInfo::on_initialize(count)
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::<T>::get() == Releases::V1_0_0 {
+				migrations::v2::migrate::<T>()
 			} else {
-				0
+				T::DbWeight::get().reads(1)
 			}
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			migrations::v2::pre_migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			migrations::v2::post_migrate::<T>()
+		}
 	}
 
 	#[pallet::call]
@@ -352,45 +615,84 @@ pub mod module {
 		#[transactional]
 		pub fn claim_rewards(origin: OriginFor<T>, pool_id: PoolId<T::RelaychainAccountId>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::do_claim_rewards(who, pool_id)
+		}
 
-			<orml_rewards::Pallet<T>>::claim_rewards(&who, &pool_id);
+		/// Claim rewards of `pool_id` on behalf of `who`, the payout deduction
+		/// rate still applies.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		#[pallet::weight(<T as Config>::WeightInfo::force_claim_rewards())]
+		#[transactional]
+		pub fn force_claim_rewards(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			pool_id: PoolId<T::RelaychainAccountId>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_claim_rewards(who, pool_id)
+		}
 
-			let pending_reward: Balance = PendingRewards::<T>::take(&pool_id, &who);
-			if !pending_reward.is_zero() {
-				let currency_id = match pool_id {
-					PoolId::LoansIncentive(_) | PoolId::DexIncentive(_) | PoolId::HomaIncentive => {
-						T::NativeCurrencyId::get()
-					}
-					PoolId::DexSaving(_) => T::StableCurrencyId::get(),
-					PoolId::HomaValidatorAllowance(_) => T::LiquidCurrencyId::get(),
-				};
-
-				// calculate actual rewards and deduction amount
-				let (actual_amount, deduction_amount) = {
-					let deduction_amount = Self::payout_deduction_rates(&pool_id)
-						.saturating_mul_int(pending_reward)
-						.min(pending_reward);
-					if !deduction_amount.is_zero() {
-						// re-accumulate deduction to rewards pool if deduction amount is not zero
-						<orml_rewards::Pallet<T>>::accumulate_reward(&pool_id, deduction_amount);
-					}
-					(pending_reward.saturating_sub(deduction_amount), deduction_amount)
-				};
-
-				// transfer the actual reward(pending reward exclude deduction) to user from the pool. it should not
-				// affect the process, ignore the result to continue. if it fails, just the user will not
-				// be rewarded, there will not increase user balance.
-				T::Currency::transfer(currency_id, &Self::account_id(), &who, actual_amount)?;
-
-				Self::deposit_event(Event::ClaimRewards(
-					who,
-					pool_id,
-					currency_id,
-					actual_amount,
-					deduction_amount,
-				));
-			}
+		/// Claim the native rewards of the dex incentive pool of
+		/// `lp_currency_id`, swap them into the pool tokens, add liquidity and
+		/// deposit the new dex share into the incentive pools.
+		///
+		/// - `lp_currency_id`: dex share currency id.
+		/// - `min_share_increment`: the minimum dex share to get, protecting
+		///   the swaps from price manipulation.
+		#[pallet::weight(<T as Config>::WeightInfo::compound_dex_rewards())]
+		#[transactional]
+		pub fn compound_dex_rewards(
+			origin: OriginFor<T>,
+			lp_currency_id: CurrencyId,
+			#[pallet::compact] min_share_increment: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (currency_id_a, currency_id_b) = lp_currency_id
+				.split_dex_share_currency_id()
+				.ok_or(Error::<T>::InvalidCurrencyId)?;
+			let pool_id = PoolId::DexIncentive(lp_currency_id);
+			let (reward_currency_id, _) = Self::incentive_reward_source(&pool_id);
+
+			let reward_balance = T::Currency::free_balance(reward_currency_id, &who);
+			Self::do_claim_rewards(who.clone(), pool_id)?;
+			let reward_amount = T::Currency::free_balance(reward_currency_id, &who).saturating_sub(reward_balance);
+			ensure!(!reward_amount.is_zero(), Error::<T>::NoRewardsToCompound);
+
+			// supply the reward directly if the pool is paired with reward currency,
+			// otherwise swap it to currency_id_a first.
+			let (supply_currency_id, other_currency_id, supply_amount) = if currency_id_a == reward_currency_id {
+				(currency_id_a, currency_id_b, reward_amount)
+			} else if currency_id_b == reward_currency_id {
+				(currency_id_b, currency_id_a, reward_amount)
+			} else {
+				let amount = T::DEX::swap_with_exact_supply(
+					&who,
+					&[reward_currency_id, currency_id_a],
+					reward_amount,
+					Zero::zero(),
+				)?;
+				(currency_id_a, currency_id_b, amount)
+			};
 
+			let share_balance = T::Currency::free_balance(lp_currency_id, &who);
+			T::DEX::swap_and_add_liquidity(
+				&who,
+				supply_currency_id,
+				other_currency_id,
+				supply_amount,
+				min_share_increment,
+				false,
+			)?;
+			let share_increment = T::Currency::free_balance(lp_currency_id, &who).saturating_sub(share_balance);
+			Self::do_deposit_dex_share(&who, lp_currency_id, share_increment)?;
+
+			Self::deposit_event(Event::DexRewardsCompounded(
+				who,
+				lp_currency_id,
+				reward_amount,
+				share_increment,
+			));
 			Ok(())
 		}
 
@@ -460,6 +762,138 @@ pub mod module {
 			Ok(())
 		}
 
+		#[pallet::weight(<T as Config>::WeightInfo::update_dex_incentive_boost_bounds())]
+		#[transactional]
+		pub fn update_dex_incentive_boost_bounds(origin: OriginFor<T>, bounds: Option<(Rate, Rate)>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Some((min_multiplier, max_multiplier)) = bounds {
+				ensure!(
+					min_multiplier <= max_multiplier && max_multiplier <= Rate::one(),
+					Error::<T>::InvalidRate
+				);
+			}
+			DexIncentiveBoostBounds::<T>::set(bounds);
+			Self::deposit_event(Event::DexIncentiveBoostBoundsUpdated(bounds));
+			Ok(())
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::update_peg_deviation_boost())]
+		#[transactional]
+		pub fn update_peg_deviation_boost(origin: OriginFor<T>, boost: Option<(Ratio, Rate)>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Some((_, multiplier)) = boost {
+				ensure!(multiplier >= Rate::one(), Error::<T>::InvalidRate);
+			}
+			PegDeviationBoost::<T>::set(boost);
+			Self::deposit_event(Event::PegDeviationBoostUpdated(boost));
+			Ok(())
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::update_reward_start_blocks(updates.len() as u32))]
+		#[transactional]
+		pub fn update_reward_start_blocks(
+			origin: OriginFor<T>,
+			updates: Vec<(PoolId<T::RelaychainAccountId>, Option<T::BlockNumber>)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			for (pool_id, start_block) in updates {
+				match pool_id {
+					PoolId::DexSaving(currency_id) | PoolId::DexIncentive(currency_id) => {
+						ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+					}
+					PoolId::LoansIncentive(_) | PoolId::HomaIncentive => {}
+					_ => {
+						return Err(Error::<T>::InvalidPoolId.into());
+					}
+				}
+				RewardStartBlock::<T>::set(&pool_id, start_block);
+				Self::deposit_event(Event::RewardStartBlockUpdated(pool_id, start_block));
+			}
+			Ok(())
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::update_reward_schedules(updates.len() as u32))]
+		#[transactional]
+		pub fn update_reward_schedules(
+			origin: OriginFor<T>,
+			updates: Vec<(PoolId<T::RelaychainAccountId>, Option<(T::BlockNumber, T::BlockNumber)>)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			for (pool_id, schedule) in updates {
+				match pool_id {
+					PoolId::DexSaving(currency_id) | PoolId::DexIncentive(currency_id) => {
+						ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+					}
+					PoolId::LoansIncentive(_) | PoolId::HomaIncentive => {}
+					_ => {
+						return Err(Error::<T>::InvalidPoolId.into());
+					}
+				}
+				if let Some((start, end)) = schedule {
+					ensure!(start < end, Error::<T>::InvalidRewardSchedule);
+				}
+				RewardSchedule::<T>::set(&pool_id, schedule);
+				Self::deposit_event(Event::RewardScheduleUpdated(pool_id, schedule));
+			}
+			Ok(())
+		}
+
+		/// Update the reward currency of incentive pools, `None` means paying
+		/// the rewards in native currency. The reward currency of a pool can
+		/// only be switched after all its shares were removed, its pending
+		/// rewards paid out and its shortfall rewards settled.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		#[pallet::weight(<T as Config>::WeightInfo::update_pool_reward_currencies(updates.len() as u32))]
+		#[transactional]
+		pub fn update_pool_reward_currencies(
+			origin: OriginFor<T>,
+			updates: Vec<(PoolId<T::RelaychainAccountId>, Option<CurrencyId>)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			for (pool_id, reward_currency_id) in updates {
+				match pool_id {
+					PoolId::DexIncentive(currency_id) => {
+						ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+					}
+					PoolId::LoansIncentive(_) | PoolId::HomaIncentive => {}
+					_ => {
+						return Err(Error::<T>::InvalidPoolId.into());
+					}
+				}
+				if PoolRewardCurrency::<T>::get(&pool_id) != reward_currency_id {
+					ensure!(
+						!Self::has_outstanding_rewards(&pool_id),
+						Error::<T>::PoolHasOutstandingRewards
+					);
+					// drop the rounding dust of the previous reward currency
+					orml_rewards::Pools::<T>::remove(&pool_id);
+				}
+				PoolRewardCurrency::<T>::set(&pool_id, reward_currency_id);
+				Self::deposit_event(Event::PoolRewardCurrencyUpdated(pool_id, reward_currency_id));
+			}
+			Ok(())
+		}
+
+		/// Retry the transfer of shortfall rewards of `pool_id` from
+		/// `NativeRewardsSource` and accumulate them to the pool.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		#[pallet::weight(<T as Config>::WeightInfo::settle_shortfall())]
+		#[transactional]
+		pub fn settle_shortfall(origin: OriginFor<T>, pool_id: PoolId<T::RelaychainAccountId>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let amount = ShortfallRewards::<T>::take(&pool_id);
+			ensure!(!amount.is_zero(), Error::<T>::NoShortfallRewards);
+
+			let (reward_currency_id, rewards_source) = Self::incentive_reward_source(&pool_id);
+			T::Currency::transfer(reward_currency_id, &rewards_source, &Self::account_id(), amount)?;
+			<orml_rewards::Pallet<T>>::accumulate_reward(&pool_id, amount);
+
+			Self::deposit_event(Event::ShortfallRewardsSettled(pool_id, amount));
+			Ok(())
+		}
+
 		#[pallet::weight(<T as Config>::WeightInfo::add_allowance())]
 		#[transactional]
 		pub fn add_allowance(
@@ -488,6 +922,165 @@ impl<T: Config> Pallet<T> {
 	pub fn account_id() -> T::AccountId {
 		T::PalletId::get().into_account()
 	}
+
+	fn do_claim_rewards(who: T::AccountId, pool_id: PoolId<T::RelaychainAccountId>) -> DispatchResult {
+		<orml_rewards::Pallet<T>>::claim_rewards(&who, &pool_id);
+
+		let pending_reward: Balance = PendingRewards::<T>::take(&pool_id, &who);
+		if !pending_reward.is_zero() {
+			let currency_id = match pool_id {
+				PoolId::LoansIncentive(_) | PoolId::DexIncentive(_) | PoolId::HomaIncentive => {
+					Self::incentive_reward_source(&pool_id).0
+				}
+				PoolId::DexSaving(_) => T::StableCurrencyId::get(),
+				PoolId::HomaValidatorAllowance(_) => T::LiquidCurrencyId::get(),
+			};
+
+			// calculate actual rewards and deduction amount
+			let (actual_amount, deduction_amount) = {
+				let deduction_amount = Self::payout_deduction_rates(&pool_id)
+					.saturating_mul_int(pending_reward)
+					.min(pending_reward);
+				if !deduction_amount.is_zero() {
+					// re-accumulate deduction to rewards pool if deduction amount is not zero
+					<orml_rewards::Pallet<T>>::accumulate_reward(&pool_id, deduction_amount);
+				}
+				(pending_reward.saturating_sub(deduction_amount), deduction_amount)
+			};
+
+			// transfer the actual reward(pending reward exclude deduction) to user from the pool. it should not
+			// affect the process, ignore the result to continue. if it fails, just the user will not
+			// be rewarded, there will not increase user balance.
+			T::Currency::transfer(currency_id, &Self::account_id(), &who, actual_amount)?;
+
+			Self::deposit_event(Event::ClaimRewards(
+				who,
+				pool_id,
+				currency_id,
+				actual_amount,
+				deduction_amount,
+			));
+		}
+
+		Ok(())
+	}
+
+	/// The reward currency of incentive `pool_id` and the account its rewards
+	/// are sourced from.
+	fn incentive_reward_source(pool_id: &PoolId<T::RelaychainAccountId>) -> (CurrencyId, T::AccountId) {
+		match Self::pool_reward_currency(pool_id) {
+			Some(currency_id) => (currency_id, T::AlternativeRewardsSource::get()),
+			None => (T::NativeCurrencyId::get(), T::NativeRewardsSource::get()),
+		}
+	}
+
+	/// Whether `pool_id` has shares which can still claim rewards, pending
+	/// rewards which are not paid out yet, or shortfall rewards. The rounding
+	/// dust of claimed rewards never leaves the pool, so the accumulated
+	/// rewards can't tell whether all rewards were claimed.
+	fn has_outstanding_rewards(pool_id: &PoolId<T::RelaychainAccountId>) -> bool {
+		!<orml_rewards::Pallet<T>>::pools(pool_id).total_shares.is_zero()
+			|| PendingRewards::<T>::iter_prefix(pool_id).next().is_some()
+			|| !Self::shortfall_rewards(pool_id).is_zero()
+	}
+
+	/// Whether the rewards of `pool_id` have started to accumulate at `now`.
+	fn is_reward_started(pool_id: &PoolId<T::RelaychainAccountId>, now: T::BlockNumber) -> bool {
+		Self::reward_start_block(pool_id).map_or(true, |start_block| now >= start_block)
+	}
+
+	/// Whether `now` is within the reward schedule of `pool_id`.
+	fn is_in_reward_schedule(pool_id: &PoolId<T::RelaychainAccountId>, now: T::BlockNumber) -> bool {
+		Self::reward_schedule(pool_id).map_or(true, |(start, end)| now >= start && now < end)
+	}
+
+	/// The stable currency amount in the liquidity pool of `lp_currency_id`,
+	/// zero if the pool is not paired with stable currency.
+	fn stable_liquidity(lp_currency_id: CurrencyId) -> Balance {
+		let stable_currency_id = T::StableCurrencyId::get();
+		match lp_currency_id.split_dex_share_currency_id() {
+			Some((currency_id_a, currency_id_b)) if currency_id_a == stable_currency_id => {
+				T::DEX::get_liquidity_pool(stable_currency_id, currency_id_b).0
+			}
+			Some((currency_id_a, currency_id_b)) if currency_id_b == stable_currency_id => {
+				T::DEX::get_liquidity_pool(stable_currency_id, currency_id_a).0
+			}
+			_ => Zero::zero(),
+		}
+	}
+
+	/// The deviation of the price in the liquidity pool of `lp_currency_id`
+	/// from the oracle price, `None` if the pool is not paired with stable
+	/// currency or the prices are unavailable.
+	fn peg_deviation(lp_currency_id: CurrencyId) -> Option<Ratio> {
+		let stable_currency_id = T::StableCurrencyId::get();
+		let other_currency_id = match lp_currency_id.split_dex_share_currency_id() {
+			Some((currency_id_a, currency_id_b)) if currency_id_a == stable_currency_id => currency_id_b,
+			Some((currency_id_a, currency_id_b)) if currency_id_b == stable_currency_id => currency_id_a,
+			_ => return None,
+		};
+		let (stable_pool, other_pool) = T::DEX::get_liquidity_pool(stable_currency_id, other_currency_id);
+		let dex_price = Price::checked_from_rational(stable_pool, other_pool)?;
+		let oracle_price = T::PriceSource::get_relative_price(other_currency_id, stable_currency_id)?;
+		let relative_price = dex_price.checked_div(&oracle_price)?;
+
+		Some(if relative_price > Price::one() {
+			relative_price.saturating_sub(Price::one())
+		} else {
+			Price::one().saturating_sub(relative_price)
+		})
+	}
+
+	/// The reward multipliers of `DexIncentive` pools which will accumulate
+	/// rewards. The multiplier of a pool is its average share divided by its
+	/// actual share of total liquidity valued in stable currency, clamped by
+	/// `DexIncentiveBoostBounds`. Returns empty if the bounds are not set.
+	pub fn dex_incentive_multipliers() -> BTreeMap<CurrencyId, Rate> {
+		Self::calculate_dex_incentive_multipliers().0
+	}
+
+	/// The reward multipliers of `DexIncentive` pools and the number of the
+	/// scanned `IncentiveRewardAmount` entries. Only the pools configured by
+	/// governance are scanned, so the number is bounded by governance.
+	fn calculate_dex_incentive_multipliers() -> (BTreeMap<CurrencyId, Rate>, u32) {
+		let (min_multiplier, max_multiplier) = match Self::dex_incentive_boost_bounds() {
+			Some(bounds) => bounds,
+			None => return (Default::default(), 0),
+		};
+
+		let mut scanned: u32 = 0;
+		let liquidities: Vec<(CurrencyId, Balance)> = IncentiveRewardAmount::<T>::iter()
+			.filter_map(|(pool_id, incentive_reward_amount)| {
+				scanned = scanned.saturating_add(1);
+				match pool_id {
+					PoolId::DexIncentive(lp_currency_id)
+						if !incentive_reward_amount.is_zero()
+							&& !<orml_rewards::Pallet<T>>::pools(&pool_id).total_shares.is_zero() =>
+					{
+						Some((lp_currency_id, Self::stable_liquidity(lp_currency_id)))
+					}
+					_ => None,
+				}
+			})
+			.filter(|(_, liquidity)| !liquidity.is_zero())
+			.collect();
+		let total_liquidity = liquidities
+			.iter()
+			.fold(Balance::zero(), |acc, (_, liquidity)| acc.saturating_add(*liquidity));
+		let pools_count = liquidities.len() as Balance;
+
+		let multipliers = liquidities
+			.into_iter()
+			.map(|(lp_currency_id, liquidity)| {
+				let multiplier = Rate::checked_from_rational(total_liquidity, liquidity.saturating_mul(pools_count))
+					.unwrap_or(max_multiplier)
+					.max(min_multiplier)
+					.min(max_multiplier);
+				(lp_currency_id, multiplier)
+			})
+			.collect();
+		(multipliers, scanned)
+	}
 }
 
 impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
@@ -0,0 +1,365 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the incentives module.
+//!
+//! `PoolId` is used as the key of the pool config storages,
+//! `ShortfallRewards`, `PendingRewards` and `AccumulationCursor`, and of the
+//! `Pools` and `ShareAndWithdrawnReward` storages of orml_rewards, so a change
+//! of its encoding needs a migration which decodes the keys with the old
+//! `PoolId` definition and re-inserts the entries with the new one, see
+//! `migrate_pool_id_keys`. `PendingRewards` and `ShareAndWithdrawnReward` have
+//! an entry per pool and account, so they are migrated separately in bounded
+//! steps by `migrate_pending_rewards_keys` and
+//! `migrate_share_and_withdrawn_reward_keys`, which have to be driven across
+//! blocks until all the entries are visited. The shares and rewards of the
+//! users are lost if `ShareAndWithdrawnReward` is not migrated along with
+//! `Pools`.
+
+use super::*;
+use frame_support::storage::{
+	migration::{storage_iter, storage_key_iter},
+	unhashed, StoragePrefixedMap,
+};
+use orml_rewards::PoolInfo;
+
+/// Re-key the entries of the `PoolId` keyed storages except
+/// `PendingRewards` and `ShareAndWithdrawnReward`, by decoding the keys as
+/// `OldPoolId` and converting them with `migrate_pool_id`. The entries
+/// converted to `None` or whose key can't be decoded are removed, the `Pools`
+/// entries converted to the same key are added up. An accumulation round in
+/// progress whose cursor can't be migrated is abandoned.
+///
+/// Returns the number of the migrated entries.
+pub fn migrate_pool_id_keys<T: Config, OldPoolId: Decode>(
+	migrate_pool_id: impl Fn(OldPoolId) -> Option<PoolId<T::RelaychainAccountId>>,
+) -> u64 {
+	let mut count: u64 = 0;
+
+	count += migrate_map_keys::<OldPoolId, Balance, T::RelaychainAccountId>(
+		IncentiveRewardAmount::<T>::module_prefix(),
+		IncentiveRewardAmount::<T>::storage_prefix(),
+		&migrate_pool_id,
+		|pool_id, amount| IncentiveRewardAmount::<T>::insert(pool_id, amount),
+	);
+	count += migrate_map_keys::<OldPoolId, Rate, T::RelaychainAccountId>(
+		DexSavingRewardRate::<T>::module_prefix(),
+		DexSavingRewardRate::<T>::storage_prefix(),
+		&migrate_pool_id,
+		|pool_id, rate| DexSavingRewardRate::<T>::insert(pool_id, rate),
+	);
+	count += migrate_map_keys::<OldPoolId, Rate, T::RelaychainAccountId>(
+		PayoutDeductionRates::<T>::module_prefix(),
+		PayoutDeductionRates::<T>::storage_prefix(),
+		&migrate_pool_id,
+		|pool_id, rate| PayoutDeductionRates::<T>::insert(pool_id, rate),
+	);
+	count += migrate_map_keys::<OldPoolId, T::BlockNumber, T::RelaychainAccountId>(
+		RewardStartBlock::<T>::module_prefix(),
+		RewardStartBlock::<T>::storage_prefix(),
+		&migrate_pool_id,
+		|pool_id, start_block| RewardStartBlock::<T>::insert(pool_id, start_block),
+	);
+	count += migrate_map_keys::<OldPoolId, (T::BlockNumber, T::BlockNumber), T::RelaychainAccountId>(
+		RewardSchedule::<T>::module_prefix(),
+		RewardSchedule::<T>::storage_prefix(),
+		&migrate_pool_id,
+		|pool_id, schedule| RewardSchedule::<T>::insert(pool_id, schedule),
+	);
+	count += migrate_map_keys::<OldPoolId, Balance, T::RelaychainAccountId>(
+		ShortfallRewards::<T>::module_prefix(),
+		ShortfallRewards::<T>::storage_prefix(),
+		&migrate_pool_id,
+		|pool_id, amount| ShortfallRewards::<T>::insert(pool_id, amount),
+	);
+	count += migrate_map_keys::<OldPoolId, CurrencyId, T::RelaychainAccountId>(
+		PoolRewardCurrency::<T>::module_prefix(),
+		PoolRewardCurrency::<T>::storage_prefix(),
+		&migrate_pool_id,
+		|pool_id, currency_id| PoolRewardCurrency::<T>::insert(pool_id, currency_id),
+	);
+	count += migrate_map_keys::<OldPoolId, PoolInfo<Balance, Balance>, T::RelaychainAccountId>(
+		orml_rewards::Pools::<T>::module_prefix(),
+		orml_rewards::Pools::<T>::storage_prefix(),
+		&migrate_pool_id,
+		|pool_id, pool_info| {
+			orml_rewards::Pools::<T>::mutate(pool_id, |info| {
+				info.total_shares = info.total_shares.saturating_add(pool_info.total_shares);
+				info.total_rewards = info.total_rewards.saturating_add(pool_info.total_rewards);
+				info.total_withdrawn_rewards = info
+					.total_withdrawn_rewards
+					.saturating_add(pool_info.total_withdrawn_rewards);
+			})
+		},
+	);
+
+	let cursor_key = AccumulationCursor::<T>::hashed_key();
+	if let Some(raw_cursor) = unhashed::get_raw(&cursor_key) {
+		count += 1;
+		match Option::<OldPoolId>::decode(&mut &raw_cursor[..]) {
+			Ok(None) => {}
+			Ok(Some(old_pool_id)) => match migrate_pool_id(old_pool_id) {
+				Some(pool_id) => AccumulationCursor::<T>::put(Some(pool_id)),
+				None => {
+					log::warn!(
+						target: "incentives",
+						"migrate pool id keys: the last accumulated pool is removed, abandon the accumulation round"
+					);
+					AccumulationCursor::<T>::kill();
+				}
+			},
+			Err(_) => AccumulationCursor::<T>::kill(),
+		}
+	}
+
+	count
+}
+
+/// Re-key at most `limit` entries of `PendingRewards` following the raw
+/// storage key `start_key`, or from the first entry if `start_key` is
+/// `None`. The pool ids are decoded as `OldPoolId` and converted with
+/// `migrate_pool_id`, the amounts of the entries converted to the same key
+/// are added up. The entries converted to `None` or whose key can't be
+/// decoded are removed.
+///
+/// `migrate_pool_id` must convert the migrated pool ids to themselves, so the
+/// entries re-keyed after the current position are kept as they are when
+/// visited again.
+///
+/// Returns the number of the visited entries and the raw key to continue
+/// from, `None` if all the entries were visited.
+pub fn migrate_pending_rewards_keys<T: Config, OldPoolId: Decode>(
+	migrate_pool_id: impl Fn(OldPoolId) -> Option<PoolId<T::RelaychainAccountId>>,
+	start_key: Option<Vec<u8>>,
+	limit: u32,
+) -> (u32, Option<Vec<u8>>) {
+	migrate_double_map_keys::<T, OldPoolId, Balance>(
+		PendingRewards::<T>::final_prefix(),
+		migrate_pool_id,
+		start_key,
+		limit,
+		|pool_id, who, amount| {
+			PendingRewards::<T>::mutate(pool_id, who, |pending| *pending = pending.saturating_add(amount))
+		},
+	)
+}
+
+/// Re-key at most `limit` entries of `ShareAndWithdrawnReward` of
+/// orml_rewards in the same way as `migrate_pending_rewards_keys`, the shares
+/// and the withdrawn rewards of the entries converted to the same key are
+/// added up.
+///
+/// Returns the number of the visited entries and the raw key to continue
+/// from, `None` if all the entries were visited.
+pub fn migrate_share_and_withdrawn_reward_keys<T: Config, OldPoolId: Decode>(
+	migrate_pool_id: impl Fn(OldPoolId) -> Option<PoolId<T::RelaychainAccountId>>,
+	start_key: Option<Vec<u8>>,
+	limit: u32,
+) -> (u32, Option<Vec<u8>>) {
+	migrate_double_map_keys::<T, OldPoolId, (Balance, Balance)>(
+		orml_rewards::ShareAndWithdrawnReward::<T>::final_prefix(),
+		migrate_pool_id,
+		start_key,
+		limit,
+		|pool_id, who, (share, withdrawn_reward)| {
+			orml_rewards::ShareAndWithdrawnReward::<T>::mutate(pool_id, who, |(total_share, total_withdrawn)| {
+				*total_share = total_share.saturating_add(share);
+				*total_withdrawn = total_withdrawn.saturating_add(withdrawn_reward);
+			})
+		},
+	)
+}
+
+fn migrate_double_map_keys<T: Config, OldPoolId: Decode, Value: Decode>(
+	prefix: [u8; 32],
+	migrate_pool_id: impl Fn(OldPoolId) -> Option<PoolId<T::RelaychainAccountId>>,
+	start_key: Option<Vec<u8>>,
+	limit: u32,
+	merge: impl Fn(PoolId<T::RelaychainAccountId>, T::AccountId, Value),
+) -> (u32, Option<Vec<u8>>) {
+	let mut previous_key = start_key.unwrap_or_else(|| prefix.to_vec());
+	let mut visited: u32 = 0;
+
+	while visited < limit {
+		let raw_key = match sp_io::storage::next_key(&previous_key).filter(|key| key.starts_with(&prefix)) {
+			Some(raw_key) => raw_key,
+			None => return (visited, None),
+		};
+		visited += 1;
+
+		// the double map has `Twox64Concat` hashers, the raw key is
+		// prefix ++ twox64(pool_id) ++ pool_id ++ twox64(who) ++ who.
+		let migrated = raw_key.get(prefix.len() + 8..).and_then(|encoded| {
+			let mut key = encoded;
+			let old_pool_id = OldPoolId::decode(&mut key).ok()?;
+			let encoded_pool_id = &encoded[..encoded.len() - key.len()];
+			let who = T::AccountId::decode(&mut key.get(8..)?).ok()?;
+			let pool_id = migrate_pool_id(old_pool_id)?;
+			Some((pool_id.encode() == encoded_pool_id, pool_id, who))
+		});
+		match migrated {
+			Some((true, _, _)) => {}
+			Some((false, pool_id, who)) => {
+				if let Some(value) = unhashed::take::<Value>(&raw_key) {
+					merge(pool_id, who, value);
+				}
+			}
+			None => unhashed::kill(&raw_key),
+		}
+
+		previous_key = raw_key;
+	}
+
+	(visited, Some(previous_key))
+}
+
+fn migrate_map_keys<OldPoolId: Decode, Value: Decode, RelaychainAccountId>(
+	module: &[u8],
+	item: &[u8],
+	migrate_pool_id: &impl Fn(OldPoolId) -> Option<PoolId<RelaychainAccountId>>,
+	insert: impl Fn(PoolId<RelaychainAccountId>, Value),
+) -> u64 {
+	let entries: Vec<(OldPoolId, Value)> = storage_key_iter::<OldPoolId, Value, Twox64Concat>(module, item)
+		.drain()
+		.collect();
+	let count = entries.len() as u64;
+	for (old_pool_id, value) in entries {
+		if let Some(pool_id) = migrate_pool_id(old_pool_id) {
+			insert(pool_id, value);
+		}
+	}
+	count
+}
+
+/// Migrate to `Releases::V2_0_0`. The encoding of `PoolId` is not changed
+/// in V2, so no entries need to be migrated.
+pub mod v2 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		log::info!(target: "incentives", "migrate storage to {:?}", Releases::V2_0_0);
+		StorageVersion::<T>::put(Releases::V2_0_0);
+		T::DbWeight::get().writes(1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::<T>::get() == Releases::V1_0_0,
+			"incentives: storage version must be V1_0_0"
+		);
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::<T>::get() == Releases::V2_0_0,
+			"incentives: storage version must be V2_0_0"
+		);
+
+		// all the keys must still be decodable as `PoolId`
+		ensure!(
+			storage_iter::<Balance>(
+				IncentiveRewardAmount::<T>::module_prefix(),
+				IncentiveRewardAmount::<T>::storage_prefix()
+			)
+			.count() == IncentiveRewardAmount::<T>::iter().count(),
+			"incentives: undecodable key in IncentiveRewardAmount"
+		);
+		ensure!(
+			storage_iter::<Rate>(
+				DexSavingRewardRate::<T>::module_prefix(),
+				DexSavingRewardRate::<T>::storage_prefix()
+			)
+			.count() == DexSavingRewardRate::<T>::iter().count(),
+			"incentives: undecodable key in DexSavingRewardRate"
+		);
+		ensure!(
+			storage_iter::<Rate>(
+				PayoutDeductionRates::<T>::module_prefix(),
+				PayoutDeductionRates::<T>::storage_prefix()
+			)
+			.count() == PayoutDeductionRates::<T>::iter().count(),
+			"incentives: undecodable key in PayoutDeductionRates"
+		);
+		ensure!(
+			storage_iter::<T::BlockNumber>(
+				RewardStartBlock::<T>::module_prefix(),
+				RewardStartBlock::<T>::storage_prefix()
+			)
+			.count() == RewardStartBlock::<T>::iter().count(),
+			"incentives: undecodable key in RewardStartBlock"
+		);
+		ensure!(
+			storage_iter::<(T::BlockNumber, T::BlockNumber)>(
+				RewardSchedule::<T>::module_prefix(),
+				RewardSchedule::<T>::storage_prefix()
+			)
+			.count() == RewardSchedule::<T>::iter().count(),
+			"incentives: undecodable key in RewardSchedule"
+		);
+		ensure!(
+			storage_iter::<Balance>(
+				ShortfallRewards::<T>::module_prefix(),
+				ShortfallRewards::<T>::storage_prefix()
+			)
+			.count() == ShortfallRewards::<T>::iter().count(),
+			"incentives: undecodable key in ShortfallRewards"
+		);
+		ensure!(
+			storage_iter::<CurrencyId>(
+				PoolRewardCurrency::<T>::module_prefix(),
+				PoolRewardCurrency::<T>::storage_prefix()
+			)
+			.count() == PoolRewardCurrency::<T>::iter().count(),
+			"incentives: undecodable key in PoolRewardCurrency"
+		);
+		ensure!(
+			storage_iter::<Balance>(
+				PendingRewards::<T>::module_prefix(),
+				PendingRewards::<T>::storage_prefix()
+			)
+			.count() == PendingRewards::<T>::iter().count(),
+			"incentives: undecodable key in PendingRewards"
+		);
+		ensure!(
+			storage_iter::<PoolInfo<Balance, Balance>>(
+				orml_rewards::Pools::<T>::module_prefix(),
+				orml_rewards::Pools::<T>::storage_prefix()
+			)
+			.count() == orml_rewards::Pools::<T>::iter().count(),
+			"incentives: undecodable key in orml_rewards Pools"
+		);
+		ensure!(
+			storage_iter::<(Balance, Balance)>(
+				orml_rewards::ShareAndWithdrawnReward::<T>::module_prefix(),
+				orml_rewards::ShareAndWithdrawnReward::<T>::storage_prefix()
+			)
+			.count() == orml_rewards::ShareAndWithdrawnReward::<T>::iter().count(),
+			"incentives: undecodable key in orml_rewards ShareAndWithdrawnReward"
+		);
+		ensure!(
+			unhashed::get_raw(&AccumulationCursor::<T>::hashed_key()).map_or(true, |raw_cursor| {
+				Option::<PoolId<T::RelaychainAccountId>>::decode(&mut &raw_cursor[..]).is_ok()
+			}),
+			"incentives: undecodable AccumulationCursor"
+		);
+		Ok(())
+	}
+}
@@ -57,6 +57,7 @@ ord_parameter_types! {
 	pub const VAULT: AccountId = IncentivesModule::account_id();
 	pub const UNRELEASED: AccountId = AccountId::from([3u8; 32]);
 	pub const VALIDATOR: AccountId = AccountId::from([4u8; 32]);
+	pub const REWARDS_SOURCE: AccountId = AccountId::from([5u8; 32]);
 	pub const ROOT: AccountId = AccountId32::new([255u8; 32]);
 }
 
@@ -148,6 +149,10 @@ impl CDPTreasury<AccountId> for MockCDPTreasury {
 		unimplemented!()
 	}
 
+	fn withdraw_surplus(_: &AccountId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
 	fn deposit_collateral(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
 		unimplemented!()
 	}
@@ -181,13 +186,20 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn get_time_weighted_average_price(_: CurrencyId, _: CurrencyId, _: u32) -> Option<Price> {
+		unimplemented!()
+	}
+
 	fn swap_with_exact_supply(
-		_: &AccountId,
-		_: &[CurrencyId],
-		_: Balance,
+		who: &AccountId,
+		path: &[CurrencyId],
+		supply_amount: Balance,
 		_: Balance,
 	) -> sp_std::result::Result<Balance, DispatchError> {
-		unimplemented!()
+		// swap at 1:1 rate
+		TokensModule::withdraw(path[0], who, supply_amount)?;
+		TokensModule::deposit(path[path.len() - 1], who, supply_amount)?;
+		Ok(supply_amount)
 	}
 
 	fn swap_with_exact_target(
@@ -211,6 +223,25 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn swap_and_add_liquidity(
+		who: &AccountId,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		amount_a: Balance,
+		min_share_increment: Balance,
+		_: bool,
+	) -> DispatchResult {
+		// issue one share for every two units of supply
+		let lp_currency_id = CurrencyId::join_dex_share_currency_id(currency_id_a, currency_id_b)
+			.ok_or(Error::<Runtime>::InvalidCurrencyId)?;
+		ensure!(
+			amount_a / 2 >= min_share_increment,
+			DispatchError::Other("InsufficientShareIncrement")
+		);
+		TokensModule::withdraw(currency_id_a, who, amount_a)?;
+		TokensModule::deposit(lp_currency_id, who, amount_a / 2)
+	}
+
 	fn remove_liquidity(
 		_: &AccountId,
 		_: CurrencyId,
@@ -226,6 +257,23 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 
 thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
+	static BTC_PRICE: RefCell<Option<Price>> = RefCell::new(Some(Price::saturating_from_integer(5)));
+}
+
+pub struct MockPriceSource;
+impl MockPriceSource {
+	pub fn set_btc_price(price: Option<Price>) {
+		BTC_PRICE.with(|v| *v.borrow_mut() = price);
+	}
+}
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			AUSD => Some(Price::one()),
+			BTC => BTC_PRICE.with(|v| *v.borrow()),
+			_ => None,
+		}
+	}
 }
 
 pub fn mock_shutdown() {
@@ -237,6 +285,10 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	fn is_shutdown() -> bool {
 		IS_SHUTDOWN.with(|v| *v.borrow_mut())
 	}
+
+	fn is_collateral_shutdown(_currency_id: CurrencyId) -> bool {
+		false
+	}
 }
 
 impl orml_rewards::Config for Runtime {
@@ -248,7 +300,10 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub NativeRewardsSource: AccountId = UNRELEASED::get();
+	pub AlternativeRewardsSource: AccountId = REWARDS_SOURCE::get();
 	pub const AccumulatePeriod: BlockNumber = 10;
+	pub const MaxPoolsPerBlock: u32 = 10;
+	pub const ExpectedMaxPools: u32 = 100;
 	pub const NativeCurrencyId: CurrencyId = ACA;
 	pub const StableCurrencyId: CurrencyId = AUSD;
 	pub const LiquidCurrencyId: CurrencyId = LDOT;
@@ -263,7 +318,10 @@ impl Config for Runtime {
 	type Event = Event;
 	type RelaychainAccountId = AccountId;
 	type NativeRewardsSource = NativeRewardsSource;
+	type AlternativeRewardsSource = AlternativeRewardsSource;
 	type AccumulatePeriod = AccumulatePeriod;
+	type MaxPoolsPerBlock = MaxPoolsPerBlock;
+	type ExpectedMaxPools = ExpectedMaxPools;
 	type NativeCurrencyId = NativeCurrencyId;
 	type StableCurrencyId = StableCurrencyId;
 	type LiquidCurrencyId = LiquidCurrencyId;
@@ -272,6 +330,7 @@ impl Config for Runtime {
 	type Currency = TokensModule;
 	type DEX = MockDEX;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type PriceSource = MockPriceSource;
 	type PalletId = IncentivesPalletId;
 	type WeightInfo = ();
 }
@@ -25,7 +25,7 @@ use frame_support::{assert_noop, assert_ok};
 use mock::{Event, *};
 use orml_rewards::PoolInfo;
 use orml_traits::MultiCurrency;
-use sp_runtime::{traits::BadOrigin, FixedPointNumber};
+use sp_runtime::{traits::BadOrigin, DispatchError, FixedPointNumber};
 
 #[test]
 fn deposit_dex_share_works() {
@@ -350,6 +350,97 @@ fn update_payout_deduction_rates_works() {
 	});
 }
 
+#[test]
+fn update_dex_incentive_boost_bounds_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			IncentivesModule::update_dex_incentive_boost_bounds(Origin::signed(ALICE::get()), None),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_dex_incentive_boost_bounds(
+				Origin::signed(Root::get()),
+				Some((Rate::saturating_from_integer(2), Rate::one()))
+			),
+			Error::<Runtime>::InvalidRate,
+		);
+		assert_noop!(
+			IncentivesModule::update_dex_incentive_boost_bounds(
+				Origin::signed(Root::get()),
+				Some((Rate::saturating_from_rational(1, 2), Rate::saturating_from_integer(2)))
+			),
+			Error::<Runtime>::InvalidRate,
+		);
+
+		assert_eq!(IncentivesModule::dex_incentive_boost_bounds(), None);
+		assert_ok!(IncentivesModule::update_dex_incentive_boost_bounds(
+			Origin::signed(Root::get()),
+			Some((Rate::saturating_from_rational(1, 2), Rate::one()))
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::DexIncentiveBoostBoundsUpdated(
+			Some((Rate::saturating_from_rational(1, 2), Rate::one())),
+		)));
+		assert_eq!(
+			IncentivesModule::dex_incentive_boost_bounds(),
+			Some((Rate::saturating_from_rational(1, 2), Rate::one()))
+		);
+
+		assert_ok!(IncentivesModule::update_dex_incentive_boost_bounds(
+			Origin::signed(Root::get()),
+			None
+		));
+		assert_eq!(IncentivesModule::dex_incentive_boost_bounds(), None);
+	});
+}
+
+#[test]
+fn update_peg_deviation_boost_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			IncentivesModule::update_peg_deviation_boost(Origin::signed(ALICE::get()), None),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_peg_deviation_boost(
+				Origin::signed(Root::get()),
+				Some((
+					Ratio::saturating_from_rational(5, 100),
+					Rate::saturating_from_rational(1, 2)
+				))
+			),
+			Error::<Runtime>::InvalidRate,
+		);
+
+		assert_eq!(IncentivesModule::peg_deviation_boost(), None);
+		assert_ok!(IncentivesModule::update_peg_deviation_boost(
+			Origin::signed(Root::get()),
+			Some((
+				Ratio::saturating_from_rational(5, 100),
+				Rate::saturating_from_integer(2)
+			))
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::PegDeviationBoostUpdated(Some((
+			Ratio::saturating_from_rational(5, 100),
+			Rate::saturating_from_integer(2),
+		)))));
+		assert_eq!(
+			IncentivesModule::peg_deviation_boost(),
+			Some((
+				Ratio::saturating_from_rational(5, 100),
+				Rate::saturating_from_integer(2)
+			))
+		);
+
+		assert_ok!(IncentivesModule::update_peg_deviation_boost(
+			Origin::signed(Root::get()),
+			None
+		));
+		assert_eq!(IncentivesModule::peg_deviation_boost(), None);
+	});
+}
+
 #[test]
 fn add_allowance_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -849,6 +940,57 @@ fn claim_rewards_works() {
 	});
 }
 
+#[test]
+fn force_claim_rewards_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+		assert_ok!(IncentivesModule::update_payout_deduction_rates(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), Rate::saturating_from_rational(20, 100))]
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::LoansIncentive(BTC), 100);
+		RewardsModule::accumulate_reward(&PoolId::LoansIncentive(BTC), 1000);
+
+		assert_noop!(
+			IncentivesModule::force_claim_rewards(
+				Origin::signed(BOB::get()),
+				ALICE::get(),
+				PoolId::LoansIncentive(BTC)
+			),
+			BadOrigin
+		);
+
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 0);
+		assert_ok!(IncentivesModule::force_claim_rewards(
+			Origin::signed(Root::get()),
+			ALICE::get(),
+			PoolId::LoansIncentive(BTC)
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ClaimRewards(
+			ALICE::get(),
+			PoolId::LoansIncentive(BTC),
+			ACA,
+			800,
+			200,
+		)));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 800);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9200);
+		assert_eq!(
+			IncentivesModule::pending_rewards(PoolId::LoansIncentive(BTC), ALICE::get()),
+			0
+		);
+		assert_eq!(
+			RewardsModule::pools(PoolId::LoansIncentive(BTC)),
+			PoolInfo {
+				total_shares: 100,
+				total_rewards: 1200,
+				total_withdrawn_rewards: 1000
+			}
+		);
+	});
+}
+
 #[test]
 fn on_initialize_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -951,3 +1093,950 @@ fn on_initialize_should_work() {
 		assert_eq!(RewardsModule::pools(PoolId::DexSaving(DOT_AUSD_LP)).total_rewards, 8);
 	});
 }
+
+#[test]
+fn on_initialize_should_accumulate_pools_round_robin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), 1000)],
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::LoansIncentive(BTC), 1);
+		for i in 0..25u8 {
+			RewardsModule::add_share(
+				&ALICE::get(),
+				&PoolId::HomaValidatorAllowance(AccountId::from([100 + i; 32])),
+				1,
+			);
+		}
+
+		// 26 pools are processed in 3 blocks, at most 10 pools per block
+		assert_eq!(
+			IncentivesModule::on_initialize(10),
+			<() as WeightInfo>::on_initialize(MaxPoolsPerBlock::get())
+		);
+		assert!(IncentivesModule::accumulation_cursor().is_some());
+		assert_eq!(
+			IncentivesModule::on_initialize(11),
+			<() as WeightInfo>::on_initialize(MaxPoolsPerBlock::get())
+		);
+		assert!(IncentivesModule::accumulation_cursor().is_some());
+		assert_eq!(
+			IncentivesModule::on_initialize(12),
+			<() as WeightInfo>::on_initialize(6)
+		);
+		assert_eq!(IncentivesModule::accumulation_cursor(), None);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 1000);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 1000);
+
+		// no accumulation until the next period
+		assert_eq!(
+			IncentivesModule::on_initialize(13),
+			<Runtime as frame_system::Config>::DbWeight::get().reads(2)
+		);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 1000);
+
+		IncentivesModule::on_initialize(20);
+		IncentivesModule::on_initialize(21);
+		IncentivesModule::on_initialize(22);
+		assert_eq!(IncentivesModule::accumulation_cursor(), None);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 2000);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 2000);
+	});
+}
+
+#[test]
+fn on_initialize_should_postpone_the_round_of_overrun_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), 1000)],
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::LoansIncentive(BTC), 1);
+		for i in 0..25u8 {
+			RewardsModule::add_share(
+				&ALICE::get(),
+				&PoolId::HomaValidatorAllowance(AccountId::from([100 + i; 32])),
+				1,
+			);
+		}
+
+		// the round of block 10 is still in progress at block 20
+		IncentivesModule::on_initialize(10);
+		IncentivesModule::on_initialize(20);
+		assert_eq!(IncentivesModule::pending_accumulation_rounds(), 1);
+		IncentivesModule::on_initialize(21);
+		assert_eq!(IncentivesModule::accumulation_cursor(), None);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 1000);
+
+		// the postponed round is started right after
+		IncentivesModule::on_initialize(22);
+		assert_eq!(IncentivesModule::pending_accumulation_rounds(), 0);
+		assert!(IncentivesModule::accumulation_cursor().is_some());
+		IncentivesModule::on_initialize(23);
+		IncentivesModule::on_initialize(24);
+		assert_eq!(IncentivesModule::accumulation_cursor(), None);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 2000);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 2000);
+
+		assert_eq!(
+			IncentivesModule::on_initialize(25),
+			<Runtime as frame_system::Config>::DbWeight::get().reads(2)
+		);
+	});
+}
+
+#[test]
+fn integrity_test_should_work() {
+	IncentivesModule::integrity_test();
+}
+
+#[test]
+fn on_initialize_with_dex_incentive_boost_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![
+				(PoolId::DexIncentive(BTC_AUSD_LP), 100),
+				(PoolId::DexIncentive(DOT_AUSD_LP), 100),
+			],
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::DexIncentive(BTC_AUSD_LP), 1);
+		RewardsModule::add_share(&ALICE::get(), &PoolId::DexIncentive(DOT_AUSD_LP), 1);
+
+		// no boost without bounds
+		assert!(IncentivesModule::dex_incentive_multipliers().is_empty());
+		IncentivesModule::on_initialize(10);
+		assert_eq!(
+			RewardsModule::pools(PoolId::DexIncentive(BTC_AUSD_LP)).total_rewards,
+			100
+		);
+		assert_eq!(
+			RewardsModule::pools(PoolId::DexIncentive(DOT_AUSD_LP)).total_rewards,
+			100
+		);
+
+		// stable liquidity of BTC/AUSD is 500, DOT/AUSD is 400, the reward of the
+		// thicker BTC/AUSD pool is cut and the thinner DOT/AUSD pool is capped at 1.
+		assert_ok!(IncentivesModule::update_dex_incentive_boost_bounds(
+			Origin::signed(Root::get()),
+			Some((Rate::saturating_from_rational(1, 2), Rate::one()))
+		));
+		assert_eq!(
+			IncentivesModule::dex_incentive_multipliers().get(&BTC_AUSD_LP),
+			Some(&Rate::saturating_from_rational(9, 10))
+		);
+		assert_eq!(
+			IncentivesModule::dex_incentive_multipliers().get(&DOT_AUSD_LP),
+			Some(&Rate::one())
+		);
+		IncentivesModule::on_initialize(20);
+		assert_eq!(
+			RewardsModule::pools(PoolId::DexIncentive(BTC_AUSD_LP)).total_rewards,
+			190
+		);
+		assert_eq!(
+			RewardsModule::pools(PoolId::DexIncentive(DOT_AUSD_LP)).total_rewards,
+			200
+		);
+
+		// multiplier is clamped by bounds
+		assert_ok!(IncentivesModule::update_dex_incentive_boost_bounds(
+			Origin::signed(Root::get()),
+			Some((Rate::saturating_from_rational(19, 20), Rate::one()))
+		));
+		IncentivesModule::on_initialize(30);
+		assert_eq!(
+			RewardsModule::pools(PoolId::DexIncentive(BTC_AUSD_LP)).total_rewards,
+			285
+		);
+		assert_eq!(
+			RewardsModule::pools(PoolId::DexIncentive(DOT_AUSD_LP)).total_rewards,
+			300
+		);
+	});
+}
+
+#[test]
+fn on_initialize_keeps_dex_incentive_multipliers_for_the_round() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![
+				(PoolId::DexIncentive(BTC_AUSD_LP), 100),
+				(PoolId::DexIncentive(DOT_AUSD_LP), 100),
+			],
+		));
+		assert_ok!(IncentivesModule::update_dex_incentive_boost_bounds(
+			Origin::signed(Root::get()),
+			Some((Rate::saturating_from_rational(1, 2), Rate::one()))
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::DexIncentive(BTC_AUSD_LP), 1);
+		RewardsModule::add_share(&ALICE::get(), &PoolId::DexIncentive(DOT_AUSD_LP), 1);
+		for i in 0..25u8 {
+			RewardsModule::add_share(
+				&ALICE::get(),
+				&PoolId::HomaValidatorAllowance(AccountId::from([100 + i; 32])),
+				1,
+			);
+		}
+
+		// only the pools in `IncentiveRewardAmount` are scanned at the start of the round
+		assert_eq!(
+			IncentivesModule::on_initialize(10),
+			<() as WeightInfo>::on_initialize(MaxPoolsPerBlock::get())
+				+ <Runtime as frame_system::Config>::DbWeight::get().reads_writes(6, 1)
+		);
+		assert_eq!(
+			IncentivesModule::round_dex_incentive_multipliers().get(&BTC_AUSD_LP),
+			Some(&Rate::saturating_from_rational(9, 10))
+		);
+		assert_eq!(
+			IncentivesModule::round_dex_incentive_multipliers().get(&DOT_AUSD_LP),
+			Some(&Rate::one())
+		);
+
+		// the multipliers of the round are used even if the bounds are removed
+		assert_ok!(IncentivesModule::update_dex_incentive_boost_bounds(
+			Origin::signed(Root::get()),
+			None
+		));
+		IncentivesModule::on_initialize(11);
+		IncentivesModule::on_initialize(12);
+		assert_eq!(IncentivesModule::accumulation_cursor(), None);
+		assert_eq!(
+			RewardsModule::pools(PoolId::DexIncentive(BTC_AUSD_LP)).total_rewards,
+			90
+		);
+		assert_eq!(
+			RewardsModule::pools(PoolId::DexIncentive(DOT_AUSD_LP)).total_rewards,
+			100
+		);
+		assert!(IncentivesModule::round_dex_incentive_multipliers().is_empty());
+	});
+}
+
+#[test]
+fn on_initialize_with_peg_deviation_boost_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IncentivesModule::update_dex_saving_rewards(
+			Origin::signed(Root::get()),
+			vec![(PoolId::DexSaving(BTC_AUSD_LP), Rate::saturating_from_rational(1, 100))],
+		));
+		assert_ok!(IncentivesModule::update_peg_deviation_boost(
+			Origin::signed(Root::get()),
+			Some((
+				Ratio::saturating_from_rational(5, 100),
+				Rate::saturating_from_integer(2)
+			))
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::DexSaving(BTC_AUSD_LP), 1);
+
+		// price of BTC/AUSD pool is 5, same as the oracle price, no boost
+		IncentivesModule::on_initialize(10);
+		assert_eq!(RewardsModule::pools(PoolId::DexSaving(BTC_AUSD_LP)).total_rewards, 5);
+
+		// oracle price is 4, the pool price deviates 25%, reward rate is doubled
+		MockPriceSource::set_btc_price(Some(Price::saturating_from_integer(4)));
+		IncentivesModule::on_initialize(20);
+		assert_eq!(RewardsModule::pools(PoolId::DexSaving(BTC_AUSD_LP)).total_rewards, 15);
+
+		// no boost if the oracle price is unavailable
+		MockPriceSource::set_btc_price(None);
+		IncentivesModule::on_initialize(30);
+		assert_eq!(RewardsModule::pools(PoolId::DexSaving(BTC_AUSD_LP)).total_rewards, 20);
+	});
+}
+
+#[test]
+fn update_reward_start_blocks_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			IncentivesModule::update_reward_start_blocks(Origin::signed(ALICE::get()), vec![]),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_reward_start_blocks(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexIncentive(DOT), Some(20))]
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			IncentivesModule::update_reward_start_blocks(
+				Origin::signed(Root::get()),
+				vec![(PoolId::HomaValidatorAllowance(VALIDATOR::get()), Some(20))]
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+
+		assert_eq!(IncentivesModule::reward_start_block(PoolId::LoansIncentive(BTC)), None);
+		assert_ok!(IncentivesModule::update_reward_start_blocks(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), Some(20))]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::RewardStartBlockUpdated(
+			PoolId::LoansIncentive(BTC),
+			Some(20),
+		)));
+		assert_eq!(
+			IncentivesModule::reward_start_block(PoolId::LoansIncentive(BTC)),
+			Some(20)
+		);
+
+		assert_ok!(IncentivesModule::update_reward_start_blocks(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), None)]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::RewardStartBlockUpdated(
+			PoolId::LoansIncentive(BTC),
+			None,
+		)));
+		assert_eq!(IncentivesModule::reward_start_block(PoolId::LoansIncentive(BTC)), None);
+	});
+}
+
+#[test]
+fn on_initialize_should_not_accumulate_before_reward_start_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), 1000), (PoolId::LoansIncentive(DOT), 2000)],
+		));
+		assert_ok!(IncentivesModule::update_reward_start_blocks(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), Some(20))]
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::LoansIncentive(BTC), 1);
+		RewardsModule::add_share(&ALICE::get(), &PoolId::LoansIncentive(DOT), 1);
+
+		IncentivesModule::on_initialize(10);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 2000);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 0);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(DOT)).total_rewards, 2000);
+
+		IncentivesModule::on_initialize(20);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 5000);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 1000);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(DOT)).total_rewards, 4000);
+	});
+}
+
+#[test]
+fn update_reward_schedules_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			IncentivesModule::update_reward_schedules(Origin::signed(ALICE::get()), vec![]),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_reward_schedules(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexIncentive(DOT), Some((20, 40)))]
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			IncentivesModule::update_reward_schedules(
+				Origin::signed(Root::get()),
+				vec![(PoolId::HomaValidatorAllowance(VALIDATOR::get()), Some((20, 40)))]
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::update_reward_schedules(
+				Origin::signed(Root::get()),
+				vec![(PoolId::LoansIncentive(BTC), Some((40, 40)))]
+			),
+			Error::<Runtime>::InvalidRewardSchedule
+		);
+
+		assert_eq!(IncentivesModule::reward_schedule(PoolId::LoansIncentive(BTC)), None);
+		assert_ok!(IncentivesModule::update_reward_schedules(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), Some((20, 40)))]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::RewardScheduleUpdated(
+			PoolId::LoansIncentive(BTC),
+			Some((20, 40)),
+		)));
+		assert_eq!(
+			IncentivesModule::reward_schedule(PoolId::LoansIncentive(BTC)),
+			Some((20, 40))
+		);
+
+		assert_ok!(IncentivesModule::update_reward_schedules(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), None)]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::RewardScheduleUpdated(
+			PoolId::LoansIncentive(BTC),
+			None,
+		)));
+		assert_eq!(IncentivesModule::reward_schedule(PoolId::LoansIncentive(BTC)), None);
+	});
+}
+
+#[test]
+fn on_initialize_should_accumulate_within_reward_schedule() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), 1000), (PoolId::LoansIncentive(DOT), 1000)],
+		));
+		assert_ok!(IncentivesModule::update_reward_schedules(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), Some((20, 40)))]
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::LoansIncentive(BTC), 1);
+		RewardsModule::add_share(&ALICE::get(), &PoolId::LoansIncentive(DOT), 1);
+
+		// before the start block
+		IncentivesModule::on_initialize(10);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 0);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(DOT)).total_rewards, 1000);
+
+		// within the window
+		IncentivesModule::on_initialize(20);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 1000);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(DOT)).total_rewards, 2000);
+		IncentivesModule::on_initialize(30);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 2000);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(DOT)).total_rewards, 3000);
+
+		// stop exactly at the end block
+		IncentivesModule::on_initialize(40);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 2000);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(DOT)).total_rewards, 4000);
+		IncentivesModule::on_initialize(50);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 2000);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(DOT)).total_rewards, 5000);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 7000);
+	});
+}
+
+#[test]
+fn settle_shortfall_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), 6000)],
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::LoansIncentive(BTC), 1);
+
+		IncentivesModule::on_initialize(10);
+		assert_eq!(TokensModule::free_balance(ACA, &UNRELEASED::get()), 4000);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 6000);
+		assert_eq!(IncentivesModule::shortfall_rewards(PoolId::LoansIncentive(BTC)), 0);
+
+		// the source is depleted, record the shortfall
+		IncentivesModule::on_initialize(20);
+		assert_eq!(TokensModule::free_balance(ACA, &UNRELEASED::get()), 4000);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 6000);
+		assert_eq!(IncentivesModule::shortfall_rewards(PoolId::LoansIncentive(BTC)), 6000);
+
+		assert_noop!(
+			IncentivesModule::settle_shortfall(Origin::signed(ALICE::get()), PoolId::LoansIncentive(BTC)),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::settle_shortfall(Origin::signed(Root::get()), PoolId::LoansIncentive(BTC)),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		// refill the source and settle the shortfall
+		assert_ok!(TokensModule::deposit(ACA, &UNRELEASED::get(), 10_000));
+		assert_ok!(IncentivesModule::settle_shortfall(
+			Origin::signed(Root::get()),
+			PoolId::LoansIncentive(BTC)
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ShortfallRewardsSettled(
+			PoolId::LoansIncentive(BTC),
+			6000,
+		)));
+		assert_eq!(TokensModule::free_balance(ACA, &UNRELEASED::get()), 8000);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 12000);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)).total_rewards, 12000);
+		assert_eq!(IncentivesModule::shortfall_rewards(PoolId::LoansIncentive(BTC)), 0);
+
+		assert_noop!(
+			IncentivesModule::settle_shortfall(Origin::signed(Root::get()), PoolId::LoansIncentive(BTC)),
+			Error::<Runtime>::NoShortfallRewards
+		);
+	});
+}
+
+#[test]
+fn compound_dex_rewards_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 100));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+
+		assert_noop!(
+			IncentivesModule::compound_dex_rewards(Origin::signed(ALICE::get()), BTC, 0),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			IncentivesModule::compound_dex_rewards(Origin::signed(ALICE::get()), BTC_AUSD_LP, 0),
+			Error::<Runtime>::NoRewardsToCompound
+		);
+
+		RewardsModule::accumulate_reward(&PoolId::DexIncentive(BTC_AUSD_LP), 1000);
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(PoolId::DexIncentive(BTC_AUSD_LP), ALICE::get()).0,
+			100
+		);
+
+		// the share increment is lower than expected
+		assert_noop!(
+			IncentivesModule::compound_dex_rewards(Origin::signed(ALICE::get()), BTC_AUSD_LP, 501),
+			DispatchError::Other("InsufficientShareIncrement")
+		);
+
+		assert_ok!(IncentivesModule::compound_dex_rewards(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			500
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::DexRewardsCompounded(
+			ALICE::get(),
+			BTC_AUSD_LP,
+			1000,
+			500,
+		)));
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(PoolId::DexIncentive(BTC_AUSD_LP), ALICE::get()).0,
+			600
+		);
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(PoolId::DexSaving(BTC_AUSD_LP), ALICE::get()).0,
+			600
+		);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &VAULT::get()), 600);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 0);
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 0);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9000);
+	});
+}
+
+#[test]
+fn update_pool_reward_currencies_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			IncentivesModule::update_pool_reward_currencies(Origin::signed(ALICE::get()), vec![]),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_pool_reward_currencies(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexIncentive(DOT), Some(DOT))]
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			IncentivesModule::update_pool_reward_currencies(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexSaving(BTC_AUSD_LP), Some(DOT))]
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+
+		assert_eq!(
+			IncentivesModule::pool_reward_currency(PoolId::DexIncentive(BTC_AUSD_LP)),
+			None
+		);
+		assert_ok!(IncentivesModule::update_pool_reward_currencies(
+			Origin::signed(Root::get()),
+			vec![(PoolId::DexIncentive(BTC_AUSD_LP), Some(DOT))]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::PoolRewardCurrencyUpdated(
+			PoolId::DexIncentive(BTC_AUSD_LP),
+			Some(DOT),
+		)));
+		assert_eq!(
+			IncentivesModule::pool_reward_currency(PoolId::DexIncentive(BTC_AUSD_LP)),
+			Some(DOT)
+		);
+
+		assert_ok!(IncentivesModule::update_pool_reward_currencies(
+			Origin::signed(Root::get()),
+			vec![(PoolId::DexIncentive(BTC_AUSD_LP), None)]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::PoolRewardCurrencyUpdated(
+			PoolId::DexIncentive(BTC_AUSD_LP),
+			None,
+		)));
+		assert_eq!(
+			IncentivesModule::pool_reward_currency(PoolId::DexIncentive(BTC_AUSD_LP)),
+			None
+		);
+	});
+}
+
+#[test]
+fn update_pool_reward_currencies_fails_with_outstanding_rewards() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(PoolId::DexIncentive(BTC_AUSD_LP), 100)],
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::DexIncentive(BTC_AUSD_LP), 1);
+
+		// rewards accumulated in native currency are not claimed yet
+		IncentivesModule::on_initialize(10);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 100);
+		assert_noop!(
+			IncentivesModule::update_pool_reward_currencies(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexIncentive(BTC_AUSD_LP), Some(DOT))]
+			),
+			Error::<Runtime>::PoolHasOutstandingRewards
+		);
+		// keeping the reward currency is fine
+		assert_ok!(IncentivesModule::update_pool_reward_currencies(
+			Origin::signed(Root::get()),
+			vec![(PoolId::DexIncentive(BTC_AUSD_LP), None)]
+		));
+
+		// the pool has shortfall rewards
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			PoolId::DexIncentive(BTC_AUSD_LP)
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 100);
+		ShortfallRewards::<Runtime>::insert(PoolId::DexIncentive(BTC_AUSD_LP), 50);
+		assert_noop!(
+			IncentivesModule::update_pool_reward_currencies(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexIncentive(BTC_AUSD_LP), Some(DOT))]
+			),
+			Error::<Runtime>::PoolHasOutstandingRewards
+		);
+
+		// the pool still has shares
+		ShortfallRewards::<Runtime>::remove(PoolId::DexIncentive(BTC_AUSD_LP));
+		assert_noop!(
+			IncentivesModule::update_pool_reward_currencies(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexIncentive(BTC_AUSD_LP), Some(DOT))]
+			),
+			Error::<Runtime>::PoolHasOutstandingRewards
+		);
+
+		// all shares are removed, the rounding dust of the pool is dropped
+		RewardsModule::remove_share(&ALICE::get(), &PoolId::DexIncentive(BTC_AUSD_LP), 1);
+		orml_rewards::Pools::<Runtime>::mutate(PoolId::DexIncentive(BTC_AUSD_LP), |pool_info| {
+			pool_info.total_rewards += 1;
+		});
+		assert_ok!(IncentivesModule::update_pool_reward_currencies(
+			Origin::signed(Root::get()),
+			vec![(PoolId::DexIncentive(BTC_AUSD_LP), Some(DOT))]
+		));
+		assert_eq!(
+			IncentivesModule::pool_reward_currency(PoolId::DexIncentive(BTC_AUSD_LP)),
+			Some(DOT)
+		);
+		assert_eq!(
+			RewardsModule::pools(PoolId::DexIncentive(BTC_AUSD_LP)),
+			Default::default()
+		);
+	});
+}
+
+#[test]
+fn dex_incentive_pays_in_pool_reward_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(DOT, &REWARDS_SOURCE::get(), 1000));
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![
+				(PoolId::DexIncentive(BTC_AUSD_LP), 100),
+				(PoolId::DexIncentive(DOT_AUSD_LP), 200),
+			],
+		));
+		assert_ok!(IncentivesModule::update_pool_reward_currencies(
+			Origin::signed(Root::get()),
+			vec![(PoolId::DexIncentive(BTC_AUSD_LP), Some(DOT))]
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::DexIncentive(BTC_AUSD_LP), 1);
+		RewardsModule::add_share(&ALICE::get(), &PoolId::DexIncentive(DOT_AUSD_LP), 1);
+
+		// the overridden pool is funded from the alternative source, the other from the native source
+		IncentivesModule::on_initialize(10);
+		assert_eq!(TokensModule::free_balance(DOT, &REWARDS_SOURCE::get()), 900);
+		assert_eq!(TokensModule::free_balance(ACA, &UNRELEASED::get()), 9800);
+		assert_eq!(TokensModule::free_balance(DOT, &VAULT::get()), 100);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 200);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			PoolId::DexIncentive(BTC_AUSD_LP)
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ClaimRewards(
+			ALICE::get(),
+			PoolId::DexIncentive(BTC_AUSD_LP),
+			DOT,
+			100,
+			0,
+		)));
+		assert_eq!(TokensModule::free_balance(DOT, &ALICE::get()), 100);
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 0);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			PoolId::DexIncentive(DOT_AUSD_LP)
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 200);
+	});
+}
+
+#[test]
+fn migrate_pool_id_keys_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		IncentiveRewardAmount::<Runtime>::insert(PoolId::LoansIncentive(BTC), 1000);
+		IncentiveRewardAmount::<Runtime>::insert(PoolId::HomaIncentive, 30);
+		DexSavingRewardRate::<Runtime>::insert(PoolId::DexSaving(BTC_AUSD_LP), Rate::saturating_from_rational(1, 100));
+		PayoutDeductionRates::<Runtime>::insert(PoolId::LoansIncentive(BTC), Rate::saturating_from_rational(2, 10));
+		RewardStartBlock::<Runtime>::insert(PoolId::LoansIncentive(BTC), 10);
+		RewardSchedule::<Runtime>::insert(PoolId::LoansIncentive(BTC), (10, 100));
+		ShortfallRewards::<Runtime>::insert(PoolId::HomaIncentive, 20);
+		PoolRewardCurrency::<Runtime>::insert(PoolId::LoansIncentive(BTC), DOT);
+		AccumulationCursor::<Runtime>::put(Some(PoolId::LoansIncentive(BTC)));
+		orml_rewards::Pools::<Runtime>::insert(
+			PoolId::LoansIncentive(BTC),
+			PoolInfo {
+				total_shares: 10,
+				total_rewards: 100,
+				total_withdrawn_rewards: 50,
+			},
+		);
+		orml_rewards::Pools::<Runtime>::insert(
+			PoolId::LoansIncentive(DOT),
+			PoolInfo {
+				total_shares: 5,
+				total_rewards: 10,
+				total_withdrawn_rewards: 0,
+			},
+		);
+
+		let migrate = |pool_id: PoolId<AccountId>| match pool_id {
+			PoolId::LoansIncentive(BTC) => Some(PoolId::LoansIncentive(DOT)),
+			PoolId::HomaIncentive => None,
+			pool_id => Some(pool_id),
+		};
+		let migrated = migrations::migrate_pool_id_keys::<Runtime, PoolId<AccountId>>(&migrate);
+		assert_eq!(migrated, 11);
+
+		assert_eq!(
+			IncentivesModule::incentive_reward_amount(PoolId::LoansIncentive(BTC)),
+			0
+		);
+		assert_eq!(
+			IncentivesModule::incentive_reward_amount(PoolId::LoansIncentive(DOT)),
+			1000
+		);
+		assert_eq!(IncentivesModule::incentive_reward_amount(PoolId::HomaIncentive), 0);
+		assert_eq!(
+			IncentivesModule::dex_saving_reward_rate(PoolId::DexSaving(BTC_AUSD_LP)),
+			Rate::saturating_from_rational(1, 100)
+		);
+		assert_eq!(
+			IncentivesModule::payout_deduction_rates(PoolId::LoansIncentive(BTC)),
+			Rate::zero()
+		);
+		assert_eq!(
+			IncentivesModule::payout_deduction_rates(PoolId::LoansIncentive(DOT)),
+			Rate::saturating_from_rational(2, 10)
+		);
+		assert_eq!(IncentivesModule::reward_start_block(PoolId::LoansIncentive(BTC)), None);
+		assert_eq!(
+			IncentivesModule::reward_start_block(PoolId::LoansIncentive(DOT)),
+			Some(10)
+		);
+		assert_eq!(IncentivesModule::reward_schedule(PoolId::LoansIncentive(BTC)), None);
+		assert_eq!(
+			IncentivesModule::reward_schedule(PoolId::LoansIncentive(DOT)),
+			Some((10, 100))
+		);
+		assert_eq!(IncentivesModule::shortfall_rewards(PoolId::HomaIncentive), 0);
+		assert_eq!(
+			IncentivesModule::pool_reward_currency(PoolId::LoansIncentive(BTC)),
+			None
+		);
+		assert_eq!(
+			IncentivesModule::pool_reward_currency(PoolId::LoansIncentive(DOT)),
+			Some(DOT)
+		);
+		assert_eq!(
+			IncentivesModule::accumulation_cursor(),
+			Some(Some(PoolId::LoansIncentive(DOT)))
+		);
+		assert_eq!(RewardsModule::pools(PoolId::LoansIncentive(BTC)), PoolInfo::default());
+		assert_eq!(
+			RewardsModule::pools(PoolId::LoansIncentive(DOT)),
+			PoolInfo {
+				total_shares: 15,
+				total_rewards: 110,
+				total_withdrawn_rewards: 50,
+			}
+		);
+
+		// the accumulation round is abandoned if the last accumulated pool is removed
+		AccumulationCursor::<Runtime>::put(Some(PoolId::HomaIncentive));
+		migrations::migrate_pool_id_keys::<Runtime, PoolId<AccountId>>(&migrate);
+		assert_eq!(IncentivesModule::accumulation_cursor(), None);
+	});
+}
+
+#[test]
+fn migrate_pending_rewards_keys_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		PendingRewards::<Runtime>::insert(PoolId::LoansIncentive(BTC), ALICE::get(), 100);
+		PendingRewards::<Runtime>::insert(PoolId::LoansIncentive(DOT), ALICE::get(), 5);
+		PendingRewards::<Runtime>::insert(PoolId::HomaIncentive, BOB::get(), 50);
+		PendingRewards::<Runtime>::insert(PoolId::DexIncentive(BTC_AUSD_LP), BOB::get(), 10);
+
+		let migrate = |pool_id: PoolId<AccountId>| match pool_id {
+			PoolId::LoansIncentive(BTC) => Some(PoolId::LoansIncentive(DOT)),
+			PoolId::HomaIncentive => None,
+			pool_id => Some(pool_id),
+		};
+
+		// migrate one entry per step
+		let mut start_key = None;
+		let mut steps = 0;
+		loop {
+			let (visited, next_key) =
+				migrations::migrate_pending_rewards_keys::<Runtime, PoolId<AccountId>>(&migrate, start_key, 1);
+			assert!(visited <= 1);
+			steps += 1;
+			match next_key {
+				Some(key) => start_key = Some(key),
+				None => break,
+			}
+		}
+		assert!(steps > 4);
+
+		assert_eq!(
+			IncentivesModule::pending_rewards(PoolId::LoansIncentive(BTC), ALICE::get()),
+			0
+		);
+		assert_eq!(
+			IncentivesModule::pending_rewards(PoolId::LoansIncentive(DOT), ALICE::get()),
+			105
+		);
+		assert_eq!(IncentivesModule::pending_rewards(PoolId::HomaIncentive, BOB::get()), 0);
+		assert_eq!(
+			IncentivesModule::pending_rewards(PoolId::DexIncentive(BTC_AUSD_LP), BOB::get()),
+			10
+		);
+		assert_eq!(PendingRewards::<Runtime>::iter().count(), 2);
+	});
+}
+
+#[test]
+fn migrate_share_and_withdrawn_reward_keys_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		orml_rewards::ShareAndWithdrawnReward::<Runtime>::insert(PoolId::LoansIncentive(BTC), ALICE::get(), (100, 10));
+		orml_rewards::ShareAndWithdrawnReward::<Runtime>::insert(PoolId::LoansIncentive(DOT), ALICE::get(), (5, 1));
+		orml_rewards::ShareAndWithdrawnReward::<Runtime>::insert(PoolId::HomaIncentive, BOB::get(), (50, 0));
+		orml_rewards::ShareAndWithdrawnReward::<Runtime>::insert(
+			PoolId::DexIncentive(BTC_AUSD_LP),
+			BOB::get(),
+			(10, 2),
+		);
+
+		let migrate = |pool_id: PoolId<AccountId>| match pool_id {
+			PoolId::LoansIncentive(BTC) => Some(PoolId::LoansIncentive(DOT)),
+			PoolId::HomaIncentive => None,
+			pool_id => Some(pool_id),
+		};
+
+		// migrate one entry per step
+		let mut start_key = None;
+		let mut steps = 0;
+		loop {
+			let (visited, next_key) = migrations::migrate_share_and_withdrawn_reward_keys::<Runtime, PoolId<AccountId>>(
+				&migrate, start_key, 1,
+			);
+			assert!(visited <= 1);
+			steps += 1;
+			match next_key {
+				Some(key) => start_key = Some(key),
+				None => break,
+			}
+		}
+		assert!(steps > 4);
+
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(PoolId::LoansIncentive(BTC), ALICE::get()),
+			(0, 0)
+		);
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(PoolId::LoansIncentive(DOT), ALICE::get()),
+			(105, 11)
+		);
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(PoolId::HomaIncentive, BOB::get()),
+			(0, 0)
+		);
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(PoolId::DexIncentive(BTC_AUSD_LP), BOB::get()),
+			(10, 2)
+		);
+		assert_eq!(orml_rewards::ShareAndWithdrawnReward::<Runtime>::iter().count(), 2);
+	});
+}
+
+#[test]
+fn genesis_build_sets_latest_storage_version() {
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<Runtime>()
+		.unwrap();
+	GenesisBuild::<Runtime>::assimilate_storage(&crate::GenesisConfig::default(), &mut t).unwrap();
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V2_0_0);
+	});
+}
+
+#[test]
+fn on_runtime_upgrade_should_migrate_to_v2() {
+	ExtBuilder::default().build().execute_with(|| {
+		IncentiveRewardAmount::<Runtime>::insert(PoolId::LoansIncentive(BTC), 1000);
+		PendingRewards::<Runtime>::insert(PoolId::LoansIncentive(BTC), ALICE::get(), 100);
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V1_0_0);
+
+		assert_eq!(
+			IncentivesModule::on_runtime_upgrade(),
+			<Runtime as frame_system::Config>::DbWeight::get().writes(1)
+		);
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V2_0_0);
+		assert_eq!(
+			IncentivesModule::incentive_reward_amount(PoolId::LoansIncentive(BTC)),
+			1000
+		);
+		assert_eq!(
+			IncentivesModule::pending_rewards(PoolId::LoansIncentive(BTC), ALICE::get()),
+			100
+		);
+
+		// already migrated
+		assert_eq!(
+			IncentivesModule::on_runtime_upgrade(),
+			<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+		);
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V2_0_0);
+	});
+}
@@ -52,10 +52,18 @@ pub trait WeightInfo {
 	fn deposit_dex_share() -> Weight;
 	fn withdraw_dex_share() -> Weight;
 	fn claim_rewards() -> Weight;
+	fn compound_dex_rewards() -> Weight;
+	fn force_claim_rewards() -> Weight;
 	fn update_incentive_rewards(c: u32, ) -> Weight;
 	fn update_dex_saving_rewards(c: u32, ) -> Weight;
 	fn update_payout_deduction_rates(c: u32, ) -> Weight;
+	fn update_reward_start_blocks(c: u32, ) -> Weight;
+	fn update_reward_schedules(c: u32, ) -> Weight;
+	fn update_pool_reward_currencies(c: u32, ) -> Weight;
+	fn update_dex_incentive_boost_bounds() -> Weight;
+	fn update_peg_deviation_boost() -> Weight;
 	fn add_allowance() -> Weight;
+	fn settle_shortfall() -> Weight;
 }
 
 /// Weights for module_incentives using the Acala node and recommended hardware.
@@ -64,8 +72,9 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	fn on_initialize(c: u32) -> Weight {
 		(33_360_000 as Weight)
 			.saturating_add((23_139_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
 	}
 	fn deposit_dex_share() -> Weight {
 		(84_000_000 as Weight)
@@ -79,7 +88,17 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	}
 	fn claim_rewards() -> Weight {
 		(27_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn compound_dex_rewards() -> Weight {
+		(410_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+	}
+	fn force_claim_rewards() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn update_incentive_rewards(c: u32, ) -> Weight {
@@ -100,10 +119,41 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn update_reward_start_blocks(c: u32, ) -> Weight {
+		(914_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_reward_schedules(c: u32, ) -> Weight {
+		(914_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_pool_reward_currencies(c: u32, ) -> Weight {
+		(914_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_dex_incentive_boost_bounds() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn update_peg_deviation_boost() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn add_allowance() -> Weight {
 		(2_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn settle_shortfall() -> Weight {
+		(2_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -111,8 +161,9 @@ impl WeightInfo for () {
 	fn on_initialize(c: u32) -> Weight {
 		(33_360_000 as Weight)
 			.saturating_add((23_139_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
 	}
 	fn deposit_dex_share() -> Weight {
 		(84_000_000 as Weight)
@@ -126,7 +177,17 @@ impl WeightInfo for () {
 	}
 	fn claim_rewards() -> Weight {
 		(27_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn compound_dex_rewards() -> Weight {
+		(410_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
+	}
+	fn force_claim_rewards() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
 	fn update_incentive_rewards(c: u32, ) -> Weight {
@@ -147,8 +208,39 @@ impl WeightInfo for () {
 			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn update_reward_start_blocks(c: u32, ) -> Weight {
+		(914_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_reward_schedules(c: u32, ) -> Weight {
+		(914_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_pool_reward_currencies(c: u32, ) -> Weight {
+		(914_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_dex_incentive_boost_bounds() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn update_peg_deviation_boost() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 	fn add_allowance() -> Weight {
 		(2_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn settle_shortfall() -> Weight {
+		(2_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
@@ -30,7 +30,7 @@ use sp_runtime::{
 	testing::Header,
 	traits::{AccountIdConversion, IdentityLookup},
 };
-use support::{AuctionManager, RiskManager};
+use support::{AuctionManager, EmergencyShutdown, RiskManager};
 
 pub type AccountId = u128;
 pub type AuctionId = u32;
@@ -134,6 +134,7 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		_currency_id: Self::CurrencyId,
 		_amount: Self::Balance,
 		_target: Self::Balance,
+		_reserve_price: Option<Self::Balance>,
 	) -> DispatchResult {
 		Ok(())
 	}
@@ -162,6 +163,17 @@ parameter_types! {
 	pub TreasuryAccount: AccountId = PalletId(*b"aca/hztr").into_account();
 }
 
+pub struct MockEmergencyShutdown;
+impl EmergencyShutdown for MockEmergencyShutdown {
+	fn is_shutdown() -> bool {
+		false
+	}
+
+	fn is_collateral_shutdown(_currency_id: CurrencyId) -> bool {
+		false
+	}
+}
+
 impl cdp_treasury::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
@@ -172,6 +184,7 @@ impl cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type EmergencyShutdown = MockEmergencyShutdown;
 	type WeightInfo = ();
 }
 
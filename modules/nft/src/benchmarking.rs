@@ -63,6 +63,7 @@ fn create_token_class<T: Config>(caller: T::AccountId) -> Result<T::AccountId, D
 				| ClassProperty::ClassPropertiesMutable,
 		),
 		test_attr(),
+		Some((module_account.clone(), Permill::from_percent(10))),
 	)?;
 
 	<T as module::Config>::Currency::make_free_balance_be(
@@ -80,7 +81,7 @@ benchmarks! {
 		let base_currency_amount = dollar(1000);
 
 		<T as module::Config>::Currency::make_free_balance_be(&caller, base_currency_amount.unique_saturated_into());
-	}: _(RawOrigin::Signed(caller), vec![1], Properties(ClassProperty::Transferable | ClassProperty::Burnable), test_attr())
+	}: _(RawOrigin::Signed(caller), vec![1], Properties(ClassProperty::Transferable | ClassProperty::Burnable), test_attr(), None)
 
 	// mint NFT token
 	mint {
@@ -91,7 +92,19 @@ benchmarks! {
 		let to_lookup = T::Lookup::unlookup(to);
 
 		let module_account = create_token_class::<T>(caller)?;
-	}: _(RawOrigin::Signed(module_account), to_lookup, 0u32.into(), vec![1], test_attr(), i)
+	}: _(RawOrigin::Signed(module_account), to_lookup, 0u32.into(), vec![1], test_attr(), i, None)
+
+	// mint a batch of NFT tokens
+	mint_batch {
+		let i in 1 .. 1000;
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+		let tokens = (0..i).map(|_| (vec![1], test_attr())).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(module_account), to_lookup, 0u32.into(), tokens, None)
 
 	// transfer NFT token to another account
 	transfer {
@@ -102,9 +115,36 @@ benchmarks! {
 
 		let module_account = create_token_class::<T>(caller)?;
 
-		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1, None)?;
 	}: _(RawOrigin::Signed(to), caller_lookup, (0u32.into(), 0u32.into()))
 
+	// transfer NFT token to another account with royalty
+	transfer_with_payment {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1, None)?;
+		<T as module::Config>::Currency::make_free_balance_be(&buyer, dollar(1000).unique_saturated_into());
+		crate::Pallet::<T>::approve_payment(RawOrigin::Signed(buyer).into(), (0u32.into(), 0u32.into()), dollar(100).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(to), buyer_lookup, (0u32.into(), 0u32.into()), dollar(100).unique_saturated_into())
+
+	// approve paying for NFT token
+	approve_payment {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1, None)?;
+	}: _(RawOrigin::Signed(buyer), (0u32.into(), 0u32.into()), dollar(100).unique_saturated_into())
+
 	// burn NFT token
 	burn {
 		let caller: T::AccountId = account("caller", 0, SEED);
@@ -113,7 +153,7 @@ benchmarks! {
 
 		let module_account = create_token_class::<T>(caller)?;
 
-		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1, None)?;
 	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()))
 
 	// burn NFT token with remark
@@ -126,7 +166,7 @@ benchmarks! {
 
 		let module_account = create_token_class::<T>(caller)?;
 
-		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1, None)?;
 	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()), remark_message)
 
 	// destroy NFT class
@@ -147,6 +187,29 @@ benchmarks! {
 
 		let module_account = create_token_class::<T>(caller)?;
 	}: _(RawOrigin::Signed(module_account), 0u32.into(), Properties(ClassProperty::Transferable.into()))
+
+	update_class_metadata {
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		let module_account = create_token_class::<T>(caller)?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), vec![1; 64])
+
+	propose_class_owner {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), to_lookup)
+
+	accept_class_owner {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+		crate::Pallet::<T>::propose_class_owner(RawOrigin::Signed(module_account).into(), 0u32.into(), to_lookup)?;
+	}: _(RawOrigin::Signed(to), 0u32.into())
 }
 
 #[cfg(test)]
@@ -295,6 +358,7 @@ mod mock {
 		type DataDepositPerByte = DataDepositPerByte;
 		type PalletId = NftPalletId;
 		type MaxAttributesBytes = MaxAttributesBytes;
+		type BlockNumberProvider = System;
 		type WeightInfo = ();
 	}
 
@@ -306,8 +370,8 @@ mod mock {
 	impl orml_nft::Config for Runtime {
 		type ClassId = u32;
 		type TokenId = u64;
-		type ClassData = ClassData<Balance>;
-		type TokenData = TokenData<Balance>;
+		type ClassData = ClassData<Balance, AccountId>;
+		type TokenData = TokenData<Balance, u64>;
 		type MaxClassMetadata = MaxClassMetadata;
 		type MaxTokenMetadata = MaxTokenMetadata;
 	}
@@ -38,12 +38,15 @@ use primitives::{NFTBalance, ReserveIdentifier};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
+	offchain::storage_lock::BlockNumberProvider,
 	traits::{AccountIdConversion, Hash, Saturating, StaticLookup, Zero},
-	DispatchResult, RuntimeDebug,
+	DispatchResult, Permill, RuntimeDebug,
 };
-use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, prelude::*};
+use support::NFTMetadata;
 
 pub mod benchmarking;
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -65,6 +68,8 @@ pub enum ClassProperty {
 	Mintable = 0b00000100,
 	/// Is class properties mutable
 	ClassPropertiesMutable = 0b00001000,
+	/// Is metadata of the class and its tokens immutable
+	ImmutableMetadata = 0b00010000,
 }
 
 #[derive(Clone, Copy, PartialEq, Default, RuntimeDebug)]
@@ -88,22 +93,42 @@ impl Decode for Properties {
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct ClassData<Balance> {
+pub struct ClassData<Balance, AccountId> {
 	/// Deposit reserved to create token class
 	pub deposit: Balance,
 	/// Class properties
 	pub properties: Properties,
 	/// Class attributes
 	pub attributes: Attributes,
+	/// Royalty beneficiary and rate charged on paid transfers
+	#[cfg_attr(feature = "std", serde(default))]
+	pub royalty: Option<(AccountId, Permill)>,
 }
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct TokenData<Balance> {
+pub struct TokenData<Balance, BlockNumber> {
 	/// Deposit reserved to create token
 	pub deposit: Balance,
 	/// Token attributes
 	pub attributes: Attributes,
+	/// Relaychain block number until which the token can't be transferred
+	#[cfg_attr(feature = "std", serde(default))]
+	pub locked_until: Option<BlockNumber>,
+}
+
+/// Storage version of the NFT module.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+	V1_0_0,
+	V2_0_0,
+	V3_0_0,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V1_0_0
+	}
 }
 
 pub type TokenIdOf<T> = <T as orml_nft::Config>::TokenId;
@@ -120,8 +145,10 @@ pub mod module {
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config
-		+ orml_nft::Config<ClassData = ClassData<BalanceOf<Self>>, TokenData = TokenData<BalanceOf<Self>>>
-		+ pallet_proxy::Config
+		+ orml_nft::Config<
+			ClassData = ClassData<BalanceOf<Self>, <Self as frame_system::Config>::AccountId>,
+			TokenData = TokenData<BalanceOf<Self>, <Self as frame_system::Config>::BlockNumber>,
+		> + pallet_proxy::Config
 	{
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
@@ -152,6 +179,9 @@ pub mod module {
 		#[pallet::constant]
 		type MaxAttributesBytes: Get<u32>;
 
+		/// Block number provider for the relaychain, used by token locks.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -179,11 +209,19 @@ pub mod module {
 		Immutable,
 		/// Attributes too large
 		AttributesTooLarge,
+		/// Token is locked and can't be transferred yet
+		TokenLocked,
+		/// No class owner has been proposed for the class
+		NoPendingClassOwner,
+		/// Metadata of the class is immutable
+		MetadataImmutable,
+		/// The receiver hasn't approved paying the price for the token
+		PaymentNotApproved,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId", ClassIdOf<T> = "ClassId", TokenIdOf<T> = "TokenId", T::Hash = "Hash")]
+	#[pallet::metadata(T::AccountId = "AccountId", ClassIdOf<T> = "ClassId", TokenIdOf<T> = "TokenId", T::Hash = "Hash", BalanceOf<T> = "Balance")]
 	pub enum Event<T: Config> {
 		/// Created NFT class. \[owner, class_id\]
 		CreatedClass(T::AccountId, ClassIdOf<T>),
@@ -197,13 +235,96 @@ pub mod module {
 		BurnedTokenWithRemark(T::AccountId, ClassIdOf<T>, TokenIdOf<T>, T::Hash),
 		/// Destroyed NFT class. \[owner, class_id\]
 		DestroyedClass(T::AccountId, ClassIdOf<T>),
+		/// Paid NFT token royalty. \[from, beneficiary, class_id, token_id, royalty_amount\]
+		PaidRoyalty(T::AccountId, T::AccountId, ClassIdOf<T>, TokenIdOf<T>, BalanceOf<T>),
+		/// Proposed new NFT class owner. \[owner, class_id, new_owner\]
+		ProposedClassOwner(T::AccountId, ClassIdOf<T>, T::AccountId),
+		/// Accepted NFT class ownership. \[owner, class_id, new_owner\]
+		AcceptedClassOwner(T::AccountId, ClassIdOf<T>, T::AccountId),
+		/// Approved paying for NFT token. \[buyer, class_id, token_id, max_price\]
+		ApprovedPayment(T::AccountId, ClassIdOf<T>, TokenIdOf<T>, BalanceOf<T>),
+		/// Paid NFT token price. \[buyer, seller, class_id, token_id, price\]
+		PaidPrice(T::AccountId, T::AccountId, ClassIdOf<T>, TokenIdOf<T>, BalanceOf<T>),
+	}
+
+	/// The account proposed to take over the proxy of a class owner, which
+	/// takes effect once the account accepts it.
+	///
+	/// PendingClassOwner: map ClassId => Option<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_class_owner)]
+	pub type PendingClassOwner<T: Config> = StorageMap<_, Twox64Concat, ClassIdOf<T>, T::AccountId, OptionQuery>;
+
+	/// The maximum price the buyer approved to pay for the token in
+	/// `transfer_with_payment`. All approvals of a token are removed when
+	/// the token is transferred or burned.
+	///
+	/// PaymentApprovals: double_map (ClassId, TokenId), AccountId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn payment_approvals)]
+	pub type PaymentApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Storage version of the pallet, used to migrate the class and token
+	/// data stored in `orml_nft` on runtime upgrade.
+	///
+	/// StorageVersion: Releases
+	#[pallet::storage]
+	pub(crate) type StorageVersion<T: Config> = StorageValue<_, Releases, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig {}
+
+	#[cfg(feature = "std")]
+	impl Default for GenesisConfig {
+		fn default() -> Self {
+			GenesisConfig {}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			StorageVersion::<T>::put(Releases::V3_0_0);
+		}
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			if StorageVersion::<T>::get() == Releases::V1_0_0 {
+				weight = weight.saturating_add(migrations::v2::migrate::<T>());
+			}
+			if StorageVersion::<T>::get() == Releases::V2_0_0 {
+				weight = weight.saturating_add(migrations::v3::migrate::<T>());
+			}
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			if StorageVersion::<T>::get() == Releases::V1_0_0 {
+				migrations::v2::pre_migrate::<T>()?;
+			}
+			migrations::v3::pre_migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			migrations::v3::post_migrate::<T>()
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -211,6 +332,8 @@ pub mod module {
 		///
 		/// - `metadata`: external metadata
 		/// - `properties`: class property, include `Transferable` `Burnable`
+		/// - `attributes`: class attributes
+		/// - `royalty`: royalty beneficiary and rate charged on paid transfers
 		#[pallet::weight(<T as Config>::WeightInfo::create_class())]
 		#[transactional]
 		pub fn create_class(
@@ -218,6 +341,7 @@ pub mod module {
 			metadata: CID,
 			properties: Properties,
 			attributes: Attributes,
+			royalty: Option<(T::AccountId, Permill)>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			let next_id = orml_nft::Pallet::<T>::next_class_id();
@@ -241,6 +365,7 @@ pub mod module {
 				deposit,
 				properties,
 				attributes,
+				royalty,
 			};
 			orml_nft::Pallet::<T>::create_class(&owner, metadata, data)?;
 
@@ -254,6 +379,8 @@ pub mod module {
 		/// - `class_id`: token belong to the class id
 		/// - `metadata`: external metadata
 		/// - `quantity`: token quantity
+		/// - `locked_until`: relaychain block number until which the tokens
+		///   can't be transferred
 		#[pallet::weight(<T as Config>::WeightInfo::mint(*quantity))]
 		#[transactional]
 		pub fn mint(
@@ -263,10 +390,32 @@ pub mod module {
 			metadata: CID,
 			attributes: Attributes,
 			quantity: u32,
+			locked_until: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			Self::do_mint(who, to, class_id, metadata, attributes, quantity, locked_until)
+		}
+
+		/// Mint a batch of distinct NFT tokens to one account
+		///
+		/// - `to`: the token owner's account
+		/// - `class_id`: token belong to the class id
+		/// - `tokens`: external metadata and attributes of each token
+		/// - `locked_until`: relaychain block number until which the tokens
+		///   can't be transferred
+		#[pallet::weight(<T as Config>::WeightInfo::mint_batch(tokens.len() as u32))]
+		#[transactional]
+		pub fn mint_batch(
+			origin: OriginFor<T>,
+			to: <T::Lookup as StaticLookup>::Source,
+			class_id: ClassIdOf<T>,
+			tokens: Vec<(CID, Attributes)>,
+			locked_until: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let to = T::Lookup::lookup(to)?;
-			Self::do_mint(who, to, class_id, metadata, attributes, quantity)
+			Self::do_mint_batch(who, to, class_id, tokens, locked_until)
 		}
 
 		/// Transfer NFT token to another account
@@ -285,6 +434,90 @@ pub mod module {
 			Self::do_transfer(&who, &to, token)
 		}
 
+		/// Transfer NFT token to another account as a sale. The receiver pays
+		/// `price`, the royalty cut of it goes to the royalty beneficiary of
+		/// the class and the rest to the sender. The receiver must have
+		/// approved paying at least `price` for the token by
+		/// `approve_payment`.
+		///
+		/// - `to`: the token owner's account
+		/// - `token`: (class_id, token_id)
+		/// - `price`: the sale price of the token
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_with_payment())]
+		#[transactional]
+		pub fn transfer_with_payment(
+			origin: OriginFor<T>,
+			to: <T::Lookup as StaticLookup>::Source,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+
+			ensure!(
+				price <= PaymentApprovals::<T>::take(token, &to),
+				Error::<T>::PaymentNotApproved
+			);
+
+			let mut seller_amount = price;
+			if let Some((beneficiary, rate)) = class_info.data.royalty {
+				let royalty_amount = rate.mul_floor(price);
+				if !royalty_amount.is_zero() && to != beneficiary {
+					<T as module::Config>::Currency::transfer(&to, &beneficiary, royalty_amount, KeepAlive)?;
+					Self::deposit_event(Event::PaidRoyalty(
+						to.clone(),
+						beneficiary,
+						token.0,
+						token.1,
+						royalty_amount,
+					));
+				}
+				seller_amount = price.saturating_sub(royalty_amount);
+			}
+
+			if !seller_amount.is_zero() {
+				<T as module::Config>::Currency::transfer(&to, &who, seller_amount, KeepAlive)?;
+				Self::deposit_event(Event::PaidPrice(
+					to.clone(),
+					who.clone(),
+					token.0,
+					token.1,
+					seller_amount,
+				));
+			}
+
+			Self::do_transfer(&who, &to, token)
+		}
+
+		/// Approve paying up to `max_price` for the token in
+		/// `transfer_with_payment`, the approval is used up by the transfer.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `max_price`: the maximum price to pay for the token, zero removes
+		///   the approval
+		#[pallet::weight(<T as Config>::WeightInfo::approve_payment())]
+		#[transactional]
+		pub fn approve_payment(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				orml_nft::Pallet::<T>::tokens(token.0, token.1).is_some(),
+				Error::<T>::TokenIdNotFound
+			);
+
+			if max_price.is_zero() {
+				PaymentApprovals::<T>::remove(token, &who);
+			} else {
+				PaymentApprovals::<T>::insert(token, &who, max_price);
+			}
+			Self::deposit_event(Event::ApprovedPayment(who, token.0, token.1, max_price));
+			Ok(())
+		}
+
 		/// Burn NFT token
 		///
 		/// - `token`: (class_id, token_id)
@@ -336,6 +569,7 @@ pub mod module {
 			<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, data.deposit);
 
 			orml_nft::Pallet::<T>::destroy_class(&who, class_id)?;
+			PendingClassOwner::<T>::remove(class_id);
 
 			// this should unresere proxy deposit
 			pallet_proxy::Pallet::<T>::remove_proxy_delegate(&who, dest.clone(), Default::default(), Zero::zero())?;
@@ -373,12 +607,118 @@ pub mod module {
 					data.properties.0.contains(ClassProperty::ClassPropertiesMutable),
 					Error::<T>::Immutable
 				);
+				ensure!(
+					!data.properties.0.contains(ClassProperty::ImmutableMetadata)
+						|| properties.0.contains(ClassProperty::ImmutableMetadata),
+					Error::<T>::MetadataImmutable
+				);
 
 				data.properties = properties;
 
 				Ok(())
 			})
 		}
+
+		/// Update NFT class metadata. The class properties must not contain
+		/// ImmutableMetadata, the data deposit is adjusted to the new metadata.
+		///
+		/// - `class_id`: The class ID to update
+		/// - `metadata`: The new external metadata
+		#[pallet::weight(<T as Config>::WeightInfo::update_class_metadata())]
+		#[transactional]
+		pub fn update_class_metadata(origin: OriginFor<T>, class_id: ClassIdOf<T>, metadata: CID) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			orml_nft::Classes::<T>::try_mutate(class_id, |class_info| -> DispatchResult {
+				let class_info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
+				ensure!(who == class_info.owner, Error::<T>::NoPermission);
+				ensure!(
+					!class_info.data.properties.0.contains(ClassProperty::ImmutableMetadata),
+					Error::<T>::MetadataImmutable
+				);
+
+				let old_data_deposit = Self::data_deposit(&class_info.metadata, &class_info.data.attributes)?;
+				let new_data_deposit = Self::data_deposit(&metadata, &class_info.data.attributes)?;
+				if new_data_deposit > old_data_deposit {
+					<T as module::Config>::Currency::reserve_named(
+						&RESERVE_ID,
+						&who,
+						new_data_deposit.saturating_sub(old_data_deposit),
+					)?;
+				} else {
+					<T as module::Config>::Currency::unreserve_named(
+						&RESERVE_ID,
+						&who,
+						old_data_deposit.saturating_sub(new_data_deposit),
+					);
+				}
+
+				class_info.data.deposit = class_info
+					.data
+					.deposit
+					.saturating_add(new_data_deposit)
+					.saturating_sub(old_data_deposit);
+				class_info.metadata = metadata
+					.try_into()
+					.map_err(|_| orml_nft::Error::<T>::MaxMetadataExceeded)?;
+
+				Ok(())
+			})
+		}
+
+		/// Propose a new account to manage the NFT class. The class owner
+		/// proxy only moves to the new account once it calls
+		/// `accept_class_owner`, a later proposal replaces the pending one.
+		///
+		/// - `class_id`: The class ID to hand off
+		/// - `new_owner`: The account proposed to manage the class
+		#[pallet::weight(<T as Config>::WeightInfo::propose_class_owner())]
+		#[transactional]
+		pub fn propose_class_owner(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			new_owner: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let new_owner = T::Lookup::lookup(new_owner)?;
+			let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+
+			PendingClassOwner::<T>::insert(class_id, &new_owner);
+
+			Self::deposit_event(Event::ProposedClassOwner(who, class_id, new_owner));
+			Ok(())
+		}
+
+		/// Accept the proposed management of the NFT class, replace all the
+		/// existing proxies of the class owner with the caller.
+		///
+		/// - `class_id`: The class ID to take over
+		#[pallet::weight(<T as Config>::WeightInfo::accept_class_owner())]
+		#[transactional]
+		pub fn accept_class_owner(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			let pending_owner = Self::pending_class_owner(class_id).ok_or(Error::<T>::NoPendingClassOwner)?;
+			ensure!(who == pending_owner, Error::<T>::NoPermission);
+
+			let owner = class_info.owner;
+			// this should unreserve the proxy deposits of the previous delegates
+			let (proxies, _) = pallet_proxy::Proxies::<T>::get(&owner);
+			for proxy in proxies.iter() {
+				pallet_proxy::Pallet::<T>::remove_proxy_delegate(
+					&owner,
+					proxy.delegate.clone(),
+					proxy.proxy_type.clone(),
+					proxy.delay,
+				)?;
+			}
+			<pallet_proxy::Pallet<T>>::add_proxy_delegate(&owner, who.clone(), Default::default(), Zero::zero())?;
+
+			PendingClassOwner::<T>::remove(class_id);
+
+			Self::deposit_event(Event::AcceptedClassOwner(owner, class_id, who));
+			Ok(())
+		}
 	}
 }
 
@@ -393,8 +733,15 @@ impl<T: Config> Pallet<T> {
 		);
 
 		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+		if let Some(locked_until) = token_info.data.locked_until {
+			ensure!(
+				T::BlockNumberProvider::current_block_number() >= locked_until,
+				Error::<T>::TokenLocked
+			);
+		}
 
 		orml_nft::Pallet::<T>::transfer(from, to, token)?;
+		PaymentApprovals::<T>::remove_prefix(token, None);
 
 		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &from, token_info.data.deposit);
 		<T as module::Config>::Currency::transfer(&from, &to, token_info.data.deposit, AllowDeath)?;
@@ -412,6 +759,7 @@ impl<T: Config> Pallet<T> {
 		metadata: CID,
 		attributes: Attributes,
 		quantity: u32,
+		locked_until: Option<T::BlockNumber>,
 	) -> DispatchResult {
 		ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
 		let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
@@ -431,7 +779,11 @@ impl<T: Config> Pallet<T> {
 		<T as module::Config>::Currency::transfer(&who, &to, total_deposit, KeepAlive)?;
 		<T as module::Config>::Currency::reserve_named(&RESERVE_ID, &to, total_deposit)?;
 
-		let data = TokenData { deposit, attributes };
+		let data = TokenData {
+			deposit,
+			attributes,
+			locked_until,
+		};
 		for _ in 0..quantity {
 			orml_nft::Pallet::<T>::mint(&to, class_id, metadata.clone(), data.clone())?;
 		}
@@ -440,6 +792,51 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	#[require_transactional]
+	fn do_mint_batch(
+		who: T::AccountId,
+		to: T::AccountId,
+		class_id: ClassIdOf<T>,
+		tokens: Vec<(CID, Attributes)>,
+		locked_until: Option<T::BlockNumber>,
+	) -> DispatchResult {
+		ensure!(!tokens.is_empty(), Error::<T>::InvalidQuantity);
+		let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(who == class_info.owner, Error::<T>::NoPermission);
+
+		ensure!(
+			class_info.data.properties.0.contains(ClassProperty::Mintable),
+			Error::<T>::NonMintable
+		);
+
+		let mut deposits = Vec::with_capacity(tokens.len());
+		let mut total_deposit: BalanceOf<T> = Zero::zero();
+		for (metadata, attributes) in tokens.iter() {
+			let data_deposit = Self::data_deposit(metadata, attributes)?;
+			let deposit = T::CreateTokenDeposit::get().saturating_add(data_deposit);
+			total_deposit = total_deposit.saturating_add(deposit);
+			deposits.push(deposit);
+		}
+
+		// `repatriate_reserved` will check `to` account exist and may return
+		// `DeadAccount`.
+		<T as module::Config>::Currency::transfer(&who, &to, total_deposit, KeepAlive)?;
+		<T as module::Config>::Currency::reserve_named(&RESERVE_ID, &to, total_deposit)?;
+
+		let quantity = tokens.len() as u32;
+		for ((metadata, attributes), deposit) in tokens.into_iter().zip(deposits) {
+			let data = TokenData {
+				deposit,
+				attributes,
+				locked_until,
+			};
+			orml_nft::Pallet::<T>::mint(&to, class_id, metadata, data)?;
+		}
+
+		Self::deposit_event(Event::MintedToken(who, to, class_id, quantity));
+		Ok(())
+	}
+
 	fn do_burn(who: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), remark: Option<Vec<u8>>) -> DispatchResult {
 		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
 		let data = class_info.data;
@@ -452,6 +849,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(who == token_info.owner, Error::<T>::NoPermission);
 
 		orml_nft::Pallet::<T>::burn(&who, token)?;
+		PaymentApprovals::<T>::remove_prefix(token, None);
 
 		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, token_info.data.deposit);
 
@@ -498,3 +896,9 @@ impl<T: Config> NFT<T::AccountId> for Pallet<T> {
 		Self::do_transfer(from, to, token)
 	}
 }
+
+impl<T: Config> NFTMetadata<ClassIdOf<T>, TokenIdOf<T>> for Pallet<T> {
+	fn token_metadata(token: (ClassIdOf<T>, TokenIdOf<T>)) -> Option<Vec<u8>> {
+		orml_nft::Pallet::<T>::tokens(token.0, token.1).map(|t| t.metadata.to_vec())
+	}
+}
@@ -0,0 +1,165 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the NFT module.
+//!
+//! The class and token data of this module are stored in `orml_nft`, so a
+//! change of `ClassData` or `TokenData` needs a migration which re-encodes
+//! the existing `orml_nft::Classes` or `orml_nft::Tokens` entries.
+
+use super::*;
+use frame_support::{log, BoundedVec};
+use sp_std::fmt::Debug;
+
+/// Convert the metadata of a migrated entry to the bounded metadata of
+/// `orml_nft`. Metadata over the bound is truncated and logged, instead of
+/// dropping the entry.
+fn bounded_metadata<Key: Debug, Bound: Get<u32>>(key: Key, mut metadata: Vec<u8>) -> BoundedVec<u8, Bound> {
+	let max = Bound::get() as usize;
+	if metadata.len() > max {
+		log::warn!(
+			target: "nft",
+			"migrate: metadata of {:?} has {} bytes, truncated to {} bytes",
+			key,
+			metadata.len(),
+			max
+		);
+		metadata.truncate(max);
+	}
+	metadata.try_into().expect("metadata is truncated to the bound; qed")
+}
+
+/// Migrate to `Releases::V2_0_0`, which adds `ClassData::royalty`.
+pub mod v2 {
+	use super::*;
+
+	/// `ClassData` before `Releases::V2_0_0`.
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+	pub struct OldClassData<Balance> {
+		pub deposit: Balance,
+		pub properties: Properties,
+		pub attributes: Attributes,
+	}
+
+	/// `orml_nft::ClassInfo` with the `ClassData` before `Releases::V2_0_0`.
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+	pub struct OldClassInfo<TokenId, AccountId, Balance> {
+		pub metadata: Vec<u8>,
+		pub total_issuance: TokenId,
+		pub owner: AccountId,
+		pub data: OldClassData<Balance>,
+	}
+
+	/// Re-encode the existing classes with `royalty: None`.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut count: u64 = 0;
+		orml_nft::Classes::<T>::translate::<OldClassInfo<TokenIdOf<T>, T::AccountId, BalanceOf<T>>, _>(
+			|class_id, old| {
+				count += 1;
+				Some(orml_nft::ClassInfo {
+					metadata: bounded_metadata(class_id, old.metadata),
+					total_issuance: old.total_issuance,
+					owner: old.owner,
+					data: ClassData {
+						deposit: old.data.deposit,
+						properties: old.data.properties,
+						attributes: old.data.attributes,
+						royalty: None,
+					},
+				})
+			},
+		);
+		StorageVersion::<T>::put(Releases::V2_0_0);
+		T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::<T>::get() == Releases::V1_0_0,
+			"nft: storage version must be V1_0_0"
+		);
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::<T>::get() == Releases::V2_0_0,
+			"nft: storage version must be V2_0_0"
+		);
+		Ok(())
+	}
+}
+
+/// Migrate to `Releases::V3_0_0`, which adds `TokenData::locked_until`.
+pub mod v3 {
+	use super::*;
+
+	/// `TokenData` before `Releases::V3_0_0`.
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+	pub struct OldTokenData<Balance> {
+		pub deposit: Balance,
+		pub attributes: Attributes,
+	}
+
+	/// `orml_nft::TokenInfo` with the `TokenData` before `Releases::V3_0_0`.
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+	pub struct OldTokenInfo<AccountId, Balance> {
+		pub metadata: Vec<u8>,
+		pub owner: AccountId,
+		pub data: OldTokenData<Balance>,
+	}
+
+	/// Re-encode the existing tokens with `locked_until: None`.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut count: u64 = 0;
+		orml_nft::Tokens::<T>::translate::<OldTokenInfo<T::AccountId, BalanceOf<T>>, _>(|class_id, token_id, old| {
+			count += 1;
+			Some(orml_nft::TokenInfo {
+				metadata: bounded_metadata((class_id, token_id), old.metadata),
+				owner: old.owner,
+				data: TokenData {
+					deposit: old.data.deposit,
+					attributes: old.data.attributes,
+					locked_until: None,
+				},
+			})
+		});
+		StorageVersion::<T>::put(Releases::V3_0_0);
+		T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::<T>::get() != Releases::V3_0_0,
+			"nft: storage version must be before V3_0_0"
+		);
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::<T>::get() == Releases::V3_0_0,
+			"nft: storage version must be V3_0_0"
+		);
+		Ok(())
+	}
+}
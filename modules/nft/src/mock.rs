@@ -166,6 +166,7 @@ impl orml_tokens::Config for Runtime {
 pub const NATIVE_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 
 parameter_types! {
+	pub const TreasuryAccount: AccountId = AccountId::new([255u8; 32]);
 	pub const GetNativeCurrencyId: CurrencyId = NATIVE_CURRENCY_ID;
 }
 
@@ -177,6 +178,10 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
+	type SweepOrigin = frame_system::EnsureRoot<AccountId>;
+	type TreasuryAccount = TreasuryAccount;
+	type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+	type PreTransferFilter = ();
 }
 
 parameter_types! {
@@ -185,7 +190,17 @@ parameter_types! {
 	pub const DataDepositPerByte: Balance = 10;
 	pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
 	pub MaxAttributesBytes: u32 = 10;
+	pub static MockRelayBlockNumberProvider: u64 = 0;
 }
+
+impl BlockNumberProvider for MockRelayBlockNumberProvider {
+	type BlockNumber = u64;
+
+	fn current_block_number() -> Self::BlockNumber {
+		Self::get()
+	}
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
@@ -194,6 +209,7 @@ impl Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type BlockNumberProvider = MockRelayBlockNumberProvider;
 	type WeightInfo = ();
 }
 
@@ -205,8 +221,8 @@ parameter_types! {
 impl orml_nft::Config for Runtime {
 	type ClassId = u32;
 	type TokenId = u64;
-	type ClassData = ClassData<Balance>;
-	type TokenData = TokenData<Balance>;
+	type ClassData = ClassData<Balance, AccountId>;
+	type TokenData = TokenData<Balance, u64>;
 	type MaxClassMetadata = MaxClassMetadata;
 	type MaxTokenMetadata = MaxTokenMetadata;
 }
@@ -235,6 +251,7 @@ construct_runtime!(
 
 pub const ALICE: AccountId = AccountId::new([1u8; 32]);
 pub const BOB: AccountId = AccountId::new([2u8; 32]);
+pub const CHARLIE: AccountId = AccountId::new([3u8; 32]);
 pub const CLASS_ID: <Runtime as orml_nft::Config>::ClassId = 0;
 pub const CLASS_ID_NOT_EXIST: <Runtime as orml_nft::Config>::ClassId = 1;
 pub const TOKEN_ID: <Runtime as orml_nft::Config>::TokenId = 0;
@@ -21,7 +21,7 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, OnRuntimeUpgrade};
 use frame_support::{assert_noop, assert_ok};
 use mock::{Event, *};
 use orml_nft::TokenInfo;
@@ -60,6 +60,7 @@ fn create_class_should_work() {
 			metadata.clone(),
 			Default::default(),
 			test_attr(1),
+			None,
 		));
 		System::assert_last_event(Event::NFTModule(crate::Event::CreatedClass(
 			class_id_account(),
@@ -80,6 +81,7 @@ fn create_class_should_work() {
 				deposit: cls_deposit,
 				properties: Default::default(),
 				attributes: test_attr(1),
+				royalty: None,
 			}
 		)
 	});
@@ -95,6 +97,7 @@ fn create_class_should_fail() {
 				metadata.clone(),
 				Properties(ClassProperty::Transferable | ClassProperty::Burnable),
 				Default::default(),
+				None,
 			),
 			pallet_balances::Error::<Runtime, _>::InsufficientBalance
 		);
@@ -108,6 +111,7 @@ fn create_class_should_fail() {
 				metadata.clone(),
 				Properties(ClassProperty::Transferable | ClassProperty::Burnable),
 				large_attr,
+				None,
 			),
 			Error::<Runtime>::AttributesTooLarge
 		);
@@ -124,6 +128,7 @@ fn mint_should_work() {
 			metadata.clone(),
 			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
 			test_attr(1),
+			None,
 		));
 		System::assert_last_event(Event::NFTModule(crate::Event::CreatedClass(
 			class_id_account(),
@@ -139,7 +144,8 @@ fn mint_should_work() {
 			CLASS_ID,
 			metadata_2.clone(),
 			test_attr(2),
-			2
+			2,
+			None
 		));
 		System::assert_last_event(Event::NFTModule(crate::Event::MintedToken(
 			class_id_account(),
@@ -166,6 +172,7 @@ fn mint_should_work() {
 					deposit: CreateTokenDeposit::get()
 						+ DataDepositPerByte::get() * (metadata_2.len() as u128 + TEST_ATTR_LEN),
 					attributes: test_attr(2),
+					locked_until: None,
 				}
 			}
 		);
@@ -178,6 +185,7 @@ fn mint_should_work() {
 					deposit: CreateTokenDeposit::get()
 						+ DataDepositPerByte::get() * (metadata_2.len() as u128 + TEST_ATTR_LEN),
 					attributes: test_attr(2),
+					locked_until: None,
 				}
 			}
 		);
@@ -197,6 +205,7 @@ fn mint_should_fail() {
 			metadata.clone(),
 			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
 			Default::default(),
+			None,
 		));
 		assert_noop!(
 			NFTModule::mint(
@@ -205,7 +214,8 @@ fn mint_should_fail() {
 				CLASS_ID_NOT_EXIST,
 				metadata.clone(),
 				Default::default(),
-				2
+				2,
+				None
 			),
 			Error::<Runtime>::ClassIdNotFound
 		);
@@ -217,7 +227,8 @@ fn mint_should_fail() {
 				CLASS_ID,
 				metadata.clone(),
 				Default::default(),
-				0
+				0,
+				None
 			),
 			Error::<Runtime>::InvalidQuantity
 		);
@@ -229,7 +240,8 @@ fn mint_should_fail() {
 				CLASS_ID,
 				metadata.clone(),
 				Default::default(),
-				2
+				2,
+				None
 			),
 			Error::<Runtime>::NoPermission
 		);
@@ -248,7 +260,8 @@ fn mint_should_fail() {
 				CLASS_ID,
 				metadata.clone(),
 				Default::default(),
-				2
+				2,
+				None
 			),
 			orml_nft::Error::<Runtime>::NoAvailableTokenId
 		);
@@ -264,6 +277,7 @@ fn mint_should_fail_without_mintable() {
 			metadata.clone(),
 			Default::default(),
 			Default::default(),
+			None,
 		));
 
 		assert_noop!(
@@ -273,7 +287,173 @@ fn mint_should_fail_without_mintable() {
 				CLASS_ID,
 				metadata.clone(),
 				Default::default(),
-				2
+				2,
+				None
+			),
+			Error::<Runtime>::NonMintable
+		);
+	});
+}
+
+#[test]
+fn mint_batch_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		let metadata_2 = vec![2, 3];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+			None,
+		));
+		let deposit_1 = CreateTokenDeposit::get() + DataDepositPerByte::get() * metadata.len() as u128;
+		let deposit_2 =
+			CreateTokenDeposit::get() + DataDepositPerByte::get() * (metadata_2.len() as u128 + TEST_ATTR_LEN);
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			deposit_1 + deposit_2
+		));
+		assert_ok!(NFTModule::mint_batch(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			vec![
+				(metadata.clone(), Default::default()),
+				(metadata_2.clone(), test_attr(2))
+			],
+			None,
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::MintedToken(
+			class_id_account(),
+			BOB,
+			CLASS_ID,
+			2,
+		)));
+		assert_eq!(reserved_balance(&BOB), deposit_1 + deposit_2);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(0, 0).unwrap(),
+			TokenInfo {
+				metadata: metadata.clone().try_into().unwrap(),
+				owner: BOB,
+				data: TokenData {
+					deposit: deposit_1,
+					attributes: Default::default(),
+					locked_until: None,
+				}
+			}
+		);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(0, 1).unwrap(),
+			TokenInfo {
+				metadata: metadata_2.clone().try_into().unwrap(),
+				owner: BOB,
+				data: TokenData {
+					deposit: deposit_2,
+					attributes: test_attr(2),
+					locked_until: None,
+				}
+			}
+		);
+	});
+}
+
+#[test]
+fn mint_batch_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+			None,
+		));
+		assert_noop!(
+			NFTModule::mint_batch(
+				Origin::signed(ALICE),
+				BOB,
+				CLASS_ID_NOT_EXIST,
+				vec![(metadata.clone(), Default::default())],
+				None,
+			),
+			Error::<Runtime>::ClassIdNotFound
+		);
+
+		assert_noop!(
+			NFTModule::mint_batch(Origin::signed(class_id_account()), BOB, CLASS_ID, vec![], None),
+			Error::<Runtime>::InvalidQuantity
+		);
+
+		assert_noop!(
+			NFTModule::mint_batch(
+				Origin::signed(BOB),
+				BOB,
+				CLASS_ID,
+				vec![(metadata.clone(), Default::default())],
+				None,
+			),
+			Error::<Runtime>::NoPermission
+		);
+
+		let mut large_attr: Attributes = BTreeMap::new();
+		large_attr.insert(vec![1], vec![0; 10]);
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			2 * (CreateTokenDeposit::get() + DataDepositPerByte::get() * 12)
+		));
+		assert_noop!(
+			NFTModule::mint_batch(
+				Origin::signed(class_id_account()),
+				BOB,
+				CLASS_ID,
+				vec![(metadata.clone(), Default::default()), (metadata.clone(), large_attr)],
+				None,
+			),
+			Error::<Runtime>::AttributesTooLarge
+		);
+
+		// the first token is minted before the second one fails, everything is rolled back
+		orml_nft::NextTokenId::<Runtime>::mutate(CLASS_ID, |id| {
+			*id = <Runtime as orml_nft::Config>::TokenId::max_value() - 1
+		});
+		assert_noop!(
+			NFTModule::mint_batch(
+				Origin::signed(class_id_account()),
+				BOB,
+				CLASS_ID,
+				vec![
+					(metadata.clone(), Default::default()),
+					(metadata.clone(), Default::default())
+				],
+				None,
+			),
+			orml_nft::Error::<Runtime>::NoAvailableTokenId
+		);
+		assert_eq!(reserved_balance(&BOB), 0);
+		assert_eq!(orml_nft::TokensByOwner::<Runtime>::iter_prefix((BOB,)).count(), 0);
+	});
+}
+
+#[test]
+fn mint_batch_should_fail_without_mintable() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Default::default(),
+			Default::default(),
+			None,
+		));
+
+		assert_noop!(
+			NFTModule::mint_batch(
+				Origin::signed(class_id_account()),
+				BOB,
+				CLASS_ID,
+				vec![(metadata.clone(), Default::default())],
+				None,
 			),
 			Error::<Runtime>::NonMintable
 		);
@@ -289,6 +469,7 @@ fn transfer_should_work() {
 			metadata.clone(),
 			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
 			Default::default(),
+			None,
 		));
 		assert_ok!(Balances::deposit_into_existing(
 			&class_id_account(),
@@ -300,7 +481,8 @@ fn transfer_should_work() {
 			CLASS_ID,
 			metadata.clone(),
 			Default::default(),
-			2
+			2,
+			None
 		));
 
 		assert_eq!(
@@ -333,6 +515,315 @@ fn transfer_should_work() {
 	});
 }
 
+#[test]
+fn transfer_locked_token_should_work_after_unlock() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+			None,
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			2 * (CreateTokenDeposit::get() + DataDepositPerByte::get())
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata.clone(),
+			Default::default(),
+			2,
+			Some(10)
+		));
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID)
+				.unwrap()
+				.data
+				.locked_until,
+			Some(10)
+		);
+
+		MockRelayBlockNumberProvider::set(9);
+		assert_noop!(
+			NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::TokenLocked
+		);
+		assert_noop!(
+			NFTModule::transfer_with_payment(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID), 0),
+			Error::<Runtime>::TokenLocked
+		);
+
+		// burning is still allowed while the token is locked
+		assert_ok!(NFTModule::burn(Origin::signed(BOB), (CLASS_ID, 1)));
+		System::assert_last_event(Event::NFTModule(crate::Event::BurnedToken(BOB, CLASS_ID, 1)));
+
+		MockRelayBlockNumberProvider::set(10);
+		assert_ok!(NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTModule(crate::Event::TransferredToken(
+			BOB, ALICE, CLASS_ID, TOKEN_ID,
+		)));
+	});
+}
+
+#[test]
+fn transfer_with_payment_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+			Some((ALICE, Permill::from_percent(10))),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			1 * (CreateTokenDeposit::get() + DataDepositPerByte::get())
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata.clone(),
+			Default::default(),
+			1,
+			None
+		));
+		Balances::make_free_balance_be(&BOB, 10000);
+		Balances::make_free_balance_be(&CHARLIE, 10000);
+
+		// the receiver must approve paying the price
+		assert_noop!(
+			NFTModule::transfer_with_payment(Origin::signed(BOB), CHARLIE, (CLASS_ID, TOKEN_ID), 1000),
+			Error::<Runtime>::PaymentNotApproved
+		);
+		assert_ok!(NFTModule::approve_payment(
+			Origin::signed(CHARLIE),
+			(CLASS_ID, TOKEN_ID),
+			1000
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::ApprovedPayment(
+			CHARLIE, CLASS_ID, TOKEN_ID, 1000,
+		)));
+		assert_noop!(
+			NFTModule::transfer_with_payment(Origin::signed(BOB), CHARLIE, (CLASS_ID, TOKEN_ID), 1001),
+			Error::<Runtime>::PaymentNotApproved
+		);
+		assert_ok!(NFTModule::approve_payment(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			500
+		));
+
+		let alice_free = free_balance(&ALICE);
+		assert_ok!(NFTModule::transfer_with_payment(
+			Origin::signed(BOB),
+			CHARLIE,
+			(CLASS_ID, TOKEN_ID),
+			1000
+		));
+		System::assert_has_event(Event::NFTModule(crate::Event::PaidRoyalty(
+			CHARLIE, ALICE, CLASS_ID, TOKEN_ID, 100,
+		)));
+		System::assert_has_event(Event::NFTModule(crate::Event::PaidPrice(
+			CHARLIE, BOB, CLASS_ID, TOKEN_ID, 900,
+		)));
+		System::assert_last_event(Event::NFTModule(crate::Event::TransferredToken(
+			BOB, CHARLIE, CLASS_ID, TOKEN_ID,
+		)));
+		assert_eq!(free_balance(&ALICE), alice_free + 100);
+		assert_eq!(free_balance(&BOB), 10000 + 900);
+		assert_eq!(free_balance(&CHARLIE), 10000 - 1000);
+		assert_eq!(reserved_balance(&BOB), 0);
+		assert_eq!(
+			reserved_balance(&CHARLIE),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().owner,
+			CHARLIE
+		);
+		// the approval is used up, the other approvals of the token are removed
+		assert_eq!(NFTModule::payment_approvals((CLASS_ID, TOKEN_ID), CHARLIE), 0);
+		assert_eq!(NFTModule::payment_approvals((CLASS_ID, TOKEN_ID), ALICE), 0);
+
+		// not the token owner, payments are rolled back with the failed transfer
+		assert_ok!(NFTModule::approve_payment(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			1000
+		));
+		assert_noop!(
+			NFTModule::transfer_with_payment(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID), 1000),
+			orml_nft::Error::<Runtime>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn transfer_with_payment_with_zero_royalty_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+			Some((ALICE, Permill::zero())),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			1 * (CreateTokenDeposit::get() + DataDepositPerByte::get())
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata.clone(),
+			Default::default(),
+			1,
+			None
+		));
+		Balances::make_free_balance_be(&BOB, 10000);
+		Balances::make_free_balance_be(&CHARLIE, 10000);
+		assert_ok!(NFTModule::approve_payment(
+			Origin::signed(CHARLIE),
+			(CLASS_ID, TOKEN_ID),
+			1000
+		));
+
+		let alice_free = free_balance(&ALICE);
+		assert_ok!(NFTModule::transfer_with_payment(
+			Origin::signed(BOB),
+			CHARLIE,
+			(CLASS_ID, TOKEN_ID),
+			1000
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::TransferredToken(
+			BOB, CHARLIE, CLASS_ID, TOKEN_ID,
+		)));
+		assert_eq!(free_balance(&ALICE), alice_free);
+		assert_eq!(free_balance(&BOB), 10000 + 1000);
+		assert_eq!(free_balance(&CHARLIE), 10000 - 1000);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().owner,
+			CHARLIE
+		);
+	});
+}
+
+#[test]
+fn migrate_class_data_to_v2_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let old_class = migrations::v2::OldClassInfo::<_, AccountId, Balance> {
+			metadata: vec![1],
+			total_issuance: 2u64,
+			owner: class_id_account(),
+			data: migrations::v2::OldClassData {
+				deposit: 300,
+				properties: Properties(ClassProperty::Transferable.into()),
+				attributes: test_attr(1),
+			},
+		};
+		frame_support::storage::unhashed::put(&orml_nft::Classes::<Runtime>::hashed_key_for(CLASS_ID), &old_class);
+		// metadata over the bound is truncated instead of removing the class
+		let oversized_class = migrations::v2::OldClassInfo {
+			metadata: vec![2; 1025],
+			..old_class.clone()
+		};
+		frame_support::storage::unhashed::put(
+			&orml_nft::Classes::<Runtime>::hashed_key_for(CLASS_ID_NOT_EXIST),
+			&oversized_class,
+		);
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V1_0_0);
+
+		NFTModule::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V3_0_0);
+		let class_info = orml_nft::Pallet::<Runtime>::classes(CLASS_ID).unwrap();
+		assert_eq!(class_info.metadata.to_vec(), vec![1]);
+		assert_eq!(class_info.total_issuance, 2);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::classes(CLASS_ID_NOT_EXIST)
+				.unwrap()
+				.metadata
+				.to_vec(),
+			vec![2; 1024]
+		);
+		assert_eq!(class_info.owner, class_id_account());
+		assert_eq!(
+			class_info.data,
+			ClassData {
+				deposit: 300,
+				properties: Properties(ClassProperty::Transferable.into()),
+				attributes: test_attr(1),
+				royalty: None,
+			}
+		);
+
+		// runs only once
+		NFTModule::on_runtime_upgrade();
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::classes(CLASS_ID).unwrap().data.royalty,
+			None
+		);
+	});
+}
+
+#[test]
+fn migrate_token_data_to_v3_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::<Runtime>::put(Releases::V2_0_0);
+		let old_token = migrations::v3::OldTokenInfo::<AccountId, Balance> {
+			metadata: vec![1],
+			owner: BOB,
+			data: migrations::v3::OldTokenData {
+				deposit: 200,
+				attributes: test_attr(2),
+			},
+		};
+		frame_support::storage::unhashed::put(
+			&orml_nft::Tokens::<Runtime>::hashed_key_for(CLASS_ID, TOKEN_ID),
+			&old_token,
+		);
+		// metadata over the bound is truncated instead of removing the token
+		let oversized_token = migrations::v3::OldTokenInfo {
+			metadata: vec![2; 1025],
+			..old_token.clone()
+		};
+		frame_support::storage::unhashed::put(
+			&orml_nft::Tokens::<Runtime>::hashed_key_for(CLASS_ID, TOKEN_ID_NOT_EXIST),
+			&oversized_token,
+		);
+
+		NFTModule::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::<Runtime>::get(), Releases::V3_0_0);
+		let token_info = orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap();
+		assert_eq!(token_info.metadata.to_vec(), vec![1]);
+		assert_eq!(token_info.owner, BOB);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID_NOT_EXIST)
+				.unwrap()
+				.metadata
+				.to_vec(),
+			vec![2; 1024]
+		);
+		assert_eq!(
+			token_info.data,
+			TokenData {
+				deposit: 200,
+				attributes: test_attr(2),
+				locked_until: None,
+			}
+		);
+	});
+}
+
 #[test]
 fn transfer_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -342,6 +833,7 @@ fn transfer_should_fail() {
 			metadata.clone(),
 			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
 			Default::default(),
+			None,
 		));
 		assert_ok!(Balances::deposit_into_existing(
 			&class_id_account(),
@@ -353,7 +845,8 @@ fn transfer_should_fail() {
 			CLASS_ID,
 			metadata.clone(),
 			Default::default(),
-			1
+			1,
+			None
 		));
 		assert_noop!(
 			NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID_NOT_EXIST, TOKEN_ID)),
@@ -376,6 +869,7 @@ fn transfer_should_fail() {
 			metadata.clone(),
 			Properties(ClassProperty::Mintable.into()),
 			Default::default(),
+			None,
 		));
 		assert_ok!(Balances::deposit_into_existing(
 			&class_id_account(),
@@ -387,7 +881,8 @@ fn transfer_should_fail() {
 			CLASS_ID,
 			metadata.clone(),
 			Default::default(),
-			1
+			1,
+			None
 		));
 		assert_noop!(
 			NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)),
@@ -405,6 +900,7 @@ fn burn_should_work() {
 			metadata.clone(),
 			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
 			Default::default(),
+			None,
 		));
 		assert_ok!(Balances::deposit_into_existing(
 			&class_id_account(),
@@ -416,7 +912,13 @@ fn burn_should_work() {
 			CLASS_ID,
 			metadata.clone(),
 			Default::default(),
-			1
+			1,
+			None
+		));
+		assert_ok!(NFTModule::approve_payment(
+			Origin::signed(CHARLIE),
+			(CLASS_ID, TOKEN_ID),
+			1000
 		));
 		assert_ok!(NFTModule::burn(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
 		System::assert_last_event(Event::NFTModule(crate::Event::BurnedToken(BOB, CLASS_ID, TOKEN_ID)));
@@ -424,6 +926,7 @@ fn burn_should_work() {
 			reserved_balance(&class_id_account()),
 			CreateClassDeposit::get() + Proxy::deposit(1u32) + DataDepositPerByte::get() * (metadata.len() as u128)
 		);
+		assert_eq!(NFTModule::payment_approvals((CLASS_ID, TOKEN_ID), CHARLIE), 0);
 	});
 }
 
@@ -436,6 +939,7 @@ fn burn_should_fail() {
 			metadata.clone(),
 			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
 			Default::default(),
+			None,
 		));
 		assert_ok!(Balances::deposit_into_existing(
 			&class_id_account(),
@@ -447,7 +951,8 @@ fn burn_should_fail() {
 			CLASS_ID,
 			metadata.clone(),
 			Default::default(),
-			1
+			1,
+			None
 		));
 		assert_noop!(
 			NFTModule::burn(Origin::signed(BOB), (CLASS_ID, TOKEN_ID_NOT_EXIST)),
@@ -475,6 +980,7 @@ fn burn_should_fail() {
 			metadata.clone(),
 			Properties(ClassProperty::Mintable.into()),
 			Default::default(),
+			None,
 		));
 		assert_ok!(Balances::deposit_into_existing(
 			&class_id_account(),
@@ -486,7 +992,8 @@ fn burn_should_fail() {
 			CLASS_ID,
 			metadata.clone(),
 			Default::default(),
-			1
+			1,
+			None
 		));
 		assert_noop!(
 			NFTModule::burn(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
@@ -504,6 +1011,7 @@ fn burn_with_remark_should_work() {
 			metadata.clone(),
 			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
 			Default::default(),
+			None,
 		));
 		assert_ok!(Balances::deposit_into_existing(
 			&class_id_account(),
@@ -515,7 +1023,8 @@ fn burn_with_remark_should_work() {
 			CLASS_ID,
 			metadata.clone(),
 			Default::default(),
-			1
+			1,
+			None
 		));
 
 		let remark = "remark info".as_bytes().to_vec();
@@ -548,6 +1057,7 @@ fn destroy_class_should_work() {
 			metadata.clone(),
 			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
 			Default::default(),
+			None,
 		));
 
 		let deposit =
@@ -568,7 +1078,8 @@ fn destroy_class_should_work() {
 			CLASS_ID,
 			metadata.clone(),
 			Default::default(),
-			1
+			1,
+			None
 		));
 		assert_ok!(NFTModule::burn(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
 		assert_ok!(NFTModule::destroy_class(
@@ -601,6 +1112,7 @@ fn destroy_class_should_fail() {
 			metadata.clone(),
 			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
 			Default::default(),
+			None,
 		));
 		assert_ok!(Balances::deposit_into_existing(
 			&class_id_account(),
@@ -612,7 +1124,8 @@ fn destroy_class_should_fail() {
 			CLASS_ID,
 			metadata.clone(),
 			Default::default(),
-			1
+			1,
+			None
 		));
 		assert_noop!(
 			NFTModule::destroy_class(Origin::signed(class_id_account()), CLASS_ID_NOT_EXIST, BOB),
@@ -654,6 +1167,7 @@ fn update_class_properties_should_work() {
 			metadata.clone(),
 			Properties(ClassProperty::Transferable | ClassProperty::ClassPropertiesMutable | ClassProperty::Mintable),
 			Default::default(),
+			None,
 		));
 
 		assert_ok!(Balances::deposit_into_existing(
@@ -667,7 +1181,8 @@ fn update_class_properties_should_work() {
 			CLASS_ID,
 			metadata.clone(),
 			Default::default(),
-			1
+			1,
+			None
 		));
 
 		assert_ok!(NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)));
@@ -703,9 +1218,222 @@ fn update_class_properties_should_work() {
 				CLASS_ID,
 				metadata.clone(),
 				Default::default(),
-				1
+				1,
+				None
 			),
 			Error::<Runtime>::NonMintable
 		);
 	});
 }
+
+#[test]
+fn class_owner_transfer_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+			None,
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			2 * (CreateTokenDeposit::get() + DataDepositPerByte::get() * (metadata.len() as u128))
+		));
+		let reserved = reserved_balance(&class_id_account());
+		let mint_call = Box::new(Call::NFTModule(crate::Call::mint(
+			CHARLIE,
+			CLASS_ID,
+			metadata.clone(),
+			Default::default(),
+			1,
+			None,
+		)));
+
+		assert_noop!(
+			NFTModule::propose_class_owner(Origin::signed(ALICE), CLASS_ID, BOB),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::propose_class_owner(Origin::signed(class_id_account()), CLASS_ID_NOT_EXIST, BOB),
+			Error::<Runtime>::ClassIdNotFound
+		);
+		assert_noop!(
+			NFTModule::accept_class_owner(Origin::signed(BOB), CLASS_ID),
+			Error::<Runtime>::NoPendingClassOwner
+		);
+
+		assert_ok!(NFTModule::propose_class_owner(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			BOB
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::ProposedClassOwner(
+			class_id_account(),
+			CLASS_ID,
+			BOB,
+		)));
+		assert_eq!(NFTModule::pending_class_owner(CLASS_ID), Some(BOB));
+
+		// minting rights stay with the current owner until the proposal is accepted
+		assert_noop!(
+			Proxy::proxy(Origin::signed(BOB), class_id_account(), None, mint_call.clone()),
+			pallet_proxy::Error::<Runtime>::NotProxy
+		);
+		assert_ok!(Proxy::proxy(
+			Origin::signed(ALICE),
+			class_id_account(),
+			None,
+			mint_call.clone()
+		));
+		System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Ok(()))));
+
+		// only the proposed owner can accept
+		assert_noop!(
+			NFTModule::accept_class_owner(Origin::signed(CHARLIE), CLASS_ID),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::accept_class_owner(Origin::signed(BOB), CLASS_ID));
+		System::assert_last_event(Event::NFTModule(crate::Event::AcceptedClassOwner(
+			class_id_account(),
+			CLASS_ID,
+			BOB,
+		)));
+		assert_eq!(NFTModule::pending_class_owner(CLASS_ID), None);
+		assert_eq!(reserved_balance(&class_id_account()), reserved);
+
+		assert_noop!(
+			Proxy::proxy(Origin::signed(ALICE), class_id_account(), None, mint_call.clone()),
+			pallet_proxy::Error::<Runtime>::NotProxy
+		);
+		assert_ok!(Proxy::proxy(Origin::signed(BOB), class_id_account(), None, mint_call));
+		System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Ok(()))));
+		assert_eq!(NFTModule::balance(&CHARLIE), 2);
+	});
+}
+
+#[test]
+fn update_class_metadata_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable.into()),
+			Default::default(),
+			None,
+		));
+		let reserved = reserved_balance(&class_id_account());
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			2 * DataDepositPerByte::get()
+		));
+
+		assert_noop!(
+			NFTModule::update_class_metadata(Origin::signed(ALICE), CLASS_ID, vec![1, 2, 3]),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::update_class_metadata(Origin::signed(class_id_account()), CLASS_ID_NOT_EXIST, vec![1, 2, 3]),
+			Error::<Runtime>::ClassIdNotFound
+		);
+
+		assert_ok!(NFTModule::update_class_metadata(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			vec![1, 2, 3]
+		));
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::classes(CLASS_ID)
+				.unwrap()
+				.metadata
+				.to_vec(),
+			vec![1, 2, 3]
+		);
+		assert_eq!(
+			reserved_balance(&class_id_account()),
+			reserved + 2 * DataDepositPerByte::get()
+		);
+
+		assert_ok!(NFTModule::update_class_metadata(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			vec![]
+		));
+		assert_eq!(
+			reserved_balance(&class_id_account()),
+			reserved - DataDepositPerByte::get()
+		);
+	});
+}
+
+#[test]
+fn immutable_metadata_class_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(
+				ClassProperty::Transferable
+					| ClassProperty::Mintable
+					| ClassProperty::ClassPropertiesMutable
+					| ClassProperty::ImmutableMetadata
+			),
+			Default::default(),
+			None,
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get() * (metadata.len() as u128)
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata.clone(),
+			Default::default(),
+			1,
+			None
+		));
+
+		assert_noop!(
+			NFTModule::update_class_metadata(Origin::signed(class_id_account()), CLASS_ID, vec![2]),
+			Error::<Runtime>::MetadataImmutable
+		);
+		assert_noop!(
+			NFTModule::update_class_properties(
+				Origin::signed(class_id_account()),
+				CLASS_ID,
+				Properties(ClassProperty::Transferable | ClassProperty::ClassPropertiesMutable)
+			),
+			Error::<Runtime>::MetadataImmutable
+		);
+
+		// other properties can still be updated while keeping the flag
+		assert_ok!(NFTModule::update_class_properties(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Properties(ClassProperty::Transferable | ClassProperty::ImmutableMetadata)
+		));
+		assert_noop!(
+			NFTModule::update_class_metadata(Origin::signed(class_id_account()), CLASS_ID, vec![2]),
+			Error::<Runtime>::MetadataImmutable
+		);
+
+		// transfer and burn still obey their own flags
+		assert_ok!(NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)));
+		assert_noop!(
+			NFTModule::burn(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NonBurnable
+		);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID)
+				.unwrap()
+				.metadata
+				.to_vec(),
+			metadata
+		);
+	});
+}
@@ -25,18 +25,26 @@
 //!   - specify a fixed price for stable currency
 //!   - feed price in USD or related price bewteen two currencies
 //!   - lock/unlock the price data get from oracle
+//!   - configure the priority of price sources for specific currency
+//!   - trip a circuit breaker which keeps serving the previous price if the
+//!     oracle price moves too much between consecutive updates
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
 use frame_support::{pallet_prelude::*, transactional};
 use frame_system::pallet_prelude::*;
-use orml_traits::{DataFeeder, DataProvider, MultiCurrency};
+use orml_traits::{DataFeeder, DataProvider, MultiCurrency, OnNewData};
 use primitives::{Balance, CurrencyId};
 use sp_core::U256;
-use sp_runtime::{traits::CheckedMul, FixedPointNumber};
-use sp_std::{convert::TryInto, marker::PhantomData};
-use support::{CurrencyIdMapping, DEXManager, ExchangeRateProvider, LockablePrice, Price, PriceProvider};
+use sp_runtime::{
+	traits::{CheckedDiv, CheckedMul, Saturating, Zero},
+	FixedPointNumber, RuntimeDebug,
+};
+use sp_std::{convert::TryInto, marker::PhantomData, prelude::*};
+use support::{
+	CurrencyIdMapping, DEXManager, ExchangeRateProvider, LockablePrice, Price, PriceOracleSources, PriceProvider, Ratio,
+};
 
 mod mock;
 mod tests;
@@ -45,6 +53,22 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The source a price can be accessed from.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum PriceSourceKind {
+	/// The price locked by `LockOrigin`.
+	Locked,
+	/// The real-time price.
+	Oracle,
+	/// The time-weighted average price of the currency in the stable
+	/// currency on DEX, over the last `DexPriceTwapWindow` blocks.
+	Dex,
+}
+
+/// The default priority of price sources if none is configured for the
+/// currency.
+pub const DEFAULT_PRICE_SOURCE_PRIORITY: [PriceSourceKind; 2] = [PriceSourceKind::Locked, PriceSourceKind::Oracle];
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -88,6 +112,17 @@ pub mod module {
 		/// Mapping between CurrencyId and ERC20 address so user can use Erc20.
 		type CurrencyIdMapping: CurrencyIdMapping;
 
+		/// The maximum ratio the oracle price can move between consecutive
+		/// updates, otherwise the circuit breaker is triggered and the
+		/// previous price is served until it's reset. Zero means disabled.
+		#[pallet::constant]
+		type MaxPriceMovePerBlock: Get<Ratio>;
+
+		/// The number of blocks the time-weighted average price on DEX is
+		/// taken over, for the `Dex` price source.
+		#[pallet::constant]
+		type DexPriceTwapWindow: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -98,6 +133,10 @@ pub mod module {
 		AccessPriceFailed,
 		/// There's no locked price
 		NoLockedPrice,
+		/// The priority of price sources is empty or contains duplicates
+		InvalidPriceSourcePriority,
+		/// The circuit breaker is not triggered
+		CircuitBreakerNotTriggered,
 	}
 
 	#[pallet::event]
@@ -107,6 +146,16 @@ pub mod module {
 		LockPrice(CurrencyId, Price),
 		/// Unlock price. \[currency_id\]
 		UnlockPrice(CurrencyId),
+		/// Relock price. \[currency_id, previous_locked_price, locked_price\]
+		RelockPrice(CurrencyId, Price, Price),
+		/// The priority of price sources updated. \[currency_id,
+		/// new_priority\]
+		PriceSourcePriorityUpdated(CurrencyId, Option<Vec<PriceSourceKind>>),
+		/// The oracle price moved too much, the previous price is served.
+		/// \[currency_id, previous_price, new_price\]
+		PriceCircuitBreakerTriggered(CurrencyId, Price, Price),
+		/// The circuit breaker is reset. \[currency_id, price\]
+		PriceCircuitBreakerReset(CurrencyId, Price),
 	}
 
 	/// Mapping from currency id to it's locked price
@@ -116,6 +165,31 @@ pub mod module {
 	#[pallet::getter(fn locked_price)]
 	pub type LockedPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
 
+	/// Mapping from currency id to the priority of the sources its price is
+	/// accessed from, `DEFAULT_PRICE_SOURCE_PRIORITY` is used if it's none.
+	///
+	/// map CurrencyId => Option<Vec<PriceSourceKind>>
+	#[pallet::storage]
+	#[pallet::getter(fn price_source_priority)]
+	pub type PriceSourcePriority<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, Vec<PriceSourceKind>, OptionQuery>;
+
+	/// Mapping from currency id to the last oracle price accepted by the
+	/// circuit breaker
+	///
+	/// map CurrencyId => Option<Price>
+	#[pallet::storage]
+	#[pallet::getter(fn last_accepted_price)]
+	pub type LastAcceptedPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
+
+	/// Mapping from currency id to the price served instead of the oracle
+	/// price while the circuit breaker is triggered
+	///
+	/// map CurrencyId => Option<Price>
+	#[pallet::storage]
+	#[pallet::getter(fn circuit_breaker_price)]
+	pub type CircuitBreakerPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -149,6 +223,73 @@ pub mod module {
 			<Pallet<T> as LockablePrice<CurrencyId>>::unlock_price(currency_id)?;
 			Ok(())
 		}
+
+		/// Replace the locked price with the real-time price in one call, so
+		/// there is no window in which the price is unlocked.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		#[pallet::weight((T::WeightInfo::relock_price(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn relock_price(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			let previous_price = Self::locked_price(currency_id).ok_or(Error::<T>::NoLockedPrice)?;
+			let price = Self::access_price(currency_id).ok_or(Error::<T>::AccessPriceFailed)?;
+			LockedPrice::<T>::insert(currency_id, price);
+			Self::deposit_event(Event::RelockPrice(currency_id, previous_price, price));
+			Ok(())
+		}
+
+		/// Set the priority of the sources the price of specific currency
+		/// is accessed from, the first available price is used.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `priority`: price sources in priority order, `None` means use
+		///   `DEFAULT_PRICE_SOURCE_PRIORITY`.
+		#[pallet::weight((T::WeightInfo::set_price_source_priority(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_price_source_priority(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			priority: Option<Vec<PriceSourceKind>>,
+		) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			if let Some(sources) = &priority {
+				let mut deduped = sources.clone();
+				deduped.sort_by_key(|s| *s as u8);
+				deduped.dedup();
+				ensure!(
+					!sources.is_empty() && deduped.len() == sources.len(),
+					Error::<T>::InvalidPriceSourcePriority
+				);
+			}
+
+			PriceSourcePriority::<T>::set(currency_id, priority.clone());
+			Self::deposit_event(Event::PriceSourcePriorityUpdated(currency_id, priority));
+			Ok(())
+		}
+
+		/// Reset the triggered circuit breaker and accept the real-time price.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		#[pallet::weight((T::WeightInfo::reset_price_circuit_breaker(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn reset_price_circuit_breaker(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			ensure!(
+				CircuitBreakerPrice::<T>::take(currency_id).is_some(),
+				Error::<T>::CircuitBreakerNotTriggered
+			);
+			let price = Self::access_price(currency_id).ok_or(Error::<T>::AccessPriceFailed)?;
+			LastAcceptedPrice::<T>::insert(currency_id, price);
+			Self::deposit_event(Event::PriceCircuitBreakerReset(currency_id, price));
+			Ok(())
+		}
 	}
 }
 
@@ -193,6 +334,94 @@ impl<T: Config> Pallet<T> {
 			None
 		}
 	}
+
+	/// The currencies whose oracle prices `access_price` derives the price of
+	/// `currency_id` from.
+	fn oracle_sources(currency_id: CurrencyId) -> Vec<CurrencyId> {
+		if currency_id == T::GetStableCurrencyId::get() {
+			vec![]
+		} else if currency_id == T::GetLiquidCurrencyId::get() {
+			Self::oracle_sources(T::GetStakingCurrencyId::get())
+		} else if let CurrencyId::DexShare(symbol_0, symbol_1) = currency_id {
+			let mut sources = Self::oracle_sources(symbol_0.into());
+			sources.extend(Self::oracle_sources(symbol_1.into()));
+			sources
+		} else {
+			vec![currency_id]
+		}
+	}
+
+	/// access the time-weighted average price of specific currency in stable
+	/// currency on DEX, which can't be moved by a swap within a block.
+	///
+	/// Note: this returns the price for 1 basic unit
+	fn access_dex_price(currency_id: CurrencyId) -> Option<Price> {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		if currency_id == stable_currency_id {
+			return Self::access_price(stable_currency_id);
+		}
+
+		T::DEX::get_time_weighted_average_price(currency_id, stable_currency_id, T::DexPriceTwapWindow::get())
+			.and_then(|rate| rate.checked_mul(&Self::access_price(stable_currency_id)?))
+	}
+
+	/// access the real-time price of specific currency, or the previous
+	/// price if the circuit breaker is triggered.
+	///
+	/// Note: this returns the price for 1 basic unit
+	fn access_guarded_price(currency_id: CurrencyId) -> Option<Price> {
+		if currency_id == T::GetLiquidCurrencyId::get() {
+			return Self::access_guarded_price(T::GetStakingCurrencyId::get())
+				.and_then(|n| n.checked_mul(&T::LiquidStakingExchangeRateProvider::get_exchange_rate()));
+		}
+
+		Self::circuit_breaker_price(currency_id).or_else(|| Self::access_price(currency_id))
+	}
+
+	/// access the price of specific currency from the given source.
+	fn price_from_source(currency_id: CurrencyId, source: PriceSourceKind) -> Option<Price> {
+		match source {
+			PriceSourceKind::Locked => Self::locked_price(currency_id),
+			PriceSourceKind::Oracle => Self::access_guarded_price(currency_id),
+			PriceSourceKind::Dex => Self::access_dex_price(currency_id),
+		}
+	}
+
+	/// Check the move of the new real-time price against the last accepted
+	/// price, trigger the circuit breaker if it exceeds
+	/// `MaxPriceMovePerBlock`, otherwise accept the new price and release the
+	/// circuit breaker.
+	fn check_price_move(currency_id: CurrencyId) {
+		let max_price_move = T::MaxPriceMovePerBlock::get();
+		if max_price_move.is_zero() {
+			return;
+		}
+		let price = match Self::access_price(currency_id) {
+			Some(price) => price,
+			None => return,
+		};
+
+		if let Some(previous_price) = Self::last_accepted_price(currency_id) {
+			let price_move = if price > previous_price {
+				price.saturating_sub(previous_price)
+			} else {
+				previous_price.saturating_sub(price)
+			};
+			let exceeded = price_move
+				.checked_div(&previous_price)
+				.map_or(false, |ratio| ratio > max_price_move);
+			if exceeded {
+				if !CircuitBreakerPrice::<T>::contains_key(currency_id) {
+					CircuitBreakerPrice::<T>::insert(currency_id, previous_price);
+					Self::deposit_event(Event::PriceCircuitBreakerTriggered(currency_id, previous_price, price));
+				}
+				return;
+			}
+		}
+
+		LastAcceptedPrice::<T>::insert(currency_id, price);
+		CircuitBreakerPrice::<T>::remove(currency_id);
+	}
 }
 
 impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
@@ -212,6 +441,18 @@ impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
 	}
 }
 
+impl<T: Config> OnNewData<T::AccountId, CurrencyId, Price> for Pallet<T> {
+	/// Check the price move of the fed currency for the circuit breaker, the
+	/// price of liquid currency is derived from the price of staking currency
+	/// so it's checked as well
+	fn on_new_data(_who: &T::AccountId, currency_id: &CurrencyId, _price: &Price) {
+		Self::check_price_move(*currency_id);
+		if *currency_id == T::GetStakingCurrencyId::get() {
+			Self::check_price_move(T::GetLiquidCurrencyId::get());
+		}
+	}
+}
+
 /// PriceProvider that always provider real-time prices from oracle
 pub struct RealTimePriceProvider<T>(PhantomData<T>);
 impl<T: Config> PriceProvider<CurrencyId> for RealTimePriceProvider<T> {
@@ -220,12 +461,32 @@ impl<T: Config> PriceProvider<CurrencyId> for RealTimePriceProvider<T> {
 	}
 }
 
-/// PriceProvider that priority access to the locked price, if it is none,
-/// will access to real-time price
+impl<T: Config> PriceOracleSources<CurrencyId> for RealTimePriceProvider<T> {
+	fn oracle_sources(currency_id: CurrencyId) -> Vec<CurrencyId> {
+		Pallet::<T>::oracle_sources(currency_id)
+	}
+}
+
+/// PriceProvider that access the price sources in the priority configured for
+/// the currency, by default priority access to the locked price, if it is
+/// none, will access to real-time price
 pub struct PriorityLockedPriceProvider<T>(PhantomData<T>);
 impl<T: Config> PriceProvider<CurrencyId> for PriorityLockedPriceProvider<T> {
 	fn get_price(currency_id: CurrencyId) -> Option<Price> {
-		Pallet::<T>::locked_price(currency_id).or_else(|| Pallet::<T>::access_price(currency_id))
+		match Pallet::<T>::price_source_priority(currency_id) {
+			Some(priority) => priority
+				.into_iter()
+				.find_map(|source| Pallet::<T>::price_from_source(currency_id, source)),
+			None => DEFAULT_PRICE_SOURCE_PRIORITY
+				.iter()
+				.find_map(|source| Pallet::<T>::price_from_source(currency_id, *source)),
+		}
+	}
+}
+
+impl<T: Config> PriceOracleSources<CurrencyId> for PriorityLockedPriceProvider<T> {
+	fn oracle_sources(currency_id: CurrencyId) -> Vec<CurrencyId> {
+		Pallet::<T>::oracle_sources(currency_id)
 	}
 }
 
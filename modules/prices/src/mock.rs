@@ -135,6 +135,7 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 	fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
 		match (currency_id_a, currency_id_b) {
 			(AUSD, DOT) => (10000, 200),
+			(DOT, AUSD) => (200, 10000),
 			_ => (0, 0),
 		}
 	}
@@ -151,6 +152,17 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn get_time_weighted_average_price(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: u32,
+	) -> Option<Price> {
+		match (currency_id_a, currency_id_b, window) {
+			(DOT, AUSD, 10) => Some(Price::saturating_from_integer(40)),
+			_ => None,
+		}
+	}
+
 	fn swap_with_exact_supply(
 		_who: &AccountId,
 		_path: &[CurrencyId],
@@ -181,6 +193,17 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn swap_and_add_liquidity(
+		_who: &AccountId,
+		_currency_id_a: CurrencyId,
+		_currency_id_b: CurrencyId,
+		_amount_a: Balance,
+		_min_share_increment: Balance,
+		_stake_increment_share: bool,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
 	fn remove_liquidity(
 		_who: &AccountId,
 		_currency_id_a: CurrencyId,
@@ -220,6 +243,8 @@ parameter_types! {
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 	pub StableCurrencyFixedPrice: Price = Price::one();
+	pub MaxPriceMovePerBlock: Ratio = Ratio::saturating_from_rational(50, 100);
+	pub const DexPriceTwapWindow: u32 = 10;
 }
 
 impl Config for Runtime {
@@ -234,6 +259,8 @@ impl Config for Runtime {
 	type DEX = MockDEX;
 	type Currency = Tokens;
 	type CurrencyIdMapping = MockCurrencyIdMapping;
+	type MaxPriceMovePerBlock = MaxPriceMovePerBlock;
+	type DexPriceTwapWindow = DexPriceTwapWindow;
 	type WeightInfo = ();
 }
 
@@ -307,6 +307,172 @@ fn unlock_price_work() {
 	});
 }
 
+#[test]
+fn relock_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(PricesModule::relock_price(Origin::signed(5), BTC), BadOrigin);
+
+		// relock failed when there's no locked price
+		assert_noop!(
+			PricesModule::relock_price(Origin::signed(1), BTC),
+			Error::<Runtime>::NoLockedPrice
+		);
+
+		assert_ok!(PricesModule::lock_price(Origin::signed(1), BTC));
+		assert_eq!(
+			PricesModule::locked_price(BTC),
+			Some(Price::saturating_from_integer(500000000000000u128))
+		);
+
+		mock_oracle_update();
+		assert_eq!(
+			PricesModule::access_price(BTC),
+			Some(Price::saturating_from_integer(400000000000000u128))
+		);
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(BTC),
+			Some(Price::saturating_from_integer(500000000000000u128))
+		);
+
+		assert_ok!(PricesModule::relock_price(Origin::signed(1), BTC));
+		System::assert_last_event(Event::PricesModule(crate::Event::RelockPrice(
+			BTC,
+			Price::saturating_from_integer(500000000000000u128),
+			Price::saturating_from_integer(400000000000000u128),
+		)));
+		// the price is replaced without being unlocked in between
+		assert!(!System::events()
+			.iter()
+			.any(|record| matches!(record.event, Event::PricesModule(crate::Event::UnlockPrice(_)))));
+		assert_eq!(
+			PricesModule::locked_price(BTC),
+			Some(Price::saturating_from_integer(400000000000000u128))
+		);
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(BTC),
+			Some(Price::saturating_from_integer(400000000000000u128))
+		);
+	});
+}
+
+#[test]
+fn set_price_source_priority_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			PricesModule::set_price_source_priority(Origin::signed(5), DOT, Some(vec![PriceSourceKind::Dex])),
+			BadOrigin
+		);
+		assert_noop!(
+			PricesModule::set_price_source_priority(Origin::signed(1), DOT, Some(vec![])),
+			Error::<Runtime>::InvalidPriceSourcePriority
+		);
+		assert_noop!(
+			PricesModule::set_price_source_priority(
+				Origin::signed(1),
+				DOT,
+				Some(vec![
+					PriceSourceKind::Dex,
+					PriceSourceKind::Oracle,
+					PriceSourceKind::Dex
+				])
+			),
+			Error::<Runtime>::InvalidPriceSourcePriority
+		);
+
+		assert_eq!(PricesModule::price_source_priority(DOT), None);
+		assert_ok!(PricesModule::set_price_source_priority(
+			Origin::signed(1),
+			DOT,
+			Some(vec![PriceSourceKind::Dex, PriceSourceKind::Oracle])
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceSourcePriorityUpdated(
+			DOT,
+			Some(vec![PriceSourceKind::Dex, PriceSourceKind::Oracle]),
+		)));
+		assert_eq!(
+			PricesModule::price_source_priority(DOT),
+			Some(vec![PriceSourceKind::Dex, PriceSourceKind::Oracle])
+		);
+
+		assert_ok!(PricesModule::set_price_source_priority(Origin::signed(1), DOT, None));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceSourcePriorityUpdated(DOT, None)));
+		assert_eq!(PricesModule::price_source_priority(DOT), None);
+	});
+}
+
+#[test]
+fn priority_locked_price_provider_follows_price_source_priority() {
+	ExtBuilder::default().build().execute_with(|| {
+		let oracle_price = Price::saturating_from_integer(10000000000u128);
+		let dex_price = Price::saturating_from_integer(40000000u128);
+
+		// default priority: locked price, then oracle
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(DOT),
+			Some(oracle_price)
+		);
+		assert_ok!(PricesModule::lock_price(Origin::signed(1), DOT));
+		mock_oracle_update();
+		let new_oracle_price = Price::saturating_from_integer(1000000000u128);
+		assert_eq!(PricesModule::access_price(DOT), Some(new_oracle_price));
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(DOT),
+			Some(oracle_price)
+		);
+
+		// prefer DEX over the locked price and oracle
+		assert_ok!(PricesModule::set_price_source_priority(
+			Origin::signed(1),
+			DOT,
+			Some(vec![
+				PriceSourceKind::Dex,
+				PriceSourceKind::Locked,
+				PriceSourceKind::Oracle
+			])
+		));
+		assert_eq!(PriorityLockedPriceProvider::<Runtime>::get_price(DOT), Some(dex_price));
+
+		// prefer oracle over the locked price
+		assert_ok!(PricesModule::set_price_source_priority(
+			Origin::signed(1),
+			DOT,
+			Some(vec![PriceSourceKind::Oracle, PriceSourceKind::Locked])
+		));
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(DOT),
+			Some(new_oracle_price)
+		);
+
+		// fall through to the next source if DEX has no average price for BTC
+		assert_ok!(PricesModule::set_price_source_priority(
+			Origin::signed(1),
+			BTC,
+			Some(vec![PriceSourceKind::Dex, PriceSourceKind::Oracle])
+		));
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(BTC),
+			PricesModule::access_price(BTC)
+		);
+		assert_ok!(PricesModule::set_price_source_priority(
+			Origin::signed(1),
+			BTC,
+			Some(vec![PriceSourceKind::Dex])
+		));
+		assert_eq!(PriorityLockedPriceProvider::<Runtime>::get_price(BTC), None);
+
+		// reset to the default priority
+		assert_ok!(PricesModule::set_price_source_priority(Origin::signed(1), DOT, None));
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(DOT),
+			Some(oracle_price)
+		);
+	});
+}
+
 #[test]
 fn price_providers_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -508,3 +674,116 @@ fn price_providers_work() {
 		assert_eq!(LockedPriceProvider::<Runtime>::get_relative_price(BTC, KSM), None);
 	});
 }
+
+#[test]
+fn on_new_data_check_liquid_currency_price_move() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(PricesModule::last_accepted_price(DOT), None);
+		assert_eq!(PricesModule::last_accepted_price(LDOT), None);
+
+		PricesModule::on_new_data(&1, &BTC, &Price::saturating_from_integer(50000));
+		assert_eq!(PricesModule::last_accepted_price(DOT), None);
+		assert_eq!(PricesModule::last_accepted_price(LDOT), None);
+
+		PricesModule::on_new_data(&1, &DOT, &Price::saturating_from_integer(10));
+		assert_eq!(PricesModule::last_accepted_price(DOT), PricesModule::access_price(DOT));
+		assert!(PricesModule::last_accepted_price(LDOT).is_some());
+		assert_eq!(
+			PricesModule::last_accepted_price(LDOT),
+			PricesModule::access_price(LDOT)
+		);
+	});
+}
+
+#[test]
+fn circuit_breaker_not_triggered_by_normal_price_move() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		PricesModule::on_new_data(&1, &BTC, &Price::saturating_from_integer(50000));
+		assert_eq!(
+			PricesModule::last_accepted_price(BTC),
+			Some(Price::saturating_from_integer(500000000000000u128))
+		);
+
+		// BTC price drops by 20%
+		mock_oracle_update();
+		PricesModule::on_new_data(&1, &BTC, &Price::saturating_from_integer(40000));
+		assert_eq!(PricesModule::circuit_breaker_price(BTC), None);
+		assert_eq!(
+			PricesModule::last_accepted_price(BTC),
+			Some(Price::saturating_from_integer(400000000000000u128))
+		);
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(BTC),
+			Some(Price::saturating_from_integer(400000000000000u128))
+		);
+	});
+}
+
+#[test]
+fn circuit_breaker_triggered_by_sudden_price_drop() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		PricesModule::on_new_data(&1, &DOT, &Price::saturating_from_integer(100));
+		assert_eq!(
+			PricesModule::last_accepted_price(DOT),
+			Some(Price::saturating_from_integer(10000000000u128))
+		);
+
+		// DOT price drops by 90%
+		mock_oracle_update();
+		PricesModule::on_new_data(&1, &DOT, &Price::saturating_from_integer(10));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceCircuitBreakerTriggered(
+			DOT,
+			Price::saturating_from_integer(10000000000u128),
+			Price::saturating_from_integer(1000000000u128),
+		)));
+		assert_eq!(
+			PricesModule::circuit_breaker_price(DOT),
+			Some(Price::saturating_from_integer(10000000000u128))
+		);
+		assert_eq!(
+			PricesModule::last_accepted_price(DOT),
+			Some(Price::saturating_from_integer(10000000000u128))
+		);
+
+		// the previous price is served, real-time price is not affected
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(DOT),
+			Some(Price::saturating_from_integer(10000000000u128))
+		);
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(LDOT),
+			Some(Price::saturating_from_integer(6000000000u128))
+		);
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_price(DOT),
+			Some(Price::saturating_from_integer(1000000000u128))
+		);
+
+		// reset the circuit breaker
+		assert_noop!(
+			PricesModule::reset_price_circuit_breaker(Origin::signed(2), DOT),
+			BadOrigin
+		);
+		assert_noop!(
+			PricesModule::reset_price_circuit_breaker(Origin::signed(1), BTC),
+			Error::<Runtime>::CircuitBreakerNotTriggered
+		);
+		assert_ok!(PricesModule::reset_price_circuit_breaker(Origin::signed(1), DOT));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceCircuitBreakerReset(
+			DOT,
+			Price::saturating_from_integer(1000000000u128),
+		)));
+		assert_eq!(PricesModule::circuit_breaker_price(DOT), None);
+		assert_eq!(
+			PricesModule::last_accepted_price(DOT),
+			Some(Price::saturating_from_integer(1000000000u128))
+		);
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(DOT),
+			Some(Price::saturating_from_integer(1000000000u128))
+		);
+	});
+}
@@ -49,7 +49,10 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for module_prices.
 pub trait WeightInfo {
 	fn lock_price() -> Weight;
+	fn relock_price() -> Weight;
 	fn unlock_price() -> Weight;
+	fn set_price_source_priority() -> Weight;
+	fn reset_price_circuit_breaker() -> Weight;
 }
 
 /// Weights for module_prices using the Acala node and recommended hardware.
@@ -60,10 +63,24 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(11 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	fn relock_price() -> Weight {
+		(53_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 	fn unlock_price() -> Weight {
 		(12_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_price_source_priority() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn reset_price_circuit_breaker() -> Weight {
+		(53_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -73,8 +90,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
+	fn relock_price() -> Weight {
+		(53_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
 	fn unlock_price() -> Weight {
 		(12_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_price_source_priority() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn reset_price_circuit_breaker() -> Weight {
+		(53_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
 }
@@ -27,14 +27,16 @@
 use frame_support::{
 	pallet_prelude::*,
 	traits::{EstimateNextSessionRotation, ValidatorSet},
+	transactional,
 };
 use frame_system::pallet_prelude::*;
 use pallet_session::ShouldEndSession;
 use sp_runtime::{
-	traits::{One, Saturating, Zero},
+	traits::{One, SaturatedConversion, Saturating, Zero},
 	Permill,
 };
 use sp_staking::SessionIndex;
+use sp_std::vec::Vec;
 
 pub mod migrations;
 mod mock;
@@ -42,6 +44,10 @@ mod tests;
 pub mod weights;
 
 pub use module::*;
+
+/// The maximum number of steps of a session duration ramp, the ramp
+/// schedules `steps + 1` session duration changes.
+pub const MAX_SESSION_DURATION_RAMP_STEPS: u32 = 100;
 pub use weights::WeightInfo;
 
 #[frame_support::pallet]
@@ -65,6 +71,10 @@ pub mod module {
 		InvalidDuration,
 		/// Failed to estimate next session.
 		EstimateNextSessionFailed,
+		/// The session duration ramp has too many steps.
+		TooManyRampSteps,
+		/// More session duration changes are scheduled than the witness.
+		InvalidScheduledChangesWitness,
 	}
 
 	#[pallet::event]
@@ -73,6 +83,9 @@ pub mod module {
 	pub enum Event<T: Config> {
 		/// Scheduled session duration. \[block_number, session_index, session_duration\]
 		ScheduledSessionDuration(T::BlockNumber, SessionIndex, T::BlockNumber),
+		/// A new session is forced on the next block, the scheduled session
+		/// duration changes are cleared. \[cleared_changes\]
+		NewSessionForced(u32),
 	}
 
 	/// The current session duration.
@@ -97,6 +110,13 @@ pub mod module {
 	pub type SessionDurationChanges<T: Config> =
 		StorageMap<_, Twox64Concat, T::BlockNumber, (SessionIndex, T::BlockNumber), ValueQuery>;
 
+	/// Whether the current session should end on the next block.
+	///
+	/// ForceNewSession: bool
+	#[pallet::storage]
+	#[pallet::getter(fn force_new_session_pending)]
+	pub type ForceNewSession<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub session_duration: T::BlockNumber,
@@ -165,6 +185,62 @@ pub mod module {
 			));
 			Ok(())
 		}
+
+		/// End the current session on the next block. Afterward sessions last
+		/// `session_duration` blocks again, counting from the forced rotation.
+		///
+		/// The scheduled session duration changes are estimated from the
+		/// session rotations before the forced one, so they are cleared and
+		/// need to be rescheduled.
+		///
+		/// - `scheduled_changes`: the witness of the number of scheduled
+		///   session duration changes.
+		#[pallet::weight(T::WeightInfo::force_new_session(*scheduled_changes))]
+		#[transactional]
+		pub fn force_new_session(origin: OriginFor<T>, scheduled_changes: u32) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let cleared_changes = SessionDurationChanges::<T>::drain().count() as u32;
+			ensure!(
+				cleared_changes <= scheduled_changes,
+				Error::<T>::InvalidScheduledChangesWitness
+			);
+
+			ForceNewSession::<T>::put(true);
+			Self::deposit_event(Event::NewSessionForced(cleared_changes));
+			Ok(())
+		}
+
+		/// Schedule session durations that change linearly from
+		/// `start_session` to `end_session`.
+		///
+		/// - `start_session`: the session index that the ramp begins.
+		/// - `end_session`: the session index that the ramp ends.
+		/// - `start_duration`: session duration of `start_session`.
+		/// - `end_duration`: session duration of `end_session`.
+		///
+		/// At most `MAX_SESSION_DURATION_RAMP_STEPS` sessions after
+		/// `start_session` are scheduled.
+		#[pallet::weight(T::WeightInfo::schedule_session_duration_ramp(
+			end_session.saturating_sub(*start_session).min(MAX_SESSION_DURATION_RAMP_STEPS).saturating_add(1)
+		))]
+		pub fn schedule_session_duration_ramp(
+			origin: OriginFor<T>,
+			start_session: SessionIndex,
+			end_session: SessionIndex,
+			start_duration: T::BlockNumber,
+			end_duration: T::BlockNumber,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let changes =
+				Self::do_schedule_session_duration_ramp(start_session, end_session, start_duration, end_duration)?;
+
+			for (target_block_number, session, duration) in changes {
+				Self::deposit_event(Event::ScheduledSessionDuration(target_block_number, session, duration));
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -183,22 +259,99 @@ impl<T: Config> Pallet<T> {
 			return Ok(block_number);
 		}
 
+		let target_block_number = Self::session_start_block(start_session, current_session, block_number)?;
+
+		SessionDurationChanges::<T>::insert(target_block_number, (start_session, duration));
+
+		Ok(target_block_number)
+	}
+
+	pub fn do_schedule_session_duration_ramp(
+		start_session: SessionIndex,
+		end_session: SessionIndex,
+		start_duration: T::BlockNumber,
+		end_duration: T::BlockNumber,
+	) -> Result<Vec<(T::BlockNumber, SessionIndex, T::BlockNumber)>, DispatchError> {
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let current_session = T::ValidatorSet::session_index();
+
+		ensure!(
+			start_session > current_session && end_session > start_session,
+			Error::<T>::InvalidSession
+		);
+		ensure!(
+			!start_duration.is_zero() && !end_duration.is_zero(),
+			Error::<T>::InvalidDuration
+		);
+
+		let steps = end_session.saturating_sub(start_session);
+		ensure!(steps <= MAX_SESSION_DURATION_RAMP_STEPS, Error::<T>::TooManyRampSteps);
+
+		let mut target_block_number = Self::session_start_block(start_session, current_session, block_number)?;
+		let mut changes = Vec::with_capacity(steps.saturating_add(1) as usize);
+
+		for step in 0..=steps {
+			let session = start_session.saturating_add(step);
+			let duration = Self::interpolate_duration(start_duration, end_duration, step, steps);
+
+			SessionDurationChanges::<T>::insert(target_block_number, (session, duration));
+			changes.push((target_block_number, session, duration));
+
+			target_block_number = target_block_number.saturating_add(duration);
+		}
+
+		Ok(changes)
+	}
+
+	/// The block number that `start_session` is estimated to begin at, assuming
+	/// the current session duration stays unchanged until then.
+	fn session_start_block(
+		start_session: SessionIndex,
+		current_session: SessionIndex,
+		block_number: T::BlockNumber,
+	) -> Result<T::BlockNumber, DispatchError> {
 		let next_session = Self::estimate_next_session_rotation(block_number)
 			.0
 			.ok_or(Error::<T>::EstimateNextSessionFailed)?;
-		let target_block_number =
+
+		Ok(
 			Into::<T::BlockNumber>::into(start_session.saturating_sub(current_session).saturating_sub(1))
 				.saturating_mul(Self::session_duration())
-				.saturating_add(next_session);
+				.saturating_add(next_session),
+		)
+	}
 
-		SessionDurationChanges::<T>::insert(target_block_number, (start_session, duration));
+	/// Linear interpolation between `start_duration` and `end_duration` at `step`
+	/// of `steps`, rounded towards `start_duration`.
+	fn interpolate_duration(
+		start_duration: T::BlockNumber,
+		end_duration: T::BlockNumber,
+		step: u32,
+		steps: u32,
+	) -> T::BlockNumber {
+		let delta = |diff: T::BlockNumber| -> T::BlockNumber {
+			diff.saturated_into::<u128>()
+				.saturating_mul(step.into())
+				.checked_div(steps.into())
+				.unwrap_or_default()
+				.saturated_into()
+		};
 
-		Ok(target_block_number)
+		if end_duration >= start_duration {
+			start_duration.saturating_add(delta(end_duration.saturating_sub(start_duration)))
+		} else {
+			start_duration.saturating_sub(delta(start_duration.saturating_sub(end_duration)))
+		}
 	}
 }
 
 impl<T: Config> ShouldEndSession<T::BlockNumber> for Pallet<T> {
 	fn should_end_session(now: T::BlockNumber) -> bool {
+		if ForceNewSession::<T>::take() {
+			DurationOffset::<T>::put(now);
+			return true;
+		}
+
 		let offset = Self::duration_offset();
 		let period = Self::session_duration();
 
@@ -23,6 +23,7 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{new_test_ext, Event, Origin, Runtime, Session, SessionManager, System};
+use sp_runtime::traits::BadOrigin;
 
 #[test]
 fn schedule_session_duration_work() {
@@ -53,6 +54,86 @@ fn schedule_session_duration_work() {
 	});
 }
 
+#[test]
+fn schedule_session_duration_ramp_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(System::block_number(), 1);
+		assert_eq!(Session::session_index(), 0);
+		assert_eq!(SessionManager::session_duration(), 10);
+
+		assert_noop!(
+			SessionManager::schedule_session_duration_ramp(Origin::root(), 0, 3, 10, 16),
+			Error::<Runtime>::InvalidSession
+		);
+		assert_noop!(
+			SessionManager::schedule_session_duration_ramp(Origin::root(), 3, 3, 10, 16),
+			Error::<Runtime>::InvalidSession
+		);
+		assert_noop!(
+			SessionManager::schedule_session_duration_ramp(Origin::root(), 1, 3, 0, 16),
+			Error::<Runtime>::InvalidDuration
+		);
+		assert_noop!(
+			SessionManager::schedule_session_duration_ramp(
+				Origin::root(),
+				1,
+				2 + MAX_SESSION_DURATION_RAMP_STEPS,
+				10,
+				16
+			),
+			Error::<Runtime>::TooManyRampSteps
+		);
+
+		assert_ok!(SessionManager::schedule_session_duration_ramp(
+			Origin::root(),
+			1,
+			4,
+			10,
+			16
+		));
+		System::assert_has_event(Event::SessionManager(crate::Event::ScheduledSessionDuration(10, 1, 10)));
+		System::assert_has_event(Event::SessionManager(crate::Event::ScheduledSessionDuration(20, 2, 12)));
+		System::assert_has_event(Event::SessionManager(crate::Event::ScheduledSessionDuration(32, 3, 14)));
+		System::assert_last_event(Event::SessionManager(crate::Event::ScheduledSessionDuration(46, 4, 16)));
+		assert_eq!(SessionDurationChanges::<Runtime>::iter().count(), 4);
+		assert_eq!(SessionManager::session_duration_changes(20), (2, 12));
+		assert_eq!(SessionManager::session_duration_changes(32), (3, 14));
+
+		SessionManager::on_initialize(10);
+		assert_eq!(SessionManager::session_duration(), 10);
+		assert_eq!(SessionManager::should_end_session(20), true);
+
+		SessionManager::on_initialize(20);
+		assert_eq!(SessionManager::session_duration(), 12);
+		assert_eq!(SessionManager::duration_offset(), 20);
+		assert_eq!(SessionManager::should_end_session(30), false);
+		assert_eq!(SessionManager::should_end_session(32), true);
+
+		SessionManager::on_initialize(32);
+		assert_eq!(SessionManager::session_duration(), 14);
+		assert_eq!(SessionManager::should_end_session(46), true);
+
+		SessionManager::on_initialize(46);
+		assert_eq!(SessionManager::session_duration(), 16);
+		assert_eq!(SessionManager::duration_offset(), 46);
+		assert_eq!(SessionDurationChanges::<Runtime>::iter().count(), 0);
+	});
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(SessionManager::schedule_session_duration_ramp(
+			Origin::root(),
+			1,
+			4,
+			20,
+			11
+		));
+		assert_eq!(SessionManager::session_duration_changes(10), (1, 20));
+		assert_eq!(SessionManager::session_duration_changes(30), (2, 17));
+		assert_eq!(SessionManager::session_duration_changes(47), (3, 14));
+		System::assert_last_event(Event::SessionManager(crate::Event::ScheduledSessionDuration(61, 4, 11)));
+	});
+}
+
 #[test]
 fn on_initialize_work() {
 	new_test_ext().execute_with(|| {
@@ -98,6 +179,38 @@ fn should_end_session_work() {
 	});
 }
 
+#[test]
+fn force_new_session_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(SessionManager::session_duration(), 10);
+		assert_eq!(SessionManager::duration_offset(), 0);
+
+		assert_noop!(SessionManager::force_new_session(Origin::signed(1), 0), BadOrigin);
+
+		assert_ok!(SessionManager::schedule_session_duration(Origin::root(), 2, 11));
+		assert_eq!(SessionDurationChanges::<Runtime>::iter().count(), 1);
+		assert_noop!(
+			SessionManager::force_new_session(Origin::root(), 0),
+			Error::<Runtime>::InvalidScheduledChangesWitness
+		);
+		assert_eq!(SessionDurationChanges::<Runtime>::iter().count(), 1);
+
+		assert_ok!(SessionManager::force_new_session(Origin::root(), 1));
+		System::assert_last_event(Event::SessionManager(crate::Event::NewSessionForced(1)));
+		assert_eq!(SessionManager::force_new_session_pending(), true);
+		assert_eq!(SessionDurationChanges::<Runtime>::iter().count(), 0);
+
+		assert_eq!(SessionManager::should_end_session(13), true);
+		assert_eq!(SessionManager::force_new_session_pending(), false);
+		assert_eq!(SessionManager::session_duration(), 10);
+		assert_eq!(SessionManager::duration_offset(), 13);
+
+		assert_eq!(SessionManager::should_end_session(14), false);
+		assert_eq!(SessionManager::should_end_session(20), false);
+		assert_eq!(SessionManager::should_end_session(23), true);
+	});
+}
+
 #[test]
 fn average_session_length_work() {
 	new_test_ext().execute_with(|| {
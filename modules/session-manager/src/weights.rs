@@ -48,6 +48,8 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for module_session_manager.
 pub trait WeightInfo {
 	fn schedule_session_duration() -> Weight;
+	fn schedule_session_duration_ramp(n: u32, ) -> Weight;
+	fn force_new_session(c: u32, ) -> Weight;
 	fn on_initialize_skip() -> Weight;
 	fn on_initialize() -> Weight;
 	fn estimate_current_session_progress() -> Weight;
@@ -62,6 +64,22 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn schedule_session_duration_ramp(n: u32, ) -> Weight {
+		(32_968_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((3_521_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn force_new_session(c: u32, ) -> Weight {
+		(4_120_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((2_318_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 	fn on_initialize_skip() -> Weight {
 		(5_399_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
@@ -89,6 +107,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn schedule_session_duration_ramp(n: u32, ) -> Weight {
+		(32_968_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((3_521_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn force_new_session(c: u32, ) -> Weight {
+		(4_120_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((2_318_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 	fn on_initialize_skip() -> Weight {
 		(5_399_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
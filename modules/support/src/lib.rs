@@ -93,6 +93,7 @@ pub trait AuctionManager<AccountId> {
 		currency_id: Self::CurrencyId,
 		amount: Self::Balance,
 		target: Self::Balance,
+		reserve_price: Option<Self::Balance>,
 	) -> DispatchResult;
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult;
 	fn get_total_collateral_in_auction(id: Self::CurrencyId) -> Self::Balance;
@@ -108,6 +109,16 @@ pub trait DEXManager<AccountId, CurrencyId, Balance> {
 
 	fn get_swap_supply_amount(path: &[CurrencyId], target_amount: Balance) -> Option<Balance>;
 
+	/// The time-weighted average price of `currency_id_a` in `currency_id_b`
+	/// over the last `window` blocks.
+	///
+	/// Note: this returns the price for 1 basic unit
+	fn get_time_weighted_average_price(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: u32,
+	) -> Option<Price>;
+
 	fn swap_with_exact_supply(
 		who: &AccountId,
 		path: &[CurrencyId],
@@ -132,6 +143,15 @@ pub trait DEXManager<AccountId, CurrencyId, Balance> {
 		stake_increment_share: bool,
 	) -> DispatchResult;
 
+	fn swap_and_add_liquidity(
+		who: &AccountId,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		amount_a: Balance,
+		min_share_increment: Balance,
+		stake_increment_share: bool,
+	) -> DispatchResult;
+
 	fn remove_liquidity(
 		who: &AccountId,
 		currency_id_a: CurrencyId,
@@ -163,6 +183,14 @@ where
 		Some(Default::default())
 	}
 
+	fn get_time_weighted_average_price(
+		_currency_id_a: CurrencyId,
+		_currency_id_b: CurrencyId,
+		_window: u32,
+	) -> Option<Price> {
+		None
+	}
+
 	fn swap_with_exact_supply(
 		_who: &AccountId,
 		_path: &[CurrencyId],
@@ -193,6 +221,17 @@ where
 		Ok(())
 	}
 
+	fn swap_and_add_liquidity(
+		_who: &AccountId,
+		_currency_id_a: CurrencyId,
+		_currency_id_b: CurrencyId,
+		_amount_a: Balance,
+		_min_share_increment: Balance,
+		_stake_increment_share: bool,
+	) -> DispatchResult {
+		Ok(())
+	}
+
 	fn remove_liquidity(
 		_who: &AccountId,
 		_currency_id_a: CurrencyId,
@@ -240,6 +279,9 @@ pub trait CDPTreasury<AccountId> {
 	/// deposit surplus(stable currency) to cdp treasury by `from`
 	fn deposit_surplus(from: &AccountId, surplus: Self::Balance) -> DispatchResult;
 
+	/// withdraw surplus(stable currency) of cdp treasury to `to`
+	fn withdraw_surplus(to: &AccountId, surplus: Self::Balance) -> DispatchResult;
+
 	/// deposit collateral assets to cdp treasury by `who`
 	fn deposit_collateral(from: &AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult;
 
@@ -248,6 +290,19 @@ pub trait CDPTreasury<AccountId> {
 }
 
 pub trait CDPTreasuryExtended<AccountId>: CDPTreasury<AccountId> {
+	/// The maximum collateral amount that liquidation should try to swap
+	/// through DEX, `None` means no limit.
+	fn get_dex_liquidation_size_threshold(currency_id: Self::CurrencyId) -> Option<Self::Balance>;
+
+	/// The collateral amount required to swap `target_amount` of stable
+	/// currency through `maybe_path` or the default DEX path, `None` means
+	/// the path is invalid or unavailable.
+	fn get_swap_supply_collateral_amount(
+		currency_id: Self::CurrencyId,
+		target_amount: Self::Balance,
+		maybe_path: Option<&[Self::CurrencyId]>,
+	) -> Option<Self::Balance>;
+
 	fn swap_exact_collateral_to_stable(
 		currency_id: Self::CurrencyId,
 		supply_amount: Self::Balance,
@@ -268,6 +323,7 @@ pub trait CDPTreasuryExtended<AccountId>: CDPTreasury<AccountId> {
 		currency_id: Self::CurrencyId,
 		amount: Self::Balance,
 		target: Self::Balance,
+		reserve_price: Option<Self::Balance>,
 		refund_receiver: AccountId,
 		splited: bool,
 	) -> DispatchResult;
@@ -284,6 +340,19 @@ pub trait PriceProvider<CurrencyId> {
 	}
 }
 
+/// The oracle currencies the price of a currency is derived from.
+pub trait PriceOracleSources<CurrencyId> {
+	/// Returns the currencies whose oracle prices the price of `currency_id`
+	/// is derived from, empty if the price is fixed.
+	fn oracle_sources(currency_id: CurrencyId) -> Vec<CurrencyId>;
+}
+
+impl<CurrencyId> PriceProvider<CurrencyId> for () {
+	fn get_price(_currency_id: CurrencyId) -> Option<Price> {
+		None
+	}
+}
+
 pub trait LockablePrice<CurrencyId> {
 	fn lock_price(currency_id: CurrencyId) -> DispatchResult;
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult;
@@ -295,6 +364,7 @@ pub trait ExchangeRateProvider {
 
 pub trait EmergencyShutdown {
 	fn is_shutdown() -> bool;
+	fn is_collateral_shutdown(currency_id: CurrencyId) -> bool;
 }
 
 pub trait DEXIncentives<AccountId, CurrencyId, Balance> {
@@ -312,6 +382,25 @@ impl<AccountId, CurrencyId, Balance> DEXIncentives<AccountId, CurrencyId, Balanc
 	}
 }
 
+/// A filter that may veto transfers, e.g. for compliance checks.
+pub trait TransferFilter<AccountId, CurrencyId, Balance> {
+	/// Return true if `amount` of `currency_id` is allowed to be transferred
+	/// from `from` to `to`.
+	fn is_allowed(from: &AccountId, to: &AccountId, currency_id: CurrencyId, amount: Balance) -> bool;
+}
+
+impl<AccountId, CurrencyId, Balance> TransferFilter<AccountId, CurrencyId, Balance> for () {
+	fn is_allowed(_: &AccountId, _: &AccountId, _: CurrencyId, _: Balance) -> bool {
+		true
+	}
+}
+
+/// Read-only access to NFT token data not covered by `orml_traits::NFT`.
+pub trait NFTMetadata<ClassId, TokenId> {
+	/// Returns the metadata of the token, `None` if the token doesn't exist.
+	fn token_metadata(token: (ClassId, TokenId)) -> Option<Vec<u8>>;
+}
+
 /// Return true if the call of EVM precompile contract is allowed.
 pub trait PrecompileCallerFilter {
 	fn is_allowed(caller: H160) -> bool;
@@ -517,6 +606,11 @@ pub trait CurrencyIdMapping {
 	/// If CurrencyId is CurrencyId::DexShare and contain DexShare::Erc20,
 	/// the EvmAddress must have been mapped.
 	fn decimals(currency_id: CurrencyId) -> Option<u8>;
+	/// Returns the decimals reported to EVM by the MultiCurrency precompile.
+	/// Only for display, amounts and prices must be converted by `decimals`.
+	fn display_decimals(currency_id: CurrencyId) -> Option<u8> {
+		Self::decimals(currency_id)
+	}
 	/// Encode the CurrencyId to EvmAddress.
 	/// If is CurrencyId::DexShare and contain DexShare::Erc20,
 	/// will use the u32 to get the DexShare::Erc20 from the mapping.
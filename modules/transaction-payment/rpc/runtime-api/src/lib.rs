@@ -0,0 +1,37 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for transaction payment module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use frame_support::weights::Weight;
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+
+sp_api::decl_runtime_apis! {
+	pub trait CallCostApi<Call, Balance> where
+		Call: Codec,
+		Balance: Codec + MaybeDisplay + MaybeFromStr,
+	{
+		/// Estimate the dispatch weight and the native fee of `call`,
+		/// assuming an encoded extrinsic length of `len` and no tip.
+		fn estimate_call_cost(call: Call, len: u32) -> (Weight, Balance);
+	}
+}
@@ -292,6 +292,8 @@ pub mod module {
 	pub enum Error<T> {
 		/// The swap path is invalid
 		InvalidSwapPath,
+		/// The fee currency is invalid
+		InvalidFeeCurrency,
 	}
 
 	/// The next fee multiplier.
@@ -307,6 +309,11 @@ pub mod module {
 	pub type AlternativeFeeSwapPath<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<CurrencyId, T::TradingPathLimit>, OptionQuery>;
 
+	/// The preferred fee currency of accounts.
+	#[pallet::storage]
+	#[pallet::getter(fn account_fee_currency)]
+	pub type AccountFeeCurrency<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, CurrencyId, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -391,6 +398,23 @@ pub mod module {
 			}
 			Ok(())
 		}
+
+		/// Set preferred fee currency
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_currency())]
+		pub fn set_fee_currency(origin: OriginFor<T>, currency_id: Option<CurrencyId>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if let Some(currency_id) = currency_id {
+				ensure!(
+					currency_id != T::NativeCurrencyId::get(),
+					Error::<T>::InvalidFeeCurrency
+				);
+				AccountFeeCurrency::<T>::insert(&who, currency_id);
+			} else {
+				AccountFeeCurrency::<T>::remove(&who);
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -446,6 +470,18 @@ where
 		Self::compute_fee_details(len, &dispatch_info, 0u32.into())
 	}
 
+	/// Estimate the dispatch weight and the fee of a given `call`, without
+	/// building a signed extrinsic. `len` is the expected encoded length of
+	/// the extrinsic and no tip is taken into account.
+	pub fn estimate_call_cost(call: <T as frame_system::Config>::Call, len: u32) -> (Weight, PalletBalanceOf<T>)
+	where
+		<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo> + GetDispatchInfo,
+	{
+		let dispatch_info = call.get_dispatch_info();
+		let fee = Self::compute_fee(len, &dispatch_info, 0u32.into());
+		(dispatch_info.weight, fee)
+	}
+
 	/// Compute the fee details for a particular transaction.
 	pub fn compute_fee_details(
 		len: u32,
@@ -588,14 +624,41 @@ where
 			// add extra gap to keep alive after swap
 			let amount = fee.saturating_add(native_existential_deposit.saturating_sub(total_native));
 			let native_currency_id = T::NativeCurrencyId::get();
-			let default_fee_swap_path_list = T::DefaultFeeSwapPathList::get();
-			let fee_swap_path_list: Vec<Vec<CurrencyId>> =
+
+			// try the default paths in order of the cost to swap, the cheapest first, the paths whose
+			// cost is unknown are tried last in the configured order
+			let mut default_fee_swap_path_list: Vec<(Option<Balance>, Vec<CurrencyId>)> =
+				T::DefaultFeeSwapPathList::get()
+					.into_iter()
+					.map(|trading_path| {
+						(
+							Self::fee_swap_cost(&trading_path, amount.unique_saturated_into()),
+							trading_path,
+						)
+					})
+					.collect();
+			default_fee_swap_path_list.sort_by_key(|(cost, _)| (cost.is_none(), *cost));
+			let default_fee_swap_path_list: Vec<Vec<CurrencyId>> = default_fee_swap_path_list
+				.into_iter()
+				.map(|(_, trading_path)| trading_path)
+				.collect();
+
+			let mut fee_swap_path_list: Vec<Vec<CurrencyId>> =
 				if let Some(trading_path) = AlternativeFeeSwapPath::<T>::get(who) {
 					vec![vec![trading_path.into_inner()], default_fee_swap_path_list].concat()
 				} else {
 					default_fee_swap_path_list
 				};
 
+			// try the paths that supply the preferred fee currency first, the others are the fallback
+			if let Some(fee_currency_id) = AccountFeeCurrency::<T>::get(who) {
+				let direct_path = vec![fee_currency_id, native_currency_id];
+				if !fee_swap_path_list.contains(&direct_path) {
+					fee_swap_path_list.push(direct_path);
+				}
+				fee_swap_path_list.sort_by_key(|trading_path| trading_path.first() != Some(&fee_currency_id));
+			}
+
 			for trading_path in fee_swap_path_list {
 				match trading_path.last() {
 					Some(target_currency_id) if *target_currency_id == native_currency_id => {
@@ -632,6 +695,16 @@ where
 			}
 		}
 	}
+
+	/// The cost to swap `amount` native currency through `trading_path`,
+	/// valued in native currency by the price source. `None` if the swap is
+	/// not possible or the price is not available.
+	fn fee_swap_cost(trading_path: &[CurrencyId], amount: Balance) -> Option<Balance> {
+		let supply_currency_id = *trading_path.first()?;
+		let supply_amount = T::DEX::get_swap_supply_amount(trading_path, amount)?;
+		T::PriceSource::get_relative_price(supply_currency_id, T::NativeCurrencyId::get())
+			.map(|price| price.saturating_mul_int(supply_amount))
+	}
 }
 
 impl<T> Convert<Weight, PalletBalanceOf<T>> for Pallet<T>
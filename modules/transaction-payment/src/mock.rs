@@ -126,6 +126,7 @@ impl pallet_balances::Config for Runtime {
 pub type AdaptedBasicCurrency = module_currencies::BasicCurrencyAdapter<Runtime, PalletBalances, Amount, BlockNumber>;
 
 parameter_types! {
+	pub const TreasuryAccount: AccountId = AccountId::new([255u8; 32]);
 	pub const GetNativeCurrencyId: CurrencyId = ACA;
 }
 
@@ -137,6 +138,10 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
+	type SweepOrigin = frame_system::EnsureRoot<AccountId>;
+	type TreasuryAccount = TreasuryAccount;
+	type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+	type PreTransferFilter = ();
 }
 
 thread_local! {
@@ -151,6 +156,7 @@ parameter_types! {
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxBatchSwaps: u32 = 3;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, ACA).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -162,9 +168,14 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPathLimit = TradingPathLimit;
+	type MaxBatchSwaps = MaxBatchSwaps;
+	type MinimumLiquidity = ();
 	type PalletId = DEXPalletId;
+	type MaxTwapWindow = ();
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
+	type PriceSource = ();
 	type WeightInfo = ();
 	type ListingOrigin = frame_system::EnsureSignedBy<Zero, AccountId>;
 }
@@ -194,6 +205,7 @@ impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for DealWithFees
 
 thread_local! {
 	static RELATIVE_PRICE: RefCell<Option<Price>> = RefCell::new(Some(Price::one()));
+	static UNPRICED_CURRENCY: RefCell<Option<CurrencyId>> = RefCell::new(None);
 }
 
 pub struct MockPriceSource;
@@ -201,9 +213,17 @@ impl MockPriceSource {
 	pub fn set_relative_price(price: Option<Price>) {
 		RELATIVE_PRICE.with(|v| *v.borrow_mut() = price);
 	}
+
+	pub fn set_unpriced_currency(currency_id: Option<CurrencyId>) {
+		UNPRICED_CURRENCY.with(|v| *v.borrow_mut() = currency_id);
+	}
 }
 impl PriceProvider<CurrencyId> for MockPriceSource {
-	fn get_relative_price(_base: CurrencyId, _quote: CurrencyId) -> Option<Price> {
+	fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<Price> {
+		let unpriced_currency = UNPRICED_CURRENCY.with(|v| *v.borrow());
+		if unpriced_currency == Some(base) || unpriced_currency == Some(quote) {
+			return None;
+		}
 		RELATIVE_PRICE.with(|v| *v.borrow_mut())
 	}
 
@@ -321,6 +321,22 @@ fn set_alternative_fee_swap_path_work() {
 	});
 }
 
+#[test]
+fn set_fee_currency_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(TransactionPayment::account_fee_currency(&ALICE), None);
+		assert_ok!(TransactionPayment::set_fee_currency(Origin::signed(ALICE), Some(AUSD)));
+		assert_eq!(TransactionPayment::account_fee_currency(&ALICE), Some(AUSD));
+		assert_ok!(TransactionPayment::set_fee_currency(Origin::signed(ALICE), None));
+		assert_eq!(TransactionPayment::account_fee_currency(&ALICE), None);
+
+		assert_noop!(
+			TransactionPayment::set_fee_currency(Origin::signed(ALICE), Some(ACA)),
+			Error::<Runtime>::InvalidFeeCurrency
+		);
+	});
+}
+
 #[test]
 fn charge_fee_by_default_swap_path() {
 	ExtBuilder::default()
@@ -378,6 +394,235 @@ fn charge_fee_by_default_swap_path() {
 		});
 }
 
+#[test]
+fn charge_fee_by_cheapest_default_swap_path() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			// add liquidity to DEX
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(AUSD, &ALICE, &BOB, 1000));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 100));
+
+			// the second default path costs less than the first one
+			assert_eq!(DEXModule::get_swap_supply_amount(&[AUSD, ACA], 2010), Some(252));
+			assert_eq!(DEXModule::get_swap_supply_amount(&[DOT, AUSD, ACA], 2010), Some(34));
+
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(AUSD, &BOB), 1000);
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 100 - 34);
+			assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (10000 - 2000 - 10, 1252));
+			assert_eq!(DEXModule::get_liquidity_pool(DOT, AUSD), (100 + 34, 1000 - 252));
+		});
+}
+
+#[test]
+fn charge_fee_by_default_swap_path_without_price_last() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			// add liquidity to DEX
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(AUSD, &ALICE, &BOB, 1000));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 100));
+
+			// the second default path supplies less, but its cost is unknown without the price of DOT
+			assert_eq!(DEXModule::get_swap_supply_amount(&[AUSD, ACA], 2010), Some(252));
+			assert_eq!(DEXModule::get_swap_supply_amount(&[DOT, AUSD, ACA], 2010), Some(34));
+			MockPriceSource::set_unpriced_currency(Some(DOT));
+
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(AUSD, &BOB), 1000 - 252);
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 100);
+			assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (10000 - 2000 - 10, 1252));
+			assert_eq!(DEXModule::get_liquidity_pool(DOT, AUSD), (100, 1000));
+		});
+}
+
+#[test]
+fn charge_fee_by_default_swap_path_in_configured_order_without_price() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			// add liquidity to DEX
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(AUSD, &ALICE, &BOB, 1000));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 100));
+
+			// no path can be valued, the first default path is tried first
+			MockPriceSource::set_relative_price(None);
+
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(AUSD, &BOB), 1000 - 252);
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 100);
+		});
+}
+
+#[test]
+fn charge_fee_by_preferred_fee_currency() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			// add liquidity to DEX
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(AUSD, &ALICE, &BOB, 1000));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 100));
+			assert_ok!(TransactionPayment::set_fee_currency(Origin::signed(BOB), Some(AUSD)));
+
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+
+			// pay by AUSD even if the path supplied by DOT is cheaper
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(AUSD, &BOB), 1000 - 252);
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 100);
+			assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (10000 - 2000 - 10, 1252));
+			assert_eq!(DEXModule::get_liquidity_pool(DOT, AUSD), (100, 1000));
+		});
+}
+
+#[test]
+fn charge_fee_fallback_to_default_swap_path_when_preferred_fee_currency_unavailable() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			// add liquidity to DEX
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(AUSD, &ALICE, &BOB, 1000));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 100));
+			// there's no viable path to swap DOT to ACA
+			assert_ok!(TransactionPayment::set_fee_currency(Origin::signed(BOB), Some(DOT)));
+
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(AUSD, &BOB), 1000 - 252);
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 100);
+			assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (10000 - 2000 - 10, 1252));
+		});
+}
+
 #[test]
 fn query_info_works() {
 	ExtBuilder::default()
@@ -410,6 +655,40 @@ fn query_info_works() {
 		});
 }
 
+#[test]
+fn estimate_call_cost_matches_charged_fee() {
+	ExtBuilder::default()
+		.base_weight(5)
+		.weight_fee(2)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::update_balance(Origin::root(), ALICE, ACA, 1_000_000_000,));
+			// all fees should be x1.5
+			NextFeeMultiplier::<Runtime>::put(Multiplier::saturating_from_rational(3, 2));
+
+			let info = CALL2.get_dispatch_info();
+			let (weight, estimated_fee) = TransactionPayment::estimate_call_cost(CALL2.clone(), 23);
+			assert_eq!(weight, info.weight);
+			assert_eq!(estimated_fee, TransactionPayment::compute_fee(23, &info, 0));
+
+			let balance_before = Currencies::free_balance(ACA, &ALICE);
+			let pre = ChargeTransactionPayment::<Runtime>::from(0)
+				.pre_dispatch(&ALICE, CALL2, &info, 23)
+				.unwrap();
+			assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+				pre,
+				&info,
+				&PostDispatchInfo {
+					actual_weight: None,
+					pays_fee: Pays::Yes,
+				},
+				23,
+				&Ok(())
+			));
+			assert_eq!(balance_before - Currencies::free_balance(ACA, &ALICE), estimated_fee);
+		});
+}
+
 #[test]
 fn compute_fee_works_without_multiplier() {
 	ExtBuilder::default()
@@ -49,6 +49,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for module_transaction_payment.
 pub trait WeightInfo {
 	fn set_alternative_fee_swap_path() -> Weight;
+	fn set_fee_currency() -> Weight;
 	fn on_finalize() -> Weight;
 }
 
@@ -59,6 +60,10 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(3_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_fee_currency() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn on_finalize() -> Weight {
 		(8_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
@@ -72,6 +77,10 @@ impl WeightInfo for () {
 		(3_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_fee_currency() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 	fn on_finalize() -> Weight {
 		(8_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
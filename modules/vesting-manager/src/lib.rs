@@ -0,0 +1,214 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Vesting Manager Module
+//!
+//! ## Overview
+//!
+//! Vesting Manager module lets users with many `orml_vesting` schedules merge
+//! them into fewer schedules and claim in one call, reducing storage and the
+//! cost of later claims.
+//!
+//! Schedules that have started vesting and share the same `period` and end
+//! block are merged into one, fully vested schedules are dropped and
+//! schedules that haven't started are kept as they are. The locked amount is
+//! unchanged at the time of merging and at every block after it.
+//!
+//! It also lets `VestedTransferOrigin` make vested transfers with a cliff,
+//! nothing vests until the cliff block and then it vests linearly.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::Currency, transactional};
+use frame_system::{ensure_signed, pallet_prelude::*, RawOrigin};
+use orml_vesting::{VestingSchedule, VestingScheduleOf};
+use sp_runtime::{
+	offchain::storage_lock::BlockNumberProvider,
+	traits::{CheckedAdd, Saturating, StaticLookup, UniqueSaturatedInto, Zero},
+	ArithmeticError,
+};
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> =
+	<<T as orml_vesting::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + orml_vesting::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The vesting period count is zero
+		ZeroVestingPeriodCount,
+		/// The vested amount is lower than `MinVestedTransfer` or can't vest
+		/// in every period
+		AmountLow,
+		/// The vested amount can't be split evenly over the periods
+		AmountNotDivisible,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config> {
+		/// Merged vesting schedules. \[who, schedules_before, schedules_after\]
+		VestingSchedulesMerged(T::AccountId, u32, u32),
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Merge the caller's compatible vesting schedules into one and claim
+		/// the vested balance.
+		#[pallet::weight(<T as Config>::WeightInfo::merge_and_claim(<T as orml_vesting::Config>::MaxVestingSchedules::get()))]
+		#[transactional]
+		pub fn merge_and_claim(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let schedules: Vec<VestingScheduleOf<T>> =
+				orml_vesting::Pallet::<T>::vesting_schedules(&who).into_iter().collect();
+			let now = <T as orml_vesting::Config>::BlockNumberProvider::current_block_number();
+			let merged = Self::merge_schedules(&schedules, now)?;
+
+			if merged != schedules {
+				orml_vesting::Pallet::<T>::update_vesting_schedules(
+					RawOrigin::Root.into(),
+					T::Lookup::unlookup(who.clone()),
+					merged.clone(),
+				)?;
+			}
+			orml_vesting::Pallet::<T>::claim(RawOrigin::Signed(who.clone()).into())?;
+
+			Self::deposit_event(Event::VestingSchedulesMerged(
+				who,
+				schedules.len() as u32,
+				merged.len() as u32,
+			));
+			Ok(())
+		}
+
+		/// Vested transfer `amount` to `dest` with a cliff. Nothing vests
+		/// until `cliff`, then `amount` vests linearly over `period_count`
+		/// periods of `period` blocks. `amount` must be a multiple of
+		/// `period_count`, so it vests in one schedule.
+		///
+		/// The dispatch origin must be `VestedTransferOrigin` of `orml_vesting`.
+		#[pallet::weight(<T as Config>::WeightInfo::vested_transfer_with_cliff())]
+		#[transactional]
+		pub fn vested_transfer_with_cliff(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			cliff: T::BlockNumber,
+			period: T::BlockNumber,
+			period_count: u32,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(!period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+			ensure!(
+				amount >= <T as orml_vesting::Config>::MinVestedTransfer::get(),
+				Error::<T>::AmountLow
+			);
+
+			let per_period = amount / period_count.into();
+			ensure!(!per_period.is_zero(), Error::<T>::AmountLow);
+			ensure!((amount % period_count.into()).is_zero(), Error::<T>::AmountNotDivisible);
+
+			orml_vesting::Pallet::<T>::vested_transfer(
+				origin,
+				dest,
+				VestingSchedule {
+					start: cliff,
+					period,
+					period_count,
+					per_period,
+				},
+			)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Merge `schedules` at `now`.
+	///
+	/// A started schedule is rebased to start at its current period, so its
+	/// total amount is the amount locked at `now`. Rebased schedules with the same
+	/// `start`, `period` and `period_count` share the same end block and are
+	/// merged by summing `per_period`.
+	fn merge_schedules(
+		schedules: &[VestingScheduleOf<T>],
+		now: T::BlockNumber,
+	) -> Result<Vec<VestingScheduleOf<T>>, DispatchError> {
+		let mut merged: Vec<VestingScheduleOf<T>> = Vec::new();
+
+		for schedule in schedules {
+			// keep schedules that haven't started, merging them would vest them early
+			if schedule.period.is_zero() || schedule.start > now {
+				merged.push(schedule.clone());
+				continue;
+			}
+
+			// drop fully vested schedules
+			if schedule.locked_amount(now).is_zero() {
+				continue;
+			}
+
+			let vested_count: u32 = ((now - schedule.start) / schedule.period)
+				.min(schedule.period_count.into())
+				.unique_saturated_into();
+			let mut rebased = schedule.clone();
+			rebased.start = schedule
+				.start
+				.saturating_add(schedule.period.saturating_mul(vested_count.into()));
+			rebased.period_count = schedule.period_count - vested_count;
+
+			if let Some(existing) = merged.iter_mut().find(|s| {
+				s.start == rebased.start && s.period == rebased.period && s.period_count == rebased.period_count
+			}) {
+				existing.per_period = existing
+					.per_period
+					.checked_add(&rebased.per_period)
+					.ok_or(ArithmeticError::Overflow)?;
+			} else {
+				merged.push(rebased);
+			}
+		}
+
+		Ok(merged)
+	}
+}
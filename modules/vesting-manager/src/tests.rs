@@ -0,0 +1,167 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the vesting manager module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{
+	Balance, Balances, BlockNumber, Event, ExtBuilder, Origin, Runtime, System, Vesting, VestingManager, ALICE, BOB,
+};
+use orml_vesting::VestingSchedule;
+use sp_runtime::traits::BadOrigin;
+
+fn schedule(
+	start: BlockNumber,
+	period: BlockNumber,
+	period_count: u32,
+	per_period: Balance,
+) -> VestingScheduleOf<Runtime> {
+	VestingSchedule {
+		start,
+		period,
+		period_count,
+		per_period,
+	}
+}
+
+fn locked_at(schedules: &[VestingScheduleOf<Runtime>], time: BlockNumber) -> Balance {
+	schedules.iter().map(|s| s.locked_amount(time)).sum()
+}
+
+fn vested_transfer_all(schedules: &[VestingScheduleOf<Runtime>]) {
+	for s in schedules {
+		assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, s.clone()));
+	}
+}
+
+#[test]
+fn merge_and_claim_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let schedules = vec![
+			schedule(0, 10, 4, 10),
+			schedule(10, 10, 3, 20),
+			schedule(5, 10, 2, 5),
+			schedule(100, 10, 1, 7),
+		];
+		vested_transfer_all(&schedules);
+		assert_eq!(Balances::free_balance(BOB), 117);
+
+		System::set_block_number(15);
+		assert_eq!(locked_at(&schedules, 15), 102);
+
+		assert_ok!(VestingManager::merge_and_claim(Origin::signed(BOB)));
+		System::assert_last_event(Event::VestingManager(crate::Event::VestingSchedulesMerged(BOB, 4, 3)));
+
+		// schedules ending at the same block with the same period are merged
+		let merged: Vec<VestingScheduleOf<Runtime>> = Vesting::vesting_schedules(&BOB).into_iter().collect();
+		assert_eq!(
+			merged,
+			vec![schedule(10, 10, 3, 30), schedule(15, 10, 1, 5), schedule(100, 10, 1, 7)]
+		);
+		assert_eq!(Balances::free_balance(BOB), 117);
+		assert_eq!(Balances::usable_balance(BOB), 15);
+
+		// the locked amount is unchanged from now on
+		for time in 15..120 {
+			assert_eq!(locked_at(&merged, time), locked_at(&schedules, time));
+		}
+	});
+}
+
+#[test]
+fn merge_and_claim_keeps_incompatible_periods() {
+	ExtBuilder::default().build().execute_with(|| {
+		let schedules = vec![schedule(0, 5, 2, 10), schedule(0, 10, 4, 10), schedule(0, 20, 2, 20)];
+		vested_transfer_all(&schedules);
+
+		System::set_block_number(20);
+		assert_ok!(VestingManager::merge_and_claim(Origin::signed(BOB)));
+
+		// fully vested schedules are dropped, different periods are not merged
+		let merged: Vec<VestingScheduleOf<Runtime>> = Vesting::vesting_schedules(&BOB).into_iter().collect();
+		assert_eq!(merged, vec![schedule(20, 10, 2, 10), schedule(20, 20, 1, 20)]);
+		assert_eq!(Balances::usable_balance(BOB), 60);
+
+		for time in 20..60 {
+			assert_eq!(locked_at(&merged, time), locked_at(&schedules, time));
+		}
+	});
+}
+
+#[test]
+fn merge_and_claim_removes_fully_vested_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		let schedules = vec![schedule(0, 10, 4, 10), schedule(5, 10, 2, 5)];
+		vested_transfer_all(&schedules);
+
+		System::set_block_number(100);
+		assert_ok!(VestingManager::merge_and_claim(Origin::signed(BOB)));
+		System::assert_last_event(Event::VestingManager(crate::Event::VestingSchedulesMerged(BOB, 2, 0)));
+
+		assert!(Vesting::vesting_schedules(&BOB).is_empty());
+		assert_eq!(Balances::usable_balance(BOB), 50);
+	});
+}
+
+#[test]
+fn vested_transfer_with_cliff_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(VestingManager::vested_transfer_with_cliff(
+			Origin::signed(ALICE),
+			BOB,
+			10,
+			5,
+			4,
+			100
+		));
+		assert_eq!(Vesting::vesting_schedules(&BOB).to_vec(), vec![schedule(10, 5, 4, 25)]);
+		assert_eq!(Balances::free_balance(BOB), 100);
+
+		// nothing vests until the cliff
+		for &(time, usable) in [(9, 0), (10, 0), (14, 0), (15, 25), (20, 50), (25, 75), (30, 100)].iter() {
+			System::set_block_number(time);
+			assert_ok!(Vesting::claim(Origin::signed(BOB)));
+			assert_eq!(Balances::usable_balance(BOB), usable);
+		}
+	});
+}
+
+#[test]
+fn vested_transfer_with_cliff_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			VestingManager::vested_transfer_with_cliff(Origin::signed(BOB), ALICE, 10, 5, 4, 100),
+			BadOrigin
+		);
+		assert_noop!(
+			VestingManager::vested_transfer_with_cliff(Origin::signed(ALICE), BOB, 10, 5, 0, 100),
+			Error::<Runtime>::ZeroVestingPeriodCount
+		);
+		assert_noop!(
+			VestingManager::vested_transfer_with_cliff(Origin::signed(ALICE), BOB, 10, 5, 4, 3),
+			Error::<Runtime>::AmountLow
+		);
+		assert_noop!(
+			VestingManager::vested_transfer_with_cliff(Origin::signed(ALICE), BOB, 10, 5, 4, 103),
+			Error::<Runtime>::AmountNotDivisible
+		);
+	});
+}
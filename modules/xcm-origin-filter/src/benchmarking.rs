@@ -0,0 +1,44 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarks for the xcm origin filter module.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
+use xcm::v0::Junction::{Parachain, Parent};
+
+pub use crate::*;
+
+benchmarks! {
+	block_xcm_origin {
+		let location = MultiLocation::X2(Parent, Parachain(2000));
+	}: _(RawOrigin::Root, location.clone())
+	verify {
+		assert!(Pallet::<T>::is_blocked(&location));
+	}
+
+	unblock_xcm_origin {
+		let location = MultiLocation::X2(Parent, Parachain(2000));
+		Pallet::<T>::block_xcm_origin(RawOrigin::Root.into(), location.clone())?;
+	}: _(RawOrigin::Root, location.clone())
+	verify {
+		assert!(!Pallet::<T>::is_blocked(&location));
+	}
+}
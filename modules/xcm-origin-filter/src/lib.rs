@@ -0,0 +1,164 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # XCM Origin Filter Module
+//!
+//! ## Overview
+//!
+//! During an exploit on a sibling chain, the XCM messages from it need to be
+//! stopped quickly without a runtime upgrade. This module keeps the XCM
+//! origins blocked by governance, and provides `DenyBlockedXcmOrigins` to wrap
+//! the XCM barrier, which rejects the messages from the blocked origins, and
+//! from the locations interior to them, before execution.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, weights::Weight};
+use frame_system::pallet_prelude::*;
+use sp_std::{marker::PhantomData, prelude::*};
+use xcm::v0::{MultiLocation, Xcm};
+use xcm_executor::traits::ShouldExecute;
+
+pub mod benchmarking;
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which may block and unblock XCM origins.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The XCM origin is already blocked.
+		AlreadyBlocked,
+		/// The XCM origin is not blocked.
+		NotBlocked,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The XCM origin is blocked. \[location\]
+		XcmOriginBlocked(MultiLocation),
+		/// The XCM origin is unblocked. \[location\]
+		XcmOriginUnblocked(MultiLocation),
+	}
+
+	/// The XCM origins whose messages, and the messages from the locations
+	/// interior to them, are rejected.
+	///
+	/// BlockedXcmOrigins: Vec<MultiLocation>
+	#[pallet::storage]
+	#[pallet::getter(fn blocked_xcm_origins)]
+	pub type BlockedXcmOrigins<T: Config> = StorageValue<_, Vec<MultiLocation>, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Block the XCM messages from `location` and the locations interior to
+		/// it.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `location`: the XCM origin to block.
+		#[pallet::weight(T::WeightInfo::block_xcm_origin())]
+		pub fn block_xcm_origin(origin: OriginFor<T>, location: MultiLocation) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			BlockedXcmOrigins::<T>::try_mutate(|blocked_origins| -> DispatchResult {
+				ensure!(!blocked_origins.contains(&location), Error::<T>::AlreadyBlocked);
+				blocked_origins.push(location.clone());
+				Ok(())
+			})?;
+			Self::deposit_event(Event::XcmOriginBlocked(location));
+			Ok(())
+		}
+
+		/// Unblock the XCM messages from `location`.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `location`: the XCM origin to unblock.
+		#[pallet::weight(T::WeightInfo::unblock_xcm_origin())]
+		pub fn unblock_xcm_origin(origin: OriginFor<T>, location: MultiLocation) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			BlockedXcmOrigins::<T>::try_mutate(|blocked_origins| -> DispatchResult {
+				let index = blocked_origins
+					.iter()
+					.position(|blocked_origin| *blocked_origin == location)
+					.ok_or(Error::<T>::NotBlocked)?;
+				blocked_origins.remove(index);
+				Ok(())
+			})?;
+			Self::deposit_event(Event::XcmOriginUnblocked(location));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether `location` is a blocked origin or interior to one.
+	pub fn is_blocked(location: &MultiLocation) -> bool {
+		Self::blocked_xcm_origins()
+			.iter()
+			.any(|blocked_origin| Self::is_prefix_of(blocked_origin, location))
+	}
+
+	/// Whether the junctions of `prefix` are the leading junctions of `location`.
+	fn is_prefix_of(prefix: &MultiLocation, location: &MultiLocation) -> bool {
+		prefix.len() <= location.len() && prefix.iter().zip(location.iter()).all(|(a, b)| a == b)
+	}
+}
+
+/// XCM barrier which rejects the messages from the blocked origins, and passes
+/// the others to `Allow`.
+pub struct DenyBlockedXcmOrigins<T, Allow>(PhantomData<(T, Allow)>);
+impl<T: Config, Allow: ShouldExecute> ShouldExecute for DenyBlockedXcmOrigins<T, Allow> {
+	fn should_execute<Call>(
+		origin: &MultiLocation,
+		top_level: bool,
+		message: &Xcm<Call>,
+		shallow_weight: Weight,
+		weight_credit: &mut Weight,
+	) -> Result<(), ()> {
+		if Pallet::<T>::is_blocked(origin) {
+			return Err(());
+		}
+		Allow::should_execute(origin, top_level, message, shallow_weight, weight_credit)
+	}
+}
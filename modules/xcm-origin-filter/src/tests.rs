@@ -0,0 +1,177 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the xcm origin filter module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+use xcm::v0::{
+	Junction::{AccountId32, GeneralIndex, Parachain, Parent},
+	NetworkId,
+};
+
+type Barrier = DenyBlockedXcmOrigins<Runtime, AllowAll>;
+
+fn sibling(para_id: u32) -> MultiLocation {
+	MultiLocation::X2(Parent, Parachain(para_id))
+}
+
+#[test]
+fn block_xcm_origin_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmOriginFilterModule::block_xcm_origin(Origin::signed(ALICE), sibling(2000)),
+			BadOrigin
+		);
+
+		assert_ok!(XcmOriginFilterModule::block_xcm_origin(
+			Origin::signed(BOB),
+			sibling(2000)
+		));
+		System::assert_last_event(Event::XcmOriginFilterModule(crate::Event::XcmOriginBlocked(sibling(
+			2000,
+		))));
+		assert_eq!(XcmOriginFilterModule::blocked_xcm_origins(), vec![sibling(2000)]);
+		assert!(XcmOriginFilterModule::is_blocked(&sibling(2000)));
+		assert!(!XcmOriginFilterModule::is_blocked(&sibling(2001)));
+
+		assert_noop!(
+			XcmOriginFilterModule::block_xcm_origin(Origin::signed(BOB), sibling(2000)),
+			Error::<Runtime>::AlreadyBlocked
+		);
+	});
+}
+
+#[test]
+fn unblock_xcm_origin_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmOriginFilterModule::block_xcm_origin(
+			Origin::signed(BOB),
+			sibling(2000)
+		));
+		assert_ok!(XcmOriginFilterModule::block_xcm_origin(
+			Origin::signed(BOB),
+			sibling(2001)
+		));
+
+		assert_noop!(
+			XcmOriginFilterModule::unblock_xcm_origin(Origin::signed(ALICE), sibling(2000)),
+			BadOrigin
+		);
+		assert_noop!(
+			XcmOriginFilterModule::unblock_xcm_origin(Origin::signed(BOB), sibling(2002)),
+			Error::<Runtime>::NotBlocked
+		);
+
+		assert_ok!(XcmOriginFilterModule::unblock_xcm_origin(
+			Origin::signed(BOB),
+			sibling(2000)
+		));
+		System::assert_last_event(Event::XcmOriginFilterModule(crate::Event::XcmOriginUnblocked(sibling(
+			2000,
+		))));
+		assert_eq!(XcmOriginFilterModule::blocked_xcm_origins(), vec![sibling(2001)]);
+	});
+}
+
+#[test]
+fn deny_blocked_xcm_origins_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message = Xcm::<()>::WithdrawAsset {
+			assets: vec![],
+			effects: vec![],
+		};
+
+		assert_eq!(
+			Barrier::should_execute(&sibling(2000), true, &message, 100, &mut 0),
+			Ok(())
+		);
+
+		assert_ok!(XcmOriginFilterModule::block_xcm_origin(
+			Origin::signed(BOB),
+			sibling(2000)
+		));
+		assert_eq!(
+			Barrier::should_execute(&sibling(2000), true, &message, 100, &mut 0),
+			Err(())
+		);
+		assert_eq!(
+			Barrier::should_execute(&sibling(2001), true, &message, 100, &mut 0),
+			Ok(())
+		);
+
+		assert_ok!(XcmOriginFilterModule::unblock_xcm_origin(
+			Origin::signed(BOB),
+			sibling(2000)
+		));
+		assert_eq!(
+			Barrier::should_execute(&sibling(2000), true, &message, 100, &mut 0),
+			Ok(())
+		);
+	});
+}
+
+#[test]
+fn deny_interior_locations_of_blocked_xcm_origins() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message = Xcm::<()>::WithdrawAsset {
+			assets: vec![],
+			effects: vec![],
+		};
+		let sibling_account = MultiLocation::X3(
+			Parent,
+			Parachain(2000),
+			AccountId32 {
+				network: NetworkId::Any,
+				id: [0; 32],
+			},
+		);
+		let sibling_pallet = MultiLocation::X3(Parent, Parachain(2000), GeneralIndex { id: 1 });
+
+		assert_ok!(XcmOriginFilterModule::block_xcm_origin(
+			Origin::signed(BOB),
+			sibling(2000)
+		));
+		assert!(XcmOriginFilterModule::is_blocked(&sibling_account));
+		assert!(XcmOriginFilterModule::is_blocked(&sibling_pallet));
+		assert_eq!(
+			Barrier::should_execute(&sibling_account, true, &message, 100, &mut 0),
+			Err(())
+		);
+		assert_eq!(
+			Barrier::should_execute(&sibling_pallet, true, &message, 100, &mut 0),
+			Err(())
+		);
+
+		// the locations exterior to or beside the blocked origin pass
+		assert!(!XcmOriginFilterModule::is_blocked(&MultiLocation::X1(Parent)));
+		assert!(!XcmOriginFilterModule::is_blocked(&MultiLocation::X3(
+			Parent,
+			Parachain(2001),
+			GeneralIndex { id: 1 }
+		)));
+		assert_eq!(
+			Barrier::should_execute(&MultiLocation::X1(Parent), true, &message, 100, &mut 0),
+			Ok(())
+		);
+	});
+}
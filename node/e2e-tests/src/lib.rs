@@ -21,7 +21,9 @@
 #![allow(clippy::type_complexity)]
 
 use node_primitives::Block;
-use node_runtime::{api, native_version, Runtime, RuntimeApi, SignedExtra};
+use node_runtime::{
+	api, native_version, CheckXcmTransferAmount, Runtime, RuntimeApi, SignedExtra, XcmTransferAboveMinimum,
+};
 use node_service::chain_spec::mandala::dev_testnet_config;
 use node_service::default_mock_parachain_inherent_data_provider;
 use sc_consensus_manual_seal::ConsensusDataProvider;
@@ -69,6 +71,7 @@ impl ChainInfo for NodeTemplateChainInfo {
 			frame_system::CheckWeight::<Self::Runtime>::new(),
 			module_transaction_payment::ChargeTransactionPayment::<Self::Runtime>::from(0),
 			module_evm::SetEvmOrigin::<Self::Runtime>::new(),
+			CheckXcmTransferAmount::<Self::Runtime, XcmTransferAboveMinimum>::new(),
 		)
 	}
 
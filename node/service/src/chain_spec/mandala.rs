@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use acala_primitives::{AccountId, TokenSymbol};
+use acala_primitives::{AccountId, BlockNumber, TokenSymbol};
 use hex_literal::hex;
 use sc_chain_spec::ChainType;
 use sc_telemetry::TelemetryEndpoints;
@@ -404,6 +404,8 @@ fn testnet_genesis(
 			ren_vm_public_key: hex!["4b939fc8ade87cb50b78987b1dda927460dc456a"],
 		},
 		orml_nft: OrmlNFTConfig { tokens: vec![] },
+		nft: Default::default(),
+		incentives: Default::default(),
 		collator_selection: CollatorSelectionConfig {
 			invulnerables: initial_authorities.iter().cloned().map(|(acc, _, _, _)| acc).collect(),
 			candidacy_bond: initial_staking,
@@ -613,8 +615,8 @@ fn mandala_genesis(
 				let nft_airdrop: Vec<(
 					AccountId,
 					Vec<u8>,
-					module_nft::ClassData<Balance>,
-					Vec<(Vec<u8>, module_nft::TokenData<Balance>, Vec<AccountId>)>,
+					module_nft::ClassData<Balance, AccountId>,
+					Vec<(Vec<u8>, module_nft::TokenData<Balance, BlockNumber>, Vec<AccountId>)>,
 				)> = serde_json::from_slice(nft_airdrop_json).unwrap();
 
 				let mut tokens = vec![];
@@ -637,6 +639,8 @@ fn mandala_genesis(
 				tokens
 			},
 		},
+		nft: Default::default(),
+		incentives: Default::default(),
 		collator_selection: CollatorSelectionConfig {
 			invulnerables: initial_authorities.iter().cloned().map(|(acc, _, _, _)| acc).collect(),
 			candidacy_bond: initial_staking,
@@ -67,7 +67,7 @@ fn check_nfts() {
 	let _: Vec<(
 		AccountId,
 		Vec<u8>,
-		module_nft::ClassData<Balance>,
-		Vec<(Vec<u8>, module_nft::TokenData<Balance>, Vec<AccountId>)>,
+		module_nft::ClassData<Balance, AccountId>,
+		Vec<(Vec<u8>, module_nft::TokenData<Balance, BlockNumber>, Vec<AccountId>)>,
 	)> = serde_json::from_slice(nfts_json).unwrap();
 }
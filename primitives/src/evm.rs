@@ -21,7 +21,7 @@ use codec::{Decode, Encode};
 use evm::ExitReason;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256};
 use sp_runtime::RuntimeDebug;
 use sp_std::vec::Vec;
 
@@ -39,6 +39,19 @@ pub struct Vicinity {
 	pub gas_price: U256,
 	/// Origin of the transaction.
 	pub origin: EvmAddress,
+	/// Storage slots pre-warmed by the transaction access list.
+	pub access_list: Vec<AccessListItem>,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+/// EIP-2930 access list entry.
+pub struct AccessListItem {
+	/// Address of the accessed contract.
+	pub address: EvmAddress,
+	/// Storage keys of `address` accessed by the transaction.
+	pub storage_keys: Vec<H256>,
 }
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
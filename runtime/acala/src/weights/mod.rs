@@ -28,6 +28,7 @@ pub mod module_dex;
 pub mod module_emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
+pub mod module_evm_manager;
 pub mod module_homa;
 pub mod module_honzon;
 pub mod module_incentives;
@@ -36,6 +37,7 @@ pub mod module_nominees_election;
 pub mod module_prices;
 pub mod module_session_manager;
 pub mod module_transaction_payment;
+pub mod module_vesting_manager;
 
 pub mod orml_auction;
 pub mod orml_authority;
@@ -59,19 +59,37 @@ impl<T: frame_system::Config> module_cdp_engine::WeightInfo for WeightInfo<T> {
 	fn set_global_params() -> Weight {
 		(12_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_global_debit_ceiling() -> Weight {
+		(12_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_liquidation_swap_path() -> Weight {
+		(12_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn liquidate_by_auction() -> Weight {
 		(210_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(28 as Weight))
+			.saturating_add(T::DbWeight::get().reads(29 as Weight))
 			.saturating_add(T::DbWeight::get().writes(17 as Weight))
 	}
 	fn liquidate_by_dex() -> Weight {
 		(255_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(29 as Weight))
+			.saturating_add(T::DbWeight::get().reads(30 as Weight))
 			.saturating_add(T::DbWeight::get().writes(15 as Weight))
 	}
+	fn liquidate_by_keeper() -> Weight {
+		(255_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(31 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
 	fn settle() -> Weight {
 		(99_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(11 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	fn settle_cdps_batch(c: u32) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((99_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((11 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((8 as Weight).saturating_mul(c as Weight)))
+	}
 }
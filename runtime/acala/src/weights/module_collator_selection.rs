@@ -65,8 +65,8 @@ impl<T: frame_system::Config> module_collator_selection::WeightInfo for WeightIn
 		(84_790_000 as Weight)
 			// Standard Error: 0
 			.saturating_add((306_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(T::DbWeight::get().reads(7 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
 	fn register_candidate(c: u32, ) -> Weight {
 		(46_224_000 as Weight)
@@ -80,17 +80,17 @@ impl<T: frame_system::Config> module_collator_selection::WeightInfo for WeightIn
 			// Standard Error: 0
 			.saturating_add((292_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	fn withdraw_bond() -> Weight {
 		(60_398_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	fn note_author() -> Weight {
 		(63_657_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	fn new_session() -> Weight {
 		(67_147_000 as Weight)
@@ -101,7 +101,7 @@ impl<T: frame_system::Config> module_collator_selection::WeightInfo for WeightIn
 		(12_231_000 as Weight)
 			// Standard Error: 2_000
 			.saturating_add((3_117_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
@@ -109,9 +109,9 @@ impl<T: frame_system::Config> module_collator_selection::WeightInfo for WeightIn
 		(9_193_180_000 as Weight)
 			// Standard Error: 167_000
 			.saturating_add((12_357_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
-			.saturating_add(T::DbWeight::get().writes(199 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(200 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
 	}
 }
@@ -53,6 +53,14 @@ impl<T: frame_system::Config> module_currencies::WeightInfo for WeightInfo<T> {
 	fn transfer_native_currency() -> Weight {
 		(19_885_000 as Weight)
 	}
+	fn transfer_all_native_currency() -> Weight {
+		(19_885_000 as Weight)
+	}
+	fn transfer_all_non_native_currency() -> Weight {
+		(123_019_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 	fn update_balance_non_native_currency() -> Weight {
 		(57_645_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
@@ -64,4 +72,21 @@ impl<T: frame_system::Config> module_currencies::WeightInfo for WeightInfo<T> {
 	fn update_balance_native_currency_killing() -> Weight {
 		(24_402_000 as Weight)
 	}
+	fn sweep_dust(c: u32, ) -> Weight {
+		(4_380_000 as Weight)
+			// Standard Error: 14_000
+			.saturating_add((66_274_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(c as Weight)))
+	}
+	fn freeze_account() -> Weight {
+		(57_645_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn thaw_account() -> Weight {
+		(57_645_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
@@ -50,13 +50,18 @@ impl<T: frame_system::Config> module_emergency_shutdown::WeightInfo for WeightIn
 			// Standard Error: 431_000
 			.saturating_add((14_764_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().reads(60 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
 	}
+	fn shutdown_collateral() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	fn open_collateral_refund() -> Weight {
 		(55_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(17 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn refund_collaterals(c: u32) -> Weight {
 		(109_789_000 as Weight)
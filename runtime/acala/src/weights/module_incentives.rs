@@ -49,8 +49,9 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 		(7_775_000 as Weight)
 			// Standard Error: 20_000
 			.saturating_add((5_061_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
 	}
 	fn deposit_dex_share() -> Weight {
 		(84_000_000 as Weight)
@@ -64,7 +65,17 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 	}
 	fn claim_rewards() -> Weight {
 		(27_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn compound_dex_rewards() -> Weight {
+		(410_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+	}
+	fn force_claim_rewards() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn update_incentive_rewards(c: u32) -> Weight {
@@ -85,7 +96,36 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add((1_832_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn update_reward_start_blocks(c: u32) -> Weight {
+		(875_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_832_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_reward_schedules(c: u32) -> Weight {
+		(875_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_832_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_pool_reward_currencies(c: u32) -> Weight {
+		(875_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_832_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_dex_incentive_boost_bounds() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn update_peg_deviation_boost() -> Weight {
+		(14_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn add_allowance() -> Weight {
 		(2_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn settle_shortfall() -> Weight {
+		(2_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
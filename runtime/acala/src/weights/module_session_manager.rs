@@ -52,6 +52,22 @@ impl<T: frame_system::Config> module_session_manager::WeightInfo for WeightInfo<
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn schedule_session_duration_ramp(n: u32, ) -> Weight {
+		(33_209_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((3_521_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn force_new_session(c: u32, ) -> Weight {
+		(4_120_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((2_318_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 	fn on_initialize_skip() -> Weight {
 		(5_330_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
@@ -48,6 +48,9 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 	fn set_alternative_fee_swap_path() -> Weight {
 		(3_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_fee_currency() -> Weight {
+		(3_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn on_finalize() -> Weight {
 		(8_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
@@ -61,6 +61,9 @@ pub use primitives::{
 	AccountId,
 };
 
+mod xcm_impl;
+pub use xcm_impl::*;
+
 pub type TimeStampedPrice = orml_oracle::TimestampedValue<Price, primitives::Moment>;
 
 // Priority of unsigned transactions
@@ -92,6 +95,10 @@ impl PrecompileCallerFilter for SystemContractsFilter {
 	}
 }
 
+/// EVM tooling expects fees in 18 decimals while native tokens use 12 decimals, scale by this
+/// factor when reporting native fees to it.
+pub const EVM_DECIMALS_SCALE: u64 = 1_000_000;
+
 /// Convert gas to weight
 pub struct GasToWeight;
 impl Convert<u64, Weight> for GasToWeight {
@@ -328,6 +335,8 @@ pub enum ProxyType {
 	Auction,
 	Swap,
 	Loan,
+	DemocracyVoteOnly,
+	HomaLite,
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -33,8 +33,19 @@ use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
 ///
 /// Actions:
 /// - Get liquidity. Rest `input` bytes: `currency_id_a`, `currency_id_b`.
+/// - Get liquidity token address. Rest `input` bytes: `currency_id_a`, `currency_id_b`.
+/// - Get swap target amount. Rest `input` bytes: `path`, `supply_amount`. Reverts if the
+///   path is invalid or the pools on it have insufficient liquidity.
+/// - Get swap supply amount. Rest `input` bytes: `path`, `target_amount`. Reverts if the
+///   path is invalid or the pools on it have insufficient liquidity.
 /// - Swap with exact supply. Rest `input` bytes: `who`, `currency_id_a`, `currency_id_b`,
 ///   `supply_amount`, `min_target_amount`.
+/// - Swap with exact target. Rest `input` bytes: `who`, `currency_id_a`, `currency_id_b`,
+///   `target_amount`, `max_supply_amount`.
+/// - Add liquidity. Rest `input` bytes: `who`, `currency_id_a`, `currency_id_b`,
+///   `max_amount_a`, `max_amount_b`, `min_share_increment`.
+/// - Remove liquidity. Rest `input` bytes: `who`, `currency_id_a`, `currency_id_b`,
+///   `remove_share`, `min_withdrawn_a`, `min_withdrawn_b`.
 pub struct DexPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Dex>(
 	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, Dex)>,
 );
@@ -167,6 +167,10 @@ impl module_currencies::Config for Test {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = EVMBridge;
+	type SweepOrigin = EnsureRoot<AccountId>;
+	type TreasuryAccount = TreasuryAccount;
+	type FreezeOrigin = EnsureRoot<AccountId>;
+	type PreTransferFilter = ();
 }
 
 impl module_evm_bridge::Config for Test {
@@ -174,8 +178,11 @@ impl module_evm_bridge::Config for Test {
 }
 
 impl module_evm_manager::Config for Test {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type UpdateOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -193,6 +200,7 @@ impl module_nft::Config for Test {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type BlockNumberProvider = System;
 	type WeightInfo = ();
 }
 
@@ -204,8 +212,8 @@ parameter_types! {
 impl orml_nft::Config for Test {
 	type ClassId = u32;
 	type TokenId = u64;
-	type ClassData = module_nft::ClassData<Balance>;
-	type TokenData = module_nft::TokenData<Balance>;
+	type ClassData = module_nft::ClassData<Balance, AccountId>;
+	type TokenData = module_nft::TokenData<Balance, BlockNumber>;
 	type MaxClassMetadata = MaxClassMetadata;
 	type MaxTokenMetadata = MaxTokenMetadata;
 }
@@ -323,6 +331,7 @@ ord_parameter_types! {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 100);
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxBatchSwaps: u32 = 3;
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 }
 
@@ -331,8 +340,13 @@ impl module_dex::Config for Test {
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPathLimit = TradingPathLimit;
+	type MaxBatchSwaps = MaxBatchSwaps;
+	type MinimumLiquidity = ();
 	type PalletId = DEXPalletId;
+	type MaxTwapWindow = ();
 	type CurrencyIdMapping = EvmCurrencyIdMapping;
+	type PriceSource = ();
 	type WeightInfo = ();
 	type DEXIncentives = MockDEXIncentives;
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
@@ -352,6 +366,8 @@ pub type OraclePrecompile = crate::OraclePrecompile<
 	MockAddressMapping,
 	EvmCurrencyIdMapping,
 	module_prices::PriorityLockedPriceProvider<Test>,
+	Oracle,
+	Timestamp,
 >;
 pub type ScheduleCallPrecompile = crate::ScheduleCallPrecompile<
 	AccountId,
@@ -427,6 +443,8 @@ impl ExchangeRateProvider for MockLiquidStakingExchangeProvider {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub MaxPriceMovePerBlock: Ratio = Ratio::saturating_from_rational(30, 100);
+	pub const DexPriceTwapWindow: u32 = 10;
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 }
@@ -447,6 +465,8 @@ impl module_prices::Config for Test {
 	type DEX = DexModule;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping;
+	type MaxPriceMovePerBlock = MaxPriceMovePerBlock;
+	type DexPriceTwapWindow = DexPriceTwapWindow;
 	type WeightInfo = ();
 }
 
@@ -508,8 +528,9 @@ frame_support::construct_runtime!(
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Currencies: module_currencies::{Pallet, Call, Event<T>},
 		EVMBridge: module_evm_bridge::{Pallet},
-		EVMManager: module_evm_manager::{Pallet, Storage},
+		EVMManager: module_evm_manager::{Pallet, Call, Storage, Event<T>},
 		NFTModule: module_nft::{Pallet, Call, Event<T>},
+		OrmlNFT: orml_nft::{Pallet, Storage, Config<T>},
 		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage},
 		Prices: module_prices::{Pallet, Storage, Call, Event<T>},
 		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
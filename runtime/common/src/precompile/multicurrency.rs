@@ -95,7 +95,7 @@ where
 				Ok((ExitSucceed::Returned, vec_u8_from_str(&symbol), 0))
 			}
 			Action::QueryDecimals => {
-				let decimals = CurrencyIdMapping::decimals(currency_id)
+				let decimals = CurrencyIdMapping::display_decimals(currency_id)
 					.ok_or_else(|| ExitError::Other("Get decimals failed".into()))?;
 				log::debug!(target: "evm", "multicurrency: decimals: {:?}", decimals);
 
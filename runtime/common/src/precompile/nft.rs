@@ -18,7 +18,7 @@
 
 use frame_support::log;
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
-use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT};
+use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, NFTMetadata};
 use sp_core::{H160, U256};
 use sp_runtime::RuntimeDebug;
 use sp_std::{borrow::Cow, fmt::Debug, marker::PhantomData, prelude::*, result};
@@ -37,6 +37,7 @@ use primitives::NFTBalance;
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Query owner. Rest `input` bytes: `class_id`, `token_id`.
 /// - Transfer. Rest `input`bytes: `from`, `to`, `class_id`, `token_id`.
+/// - Query token metadata. Rest `input` bytes: `class_id`, `token_id`.
 pub struct NFTPrecompile<AccountId, AddressMapping, CurrencyIdMapping, NFT>(
 	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, NFT)>,
 );
@@ -48,6 +49,7 @@ pub enum Action {
 	QueryBalance = "balanceOf(address)",
 	QueryOwner = "ownerOf(uint256,uint256)",
 	Transfer = "transfer(address,address,uint256,uint256)",
+	QueryTokenMetadata = "tokenMetadata(uint256,uint256)",
 }
 
 impl<AccountId, AddressMapping, CurrencyIdMapping, NFT> Precompile
@@ -56,7 +58,7 @@ where
 	AccountId: Clone + Debug,
 	AddressMapping: AddressMappingT<AccountId>,
 	CurrencyIdMapping: CurrencyIdMappingT,
-	NFT: NFTT<AccountId, Balance = NFTBalance, ClassId = u32, TokenId = u64>,
+	NFT: NFTT<AccountId, Balance = NFTBalance, ClassId = u32, TokenId = u64> + NFTMetadata<u32, u64>,
 {
 	fn execute(
 		input: &[u8],
@@ -110,6 +112,17 @@ where
 
 				Ok((ExitSucceed::Returned, vec![], 0))
 			}
+			Action::QueryTokenMetadata => {
+				let class_id = input.u32_at(1)?;
+				let token_id = input.u64_at(2)?;
+
+				log::debug!(target: "evm", "nft: query_token_metadata class_id: {:?}, token_id: {:?}", class_id, token_id);
+
+				let metadata = NFT::token_metadata((class_id, token_id))
+					.ok_or_else(|| ExitError::Other("TokenIdNotFound".into()))?;
+
+				Ok((ExitSucceed::Returned, vec_u8_from_bytes(&metadata), 0))
+			}
 		}
 	}
 }
@@ -119,3 +132,13 @@ fn vec_u8_from_balance(b: NFTBalance) -> Vec<u8> {
 	U256::from(b).to_big_endian(&mut be_bytes[..]);
 	be_bytes.to_vec()
 }
+
+fn vec_u8_from_bytes(b: &[u8]) -> Vec<u8> {
+	// abi encoded `bytes`: offset + length + right padded data
+	let padded_len = (b.len() + 31) / 32 * 32;
+	let mut be_bytes = vec![0u8; 64 + padded_len];
+	U256::from(32).to_big_endian(&mut be_bytes[..32]);
+	U256::from(b.len()).to_big_endian(&mut be_bytes[32..64]);
+	be_bytes[64..64 + b.len()].copy_from_slice(b);
+	be_bytes
+}
@@ -16,17 +16,20 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::{log, sp_runtime::FixedPointNumber};
+use frame_support::{log, sp_runtime::FixedPointNumber, traits::Time as TimeT};
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use primitives::CurrencyId;
+use orml_traits::DataProviderExtended;
+use primitives::{CurrencyId, Moment};
 use sp_core::U256;
 use sp_runtime::RuntimeDebug;
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
 
 use super::input::{Input, InputT};
+use crate::TimeStampedPrice;
 use module_support::{
-	AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, Price, PriceProvider as PriceProviderT,
+	AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, Price, PriceOracleSources,
+	PriceProvider as PriceProviderT,
 };
 
 /// The `Oracle` impl precompile.
@@ -36,8 +39,18 @@ use module_support::{
 ///
 /// Actions:
 /// - Get price. Rest `input` bytes: `currency_id`.
-pub struct OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider>(
-	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, PriceProvider)>,
+/// - Get price with timestamp. Rest `input` bytes: `currency_id`. Returns the price and the
+///   timestamp of the oldest oracle value the price is derived from, or the current time for a
+///   fixed price, reverts if there is no price.
+pub struct OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider, DataProvider, Time>(
+	PhantomData<(
+		AccountId,
+		AddressMapping,
+		CurrencyIdMapping,
+		PriceProvider,
+		DataProvider,
+		Time,
+	)>,
 );
 
 #[primitives_proc_macro::generate_function_selector]
@@ -45,15 +58,18 @@ pub struct OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceP
 #[repr(u32)]
 pub enum Action {
 	GetPrice = "getPrice(address)",
+	GetPriceWithTimestamp = "getPriceWithTimestamp(address)",
 }
 
-impl<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider> Precompile
-	for OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider>
+impl<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider, DataProvider, Time> Precompile
+	for OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider, DataProvider, Time>
 where
 	AccountId: Debug + Clone,
 	AddressMapping: AddressMappingT<AccountId>,
 	CurrencyIdMapping: CurrencyIdMappingT,
-	PriceProvider: PriceProviderT<CurrencyId>,
+	PriceProvider: PriceProviderT<CurrencyId> + PriceOracleSources<CurrencyId>,
+	DataProvider: DataProviderExtended<CurrencyId, TimeStampedPrice>,
+	Time: TimeT<Moment = Moment>,
 {
 	fn execute(
 		input: &[u8],
@@ -102,10 +118,42 @@ where
 					0,
 				))
 			}
+			Action::GetPriceWithTimestamp => {
+				let currency_id = input.currency_id_at(1)?;
+				let price = PriceProvider::get_price(currency_id).ok_or_else(|| ExitError::Other("no price".into()))?;
+				let timestamp = oldest_oracle_timestamp::<DataProvider>(&PriceProvider::oracle_sources(currency_id))
+					.ok_or_else(|| ExitError::Other("no price".into()))?
+					.unwrap_or_else(Time::now);
+
+				let adjustment_multiplier = CurrencyIdMapping::decimals(currency_id)
+					.and_then(|decimals| 18u8.checked_sub(decimals))
+					.and_then(|exponent| 10u128.checked_pow(exponent.into()))
+					.ok_or_else(|| ExitError::Other("invalid decimals".into()))?;
+
+				log::debug!(target: "evm", "oracle: getPriceWithTimestamp currency_id: {:?}, price: {:?}, timestamp: {:?}, adjustment_multiplier: {:?}", currency_id, price, timestamp, adjustment_multiplier);
+
+				let mut output = vec_u8_from_price(price, adjustment_multiplier);
+				let mut be_bytes = [0u8; 32];
+				U256::from(timestamp).to_big_endian(&mut be_bytes[..32]);
+				output.extend_from_slice(&be_bytes);
+
+				Ok((ExitSucceed::Returned, output, 0))
+			}
 		}
 	}
 }
 
+/// The timestamp of the oldest oracle value of `sources`, `None` if any of
+/// them has no value, `Some(None)` if there are no sources.
+fn oldest_oracle_timestamp<DataProvider: DataProviderExtended<CurrencyId, TimeStampedPrice>>(
+	sources: &[CurrencyId],
+) -> Option<Option<Moment>> {
+	sources.iter().try_fold(None, |oldest: Option<Moment>, currency_id| {
+		let timestamp = DataProvider::get_no_op(currency_id)?.timestamp;
+		Some(Some(oldest.map_or(timestamp, |oldest| oldest.min(timestamp))))
+	})
+}
+
 fn vec_u8_from_price(price: Price, adjustment_multiplier: u128) -> Vec<u8> {
 	let mut be_bytes = [0u8; 32];
 	U256::from(price.into_inner().wrapping_div(adjustment_multiplier)).to_big_endian(&mut be_bytes[..32]);
@@ -60,6 +60,9 @@ pub struct TaskInfo {
 /// Actions:
 /// - ScheduleCall. Rest `input` bytes: `from`, `target`, `value`, `gas_limit`, `storage_limit`,
 ///   `min_delay`, `input_len`, `input_data`.
+/// - CancelCall. Rest `input` bytes: `from`, `task_id_len`, `task_id`. Only the `from` which
+///   scheduled the task can cancel it, the reserved fee is released.
+/// - RescheduleCall. Rest `input` bytes: `from`, `min_delay`, `task_id_len`, `task_id`.
 pub struct ScheduleCallPrecompile<
 	AccountId,
 	AddressMapping,
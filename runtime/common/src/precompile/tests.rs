@@ -21,9 +21,10 @@ use super::*;
 use crate::precompile::{
 	mock::{
 		aca_evm_address, alice, alice_evm_addr, ausd_evm_address, bob, bob_evm_addr, erc20_address_not_exists,
-		get_task_id, lp_aca_ausd_evm_address, new_test_ext, renbtc_evm_address, run_to_block, Balances, DexModule,
-		DexPrecompile, Event as TestEvent, MultiCurrencyPrecompile, Oracle, OraclePrecompile, Origin, Price,
-		ScheduleCallPrecompile, System, Test, ALICE, AUSD, INITIAL_BALANCE, RENBTC,
+		get_task_id, lp_aca_ausd_evm_address, new_test_ext, renbtc_evm_address, run_to_block, Balances, CouncilAccount,
+		CurrencyId, DexModule, DexPrecompile, EVMManager, Event as TestEvent, EvmCurrencyIdMapping,
+		MultiCurrencyPrecompile, NFTModule, NFTPrecompile, NftPalletId, Oracle, OraclePrecompile, Origin, OrmlNFT,
+		Price, ScheduleCallPrecompile, System, Test, Timestamp, ALICE, AUSD, DOT, INITIAL_BALANCE, LDOT, RENBTC,
 	},
 	schedule_call::TaskInfo,
 };
@@ -31,11 +32,11 @@ use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
 use hex_literal::hex;
 use module_evm::ExitError;
-use module_support::AddressMapping;
+use module_support::{AddressMapping, CurrencyIdMapping};
 use orml_traits::DataFeeder;
 use primitives::{Balance, PREDEPLOY_ADDRESS_START};
-use sp_core::{H160, U256};
-use sp_runtime::FixedPointNumber;
+use sp_core::{crypto::AccountId32, H160, U256};
+use sp_runtime::{traits::AccountIdConversion, FixedPointNumber};
 use std::str::FromStr;
 
 pub struct DummyPrecompile;
@@ -280,6 +281,45 @@ fn multicurrency_precompile_should_work() {
 	});
 }
 
+#[test]
+fn multicurrency_precompile_decimals_returns_registered_decimals() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: renbtc_evm_address(),
+			apparent_value: Default::default(),
+		};
+		let mut input = [0u8; 36];
+		// action
+		input[1 * 32..4 + 1 * 32]
+			.copy_from_slice(&Into::<u32>::into(multicurrency::Action::QueryDecimals).to_be_bytes());
+
+		// the decimals of token metadata
+		let (reason, output, used_gas) = MultiCurrencyPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		let mut expected_output = [0u8; 32];
+		expected_output[31] = 8;
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, 0);
+
+		// the registered decimals
+		assert_ok!(EVMManager::set_currency_decimals(
+			Origin::signed(CouncilAccount::get()),
+			RENBTC,
+			Some(10)
+		));
+		let (reason, output, used_gas) = MultiCurrencyPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		let mut expected_output = [0u8; 32];
+		expected_output[31] = 10;
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, 0);
+
+		// the decimals to convert prices are not affected
+		assert_eq!(EvmCurrencyIdMapping::decimals(RENBTC), Some(8));
+	});
+}
+
 #[test]
 fn oracle_precompile_should_work() {
 	new_test_ext().execute_with(|| {
@@ -324,6 +364,90 @@ fn oracle_precompile_should_work() {
 	});
 }
 
+#[test]
+fn oracle_precompile_get_price_with_timestamp_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		let price = Price::from(30_000);
+
+		// action + currency_id
+		let mut input = [0u8; 68];
+		// action
+		input[1 * 32..4 + 1 * 32]
+			.copy_from_slice(&Into::<u32>::into(oracle::Action::GetPriceWithTimestamp).to_be_bytes());
+		// RENBTC
+		U256::from_big_endian(&renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+
+		// no price yet
+		assert_noop!(
+			OraclePrecompile::execute(&input, None, &context),
+			ExitError::Other("no price".into())
+		);
+
+		assert_ok!(Oracle::feed_value(ALICE, RENBTC, price));
+
+		// returned price + timestamp
+		let mut expected_output = [0u8; 64];
+		U256::from(price.into_inner()).to_big_endian(&mut expected_output[0..32]);
+		U256::from(1).to_big_endian(&mut expected_output[32..64]);
+
+		let (reason, output, used_gas) = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, 0);
+	});
+}
+
+#[test]
+fn oracle_precompile_get_price_with_timestamp_of_derived_prices_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		let get_price_with_timestamp = |currency_id: CurrencyId| {
+			// action + currency_id
+			let mut input = [0u8; 68];
+			// action
+			input[1 * 32..4 + 1 * 32]
+				.copy_from_slice(&Into::<u32>::into(oracle::Action::GetPriceWithTimestamp).to_be_bytes());
+			let address = EvmCurrencyIdMapping::encode_evm_address(currency_id).unwrap();
+			U256::from_big_endian(address.as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+			OraclePrecompile::execute(&input, None, &context)
+		};
+
+		let expected_output = |price: Price, timestamp: u64| {
+			let mut output = [0u8; 64];
+			U256::from(price.into_inner()).to_big_endian(&mut output[0..32]);
+			U256::from(timestamp).to_big_endian(&mut output[32..64]);
+			output.to_vec()
+		};
+
+		// no price of the staking currency yet
+		assert_noop!(get_price_with_timestamp(LDOT), ExitError::Other("no price".into()));
+
+		assert_ok!(Oracle::feed_value(ALICE, DOT, Price::from(30_000)));
+		Timestamp::set_timestamp(5);
+
+		// the liquid currency returns the timestamp of the staking currency price
+		let (reason, output, _) = get_price_with_timestamp(LDOT).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output(Price::from(15_000), 1));
+
+		// the stable currency has a fixed price, returns the current time
+		let (reason, output, _) = get_price_with_timestamp(AUSD).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output(Price::from(1), 5));
+	});
+}
+
 #[test]
 fn oracle_precompile_should_handle_invalid_input() {
 	new_test_ext().execute_with(|| {
@@ -500,6 +624,79 @@ fn schedule_call_precompile_should_work() {
 	});
 }
 
+#[test]
+fn schedule_call_precompile_cancelled_call_should_not_execute() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		let mut input = [0u8; 12 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(schedule_call::Action::Schedule).to_be_bytes());
+		// from
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// target
+		U256::from(aca_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// value
+		U256::from(0).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// gas_limit
+		U256::from(300000).to_big_endian(&mut input[4 + 4 * 32..4 + 5 * 32]);
+		// storage_limit
+		U256::from(100).to_big_endian(&mut input[4 + 5 * 32..4 + 6 * 32]);
+		// min_delay
+		U256::from(1).to_big_endian(&mut input[4 + 6 * 32..4 + 7 * 32]);
+		// skip offset
+		// input_len
+		U256::from(4 + 32 + 32).to_big_endian(&mut input[4 + 8 * 32..4 + 9 * 32]);
+
+		// input_data
+		let mut transfer_to_bob = [0u8; 68];
+		// transfer bytes4(keccak256(signature)) 0xa9059cbb
+		transfer_to_bob[0..4].copy_from_slice(&hex!("a9059cbb"));
+		// to address
+		U256::from(bob_evm_addr().as_bytes()).to_big_endian(&mut transfer_to_bob[4..36]);
+		// amount
+		U256::from(1000).to_big_endian(&mut transfer_to_bob[36..68]);
+
+		U256::from(&transfer_to_bob[0..32]).to_big_endian(&mut input[4 + 9 * 32..4 + 10 * 32]);
+		U256::from(&transfer_to_bob[32..64]).to_big_endian(&mut input[4 + 10 * 32..4 + 11 * 32]);
+		input[4 + 11 * 32..4 + 11 * 32 + 4].copy_from_slice(&transfer_to_bob[64..68]);
+
+		let (reason, output, _used_gas) = ScheduleCallPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+
+		// cancel schedule
+		let task_id = get_task_id(output);
+		let mut cancel_input = [0u8; 6 * 32];
+		// action
+		cancel_input[1 * 32..4 + 1 * 32]
+			.copy_from_slice(&Into::<u32>::into(schedule_call::Action::Cancel).to_be_bytes());
+		// from
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut cancel_input[4 + 1 * 32..4 + 2 * 32]);
+		// skip offset
+		// task_id_len
+		U256::from(task_id.len()).to_big_endian(&mut cancel_input[4 + 3 * 32..4 + 4 * 32]);
+		// task_id
+		cancel_input[4 + 4 * 32..4 + 4 * 32 + task_id.len()].copy_from_slice(&task_id[..]);
+
+		let (reason, _output, _used_gas) = ScheduleCallPrecompile::execute(&cancel_input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+
+		// the reserved fee is released and the cancelled call never executes
+		let from_account = <Test as module_evm::Config>::AddressMapping::get_account_id(&alice_evm_addr());
+		let to_account = <Test as module_evm::Config>::AddressMapping::get_account_id(&bob_evm_addr());
+		run_to_block(5);
+		assert_eq!(Balances::free_balance(from_account.clone()), 1000000000000);
+		assert_eq!(Balances::reserved_balance(from_account), 0);
+		assert_eq!(Balances::free_balance(to_account), 1000000000000);
+	});
+}
+
 #[test]
 fn schedule_call_precompile_should_handle_invalid_input() {
 	new_test_ext().execute_with(|| {
@@ -589,6 +786,124 @@ fn schedule_call_precompile_should_handle_invalid_input() {
 	});
 }
 
+#[test]
+fn nft_precompile_transfer_and_token_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		let metadata = vec![1, 2, 3];
+		let class_owner: AccountId32 = NftPalletId::get().into_sub_account(0);
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(alice()),
+			metadata.clone(),
+			module_nft::Properties(module_nft::ClassProperty::Transferable | module_nft::ClassProperty::Mintable),
+			Default::default(),
+			None,
+		));
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(alice()),
+			metadata.clone(),
+			module_nft::Properties(module_nft::ClassProperty::Mintable.into()),
+			Default::default(),
+			None,
+		));
+		assert_ok!(Balances::transfer(Origin::signed(alice()), class_owner.clone(), 1_000));
+		assert_ok!(Balances::transfer(
+			Origin::signed(alice()),
+			NftPalletId::get().into_sub_account(1),
+			1_000
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_owner),
+			alice(),
+			0,
+			metadata.clone(),
+			Default::default(),
+			1,
+			None
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(NftPalletId::get().into_sub_account(1)),
+			alice(),
+			1,
+			metadata.clone(),
+			Default::default(),
+			1,
+			None
+		));
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// array_size + action + class_id + token_id
+		let mut input = [0u8; 4 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(nft::Action::QueryTokenMetadata).to_be_bytes());
+		// class_id
+		U256::from(0).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// token_id
+		U256::from(0).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+
+		let mut expected_output = [0u8; 3 * 32];
+		U256::from(32).to_big_endian(&mut expected_output[..32]);
+		U256::from(3).to_big_endian(&mut expected_output[32..64]);
+		expected_output[64..67].copy_from_slice(&metadata);
+
+		let (reason, output, used_gas) = NFTPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, 0);
+
+		// token not exists
+		U256::from(1).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		assert_noop!(
+			NFTPrecompile::execute(&input, None, &context),
+			ExitError::Other("TokenIdNotFound".into())
+		);
+
+		// array_size + action + from + to + class_id + token_id
+		let mut input = [0u8; 6 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(nft::Action::Transfer).to_be_bytes());
+		// from
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// to
+		U256::from(bob_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// class_id
+		U256::from(0).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// token_id
+		U256::from(0).to_big_endian(&mut input[4 + 4 * 32..4 + 5 * 32]);
+
+		assert_eq!(OrmlNFT::tokens(0, 0).unwrap().owner, alice());
+		let (reason, output, used_gas) = NFTPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, [0u8; 0]);
+		assert_eq!(used_gas, 0);
+		assert_eq!(OrmlNFT::tokens(0, 0).unwrap().owner, bob());
+
+		// class is not transferable
+		U256::from(1).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		assert_noop!(
+			NFTPrecompile::execute(&input, None, &context),
+			ExitError::Other("NonTransferable".into())
+		);
+		assert_eq!(OrmlNFT::tokens(1, 0).unwrap().owner, alice());
+
+		// transferFrom is not supported without approvals
+		input[1 * 32..4 + 1 * 32]
+			.copy_from_slice(&sp_io::hashing::keccak_256(b"transferFrom(address,address,uint256,uint256)")[..4]);
+		assert_noop!(
+			NFTPrecompile::execute(&input, None, &context),
+			ExitError::Other("invalid action".into())
+		);
+	});
+}
+
 #[test]
 fn dex_precompile_get_liquidity_should_work() {
 	new_test_ext().execute_with(|| {
@@ -785,6 +1100,59 @@ fn dex_precompile_get_swap_supply_amount_should_work() {
 	});
 }
 
+#[test]
+fn dex_precompile_get_swap_amount_should_handle_invalid_path() {
+	new_test_ext().execute_with(|| {
+		// enable RENBTC/AUSD, no liquidity provided
+		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// array_size + action + path_len + currency_id_a + currency_id_b +
+		// supply_amount
+		let mut input = [0u8; 7 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(dex::Action::GetSwapTargetAmount).to_be_bytes());
+		// skip offset
+		// supply_amount
+		U256::from(1).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// path_len
+		U256::from(1).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// RENBTC
+		U256::from_big_endian(&renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 4 * 32..4 + 5 * 32]);
+
+		// invalid trading path length
+		assert_noop!(
+			DexPrecompile::execute(&input, None, &context),
+			ExitError::Other("Dex get_swap_target_amount failed".into())
+		);
+
+		// path_len
+		U256::from(2).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// AUSD
+		U256::from_big_endian(&ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 5 * 32..4 + 6 * 32]);
+
+		// insufficient liquidity
+		assert_noop!(
+			DexPrecompile::execute(&input, None, &context),
+			ExitError::Other("Dex get_swap_target_amount failed".into())
+		);
+
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(dex::Action::GetSwapSupplyAmount).to_be_bytes());
+		assert_noop!(
+			DexPrecompile::execute(&input, None, &context),
+			ExitError::Other("Dex get_swap_supply_amount failed".into())
+		);
+	});
+}
+
 #[test]
 fn dex_precompile_swap_with_exact_supply_should_work() {
 	new_test_ext().execute_with(|| {
@@ -0,0 +1,254 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Common xcm implementation
+
+use codec::{Decode, Encode};
+use frame_support::{
+	traits::{Filter, Get},
+	weights::{constants::WEIGHT_PER_SECOND, Weight},
+};
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+};
+use sp_std::{marker::PhantomData, prelude::*};
+use xcm::v0::{Error as XcmError, MultiAsset, MultiLocation};
+use xcm_builder::TakeRevenue;
+use xcm_executor::{traits::WeightTrader, Assets};
+
+/// Simple fee calculator that requires payment in one of the concrete
+/// fungible assets of `T`, at a fixed rate of units per second of weight.
+///
+/// The first asset in `T` that the payment is enough for is charged, and the
+/// later purchases are charged in the same asset. The revenue is taken by `R`.
+pub struct FixedRateOfConcreteFungibles<T: Get<Vec<(MultiLocation, u128)>>, R: TakeRevenue> {
+	weight: Weight,
+	amount: u128,
+	asset_rate: Option<(MultiLocation, u128)>,
+	_marker: PhantomData<(T, R)>,
+}
+
+impl<T: Get<Vec<(MultiLocation, u128)>>, R: TakeRevenue> WeightTrader for FixedRateOfConcreteFungibles<T, R> {
+	fn new() -> Self {
+		Self {
+			weight: 0,
+			amount: 0,
+			asset_rate: None,
+			_marker: PhantomData,
+		}
+	}
+
+	fn buy_weight(&mut self, weight: Weight, payment: Assets) -> Result<Assets, XcmError> {
+		let asset_rates = match self.asset_rate.clone() {
+			Some(asset_rate) => vec![asset_rate],
+			None => T::get(),
+		};
+
+		for (id, units_per_second) in asset_rates {
+			let amount = units_per_second.saturating_mul(weight as u128) / (WEIGHT_PER_SECOND as u128);
+			let required = MultiAsset::ConcreteFungible { amount, id: id.clone() };
+			if let Ok((unused, _)) = payment.clone().less(required) {
+				self.weight = self.weight.saturating_add(weight);
+				self.amount = self.amount.saturating_add(amount);
+				self.asset_rate = Some((id, units_per_second));
+				return Ok(unused);
+			}
+		}
+
+		Err(XcmError::TooExpensive)
+	}
+
+	fn refund_weight(&mut self, weight: Weight) -> MultiAsset {
+		if let Some((id, units_per_second)) = self.asset_rate.clone() {
+			let weight = weight.min(self.weight);
+			let amount = units_per_second.saturating_mul(weight as u128) / (WEIGHT_PER_SECOND as u128);
+			self.weight = self.weight.saturating_sub(weight);
+			self.amount = self.amount.saturating_sub(amount);
+			if amount > 0 {
+				return MultiAsset::ConcreteFungible { amount, id };
+			}
+		}
+		MultiAsset::None
+	}
+}
+
+impl<T: Get<Vec<(MultiLocation, u128)>>, R: TakeRevenue> Drop for FixedRateOfConcreteFungibles<T, R> {
+	fn drop(&mut self) {
+		if let Some((id, _)) = self.asset_rate.clone() {
+			if self.amount > 0 {
+				R::take_revenue(MultiAsset::ConcreteFungible {
+					id,
+					amount: self.amount,
+				});
+			}
+		}
+	}
+}
+
+/// The `InvalidTransaction::Custom` code of a cross-chain transfer below the
+/// minimum transfer amount of the currency.
+pub const XCM_TRANSFER_BELOW_MINIMUM: u8 = 1;
+
+/// Rejects the transactions of the cross-chain transfers that `AboveMinimum`
+/// filters out with `InvalidTransaction::Custom(XCM_TRANSFER_BELOW_MINIMUM)`,
+/// instead of letting them fail the dispatch with `CallFiltered`.
+///
+/// Only the top-level call is checked, the `BaseCallFilter` should still apply
+/// `AboveMinimum` to the nested calls.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct CheckXcmTransferAmount<T: frame_system::Config + Send + Sync, AboveMinimum: Filter<T::Call>>(
+	PhantomData<(T, AboveMinimum)>,
+);
+
+impl<T: frame_system::Config + Send + Sync, AboveMinimum: Filter<T::Call>> sp_std::fmt::Debug
+	for CheckXcmTransferAmount<T, AboveMinimum>
+{
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckXcmTransferAmount")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: frame_system::Config + Send + Sync, AboveMinimum: Filter<T::Call>> CheckXcmTransferAmount<T, AboveMinimum> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: frame_system::Config + Send + Sync, AboveMinimum: Filter<T::Call>> Default
+	for CheckXcmTransferAmount<T, AboveMinimum>
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, AboveMinimum> SignedExtension for CheckXcmTransferAmount<T, AboveMinimum>
+where
+	T: frame_system::Config + Send + Sync,
+	AboveMinimum: Filter<T::Call> + Encode + Decode + Clone + Eq + Send + Sync + 'static,
+{
+	const IDENTIFIER: &'static str = "CheckXcmTransferAmount";
+	type AccountId = T::AccountId;
+	type Call = T::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if AboveMinimum::filter(call) {
+			Ok(ValidTransaction::default())
+		} else {
+			Err(InvalidTransaction::Custom(XCM_TRANSFER_BELOW_MINIMUM).into())
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::parameter_types;
+	use sp_std::cell::RefCell;
+	use xcm::v0::{Junction::*, MultiLocation::*};
+
+	thread_local! {
+		static REVENUE: RefCell<Vec<MultiAsset>> = RefCell::new(vec![]);
+	}
+
+	pub struct MockRevenue;
+	impl TakeRevenue for MockRevenue {
+		fn take_revenue(revenue: MultiAsset) {
+			REVENUE.with(|v| v.borrow_mut().push(revenue));
+		}
+	}
+
+	parameter_types! {
+		pub FeeRates: Vec<(MultiLocation, u128)> = vec![
+			(X1(Parent), 1_000),
+			(X3(Parent, Parachain(2000), GeneralKey(vec![0])), 2_000),
+		];
+	}
+
+	type Trader = FixedRateOfConcreteFungibles<FeeRates, MockRevenue>;
+
+	fn concrete_fungible(id: MultiLocation, amount: u128) -> MultiAsset {
+		MultiAsset::ConcreteFungible { id, amount }
+	}
+
+	#[test]
+	fn buy_weight_with_non_first_asset_works() {
+		REVENUE.with(|v| v.borrow_mut().clear());
+		let sibling_asset = X3(Parent, Parachain(2000), GeneralKey(vec![0]));
+
+		let mut trader = Trader::new();
+		let payment: Assets = vec![concrete_fungible(sibling_asset.clone(), 5_000)].into();
+		assert_eq!(
+			trader.buy_weight(WEIGHT_PER_SECOND, payment),
+			Ok(vec![concrete_fungible(sibling_asset.clone(), 3_000)].into())
+		);
+		assert_eq!(
+			trader.refund_weight(WEIGHT_PER_SECOND / 2),
+			concrete_fungible(sibling_asset.clone(), 1_000)
+		);
+		drop(trader);
+
+		// the treasury takes the revenue in the asset paid
+		REVENUE.with(|v| assert_eq!(*v.borrow(), vec![concrete_fungible(sibling_asset, 1_000)]));
+	}
+
+	#[test]
+	fn buy_weight_with_unknown_asset_fails() {
+		REVENUE.with(|v| v.borrow_mut().clear());
+		let unknown_asset = X3(Parent, Parachain(3000), GeneralKey(vec![0]));
+
+		let mut trader = Trader::new();
+		let payment: Assets = vec![concrete_fungible(unknown_asset, 5_000)].into();
+		assert_eq!(
+			trader.buy_weight(WEIGHT_PER_SECOND, payment),
+			Err(XcmError::TooExpensive)
+		);
+		drop(trader);
+
+		REVENUE.with(|v| assert!(v.borrow().is_empty()));
+	}
+}
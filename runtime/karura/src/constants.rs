@@ -83,11 +83,29 @@ pub mod fee {
 		}
 	}
 
-	pub fn ksm_per_second() -> u128 {
+	pub fn kar_per_second() -> u128 {
 		let base_weight = Balance::from(ExtrinsicBaseWeight::get());
 		let base_tx_per_second = (WEIGHT_PER_SECOND as u128) / base_weight;
-		let kar_per_second = base_tx_per_second * base_tx_in_kar();
-		kar_per_second / 100
+		base_tx_per_second * base_tx_in_kar()
+	}
+
+	pub fn ksm_per_second() -> u128 {
+		kar_per_second() / 100
+	}
+
+	/// Assumes 1 KSM ~= 400 KUSD.
+	pub fn kusd_per_second() -> u128 {
+		ksm_per_second() * 400
+	}
+
+	/// Assumes 1 KSM ~= 10 LKSM, the initial exchange rate of Homa.
+	pub fn lksm_per_second() -> u128 {
+		ksm_per_second() * 10
+	}
+
+	/// Assumes 1 RENBTC ~= 100 KSM, and RENBTC has 8 decimals against the 12 of KSM.
+	pub fn renbtc_per_second() -> u128 {
+		ksm_per_second() / 100 / 10_000
 	}
 }
 
@@ -35,12 +35,12 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 use codec::{Decode, Encode};
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160};
+use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160, U256};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdConversion, AccountIdLookup, BadOrigin, BlakeTwo256, Block as BlockT, Convert, SaturatedConversion,
-		StaticLookup, Zero,
+		AccountIdConversion, AccountIdLookup, BadOrigin, BlakeTwo256, Block as BlockT, BlockNumberProvider, Convert,
+		SaturatedConversion, StaticLookup, Zero,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, DispatchResult, FixedPointNumber,
@@ -51,13 +51,16 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 use frame_system::{EnsureRoot, RawOrigin};
+use module_cdp_engine::RiskManagementParams;
 use module_currencies::BasicCurrencyAdapter;
-use module_evm::{CallInfo, CreateInfo};
+use module_evm::{AccessListItem, CallInfo, CreateInfo};
 use module_evm_accounts::EvmAddressMapping;
 use module_evm_manager::EvmCurrencyIdMapping;
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
+use module_xcm_origin_filter::DenyBlockedXcmOrigins;
 use orml_traits::{
-	create_median_value_data_provider, parameter_type_with_key, DataFeeder, DataProviderExtended, MultiCurrency,
+	create_median_value_data_provider, parameter_type_with_key, DataFeeder, DataProviderExtended, GetByKey,
+	MultiCurrency,
 };
 use pallet_transaction_payment::RuntimeDispatchInfo;
 
@@ -90,7 +93,10 @@ pub use frame_support::{
 		IsSubType, IsType, KeyOwnerProofSystem, LockIdentifier, MaxEncodedLen, OnUnbalanced, Randomness, SortedMembers,
 		U128CurrencyToVote,
 	},
-	weights::{constants::RocksDbWeight, IdentityFee, Weight},
+	weights::{
+		constants::{RocksDbWeight, WEIGHT_PER_SECOND},
+		IdentityFee, Weight,
+	},
 	PalletId, RuntimeDebug, StorageValue,
 };
 
@@ -104,19 +110,20 @@ pub use authority::AuthorityConfigImpl;
 pub use constants::{fee::*, homa::*, time::*};
 pub use primitives::{
 	evm::EstimateResourcesRequest, AccountId, AccountIndex, Amount, AuctionId, AuthoritysOriginId, Balance,
-	BlockNumber, CurrencyId, DataProviderId, EraIndex, Hash, Moment, Nonce, ReserveIdentifier, Share, Signature,
-	TokenSymbol, TradingPair,
+	BlockNumber, CurrencyId, DataProviderId, DexShare, EraIndex, Hash, Moment, Nonce, ReserveIdentifier, Share,
+	Signature, TokenSymbol, TradingPair,
 };
 pub use runtime_common::{
-	cent, dollar, microcent, millicent, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
+	cent, dollar, microcent, millicent, CheckXcmTransferAmount, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
 	EnsureRootOrAllTechnicalCommittee, EnsureRootOrHalfFinancialCouncil, EnsureRootOrHalfGeneralCouncil,
 	EnsureRootOrHalfHomaCouncil, EnsureRootOrOneThirdsTechnicalCommittee, EnsureRootOrThreeFourthsGeneralCouncil,
 	EnsureRootOrTwoThirdsGeneralCouncil, EnsureRootOrTwoThirdsTechnicalCommittee, ExchangeRate,
-	FinancialCouncilInstance, FinancialCouncilMembershipInstance, GasToWeight, GeneralCouncilInstance,
-	GeneralCouncilMembershipInstance, HomaCouncilInstance, HomaCouncilMembershipInstance,
+	FinancialCouncilInstance, FinancialCouncilMembershipInstance, FixedRateOfConcreteFungibles, GasToWeight,
+	GeneralCouncilInstance, GeneralCouncilMembershipInstance, HomaCouncilInstance, HomaCouncilMembershipInstance,
 	OperatorMembershipInstanceAcala, OperatorMembershipInstanceBand, Price, ProxyType, Rate, Ratio,
 	RelaychainBlockNumberProvider, RuntimeBlockLength, RuntimeBlockWeights, SystemContractsFilter,
-	TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance, TimeStampedPrice, KAR, KSM, KUSD, LKSM, RENBTC,
+	TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance, TimeStampedPrice, EVM_DECIMALS_SCALE, KAR, KSM,
+	KUSD, LKSM, RENBTC,
 };
 
 mod authority;
@@ -194,6 +201,11 @@ parameter_types! {
 pub struct BaseCallFilter;
 impl Filter<Call> for BaseCallFilter {
 	fn filter(call: &Call) -> bool {
+		// reject cross-chain transfers whose amount can't cover the fees
+		if !XcmTransferAboveMinimum::filter(call) {
+			return false;
+		}
+
 		matches!(
 			call,
 			// Core
@@ -212,11 +224,13 @@ impl Filter<Call> for BaseCallFilter {
 			// Collactor Selection
 			Call::CollatorSelection(_) | Call::Session(_) | Call::SessionManager(_) |
 			// Vesting
-			Call::Vesting(_) |
+			Call::Vesting(_) | Call::VestingManager(_) |
 			// TransactionPayment
 			Call::TransactionPayment(_) |
 			// Tokens
 			Call::XTokens(_) | Call::Balances(_) | Call::Currencies(_) |
+			// XCM
+			Call::XcmOriginFilter(_) |
 			// NFT
 			Call::NFT(_) |
 			// DEX
@@ -300,6 +314,7 @@ parameter_types! {
 	pub const MaxInvulnerables: u32 = 10;
 	pub const KickPenaltySessionLength: u32 = 8;
 	pub const CollatorKickThreshold: Permill = Permill::from_percent(30);
+	pub const CollatorUnbondCooldown: BlockNumber = 7 * DAYS;
 }
 
 impl module_collator_selection::Config for Runtime {
@@ -313,6 +328,7 @@ impl module_collator_selection::Config for Runtime {
 	type MaxInvulnerables = MaxInvulnerables;
 	type KickPenaltySessionLength = KickPenaltySessionLength;
 	type CollatorKickThreshold = CollatorKickThreshold;
+	type UnbondCooldown = CollatorUnbondCooldown;
 	type WeightInfo = weights::module_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -558,7 +574,8 @@ parameter_types! {
 	pub const ProposalBond: Permill = Permill::from_percent(5);
 	pub ProposalBondMinimum: Balance = 5 * dollar(KAR);
 	pub const SpendPeriod: BlockNumber = 7 * DAYS;
-	pub const Burn: Permill = Permill::from_percent(0);
+	// The share of the treasury funds burnt each spend period, updatable by governance.
+	pub storage Burn: Permill = Permill::from_percent(0);
 
 	pub const TipCountdown: BlockNumber = DAYS;
 	pub const TipFindersFee: Percent = Percent::from_percent(5);
@@ -584,7 +601,7 @@ impl pallet_treasury::Config for Runtime {
 	type ProposalBondMinimum = ProposalBondMinimum;
 	type SpendPeriod = SpendPeriod;
 	type Burn = Burn;
-	type BurnDestination = ();
+	type BurnDestination = TreasuryBurnDestination;
 	type SpendFunds = Bounties;
 	type WeightInfo = ();
 	type MaxApprovals = MaxApprovals;
@@ -697,7 +714,7 @@ parameter_types! {
 type AcalaDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type Event = Event;
-	type OnNewData = ();
+	type OnNewData = Prices;
 	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
@@ -759,6 +776,32 @@ parameter_types! {
 	pub KaruraTreasuryAccount: AccountId = TreasuryPalletId::get().into_account();
 }
 
+parameter_type_with_key! {
+	pub DustRemovalAccounts: |currency_id: CurrencyId| -> AccountId {
+		if *currency_id == GetStakingCurrencyId::get() {
+			HomaTreasuryPalletId::get().into_account()
+		} else {
+			KaruraTreasuryAccount::get()
+		}
+	};
+}
+
+/// Transfer the dust to the account configured for the currency by
+/// `DustRemovalAccounts`.
+pub struct TransferDustByCurrency;
+impl orml_tokens::OnDust<AccountId, CurrencyId, Balance> for TransferDustByCurrency {
+	fn on_dust(who: &AccountId, currency_id: CurrencyId, amount: Balance) {
+		// transfer the dust to the dust removal account, ignore the result,
+		// if failed will leave some dust which still could be recycled.
+		let _ = <Tokens as MultiCurrency<AccountId>>::transfer(
+			currency_id,
+			who,
+			&DustRemovalAccounts::get(&currency_id),
+			amount,
+		);
+	}
+}
+
 impl orml_tokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -766,12 +809,14 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyId = CurrencyId;
 	type WeightInfo = weights::orml_tokens::WeightInfo<Runtime>;
 	type ExistentialDeposits = ExistentialDeposits;
-	type OnDust = orml_tokens::TransferDust<Runtime, KaruraTreasuryAccount>;
+	type OnDust = TransferDustByCurrency;
 	type MaxLocks = MaxLocks;
 }
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub MaxPriceMovePerBlock: Ratio = Ratio::saturating_from_rational(30, 100);
+	pub const DexPriceTwapWindow: u32 = 30 * MINUTES;
 }
 
 impl module_prices::Config for Runtime {
@@ -786,6 +831,8 @@ impl module_prices::Config for Runtime {
 	type DEX = Dex;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
+	type MaxPriceMovePerBlock = MaxPriceMovePerBlock;
+	type DexPriceTwapWindow = DexPriceTwapWindow;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -801,6 +848,16 @@ parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = KUSD;
 	pub const GetLiquidCurrencyId: CurrencyId = LKSM;
 	pub const GetStakingCurrencyId: CurrencyId = KSM;
+	// The accounts that are not allowed to send or receive transfers, updatable by governance.
+	pub storage TransferBlocklist: Vec<AccountId> = vec![];
+}
+
+pub struct TransferBlocklistFilter;
+impl module_support::TransferFilter<AccountId, CurrencyId, Balance> for TransferBlocklistFilter {
+	fn is_allowed(from: &AccountId, to: &AccountId, _currency_id: CurrencyId, _amount: Balance) -> bool {
+		let blocklist = TransferBlocklist::get();
+		!blocklist.contains(from) && !blocklist.contains(to)
+	}
 }
 
 impl module_currencies::Config for Runtime {
@@ -811,6 +868,10 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = weights::module_currencies::WeightInfo<Runtime>;
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type EVMBridge = EVMBridge;
+	type SweepOrigin = EnsureRootOrHalfGeneralCouncil;
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type FreezeOrigin = EnsureRootOrHalfGeneralCouncil;
+	type PreTransferFilter = TransferBlocklistFilter;
 }
 
 parameter_types! {
@@ -858,6 +919,11 @@ impl orml_vesting::Config for Runtime {
 	type BlockNumberProvider = RelaychainBlockNumberProvider<Runtime>;
 }
 
+impl module_vesting_manager::Config for Runtime {
+	type Event = Event;
+	type WeightInfo = weights::module_vesting_manager::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
 	pub const MaxScheduledPerBlock: u32 = 30;
@@ -876,8 +942,12 @@ impl pallet_scheduler::Config for Runtime {
 
 parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(2, 100);
+	pub MinimumIncrementCloseMultiplier: Rate = Rate::saturating_from_integer(1);
 	pub const AuctionTimeToClose: BlockNumber = 15 * MINUTES;
 	pub const AuctionDurationSoftCap: BlockNumber = 2 * HOURS;
+	pub const AuctionExtensionWindow: BlockNumber = 10 * MINUTES;
+	pub const MaxAuctionExtensions: u32 = 8;
+	pub const MaxAuctionsPerBlock: u32 = 100;
 }
 
 impl module_auction_manager::Config for Runtime {
@@ -885,14 +955,19 @@ impl module_auction_manager::Config for Runtime {
 	type Currency = Currencies;
 	type Auction = Auction;
 	type MinimumIncrementSize = MinimumIncrementSize;
+	type MinimumIncrementCloseMultiplier = MinimumIncrementCloseMultiplier;
 	type AuctionTimeToClose = AuctionTimeToClose;
 	type AuctionDurationSoftCap = AuctionDurationSoftCap;
+	type AuctionExtensionWindow = AuctionExtensionWindow;
+	type MaxAuctionExtensions = MaxAuctionExtensions;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type DEX = Dex;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -939,6 +1014,7 @@ where
 			frame_system::CheckWeight::<Runtime>::new(),
 			module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
 			module_evm::SetEvmOrigin::<Runtime>::new(),
+			CheckXcmTransferAmount::<Runtime, XcmTransferAboveMinimum>::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -971,7 +1047,9 @@ parameter_types! {
 	pub DefaultDebitExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(8, 100);
 	pub MinimumDebitValue: Balance = 20 * dollar(KUSD);
+	pub MinimumCollateralValue: Balance = 20 * dollar(KUSD);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(1, 100);
+	pub KeeperBountyRate: Rate = Rate::saturating_from_rational(10, 100);
 }
 
 impl module_cdp_engine::Config for Runtime {
@@ -982,10 +1060,12 @@ impl module_cdp_engine::Config for Runtime {
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
 	type DefaultLiquidationPenalty = DefaultLiquidationPenalty;
 	type MinimumDebitValue = MinimumDebitValue;
+	type MinimumCollateralValue = MinimumCollateralValue;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
+	type KeeperBountyRate = KeeperBountyRate;
 	type UnsignedPriority = runtime_common::CdpEngineUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type UnixTime = Timestamp;
@@ -1003,12 +1083,17 @@ impl module_honzon::Config for Runtime {
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const RefundDelayPeriod: BlockNumber = 2 * HOURS;
+}
+
 impl module_emergency_shutdown::Config for Runtime {
 	type Event = Event;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type PriceSource = Prices;
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
+	type RefundDelayPeriod = RefundDelayPeriod;
 	type ShutdownOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = weights::module_emergency_shutdown::WeightInfo<Runtime>;
 }
@@ -1016,6 +1101,10 @@ impl module_emergency_shutdown::Config for Runtime {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (3, 1000);	// 0.3%
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxTradingPathLimit: u32 = 6;
+	pub const MaxBatchSwaps: u32 = 10;
+	pub const MinimumLiquidity: Balance = 1_000;
+	pub const MaxTwapWindow: BlockNumber = HOURS;
 }
 
 impl module_dex::Config for Runtime {
@@ -1023,9 +1112,14 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPathLimit = MaxTradingPathLimit;
+	type MaxBatchSwaps = MaxBatchSwaps;
+	type MinimumLiquidity = MinimumLiquidity;
 	type PalletId = DEXPalletId;
+	type MaxTwapWindow = MaxTwapWindow;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type DEXIncentives = Incentives;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 }
@@ -1045,12 +1139,15 @@ impl module_cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = HonzonTreasuryAccount;
+	type EmergencyShutdown = EmergencyShutdown;
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	// Sort by fee charge order
 	pub DefaultFeeSwapPathList: Vec<Vec<CurrencyId>> = vec![vec![KUSD, KAR], vec![KSM, KAR], vec![LKSM, KAR]];
+	// The share of tips goes to the block author
+	pub const TipAuthorShare: Percent = Percent::from_percent(50);
 }
 
 type NegativeImbalance = <Balances as PalletCurrency<AccountId>>::NegativeImbalance;
@@ -1059,14 +1156,31 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
 	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
 		if let Some(mut fees) = fees_then_tips.next() {
 			if let Some(tips) = fees_then_tips.next() {
-				tips.merge_into(&mut fees);
+				// for tips, `TipAuthorShare` to block author, the rest is handled as fees.
+				// if the author account doesn't exist, its share is handled as fees too.
+				let to_author_amount = TipAuthorShare::get() * tips.peek();
+				let (to_author, rest) = tips.split(to_author_amount);
+				if let Err(to_author) = Balances::resolve_into_existing(&Authorship::author(), to_author) {
+					to_author.merge_into(&mut fees);
+				}
+				rest.merge_into(&mut fees);
 			}
-			// for fees and tips, 100% to treasury
+			// for fees and the rest of tips, 100% to treasury
 			Treasury::on_unbalanced(fees);
 		}
 	}
 }
 
+/// The burnt treasury funds are in the native token already, so no DEX buy-back is needed:
+/// instead of being destroyed they are moved into `UnreleasedNativeVaultAccountId` to fund
+/// the incentives.
+pub struct TreasuryBurnDestination;
+impl OnUnbalanced<NegativeImbalance> for TreasuryBurnDestination {
+	fn on_nonzero_unbalanced(amount: NegativeImbalance) {
+		Balances::resolve_creating(&UnreleasedNativeVaultAccountId::get(), amount);
+	}
+}
+
 impl module_transaction_payment::Config for Runtime {
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
@@ -1092,8 +1206,11 @@ impl module_evm_accounts::Config for Runtime {
 }
 
 impl module_evm_manager::Config for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type UpdateOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_evm_manager::WeightInfo<Runtime>;
 }
 
 impl orml_rewards::Config for Runtime {
@@ -1105,21 +1222,27 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const MaxPoolsPerBlock: u32 = 100;
+	pub const ExpectedMaxPools: u32 = 500;
 }
 
 impl module_incentives::Config for Runtime {
 	type Event = Event;
 	type RelaychainAccountId = AccountId;
 	type NativeRewardsSource = UnreleasedNativeVaultAccountId;
+	type AlternativeRewardsSource = KaruraTreasuryAccount;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type StableCurrencyId = GetStableCurrencyId;
 	type LiquidCurrencyId = GetLiquidCurrencyId;
 	type AccumulatePeriod = AccumulatePeriod;
+	type MaxPoolsPerBlock = MaxPoolsPerBlock;
+	type ExpectedMaxPools = ExpectedMaxPools;
 	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
 	type CDPTreasury = CdpTreasury;
 	type Currency = Currencies;
 	type DEX = Dex;
 	type EmergencyShutdown = EmergencyShutdown;
+	type PriceSource = Prices;
 	type PalletId = IncentivesPalletId;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
@@ -1138,6 +1261,7 @@ impl module_nft::Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type BlockNumberProvider = RelaychainBlockNumberProvider<Runtime>;
 	type WeightInfo = weights::module_nft::WeightInfo<Runtime>;
 }
 
@@ -1149,8 +1273,8 @@ parameter_types! {
 impl orml_nft::Config for Runtime {
 	type ClassId = u32;
 	type TokenId = u64;
-	type ClassData = module_nft::ClassData<Balance>;
-	type TokenData = module_nft::TokenData<Balance>;
+	type ClassData = module_nft::ClassData<Balance, AccountId>;
+	type TokenData = module_nft::TokenData<Balance, BlockNumber>;
 	type MaxClassMetadata = MaxClassMetadata;
 	type MaxTokenMetadata = MaxTokenMetadata;
 }
@@ -1193,7 +1317,9 @@ impl InstanceFilter<Call> for ProxyType {
 				matches!(
 					c,
 					Call::Dex(module_dex::Call::swap_with_exact_supply(..))
+						| Call::Dex(module_dex::Call::swap_with_exact_supply_and_deadline(..))
 						| Call::Dex(module_dex::Call::swap_with_exact_target(..))
+						| Call::Dex(module_dex::Call::batch_swap(..))
 				)
 			}
 			ProxyType::Loan => {
@@ -1203,6 +1329,17 @@ impl InstanceFilter<Call> for ProxyType {
 						| Call::Honzon(module_honzon::Call::close_loan_has_debit_by_dex(..))
 				)
 			}
+			ProxyType::DemocracyVoteOnly => {
+				matches!(
+					c,
+					Call::Democracy(pallet_democracy::Call::vote(..))
+						| Call::Democracy(pallet_democracy::Call::remove_vote(..))
+						| Call::Democracy(pallet_democracy::Call::unlock(..))
+				)
+			}
+			ProxyType::HomaLite => {
+				matches!(c, Call::HomaLite(module_homa_lite::Call::mint(..)))
+			}
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -1210,6 +1347,7 @@ impl InstanceFilter<Call> for ProxyType {
 			(x, y) if x == y => true,
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
+			(ProxyType::Governance, ProxyType::DemocracyVoteOnly) => true,
 			_ => false,
 		}
 	}
@@ -1257,6 +1395,8 @@ pub type OraclePrecompile = runtime_common::OraclePrecompile<
 	EvmAddressMapping<Runtime>,
 	EvmCurrencyIdMapping<Runtime>,
 	module_prices::RealTimePriceProvider<Runtime>,
+	AggregatedDataProvider,
+	Timestamp,
 >;
 pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 	AccountId,
@@ -1375,9 +1515,23 @@ parameter_types! {
 	// One XCM operation is 200_000_000 weight, cross-chain transfer ~= 2x of transfer.
 	pub const UnitWeightCost: Weight = 200_000_000;
 	pub KsmPerSecond: (MultiLocation, u128) = (X1(Parent), ksm_per_second());
+	pub KarPerSecond: (MultiLocation, u128) = (native_currency_location(KAR), kar_per_second());
+	pub KusdPerSecond: (MultiLocation, u128) = (native_currency_location(KUSD), kusd_per_second());
+	pub LksmPerSecond: (MultiLocation, u128) = (native_currency_location(LKSM), lksm_per_second());
+	pub RenbtcPerSecond: (MultiLocation, u128) = (native_currency_location(RENBTC), renbtc_per_second());
+	// The assets and their rates to pay for XCM execution, updatable by governance. Covers all the
+	// cross-chain tokens, the cross-chain LP tokens have no fixed rate and aren't accepted.
+	pub storage XcmExecutionFeeRates: Vec<(MultiLocation, u128)> = vec![
+		KsmPerSecond::get(),
+		KarPerSecond::get(),
+		KusdPerSecond::get(),
+		LksmPerSecond::get(),
+		RenbtcPerSecond::get(),
+	];
 }
 
-pub type Barrier = (TakeWeightCredit, AllowTopLevelPaidExecutionFrom<All<MultiLocation>>);
+pub type Barrier =
+	DenyBlockedXcmOrigins<Runtime, (TakeWeightCredit, AllowTopLevelPaidExecutionFrom<All<MultiLocation>>)>;
 
 pub struct ToTreasury;
 impl TakeRevenue for ToTreasury {
@@ -1405,8 +1559,8 @@ impl xcm_executor::Config for XcmConfig {
 	type LocationInverter = LocationInverter<Ancestry>;
 	type Barrier = Barrier;
 	type Weigher = FixedWeightBounds<UnitWeightCost, Call>;
-	// Only receiving KSM is handled, and all fees must be paid in KSM.
-	type Trader = FixedRateOfConcreteFungible<KsmPerSecond, ToTreasury>;
+	// Fees must be paid in one of the assets of `XcmExecutionFeeRates`.
+	type Trader = FixedRateOfConcreteFungibles<XcmExecutionFeeRates, ToTreasury>;
 	type ResponseHandler = (); // Don't handle responses for now.
 }
 
@@ -1472,6 +1626,7 @@ parameter_types! {
 	pub MaxRewardPerEra: Permill = Permill::from_rational(411u32, 1_000_000u32); // 15% / 365 = 0.0004109
 	pub MintFee: Balance = millicent(KSM);
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
+	pub const RelaychainEraLength: BlockNumber = 3_600; // 6 hours in relaychain blocks
 }
 impl module_homa_lite::Config for Runtime {
 	type Event = Event;
@@ -1486,6 +1641,8 @@ impl module_homa_lite::Config for Runtime {
 	type DefaultExchangeRate = DefaultExchangeRate;
 	type MaxRewardPerEra = MaxRewardPerEra;
 	type MintFee = MintFee;
+	type RelaychainBlockNumber = RelaychainBlockNumberProvider<Runtime>;
+	type RelaychainEraLength = RelaychainEraLength;
 }
 
 pub type LocalAssetTransactor = MultiCurrencyAdapter<
@@ -1503,16 +1660,37 @@ fn native_currency_location(id: CurrencyId) -> MultiLocation {
 	X3(Parent, Parachain(ParachainInfo::get().into()), GeneralKey(id.encode()))
 }
 
+/// Whether the LP token of `dex_share_0` and `dex_share_1` is cross-chain asset, which requires
+/// both of the tokens are cross-chain assets.
+fn is_cross_chain_dex_share(dex_share_0: DexShare, dex_share_1: DexShare) -> bool {
+	use TokenSymbol::*;
+	matches!(
+		(dex_share_0, dex_share_1),
+		(
+			DexShare::Token(KSM | KAR | KUSD | LKSM | RENBTC),
+			DexShare::Token(KSM | KAR | KUSD | LKSM | RENBTC)
+		)
+	)
+}
+
 pub struct CurrencyIdConvert;
 impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
 	fn convert(id: CurrencyId) -> Option<MultiLocation> {
 		use CurrencyId::Token;
 		use TokenSymbol::*;
-		match id {
+		let location = match id {
 			Token(KSM) => Some(X1(Parent)),
 			Token(KAR) | Token(KUSD) | Token(LKSM) | Token(RENBTC) => Some(native_currency_location(id)),
+			CurrencyId::DexShare(dex_share_0, dex_share_1) if is_cross_chain_dex_share(dex_share_0, dex_share_1) => {
+				Some(native_currency_location(id))
+			}
 			_ => None,
-		}
+		};
+		// every cross-chain currency must be converted back from its location
+		debug_assert!(location
+			.clone()
+			.map_or(true, |location| Self::convert(location) == Some(id)));
+		location
 	}
 }
 impl Convert<MultiLocation, Option<CurrencyId>> for CurrencyIdConvert {
@@ -1527,6 +1705,11 @@ impl Convert<MultiLocation, Option<CurrencyId>> for CurrencyIdConvert {
 					// check `currency_id` is cross-chain asset
 					match currency_id {
 						Token(KAR) | Token(KUSD) | Token(LKSM) | Token(RENBTC) => Some(currency_id),
+						CurrencyId::DexShare(dex_share_0, dex_share_1)
+							if is_cross_chain_dex_share(dex_share_0, dex_share_1) =>
+						{
+							Some(currency_id)
+						}
 						_ => None,
 					}
 				} else {
@@ -1547,6 +1730,12 @@ impl Convert<MultiAsset, Option<CurrencyId>> for CurrencyIdConvert {
 	}
 }
 
+/// Whether `id` is a cross-chain currency which converts to a `MultiLocation` and back to itself
+/// through `CurrencyIdConvert`.
+pub fn assert_currency_roundtrip(id: CurrencyId) -> bool {
+	CurrencyIdConvert::convert(id).map_or(false, |location| CurrencyIdConvert::convert(location) == Some(id))
+}
+
 parameter_types! {
 	pub SelfLocation: MultiLocation = X2(Parent, Parachain(ParachainInfo::get().into()));
 }
@@ -1561,10 +1750,50 @@ impl Convert<AccountId, MultiLocation> for AccountIdToMultiLocation {
 	}
 }
 
+/// Estimate the execution fee charged on the destination of a cross-chain transfer of
+/// `currency_id` by xtokens, where `dest_weight` is the weight bought on the destination. The fee
+/// is charged in `currency_id` at its rate in `XcmExecutionFeeRates`, assuming the destination
+/// charges the same rate. Returns `None` if `currency_id` is not a cross-chain asset or has no
+/// fee rate.
+pub fn estimate_xtokens_transfer_fee(currency_id: CurrencyId, dest_weight: Weight) -> Option<Balance> {
+	let id = CurrencyIdConvert::convert(currency_id)?;
+	let (_, units_per_second) = XcmExecutionFeeRates::get()
+		.into_iter()
+		.find(|(location, _)| *location == id)?;
+	Some(units_per_second.saturating_mul(dest_weight as u128) / (WEIGHT_PER_SECOND as u128))
+}
+
 parameter_types! {
 	pub const BaseXcmWeight: Weight = 100_000_000;
 }
 
+parameter_type_with_key! {
+	pub MinXcmTransferAmount: |currency_id: CurrencyId| -> Balance {
+		match currency_id {
+			CurrencyId::Token(TokenSymbol::KSM) => cent(*currency_id),
+			CurrencyId::Token(TokenSymbol::KAR) => 10 * cent(*currency_id),
+			CurrencyId::Token(TokenSymbol::KUSD) => 10 * cent(*currency_id),
+			_ => Zero::zero(),
+		}
+	};
+}
+
+/// Rejects the cross-chain transfers below the `MinXcmTransferAmount` of the
+/// currency, with a clear error by `CheckXcmTransferAmount` and by the
+/// `BaseCallFilter` for the nested calls.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct XcmTransferAboveMinimum;
+impl Filter<Call> for XcmTransferAboveMinimum {
+	fn filter(call: &Call) -> bool {
+		match call {
+			Call::XTokens(orml_xtokens::Call::transfer(currency_id, amount, ..)) => {
+				*amount >= MinXcmTransferAmount::get(currency_id)
+			}
+			_ => true,
+		}
+	}
+}
+
 impl orml_xtokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -1581,11 +1810,43 @@ impl orml_unknown_tokens::Config for Runtime {
 	type Event = Event;
 }
 
+impl module_xcm_origin_filter::Config for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_xcm_origin_filter::WeightInfo<Runtime>;
+}
+
 impl orml_xcm::Config for Runtime {
 	type Event = Event;
 	type SovereignOrigin = EnsureRootOrHalfGeneralCouncil;
 }
 
+/// The balance of `who` still locked by the vesting schedules at the current relay chain
+/// block number, as vesting uses `RelaychainBlockNumberProvider`.
+pub fn vesting_locked_balance(who: &AccountId) -> Balance {
+	let now = RelaychainBlockNumberProvider::<Runtime>::current_block_number();
+	Vesting::vesting_schedules(who)
+		.iter()
+		.fold(Zero::zero(), |locked: Balance, schedule| {
+			locked.saturating_add(schedule.locked_amount(now))
+		})
+}
+
+/// Convert an EVM `gas_limit` into the native fee charged for it: the gas is converted by
+/// `GasToWeight` and priced by `module_transaction_payment` (`WeightToFee` adjusted by the
+/// next fee multiplier). `gas_price` is charged per unit of gas on top, as a tip.
+pub fn evm_gas_to_fee(gas_limit: u64, gas_price: u64) -> Balance {
+	let weight_fee = <TransactionPayment as Convert<Weight, Balance>>::convert(GasToWeight::convert(gas_limit));
+	let tip = Balance::from(gas_limit).saturating_mul(gas_price.into());
+	weight_fee.saturating_add(tip)
+}
+
+/// Synthetic EIP-1559 base fee reported to EVM tooling: the fee `evm_gas_to_fee` charges per unit
+/// of gas at the next fee multiplier, in 18 decimals. It's informational only.
+pub fn evm_base_fee_per_gas() -> U256 {
+	U256::from(evm_gas_to_fee(EVM_DECIMALS_SCALE, 0))
+}
+
 #[allow(clippy::large_enum_variant)]
 construct_runtime!(
 	pub enum Runtime where
@@ -1607,6 +1868,7 @@ construct_runtime!(
 		Currencies: module_currencies::{Pallet, Call, Event<T>} = 12,
 		Vesting: orml_vesting::{Pallet, Storage, Call, Event<T>, Config<T>} = 13,
 		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage} = 14,
+		VestingManager: module_vesting_manager::{Pallet, Call, Event<T>} = 15,
 
 		// Treasury
 		Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>} = 20,
@@ -1633,6 +1895,7 @@ construct_runtime!(
 		XTokens: orml_xtokens::{Pallet, Storage, Call, Event<T>} = 54,
 		UnknownTokens: orml_unknown_tokens::{Pallet, Storage, Event} = 55,
 		OrmlXcm: orml_xcm::{Pallet, Call, Event<T>} = 56,
+		XcmOriginFilter: module_xcm_origin_filter::{Pallet, Call, Storage, Event<T>} = 57,
 
 		// Governance
 		Authority: orml_authority::{Pallet, Call, Storage, Event<T>, Origin<T>} = 60,
@@ -1678,14 +1941,14 @@ construct_runtime!(
 		HomaLite: module_homa_lite::{Pallet, Call, Storage, Event<T>} = 115,
 
 		// Karura Other
-		Incentives: module_incentives::{Pallet, Storage, Call, Event<T>} = 120,
-		NFT: module_nft::{Pallet, Call, Event<T>} = 121,
+		Incentives: module_incentives::{Pallet, Storage, Call, Config, Event<T>} = 120,
+		NFT: module_nft::{Pallet, Call, Storage, Config, Event<T>} = 121,
 
 		// Smart contracts
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>} = 130,
 		EVMBridge: module_evm_bridge::{Pallet} = 131,
 		EvmAccounts: module_evm_accounts::{Pallet, Call, Storage, Event<T>} = 132,
-		EvmManager: module_evm_manager::{Pallet, Storage} = 133,
+		EvmManager: module_evm_manager::{Pallet, Call, Storage, Event<T>} = 133,
 
 		// Temporary
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 255,
@@ -1712,6 +1975,7 @@ pub type SignedExtra = (
 	frame_system::CheckWeight<Runtime>,
 	module_transaction_payment::ChargeTransactionPayment<Runtime>,
 	module_evm::SetEvmOrigin<Runtime>,
+	CheckXcmTransferAmount<Runtime, XcmTransferAboveMinimum>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -1823,6 +2087,46 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::CallCostApi<
+		Block,
+		Call,
+		Balance,
+	> for Runtime {
+		fn estimate_call_cost(call: Call, len: u32) -> (Weight, Balance) {
+			TransactionPayment::estimate_call_cost(call, len)
+		}
+	}
+
+	impl module_dex_rpc_runtime_api::DexApi<
+		Block,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn quote_swap(path: Vec<CurrencyId>, supply_amount: Balance) -> Option<(Balance, Balance)> {
+			Dex::quote_swap(&path, supply_amount)
+		}
+	}
+
+	impl module_currencies_rpc_runtime_api::XTokensApi<
+		Block,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn estimate_transfer_fee(currency_id: CurrencyId, dest_weight: Weight) -> Option<Balance> {
+			estimate_xtokens_transfer_fee(currency_id, dest_weight)
+		}
+	}
+
+	impl vesting_rpc_runtime_api::VestingApi<
+		Block,
+		AccountId,
+		Balance,
+	> for Runtime {
+		fn locked_balance(who: AccountId) -> Balance {
+			vesting_locked_balance(&who)
+		}
+	}
+
 	impl orml_oracle_rpc_runtime_api::OracleApi<
 		Block,
 		DataProviderId,
@@ -1860,6 +2164,41 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_evm_accounts_rpc_runtime_api::EvmAccountsApi<Block, AccountId> for Runtime {
+		fn evm_address_of(who: AccountId) -> Option<H160> {
+			EvmAccounts::evm_address_of(&who)
+		}
+	}
+
+	impl module_cdp_engine_rpc_runtime_api::CdpEngineApi<Block, AccountId, RiskManagementParams> for Runtime {
+		fn unsafe_cdps(currency_id: CurrencyId, max: u32) -> Vec<AccountId> {
+			CdpEngine::unsafe_cdps(currency_id, max)
+		}
+
+		fn simulate_collateral_ratio(
+			currency_id: CurrencyId,
+			collateral_adjustment: Amount,
+			debit_adjustment: Amount,
+			who: AccountId,
+		) -> Option<Ratio> {
+			CdpEngine::simulate_collateral_ratio(currency_id, collateral_adjustment, debit_adjustment, &who)
+		}
+
+		fn debit_exchange_rate(currency_id: CurrencyId) -> ExchangeRate {
+			CdpEngine::get_debit_exchange_rate(currency_id)
+		}
+
+		fn all_collateral_params() -> Vec<(CurrencyId, RiskManagementParams)> {
+			CdpEngine::all_collateral_params()
+		}
+	}
+
+	impl module_homa_lite_rpc_runtime_api::HomaLiteApi<Block> for Runtime {
+		fn estimate_mint_output(staking_amount: Balance) -> Balance {
+			HomaLite::estimate_mint_output(staking_amount)
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn call(
 			from: H160,
@@ -1868,6 +2207,7 @@ impl_runtime_apis! {
 			value: Balance,
 			gas_limit: u64,
 			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
 			estimate: bool,
 		) -> Result<CallInfo, sp_runtime::DispatchError> {
 			let config = if estimate {
@@ -1886,6 +2226,7 @@ impl_runtime_apis! {
 				value,
 				gas_limit,
 				storage_limit,
+				access_list.unwrap_or_default(),
 				config.as_ref().unwrap_or(<Runtime as module_evm::Config>::config()),
 			)
 		}
@@ -1896,6 +2237,7 @@ impl_runtime_apis! {
 			value: Balance,
 			gas_limit: u64,
 			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
 			estimate: bool,
 		) -> Result<CreateInfo, sp_runtime::DispatchError> {
 			let config = if estimate {
@@ -1912,6 +2254,7 @@ impl_runtime_apis! {
 				value,
 				gas_limit,
 				storage_limit,
+				access_list.unwrap_or_default(),
 				config.as_ref().unwrap_or(<Runtime as module_evm::Config>::config()),
 			)
 		}
@@ -1946,6 +2289,18 @@ impl_runtime_apis! {
 
 			request.ok_or(sp_runtime::DispatchError::Other("Invalid parameter extrinsic, not evm Call"))
 		}
+
+		fn gas_to_fee(gas_limit: u64, gas_price: u64) -> Balance {
+			evm_gas_to_fee(gas_limit, gas_price)
+		}
+
+		fn base_fee_per_gas() -> U256 {
+			evm_base_fee_per_gas()
+		}
+
+		fn chain_id() -> u64 {
+			EVM::chain_id()
+		}
 	}
 
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
@@ -1973,6 +2328,7 @@ impl_runtime_apis! {
 
 			use module_nft::benchmarking::Pallet as NftBench;
 			use module_homa_lite::benchmarking::Pallet as HomaLiteBench;
+			use module_xcm_origin_filter::benchmarking::Pallet as XcmOriginFilterBench;
 
 			let whitelist: Vec<TrackedStorageKey> = vec![
 				// Block Number
@@ -1996,6 +2352,7 @@ impl_runtime_apis! {
 
 			add_benchmark!(params, batches, module_nft, NftBench::<Runtime>);
 			add_benchmark!(params, batches, module_homa_lite, HomaLiteBench::<Runtime>);
+			add_benchmark!(params, batches, module_xcm_origin_filter, XcmOriginFilterBench::<Runtime>);
 			orml_add_benchmark!(params, batches, module_dex, benchmarking::dex);
 			orml_add_benchmark!(params, batches, module_auction_manager, benchmarking::auction_manager);
 			orml_add_benchmark!(params, batches, module_cdp_engine, benchmarking::cdp_engine);
@@ -2057,7 +2414,8 @@ cumulus_pallet_parachain_system::register_validate_block!(
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use frame_support::weights::DispatchClass;
+	use frame_support::traits::GenesisBuild;
+	use frame_support::weights::{DispatchClass, WeightToFeePolynomial};
 	use frame_system::offchain::CreateSignedTransaction;
 	use sp_runtime::traits::Convert;
 
@@ -2099,6 +2457,304 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn deal_with_fees_pays_tip_share_to_author() {
+		let author = AccountId::from([1u8; 32]);
+		let native_currency_id = GetNativeCurrencyId::get();
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![(author.clone(), dollar(native_currency_id))],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		module_collator_selection::GenesisConfig::<Runtime> {
+			invulnerables: vec![author.clone()],
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		pallet_session::GenesisConfig::<Runtime> {
+			keys: vec![(
+				author.clone(),
+				author.clone(),
+				SessionKeys {
+					aura: AuraId::from(sp_core::sr25519::Public::from_raw([1u8; 32])),
+				},
+			)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::from(t).execute_with(|| {
+			// the author is found by the slot in the pre-runtime digest
+			System::deposit_log(sp_runtime::DigestItem::PreRuntime(
+				sp_consensus_aura::AURA_ENGINE_ID,
+				sp_consensus_aura::Slot::from(0u64).encode(),
+			));
+			assert_eq!(Authorship::author(), author);
+
+			let fees = Balances::issue(10 * dollar(native_currency_id));
+			let tips = Balances::issue(10 * dollar(native_currency_id));
+			DealWithFees::on_unbalanceds(vec![fees, tips].into_iter());
+
+			assert_eq!(Balances::free_balance(&author), 6 * dollar(native_currency_id));
+			assert_eq!(
+				Balances::free_balance(&Treasury::account_id()),
+				15 * dollar(native_currency_id)
+			);
+		});
+	}
+
+	#[test]
+	fn deal_with_fees_handles_tip_share_of_missing_author_as_fees() {
+		let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap()
+			.into();
+		t.execute_with(|| {
+			let native_currency_id = GetNativeCurrencyId::get();
+			let fees = Balances::issue(10 * dollar(native_currency_id));
+			let tips = Balances::issue(10 * dollar(native_currency_id));
+			DealWithFees::on_unbalanceds(vec![fees, tips].into_iter());
+
+			// no author is found without pre-runtime digest, the default account doesn't exist
+			assert_eq!(Balances::free_balance(&Authorship::author()), 0);
+			assert_eq!(
+				Balances::free_balance(&Treasury::account_id()),
+				20 * dollar(native_currency_id)
+			);
+			// nothing is burned
+			assert_eq!(Balances::total_issuance(), 20 * dollar(native_currency_id));
+		});
+	}
+
+	#[test]
+	fn treasury_burn_destination_credits_unreleased_vault() {
+		let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap()
+			.into();
+		t.execute_with(|| {
+			use frame_support::traits::OnInitialize;
+
+			let treasury = Treasury::account_id();
+			let vault = UnreleasedNativeVaultAccountId::get();
+			let pot = 100 * dollar(KAR);
+			Balances::make_free_balance_be(&treasury, pot + NativeTokenExistentialDeposit::get());
+
+			// zero burn keeps the treasury funds
+			Treasury::on_initialize(SpendPeriod::get());
+			assert_eq!(
+				Balances::free_balance(&treasury),
+				pot + NativeTokenExistentialDeposit::get()
+			);
+			assert_eq!(Balances::free_balance(&vault), 0);
+
+			Burn::set(&Permill::from_percent(10));
+			Treasury::on_initialize(2 * SpendPeriod::get());
+			assert_eq!(
+				Balances::free_balance(&treasury),
+				90 * dollar(KAR) + NativeTokenExistentialDeposit::get()
+			);
+			assert_eq!(Balances::free_balance(&vault), 10 * dollar(KAR));
+		});
+	}
+
+	#[test]
+	fn dust_removal_is_routed_by_currency() {
+		let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap()
+			.into();
+		t.execute_with(|| {
+			use frame_support::assert_ok;
+
+			let alice = AccountId::from([1u8; 32]);
+			let bob = AccountId::from([2u8; 32]);
+			let homa_treasury: AccountId = HomaTreasuryPalletId::get().into_account();
+			let treasury = KaruraTreasuryAccount::get();
+
+			for currency_id in &[KSM, KUSD] {
+				let ed = ExistentialDeposits::get(currency_id);
+				assert_ok!(Tokens::deposit(*currency_id, &homa_treasury, ed));
+				assert_ok!(Tokens::deposit(*currency_id, &treasury, ed));
+				assert_ok!(Tokens::deposit(*currency_id, &alice, dollar(*currency_id)));
+				assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(
+					*currency_id,
+					&alice,
+					&bob,
+					dollar(*currency_id) - 1
+				));
+				assert_eq!(Tokens::free_balance(*currency_id, &alice), 0);
+			}
+
+			// staking currency dust goes to the Homa treasury
+			assert_eq!(
+				Tokens::free_balance(KSM, &homa_treasury),
+				ExistentialDeposits::get(&KSM) + 1
+			);
+			assert_eq!(Tokens::free_balance(KSM, &treasury), ExistentialDeposits::get(&KSM));
+
+			// other currencies dust goes to the Karura treasury
+			assert_eq!(
+				Tokens::free_balance(KUSD, &homa_treasury),
+				ExistentialDeposits::get(&KUSD)
+			);
+			assert_eq!(
+				Tokens::free_balance(KUSD, &treasury),
+				ExistentialDeposits::get(&KUSD) + 1
+			);
+		});
+	}
+
+	#[test]
+	fn xcm_transfer_below_minimum_is_rejected() {
+		use sp_runtime::{
+			traits::SignedExtension,
+			transaction_validity::{InvalidTransaction, TransactionValidityError},
+		};
+
+		let alice = AccountId::from([1u8; 32]);
+		let dest = X2(
+			Parent,
+			AccountId32 {
+				network: NetworkId::Any,
+				id: [2u8; 32],
+			},
+		);
+		let transfer = |currency_id: CurrencyId, amount: Balance| {
+			Call::XTokens(orml_xtokens::Call::transfer(
+				currency_id,
+				amount,
+				dest.clone(),
+				1_000_000_000,
+			))
+		};
+		let check = CheckXcmTransferAmount::<Runtime, XcmTransferAboveMinimum>::new();
+
+		for currency_id in vec![KSM, KAR] {
+			let min_amount = MinXcmTransferAmount::get(&currency_id);
+			assert!(!min_amount.is_zero());
+
+			let below_minimum = transfer(currency_id, min_amount - 1);
+			assert!(!BaseCallFilter::filter(&below_minimum));
+			assert_eq!(
+				check.validate(&alice, &below_minimum, &Default::default(), 0),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					runtime_common::XCM_TRANSFER_BELOW_MINIMUM
+				)))
+			);
+
+			let at_minimum = transfer(currency_id, min_amount);
+			assert!(BaseCallFilter::filter(&at_minimum));
+			assert!(check.validate(&alice, &at_minimum, &Default::default(), 0).is_ok());
+		}
+	}
+
+	#[test]
+	fn estimate_xtokens_transfer_fee_works() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let dest_weight: Weight = 4 * UnitWeightCost::get();
+
+			// the fee is charged in the transferred currency
+			let ksm_fee = ksm_per_second() * (dest_weight as u128) / (WEIGHT_PER_SECOND as u128);
+			assert!(ksm_fee > 0);
+			assert_eq!(estimate_xtokens_transfer_fee(KSM, dest_weight), Some(ksm_fee));
+			let kar_fee = kar_per_second() * (dest_weight as u128) / (WEIGHT_PER_SECOND as u128);
+			assert_eq!(estimate_xtokens_transfer_fee(KAR, dest_weight), Some(kar_fee));
+
+			// all the cross-chain tokens have a rate
+			for currency_id in vec![KUSD, LKSM, RENBTC] {
+				assert!(estimate_xtokens_transfer_fee(currency_id, dest_weight).unwrap_or_default() > 0);
+			}
+
+			// scales with the weight bought on the destination
+			assert_eq!(estimate_xtokens_transfer_fee(KSM, 2 * dest_weight), Some(2 * ksm_fee));
+
+			// not a cross-chain asset
+			assert_eq!(
+				estimate_xtokens_transfer_fee(CurrencyId::Token(TokenSymbol::ACA), dest_weight),
+				None
+			);
+		});
+	}
+
+	#[test]
+	fn currency_id_convert_dex_share() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let id: u32 = ParachainInfo::get().into();
+			let kar_kusd_lp =
+				CurrencyId::DexShare(DexShare::Token(TokenSymbol::KAR), DexShare::Token(TokenSymbol::KUSD));
+			let ksm_lksm_lp =
+				CurrencyId::DexShare(DexShare::Token(TokenSymbol::KSM), DexShare::Token(TokenSymbol::LKSM));
+			let kar_aca_lp = CurrencyId::DexShare(DexShare::Token(TokenSymbol::KAR), DexShare::Token(TokenSymbol::ACA));
+
+			assert_eq!(
+				CurrencyIdConvert::convert(kar_kusd_lp),
+				Some(X3(Parent, Parachain(id), GeneralKey(kar_kusd_lp.encode())))
+			);
+			assert_eq!(
+				CurrencyIdConvert::convert(ksm_lksm_lp),
+				Some(X3(Parent, Parachain(id), GeneralKey(ksm_lksm_lp.encode())))
+			);
+			assert_eq!(CurrencyIdConvert::convert(kar_aca_lp), None);
+
+			assert_eq!(
+				CurrencyIdConvert::convert(X3(Parent, Parachain(id), GeneralKey(kar_kusd_lp.encode()))),
+				Some(kar_kusd_lp)
+			);
+			assert_eq!(
+				CurrencyIdConvert::convert(X3(Parent, Parachain(id), GeneralKey(ksm_lksm_lp.encode()))),
+				Some(ksm_lksm_lp)
+			);
+			assert_eq!(
+				CurrencyIdConvert::convert(X3(Parent, Parachain(id), GeneralKey(kar_aca_lp.encode()))),
+				None
+			);
+			assert_eq!(
+				CurrencyIdConvert::convert(X3(Parent, Parachain(id + 1), GeneralKey(kar_kusd_lp.encode()))),
+				None
+			);
+
+			assert_eq!(
+				CurrencyIdConvert::convert(MultiAsset::ConcreteFungible {
+					id: X3(Parent, Parachain(id), GeneralKey(kar_kusd_lp.encode())),
+					amount: 1
+				}),
+				Some(kar_kusd_lp)
+			);
+		});
+	}
+
+	#[test]
+	fn barrier_denies_blocked_xcm_origins() {
+		use frame_support::assert_ok;
+		use xcm_executor::traits::ShouldExecute;
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let blocked = X2(Parent, Parachain(2000));
+			let unblocked = X2(Parent, Parachain(2001));
+			let message = Xcm::<Call>::WithdrawAsset {
+				assets: vec![],
+				effects: vec![],
+			};
+
+			assert_ok!(XcmOriginFilter::block_xcm_origin(Origin::root(), blocked.clone()));
+
+			// weight credit is enough to pass `TakeWeightCredit`
+			assert_eq!(
+				Barrier::should_execute(&blocked, true, &message, 100, &mut 100),
+				Err(())
+			);
+			assert_eq!(
+				Barrier::should_execute(&unblocked, true, &message, 100, &mut 100),
+				Ok(())
+			);
+		});
+	}
+
 	#[test]
 	fn ensure_can_create_contract() {
 		// Ensure that the `ExistentialDeposit` for creating the contract >= account `ExistentialDeposit`.
@@ -2121,4 +2777,41 @@ mod tests {
 			) > 0
 		);
 	}
+
+	#[test]
+	fn evm_gas_to_fee_works() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let gas_limit = 2_100_000;
+			let weight_fee = WeightToFee::calc(&GasToWeight::convert(gas_limit));
+			assert!(weight_fee > 0);
+
+			assert_eq!(evm_gas_to_fee(gas_limit, 0), weight_fee);
+			assert_eq!(evm_gas_to_fee(gas_limit, 2), weight_fee + 2 * gas_limit as Balance);
+
+			// the weight fee follows the transaction payment fee multiplier
+			module_transaction_payment::NextFeeMultiplier::<Runtime>::put(Multiplier::saturating_from_integer(2));
+			assert_eq!(evm_gas_to_fee(gas_limit, 0), 2 * weight_fee);
+		});
+	}
+
+	#[test]
+	fn evm_base_fee_per_gas_rises_with_fee_multiplier() {
+		let target =
+			TargetBlockFullness::get() * RuntimeBlockWeights::get().get(DispatchClass::Normal).max_total.unwrap();
+		// blocks fuller than the target grow the multiplier, see `multiplier_can_grow_from_zero`
+		run_with_system_weight(target * 2, || {
+			let base_fee = evm_base_fee_per_gas();
+			assert_eq!(
+				base_fee,
+				U256::from(WeightToFee::calc(&GasToWeight::convert(EVM_DECIMALS_SCALE)))
+			);
+
+			let multiplier = TransactionPayment::next_fee_multiplier();
+			let next = SlowAdjustingFeeUpdate::<Runtime>::convert(multiplier);
+			assert!(next > multiplier, "{:?} !>= {:?}", next, multiplier);
+
+			module_transaction_payment::NextFeeMultiplier::<Runtime>::put(next);
+			assert!(evm_base_fee_per_gas() > base_fee);
+		})
+	}
 }
@@ -28,6 +28,7 @@ pub mod module_dex;
 pub mod module_emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
+pub mod module_evm_manager;
 pub mod module_homa;
 pub mod module_homa_lite;
 pub mod module_honzon;
@@ -37,6 +38,8 @@ pub mod module_nominees_election;
 pub mod module_prices;
 pub mod module_session_manager;
 pub mod module_transaction_payment;
+pub mod module_vesting_manager;
+pub mod module_xcm_origin_filter;
 
 pub mod orml_auction;
 pub mod orml_authority;
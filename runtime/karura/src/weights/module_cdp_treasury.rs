@@ -52,10 +52,27 @@ impl<T: frame_system::Config> module_cdp_treasury::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
+	fn consolidate_collateral() -> Weight {
+		(92_451_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 	fn set_expected_collateral_auction_size() -> Weight {
 		(24_995_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_dex_liquidation_size_threshold() -> Weight {
+		(24_995_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_collateral_dust_threshold() -> Weight {
+		(24_995_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_surplus_treasury_share() -> Weight {
+		(24_995_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn extract_surplus_to_treasury() -> Weight {
 		(84_873_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
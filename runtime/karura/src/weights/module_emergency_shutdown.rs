@@ -52,13 +52,18 @@ impl<T: frame_system::Config> module_emergency_shutdown::WeightInfo for WeightIn
 			// Standard Error: 138_000
 			.saturating_add((30_028_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().reads(15 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
 	}
+	fn shutdown_collateral() -> Weight {
+		(35_184_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	fn open_collateral_refund() -> Weight {
 		(46_275_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn refund_collaterals(c: u32, ) -> Weight {
 		(127_693_000 as Weight)
@@ -51,8 +51,9 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 		(12_646_000 as Weight)
 			// Standard Error: 53_000
 			.saturating_add((35_093_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(c as Weight)))
 	}
 	fn deposit_dex_share() -> Weight {
 		(158_550_000 as Weight)
@@ -66,7 +67,17 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 	}
 	fn claim_rewards() -> Weight {
 		(128_693_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn compound_dex_rewards() -> Weight {
+		(452_316_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(19 as Weight))
+			.saturating_add(T::DbWeight::get().writes(15 as Weight))
+	}
+	fn force_claim_rewards() -> Weight {
+		(128_693_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	fn update_incentive_rewards(c: u32, ) -> Weight {
@@ -87,9 +98,41 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add((22_646_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn update_reward_start_blocks(c: u32, ) -> Weight {
+		(2_280_000 as Weight)
+			// Standard Error: 18_000
+			.saturating_add((22_646_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_reward_schedules(c: u32, ) -> Weight {
+		(2_280_000 as Weight)
+			// Standard Error: 18_000
+			.saturating_add((22_646_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_pool_reward_currencies(c: u32, ) -> Weight {
+		(2_280_000 as Weight)
+			// Standard Error: 18_000
+			.saturating_add((22_646_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_dex_incentive_boost_bounds() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn update_peg_deviation_boost() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn add_allowance() -> Weight {
 		(90_887_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
+	fn settle_shortfall() -> Weight {
+		(90_887_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
 }
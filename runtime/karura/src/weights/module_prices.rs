@@ -51,8 +51,21 @@ impl<T: frame_system::Config> module_prices::WeightInfo for WeightInfo<T> {
 		(52_278_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 	}
+	fn relock_price() -> Weight {
+		(52_278_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+	}
 	fn unlock_price() -> Weight {
 		(25_135_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_price_source_priority() -> Weight {
+		(25_135_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn reset_price_circuit_breaker() -> Weight {
+		(53_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
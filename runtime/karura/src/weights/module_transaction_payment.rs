@@ -51,6 +51,10 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 		(5_590_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_fee_currency() -> Weight {
+		(5_590_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn on_finalize() -> Weight {
 		(15_914_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
@@ -18,11 +18,12 @@
 
 use crate::{
 	dollar, AccountId, AuctionId, AuctionManager, CdpTreasury, Currencies, CurrencyId, EmergencyShutdown,
-	GetStableCurrencyId, GetStakingCurrencyId, Price, Runtime,
+	GetStableCurrencyId, GetStakingCurrencyId, MaxAuctionsPerBlock, Price, Runtime,
 };
 
 use super::utils::feed_price;
 use frame_benchmarking::account;
+use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
 use module_support::{AuctionManager as AuctionManagerTrait, CDPTreasury};
 use orml_benchmarking::runtime_benchmarks;
@@ -53,7 +54,7 @@ runtime_benchmarks! {
 		feed_price(vec![(STAKING, Price::saturating_from_integer(120))])?;
 
 		// create collateral auction
-		AuctionManager::new_collateral_auction(&funder, STAKING, dollar(STAKING), 100 * dollar(STABLECOIN))?;
+		AuctionManager::new_collateral_auction(&funder, STAKING, dollar(STAKING), 100 * dollar(STABLECOIN), None)?;
 		let auction_id: AuctionId = Default::default();
 
 		// bid collateral auction
@@ -62,6 +63,50 @@ runtime_benchmarks! {
 		// shutdown
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 	}: cancel(RawOrigin::None, auction_id)
+
+	// `cancel_all_collateral_auctions`, worst case:
+	// all auctions have been already bid
+	cancel_all_collateral_auctions {
+		let c in 1 .. 100;
+		let bidder: AccountId = account("bidder", 0, SEED);
+		let funder: AccountId = account("funder", 0, SEED);
+
+		// set balance
+		Currencies::deposit(STABLECOIN, &bidder, c as u128 * 80 * dollar(STABLECOIN))?;
+		Currencies::deposit(STAKING, &funder, c as u128 * dollar(STAKING))?;
+		CdpTreasury::deposit_collateral(&funder, STAKING, c as u128 * dollar(STAKING))?;
+
+		// feed price
+		feed_price(vec![(STAKING, Price::saturating_from_integer(120))])?;
+
+		for auction_id in 0 .. c {
+			// create collateral auction
+			AuctionManager::new_collateral_auction(&funder, STAKING, dollar(STAKING), 100 * dollar(STABLECOIN), None)?;
+
+			// bid collateral auction
+			let _ = AuctionManager::collateral_auction_bid_handler(1, auction_id, (bidder.clone(), 80 * dollar(STABLECOIN)), None);
+		}
+	}: _(RawOrigin::Root, c)
+
+	// `on_initialize`, worst case:
+	// `c` collateral auctions are queued
+	on_initialize {
+		let c in 0 .. MaxAuctionsPerBlock::get();
+		let funder: AccountId = account("funder", 0, SEED);
+
+		// set balance
+		Currencies::deposit(STAKING, &funder, (c as u128 + 1) * dollar(STAKING))?;
+		CdpTreasury::deposit_collateral(&funder, STAKING, (c as u128 + 1) * dollar(STAKING))?;
+
+		// the limit of the block is reached, queue the collateral auctions
+		let now = frame_system::Pallet::<Runtime>::block_number();
+		module_auction_manager::AuctionsCreatedInBlock::<Runtime>::put((now, MaxAuctionsPerBlock::get()));
+		for _ in 0 .. c {
+			AuctionManager::new_collateral_auction(&funder, STAKING, dollar(STAKING), 100 * dollar(STABLECOIN), None)?;
+		}
+	}: {
+		AuctionManager::on_initialize(2);
+	}
 }
 
 #[cfg(test)]
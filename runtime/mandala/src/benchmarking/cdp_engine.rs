@@ -18,13 +18,14 @@
 
 use crate::{
 	dollar, AccountId, Address, Amount, Balance, CdpEngine, CollateralCurrencyIds, CurrencyId,
-	DefaultDebitExchangeRate, Dex, EmergencyShutdown, ExistentialDeposits, GetStableCurrencyId, GetStakingCurrencyId,
-	MaxSwapSlippageCompareToOracle, MinimumDebitValue, Price, Rate, Ratio, Runtime, MILLISECS_PER_BLOCK,
+	DefaultDebitExchangeRate, Dex, EmergencyShutdown, ExistentialDeposits, GetNativeCurrencyId, GetStableCurrencyId,
+	GetStakingCurrencyId, MaxSwapSlippageCompareToOracle, MinimumDebitValue, Price, Rate, Ratio, Runtime,
+	MILLISECS_PER_BLOCK,
 };
 
 use super::utils::{feed_price, set_balance};
 use core::convert::TryInto;
-use frame_benchmarking::account;
+use frame_benchmarking::{account, whitelisted_caller};
 use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
 use module_support::DEXManager;
@@ -38,6 +39,7 @@ use sp_std::prelude::*;
 
 const SEED: u32 = 0;
 
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
 const STAKING: CurrencyId = GetStakingCurrencyId::get();
 
@@ -141,6 +143,12 @@ runtime_benchmarks! {
 	set_global_params {
 	}: _(RawOrigin::Root, Rate::saturating_from_rational(1, 1000000))
 
+	set_global_debit_ceiling {
+	}: _(RawOrigin::Root, Some(1_000_000 * dollar(STABLECOIN)))
+
+	set_liquidation_swap_path {
+	}: _(RawOrigin::Root, STAKING, Some(vec![STAKING, NATIVE, STABLECOIN]))
+
 	// `liquidate` by_auction
 	liquidate_by_auction {
 		let owner: AccountId = account("owner", 0, SEED);
@@ -185,6 +193,50 @@ runtime_benchmarks! {
 		)?;
 	}: liquidate(RawOrigin::None, STAKING, owner_lookup)
 
+	liquidate_by_keeper {
+		let keeper: AccountId = whitelisted_caller();
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let min_debit_value = MinimumDebitValue::get();
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
+		let collateral_price = Price::one();		// 1 USD
+		let min_debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(min_debit_value);
+		let min_debit_amount: Amount = min_debit_amount.unique_saturated_into();
+		let collateral_value = 2 * min_debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(STAKING), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		// set balance
+		set_balance(STAKING, &owner, collateral_amount + ExistentialDeposits::get(&STAKING));
+
+		// feed price
+		feed_price(vec![(STAKING, collateral_price)])?;
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(min_debit_value * 100),
+		)?;
+
+		// adjust position
+		CdpEngine::adjust_position(&owner, STAKING, collateral_amount.try_into().unwrap(), min_debit_amount)?;
+
+		// modify liquidation rate to make the cdp unsafe
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(1000, 100))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		)?;
+	}: _(RawOrigin::Signed(keeper), STAKING, owner_lookup)
+
 	// `liquidate` by dex
 	liquidate_by_dex {
 		let owner: AccountId = account("owner", 0, SEED);
@@ -275,6 +327,45 @@ runtime_benchmarks! {
 		// shutdown
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 	}: _(RawOrigin::None, STAKING, owner_lookup)
+
+	settle_cdps_batch {
+		let c in 1 .. 50;
+		let caller: AccountId = whitelisted_caller();
+		let min_debit_value = MinimumDebitValue::get();
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
+		let collateral_price = Price::one();		// 1 USD
+		let min_debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(min_debit_value);
+		let min_debit_amount: Amount = min_debit_amount.unique_saturated_into();
+		let collateral_value = 2 * min_debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(STAKING), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		// feed price
+		feed_price(vec![(STAKING, collateral_price)])?;
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(min_debit_value * 100 * c as Balance),
+		)?;
+
+		for i in 0 .. c {
+			let owner: AccountId = account("owner", i, SEED);
+
+			// set balance
+			set_balance(STAKING, &owner, collateral_amount + ExistentialDeposits::get(&STAKING));
+
+			// adjust position
+			CdpEngine::adjust_position(&owner, STAKING, collateral_amount.try_into().unwrap(), min_debit_amount)?;
+		}
+
+		// shutdown
+		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
+	}: _(RawOrigin::Signed(caller), STAKING, c, c)
 }
 
 #[cfg(test)]
@@ -22,6 +22,7 @@ use frame_system::RawOrigin;
 use module_support::CDPTreasury;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
+use sp_runtime::Permill;
 
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
 const STAKING: CurrencyId = GetStakingCurrencyId::get();
@@ -31,11 +32,24 @@ runtime_benchmarks! {
 
 	auction_collateral {
 		Currencies::deposit(STAKING, &CdpTreasury::account_id(), 10_000 * dollar(STAKING))?;
-	}: _(RawOrigin::Root, STAKING, 1_000 * dollar(STAKING), 1_000 * dollar(STABLECOIN), true)
+	}: _(RawOrigin::Root, STAKING, 1_000 * dollar(STAKING), 1_000 * dollar(STABLECOIN), Some(900 * dollar(STABLECOIN)), true)
+
+	consolidate_collateral {
+		Currencies::deposit(STAKING, &CdpTreasury::account_id(), 10 * dollar(STAKING))?;
+	}: _(RawOrigin::Root, STAKING)
 
 	set_expected_collateral_auction_size {
 	}: _(RawOrigin::Root, STAKING, 200 * dollar(STAKING))
 
+	set_dex_liquidation_size_threshold {
+	}: _(RawOrigin::Root, STAKING, Some(1_000 * dollar(STAKING)))
+
+	set_collateral_dust_threshold {
+	}: _(RawOrigin::Root, STAKING, dollar(STAKING))
+
+	set_surplus_treasury_share {
+	}: _(RawOrigin::Root, Permill::from_percent(10))
+
 	extract_surplus_to_treasury {
 		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
 	}: _(RawOrigin::Root, 200 * dollar(STABLECOIN))
@@ -17,8 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	AccountId, Balance, Balances, CollatorKickThreshold, CollatorSelection, Event, MaxCandidates, MaxInvulnerables,
-	MinCandidates, Period, Runtime, Session, SessionKeys, System,
+	AccountId, Balance, Balances, CollatorKickThreshold, CollatorSelection, CollatorUnbondCooldown, Event,
+	MaxCandidates, MaxInvulnerables, MinCandidates, Period, Runtime, Session, SessionKeys, System,
 };
 
 use frame_benchmarking::{account, whitelisted_caller};
@@ -163,9 +163,10 @@ runtime_benchmarks! {
 		CollatorSelection::leave_intent(RawOrigin::Signed(leaving.clone()).into())?;
 		Session::on_initialize(Period::get());
 		Session::on_initialize(2*Period::get());
+		System::set_block_number(System::block_number() + CollatorUnbondCooldown::get());
 	}: _(RawOrigin::Signed(leaving))
 
-	// worse case is paying a non-existing candidate account.
+	// the pot is distributed on `end_session`, note_author only accumulates the points.
 	note_author {
 		let c = MaxCandidates::get();
 		module_collator_selection::CandidacyBond::<Runtime>::put(Balances::minimum_balance());
@@ -244,6 +245,10 @@ runtime_benchmarks! {
 			}
 			count += 1;
 		});
+		Balances::make_free_balance_be(
+			&CollatorSelection::account_id(),
+			Balances::minimum_balance().checked_mul(c.into()).unwrap()
+		);
 
 		System::set_block_number(20u32.into());
 
@@ -19,7 +19,7 @@
 use super::utils::{lookup_of_account, set_balance};
 use crate::{
 	dollar, AccountId, Amount, Balance, Currencies, CurrencyId, GetNativeCurrencyId, GetStakingCurrencyId,
-	NativeTokenExistentialDeposit, Runtime,
+	NativeTokenExistentialDeposit, Runtime, TreasuryAccount,
 };
 
 use sp_std::prelude::*;
@@ -83,12 +83,41 @@ runtime_benchmarks! {
 		assert_eq!(<Currencies as MultiCurrency<_>>::total_balance(NATIVE, &to), amount);
 	}
 
+	// `transfer_all` non-native currency
+	transfer_all_non_native_currency {
+		let amount: Balance = 1_000 * dollar(STAKING);
+		let from: AccountId = whitelisted_caller();
+		set_balance(STAKING, &from, amount);
+
+		let to: AccountId = account("to", 0, SEED);
+		let to_lookup = lookup_of_account(to.clone());
+	}: transfer_all(RawOrigin::Signed(from), to_lookup, STAKING, false)
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::total_balance(STAKING, &to), amount);
+	}
+
+	// `transfer_all` native currency in worst case
+	// * will create the `to` account.
+	// * will kill the `from` account.
+	transfer_all_native_currency {
+		let existential_deposit = NativeTokenExistentialDeposit::get();
+		let amount: Balance = existential_deposit.saturating_mul(1000);
+		let from: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &from, amount);
+
+		let to: AccountId = account("to", 0, SEED);
+		let to_lookup = lookup_of_account(to.clone());
+	}: transfer_all(RawOrigin::Signed(from), to_lookup, NATIVE, false)
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::total_balance(NATIVE, &to), amount);
+	}
+
 	// `update_balance` for non-native currency
 	update_balance_non_native_currency {
 		let balance: Balance = 2 * dollar(STAKING);
 		let amount: Amount = balance.unique_saturated_into();
 		let who: AccountId = account("who", 0, SEED);
-		let who_lookup = lookup_of_account(who.clone());
+		let who_lookup = lookup_of_account(who);
 	}: update_balance(RawOrigin::Root, who_lookup, STAKING, amount)
 	verify {
 		assert_eq!(<Currencies as MultiCurrency<_>>::total_balance(STAKING, &who), balance);
@@ -101,7 +130,7 @@ runtime_benchmarks! {
 		let balance: Balance = existential_deposit.saturating_mul(1000);
 		let amount: Amount = balance.unique_saturated_into();
 		let who: AccountId = account("who", 0, SEED);
-		let who_lookup = lookup_of_account(who.clone());
+		let who_lookup = lookup_of_account(who);
 	}: update_balance(RawOrigin::Root, who_lookup, NATIVE, amount)
 	verify {
 		assert_eq!(<Currencies as MultiCurrency<_>>::total_balance(NATIVE, &who), balance);
@@ -114,12 +143,43 @@ runtime_benchmarks! {
 		let balance: Balance = existential_deposit.saturating_mul(1000);
 		let amount: Amount = balance.unique_saturated_into();
 		let who: AccountId = account("who", 0, SEED);
-		let who_lookup = lookup_of_account(who.clone());
+		let who_lookup = lookup_of_account(who);
 		set_balance(NATIVE, &who, balance);
 	}: update_balance(RawOrigin::Root, who_lookup, NATIVE, -amount)
 	verify {
 		assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(NATIVE, &who), 0);
 	}
+
+	// `sweep_dust` in worst case
+	// * every account holds a balance below the existential deposit.
+	sweep_dust {
+		let c in 1 .. 100;
+		let existential_deposit = <Currencies as MultiCurrency<_>>::minimum_balance(STAKING);
+		set_balance(STAKING, &TreasuryAccount::get(), existential_deposit);
+
+		let mut accounts = vec![];
+		for i in 0 .. c {
+			let who: AccountId = account("who", i, SEED);
+			orml_tokens::Accounts::<Runtime>::insert(&who, STAKING, orml_tokens::AccountData {
+				free: existential_deposit.saturating_sub(1),
+				..Default::default()
+			});
+			accounts.push(who);
+		}
+	}: _(RawOrigin::Root, vec![STAKING], accounts)
+
+	freeze_account {
+		let who: AccountId = account("who", 0, SEED);
+		set_balance(STAKING, &who, dollar(STAKING));
+		let who_lookup = lookup_of_account(who);
+	}: _(RawOrigin::Root, STAKING, who_lookup)
+
+	thaw_account {
+		let who: AccountId = account("who", 0, SEED);
+		set_balance(STAKING, &who, dollar(STAKING));
+		let who_lookup = lookup_of_account(who);
+		Currencies::freeze_account(RawOrigin::Root.into(), STAKING, who_lookup.clone())?;
+	}: _(RawOrigin::Root, STAKING, who_lookup)
 }
 
 #[cfg(test)]
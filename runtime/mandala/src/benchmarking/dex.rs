@@ -17,17 +17,18 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, AccountId, Balance, Currencies, CurrencyId, Dex, GetNativeCurrencyId, GetStableCurrencyId, Runtime,
-	TradingPathLimit,
+	dollar, AccountId, Balance, CollateralCurrencyIds, Currencies, CurrencyId, Dex, GetNativeCurrencyId,
+	GetStableCurrencyId, Ratio, Runtime, TradingPathLimit,
 };
 
 use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
 use module_dex::TradingPairStatus;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrencyExtended;
 use primitives::TradingPair;
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::{traits::UniqueSaturatedInto, FixedPointNumber};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -73,6 +74,21 @@ fn inject_liquidity(
 runtime_benchmarks! {
 	{ Runtime, module_dex }
 
+	on_initialize {
+		let c in 0 .. CollateralCurrencyIds::get().len() as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+		let maker: AccountId = account("maker", 0, SEED);
+
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			inject_liquidity(maker.clone(), currency_id, STABLECOIN, 100 * dollar(currency_id), 100 * dollar(STABLECOIN), false)?;
+		}
+
+		Dex::on_initialize(1);
+	}: {
+		Dex::on_initialize(2);
+	}
+
 	// enable a Disabled trading pair
 	enable_trading_pair {
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
@@ -89,6 +105,13 @@ runtime_benchmarks! {
 		}
 	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second())
 
+	set_extended_trading_path_account {
+		let who: AccountId = account("who", 0, SEED);
+	}: _(RawOrigin::Root, who, true)
+
+	set_max_dex_oracle_deviation {
+	}: _(RawOrigin::Root, Some(Ratio::saturating_from_rational(10, 100)))
+
 	// list a Provisioning trading pair
 	list_provisioning {
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
@@ -298,7 +321,7 @@ runtime_benchmarks! {
 		inject_liquidity(maker, trading_pair.first(), trading_pair.second(), 10_000 * dollar(trading_pair.first()), 10_000 * dollar(trading_pair.second()), false)?;
 
 		<Currencies as MultiCurrencyExtended<_>>::update_balance(path[0], &taker, (10_000 * dollar(path[0])).unique_saturated_into())?;
-	}: swap_with_exact_target(RawOrigin::Signed(taker), path.clone(), 10 * dollar(path[path.len() - 1]), 100 * dollar(path[0]))
+	}: swap_with_exact_target(RawOrigin::Signed(taker), path.clone(), 10 * dollar(path[path.len() - 1]), 100 * dollar(path[0]), None)
 }
 
 #[cfg(test)]
@@ -18,7 +18,7 @@
 
 use crate::{
 	dollar, AccountId, CdpTreasury, CollateralCurrencyIds, CurrencyId, EmergencyShutdown, GetStableCurrencyId, Price,
-	Runtime,
+	RefundDelayPeriod, Runtime, System,
 };
 
 use super::utils::{feed_price, set_balance};
@@ -47,8 +47,14 @@ runtime_benchmarks! {
 		feed_price(values)?;
 	}: _(RawOrigin::Root)
 
+	shutdown_collateral {
+		let currency_id = CollateralCurrencyIds::get()[0];
+		feed_price(vec![(currency_id, Price::one())])?;
+	}: _(RawOrigin::Root, currency_id)
+
 	open_collateral_refund {
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
+		System::set_block_number(System::block_number() + RefundDelayPeriod::get());
 	}: _(RawOrigin::Root)
 
 	refund_collaterals {
@@ -70,6 +76,7 @@ runtime_benchmarks! {
 		CdpTreasury::issue_debit(&funder, 1_000 * dollar(STABLECOIN), true)?;
 
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
+		System::set_block_number(System::block_number() + RefundDelayPeriod::get());
 		EmergencyShutdown::open_collateral_refund(RawOrigin::Root.into())?;
 	}: _(RawOrigin::Signed(caller),  1_000 * dollar(STABLECOIN))
 }
@@ -93,6 +93,15 @@ runtime_benchmarks! {
 		whitelist_account!(alice_account);
 	}: _(RawOrigin::Signed(alice_account_id()), contract, bob_address)
 
+	renounce_maintainer {
+		let alice_account = alice_account_id();
+
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+
+		whitelist_account!(alice_account);
+	}: _(RawOrigin::Signed(alice_account_id()), contract)
+
 	deploy {
 		let alice_account = alice_account_id();
 
@@ -111,6 +120,21 @@ runtime_benchmarks! {
 		let contract = deploy_contract(alice_account_id())?;
 	}: _(RawOrigin::Root, contract)
 
+	add_free_deployer {
+		let alice_address = EvmAccounts::eth_address(&alice());
+	}: _(RawOrigin::Root, alice_address)
+
+	remove_free_deployer {
+		let alice_address = EvmAccounts::eth_address(&alice());
+		EVM::add_free_deployer(RawOrigin::Root.into(), alice_address)?;
+	}: _(RawOrigin::Root, alice_address)
+
+	set_per_account_gas_limit {
+	}: _(RawOrigin::Root, Some(1_000_000))
+
+	set_chain_id {
+	}: _(RawOrigin::Root, 1_000)
+
 	enable_contract_development {
 		let alice_account = alice_account_id();
 
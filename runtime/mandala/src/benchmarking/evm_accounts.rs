@@ -22,6 +22,8 @@ use super::utils::set_balance;
 use codec::Encode;
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
+use module_evm_accounts::EvmAddressMapping;
+use module_support::AddressMapping;
 use orml_benchmarking::runtime_benchmarks;
 use sp_io::hashing::keccak_256;
 
@@ -57,6 +59,12 @@ runtime_benchmarks! {
 	claim_default_account {
 		let caller = whitelisted_caller();
 	}: _(RawOrigin::Signed(caller))
+
+	claim_default_evm_address {
+		let caller: AccountId = whitelisted_caller();
+		let eth_address = EvmAddressMapping::<Runtime>::get_default_evm_address(&caller);
+		set_balance(NATIVE, &EvmAddressMapping::<Runtime>::get_account_id(&eth_address), 1_000 * dollar(NATIVE));
+	}: _(RawOrigin::Signed(caller))
 }
 
 #[cfg(test)]
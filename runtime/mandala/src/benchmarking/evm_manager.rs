@@ -0,0 +1,73 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{dollar, CurrencyId, Event, GetNativeCurrencyId, GetStakingCurrencyId, Origin, Runtime, System, EVM};
+
+use super::{evm::alice_account_id, utils::set_balance};
+use frame_support::dispatch::DispatchError;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use primitives::evm::EvmAddress;
+use sp_std::prelude::*;
+
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
+const STAKING: CurrencyId = GetStakingCurrencyId::get();
+
+fn erc20_demo_contract() -> Vec<u8> {
+	let code = include!("../../../../modules/evm-bridge/src/erc20_demo_contract");
+	(2..code.len())
+		.step_by(2)
+		.map(|i| u8::from_str_radix(&code[i..i + 2], 16).unwrap())
+		.collect()
+}
+
+fn deploy_erc20_contract() -> Result<EvmAddress, DispatchError> {
+	let caller = alice_account_id();
+	set_balance(NATIVE, &caller, 1_000_000 * dollar(NATIVE));
+
+	System::set_block_number(1);
+	EVM::create(Origin::signed(caller), erc20_demo_contract(), 0, 1000000000, 1000000000)
+		.map_or_else(|e| Err(e.error), |_| Ok(()))?;
+	let contract = match System::events().last().map(|record| record.event.clone()) {
+		Some(Event::EVM(module_evm::Event::Created(contract))) => contract,
+		_ => return Err("deploy erc20 contract failed".into()),
+	};
+	EVM::deploy_free(RawOrigin::Root.into(), contract).map_or_else(|e| Err(e.error), |_| Ok(()))?;
+
+	Ok(contract)
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_evm_manager }
+
+	set_currency_decimals {
+	}: _(RawOrigin::Root, STAKING, Some(12))
+
+	register_erc20 {
+		let contract = deploy_erc20_contract()?;
+	}: _(RawOrigin::Root, contract)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -17,8 +17,9 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, AccountId, AccumulatePeriod, CollateralCurrencyIds, Currencies, CurrencyId, GetLiquidCurrencyId,
-	GetNativeCurrencyId, GetStableCurrencyId, GetStakingCurrencyId, Incentives, Rate, Rewards, Runtime, System,
+	dollar, AccountId, AccumulatePeriod, Balance, CollateralCurrencyIds, Currencies, CurrencyId, Dex,
+	GetLiquidCurrencyId, GetNativeCurrencyId, GetStableCurrencyId, GetStakingCurrencyId, Incentives, Rate, Ratio,
+	Rewards, Runtime, System, UnreleasedNativeVaultAccountId,
 };
 
 use super::utils::set_balance;
@@ -28,7 +29,8 @@ use frame_system::RawOrigin;
 use module_incentives::PoolId;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
-use primitives::DexShare;
+use primitives::{DexShare, TradingPair};
+use sp_runtime::{traits::One, FixedPointNumber};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -38,6 +40,28 @@ const STAKING: CurrencyId = GetStakingCurrencyId::get();
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
 const LIQUID: CurrencyId = GetLiquidCurrencyId::get();
 
+fn inject_liquidity(
+	maker: AccountId,
+	currency_id_a: CurrencyId,
+	currency_id_b: CurrencyId,
+	max_amount_a: Balance,
+	max_amount_b: Balance,
+) -> Result<(), &'static str> {
+	set_balance(currency_id_a, &maker, max_amount_a);
+	set_balance(currency_id_b, &maker, max_amount_b);
+	let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id_a, currency_id_b);
+	Dex::add_liquidity(
+		RawOrigin::Signed(maker).into(),
+		currency_id_a,
+		currency_id_b,
+		max_amount_a,
+		max_amount_b,
+		Default::default(),
+		false,
+	)?;
+	Ok(())
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_incentives }
 
@@ -89,6 +113,30 @@ runtime_benchmarks! {
 		Rewards::accumulate_reward(&pool_id, 80 * dollar(native_currency_id));
 	}: _(RawOrigin::Signed(caller), pool_id)
 
+	force_claim_rewards {
+		let caller: AccountId = account("caller", 0, SEED);
+		let pool_id = PoolId::LoansIncentive(STAKING);
+		let native_currency_id = GetNativeCurrencyId::get();
+
+		Rewards::add_share(&caller, &pool_id, 100);
+		Currencies::deposit(native_currency_id, &Incentives::account_id(), 80 * dollar(native_currency_id))?;
+		Rewards::accumulate_reward(&pool_id, 80 * dollar(native_currency_id));
+	}: _(RawOrigin::Root, caller, pool_id)
+
+	compound_dex_rewards {
+		let caller: AccountId = whitelisted_caller();
+		let maker: AccountId = account("maker", 0, SEED);
+		let lp_currency_id = TradingPair::from_currency_ids(STABLECOIN, STAKING).unwrap().dex_share_currency_id();
+		let pool_id = PoolId::DexIncentive(lp_currency_id);
+
+		inject_liquidity(maker.clone(), NATIVE, STABLECOIN, 10_000 * dollar(NATIVE), 10_000 * dollar(STABLECOIN))?;
+		inject_liquidity(maker, STABLECOIN, STAKING, 10_000 * dollar(STABLECOIN), 10_000 * dollar(STAKING))?;
+
+		Rewards::add_share(&caller, &pool_id, 100);
+		Currencies::deposit(NATIVE, &Incentives::account_id(), 80 * dollar(NATIVE))?;
+		Rewards::accumulate_reward(&pool_id, 80 * dollar(NATIVE));
+	}: _(RawOrigin::Signed(caller), lp_currency_id, 0)
+
 	update_incentive_rewards {
 		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
 		let currency_ids = CollateralCurrencyIds::get();
@@ -130,6 +178,51 @@ runtime_benchmarks! {
 		}
 	}: _(RawOrigin::Root, updates)
 
+	update_reward_start_blocks {
+		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+		let mut updates = vec![];
+
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			updates.push((PoolId::LoansIncentive(currency_id), Some(100)));
+		}
+	}: _(RawOrigin::Root, updates)
+
+	update_reward_schedules {
+		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+		let mut updates = vec![];
+
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			updates.push((PoolId::LoansIncentive(currency_id), Some((100, 200))));
+		}
+	}: _(RawOrigin::Root, updates)
+
+	update_pool_reward_currencies {
+		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+		let mut updates = vec![];
+
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			updates.push((PoolId::LoansIncentive(currency_id), Some(STABLECOIN)));
+		}
+	}: _(RawOrigin::Root, updates)
+
+	update_dex_incentive_boost_bounds {
+	}: _(RawOrigin::Root, Some((Rate::saturating_from_rational(1, 2), Rate::one())))
+
+	update_peg_deviation_boost {
+	}: _(RawOrigin::Root, Some((Ratio::saturating_from_rational(5, 100), Rate::saturating_from_integer(2))))
+
+	settle_shortfall {
+		let pool_id = PoolId::LoansIncentive(STAKING);
+		set_balance(NATIVE, &UnreleasedNativeVaultAccountId::get(), 100 * dollar(NATIVE));
+		module_incentives::ShortfallRewards::<Runtime>::insert(&pool_id, 100 * dollar(NATIVE));
+	}: _(RawOrigin::Root, pool_id)
+
 	add_allowance {
 		let caller: AccountId = whitelisted_caller();
 		set_balance(LIQUID, &caller, 10_000 * dollar(STABLECOIN));
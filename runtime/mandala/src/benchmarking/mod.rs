@@ -32,6 +32,7 @@ pub mod dex;
 pub mod emergency_shutdown;
 pub mod evm;
 pub mod evm_accounts;
+pub mod evm_manager;
 pub mod homa;
 pub mod honzon;
 pub mod incentives;
@@ -39,6 +40,7 @@ pub mod nominees_election;
 pub mod prices;
 pub mod session_manager;
 pub mod transaction_payment;
+pub mod vesting_manager;
 
 // orml benchmarking
 pub mod auction;
@@ -20,6 +20,7 @@ use crate::{CurrencyId, GetStableCurrencyId, Origin, Price, Prices, Runtime};
 
 use super::utils::feed_price;
 use frame_system::RawOrigin;
+use module_prices::PriceSourceKind;
 use orml_benchmarking::runtime_benchmarks;
 use sp_runtime::traits::One;
 use sp_std::vec;
@@ -39,6 +40,21 @@ runtime_benchmarks! {
 		feed_price(vec![(STAKING, Price::one())])?;
 		Prices::lock_price(Origin::root(), STAKING)?;
 	}: _(RawOrigin::Root, STAKING)
+
+	relock_price {
+		// feed price
+		feed_price(vec![(STAKING, Price::one())])?;
+		Prices::lock_price(Origin::root(), STAKING)?;
+	}: _(RawOrigin::Root, STAKING)
+
+	set_price_source_priority {
+	}: _(RawOrigin::Root, STAKING, Some(vec![PriceSourceKind::Dex, PriceSourceKind::Locked, PriceSourceKind::Oracle]))
+
+	reset_price_circuit_breaker {
+		// feed price
+		feed_price(vec![(STAKING, Price::one())])?;
+		module_prices::CircuitBreakerPrice::<Runtime>::insert(STAKING, Price::one());
+	}: _(RawOrigin::Root, STAKING)
 }
 
 #[cfg(test)]
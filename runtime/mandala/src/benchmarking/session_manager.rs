@@ -44,6 +44,22 @@ runtime_benchmarks! {
 		assert_last_event(module_session_manager::Event::ScheduledSessionDuration(10,1,100).into());
 	}
 
+	schedule_session_duration_ramp {
+		let n in 2 .. module_session_manager::MAX_SESSION_DURATION_RAMP_STEPS + 1;
+		System::set_block_number(2u32.into());
+		module_session_manager::SessionDuration::<Runtime>::put(10);
+	}: _(RawOrigin::Root, 1, n, 10, 100)
+
+	force_new_session {
+		let c in 2 .. module_session_manager::MAX_SESSION_DURATION_RAMP_STEPS + 1;
+		System::set_block_number(2u32.into());
+		module_session_manager::SessionDuration::<Runtime>::put(10);
+		SessionManager::schedule_session_duration_ramp(RawOrigin::Root.into(), 1, c, 10, 100)?;
+	}: _(RawOrigin::Root, c)
+	verify {
+		assert_last_event(module_session_manager::Event::NewSessionForced(c).into());
+	}
+
 	on_initialize_skip {
 		System::set_block_number(2u32.into());
 		module_session_manager::SessionDuration::<Runtime>::put(10);
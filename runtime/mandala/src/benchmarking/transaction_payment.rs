@@ -36,6 +36,13 @@ runtime_benchmarks! {
 		assert_eq!(TransactionPayment::alternative_fee_swap_path(&caller).unwrap().into_inner(), vec![STABLECOIN, NATIVECOIN]);
 	}
 
+	set_fee_currency {
+		let caller: AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), Some(STABLECOIN))
+	verify {
+		assert_eq!(TransactionPayment::account_fee_currency(&caller), Some(STABLECOIN));
+	}
+
 	on_finalize {
 	}: {
 		TransactionPayment::on_finalize(System::block_number());
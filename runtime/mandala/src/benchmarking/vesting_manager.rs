@@ -0,0 +1,93 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	get_treasury_account,
+	utils::{lookup_of_account, set_balance},
+};
+use crate::{
+	dollar, AccountId, Balance, BlockNumber, Currencies, CurrencyId, GetNativeCurrencyId, MaxVestingSchedules,
+	MinVestedTransfer, Runtime, Vesting,
+};
+
+use sp_std::prelude::*;
+
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+use orml_vesting::VestingSchedule;
+
+pub type Schedule = VestingSchedule<BlockNumber, Balance>;
+
+const SEED: u32 = 0;
+
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
+
+runtime_benchmarks! {
+	{ Runtime, module_vesting_manager }
+
+	merge_and_claim {
+		let i in 1 .. MaxVestingSchedules::get();
+
+		let schedule = Schedule {
+			start: 0,
+			period: 2,
+			period_count: 3,
+			per_period: MinVestedTransfer::get(),
+		};
+
+		let from: AccountId = get_treasury_account();
+		// extra 1 dollar to pay fees
+		set_balance(NATIVE, &from, schedule.total_amount().unwrap() * i as u128 + dollar(NATIVE));
+
+		let to: AccountId = whitelisted_caller();
+		let to_lookup = lookup_of_account(to.clone());
+
+		for _ in 0..i {
+			Vesting::vested_transfer(RawOrigin::Signed(from.clone()).into(), to_lookup.clone(), schedule.clone())?;
+		}
+	}: _(RawOrigin::Signed(to.clone()))
+	verify {
+		assert_eq!(Vesting::vesting_schedules(&to).len(), 1);
+	}
+
+	vested_transfer_with_cliff {
+		let amount = 3 * dollar(NATIVE);
+
+		let from: AccountId = get_treasury_account();
+		// extra 1 dollar to pay fees
+		set_balance(NATIVE, &from, amount + dollar(NATIVE));
+
+		let to: AccountId = account("to", 0, SEED);
+		let to_lookup = lookup_of_account(to.clone());
+	}: _(RawOrigin::Signed(from), to_lookup, 10, 2, 3, amount)
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::total_balance(NATIVE, &to), amount);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -48,8 +48,9 @@ pub use frame_support::{
 };
 use frame_system::{EnsureRoot, RawOrigin};
 use hex_literal::hex;
+use module_cdp_engine::RiskManagementParams;
 use module_currencies::{BasicCurrencyAdapter, Currency};
-use module_evm::{CallInfo, CreateInfo};
+use module_evm::{AccessListItem, CallInfo, CreateInfo};
 use module_evm_accounts::EvmAddressMapping;
 pub use module_evm_manager::EvmCurrencyIdMapping;
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
@@ -60,11 +61,12 @@ use orml_traits::{
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160};
+use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160, U256};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdConversion, BadOrigin, BlakeTwo256, Block as BlockT, Convert, SaturatedConversion, StaticLookup, Zero,
+		AccountIdConversion, BadOrigin, BlakeTwo256, Block as BlockT, BlockNumberProvider, Convert,
+		SaturatedConversion, StaticLookup, Zero,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, DispatchResult, FixedPointNumber,
@@ -110,7 +112,7 @@ pub use primitives::{
 	Signature, TokenSymbol, TradingPair,
 };
 pub use runtime_common::{
-	cent, dollar, microcent, millicent, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
+	cent, dollar, microcent, millicent, CheckXcmTransferAmount, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
 	EnsureRootOrAllTechnicalCommittee, EnsureRootOrHalfFinancialCouncil, EnsureRootOrHalfGeneralCouncil,
 	EnsureRootOrHalfHomaCouncil, EnsureRootOrOneThirdsTechnicalCommittee, EnsureRootOrThreeFourthsGeneralCouncil,
 	EnsureRootOrTwoThirdsGeneralCouncil, EnsureRootOrTwoThirdsTechnicalCommittee, ExchangeRate,
@@ -118,7 +120,8 @@ pub use runtime_common::{
 	GeneralCouncilMembershipInstance, HomaCouncilInstance, HomaCouncilMembershipInstance, OffchainSolutionWeightLimit,
 	OperatorMembershipInstanceAcala, OperatorMembershipInstanceBand, Price, ProxyType, Rate, Ratio,
 	RelaychainBlockNumberProvider, RuntimeBlockLength, RuntimeBlockWeights, SystemContractsFilter,
-	TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance, TimeStampedPrice, ACA, AUSD, DOT, LDOT, RENBTC,
+	TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance, TimeStampedPrice, ACA, AUSD, DOT,
+	EVM_DECIMALS_SCALE, LDOT, RENBTC,
 };
 
 mod authority;
@@ -200,6 +203,11 @@ parameter_types! {
 pub struct BaseCallFilter;
 impl Filter<Call> for BaseCallFilter {
 	fn filter(call: &Call) -> bool {
+		// reject cross-chain transfers whose amount can't cover the fees
+		if !XcmTransferAboveMinimum::filter(call) {
+			return false;
+		}
+
 		!matches!(call, Call::Democracy(pallet_democracy::Call::propose(..)),)
 	}
 }
@@ -274,6 +282,7 @@ parameter_types! {
 	pub const MaxInvulnerables: u32 = 50;
 	pub const KickPenaltySessionLength: u32 = 8;
 	pub const CollatorKickThreshold: Permill = Permill::from_percent(50);
+	pub const CollatorUnbondCooldown: BlockNumber = 7 * DAYS;
 }
 
 impl module_collator_selection::Config for Runtime {
@@ -287,6 +296,7 @@ impl module_collator_selection::Config for Runtime {
 	type MaxInvulnerables = MaxInvulnerables;
 	type KickPenaltySessionLength = KickPenaltySessionLength;
 	type CollatorKickThreshold = CollatorKickThreshold;
+	type UnbondCooldown = CollatorUnbondCooldown;
 	type WeightInfo = weights::module_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -729,7 +739,7 @@ parameter_types! {
 type AcalaDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type Event = Event;
-	type OnNewData = ();
+	type OnNewData = Prices;
 	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
@@ -743,7 +753,7 @@ impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 type BandDataProvider = orml_oracle::Instance2;
 impl orml_oracle::Config<BandDataProvider> for Runtime {
 	type Event = Event;
-	type OnNewData = ();
+	type OnNewData = Prices;
 	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, BandDataProvider>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
@@ -791,6 +801,8 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub MaxPriceMovePerBlock: Ratio = Ratio::saturating_from_rational(30, 100);
+	pub const DexPriceTwapWindow: u32 = 30 * MINUTES;
 }
 
 impl module_prices::Config for Runtime {
@@ -805,6 +817,8 @@ impl module_prices::Config for Runtime {
 	type DEX = Dex;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
+	type MaxPriceMovePerBlock = MaxPriceMovePerBlock;
+	type DexPriceTwapWindow = DexPriceTwapWindow;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -828,6 +842,10 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = weights::module_currencies::WeightInfo<Runtime>;
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type EVMBridge = EVMBridge;
+	type SweepOrigin = EnsureRootOrHalfGeneralCouncil;
+	type TreasuryAccount = TreasuryAccount;
+	type FreezeOrigin = EnsureRootOrHalfGeneralCouncil;
+	type PreTransferFilter = ();
 }
 
 pub struct EnsureRootOrTreasury;
@@ -869,6 +887,11 @@ impl orml_vesting::Config for Runtime {
 	type BlockNumberProvider = RelaychainBlockNumberProvider<Runtime>;
 }
 
+impl module_vesting_manager::Config for Runtime {
+	type Event = Event;
+	type WeightInfo = weights::module_vesting_manager::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
 	pub const MaxScheduledPerBlock: u32 = 50;
@@ -904,8 +927,12 @@ impl orml_gradually_update::Config for Runtime {
 
 parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(2, 100);
+	pub MinimumIncrementCloseMultiplier: Rate = Rate::saturating_from_integer(1);
 	pub const AuctionTimeToClose: BlockNumber = 15 * MINUTES;
 	pub const AuctionDurationSoftCap: BlockNumber = 2 * HOURS;
+	pub const AuctionExtensionWindow: BlockNumber = 10 * MINUTES;
+	pub const MaxAuctionExtensions: u32 = 8;
+	pub const MaxAuctionsPerBlock: u32 = 100;
 }
 
 impl module_auction_manager::Config for Runtime {
@@ -913,14 +940,19 @@ impl module_auction_manager::Config for Runtime {
 	type Currency = Currencies;
 	type Auction = Auction;
 	type MinimumIncrementSize = MinimumIncrementSize;
+	type MinimumIncrementCloseMultiplier = MinimumIncrementCloseMultiplier;
 	type AuctionTimeToClose = AuctionTimeToClose;
 	type AuctionDurationSoftCap = AuctionDurationSoftCap;
+	type AuctionExtensionWindow = AuctionExtensionWindow;
+	type MaxAuctionExtensions = MaxAuctionExtensions;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type DEX = Dex;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -967,6 +999,7 @@ where
 			frame_system::CheckWeight::<Runtime>::new(),
 			module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
 			module_evm::SetEvmOrigin::<Runtime>::new(),
+			CheckXcmTransferAmount::<Runtime, XcmTransferAboveMinimum>::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -999,7 +1032,9 @@ parameter_types! {
 	pub DefaultDebitExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(5, 100);
 	pub MinimumDebitValue: Balance = dollar(AUSD);
+	pub MinimumCollateralValue: Balance = dollar(AUSD);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(5, 100);
+	pub KeeperBountyRate: Rate = Rate::saturating_from_rational(10, 100);
 }
 
 impl module_cdp_engine::Config for Runtime {
@@ -1010,10 +1045,12 @@ impl module_cdp_engine::Config for Runtime {
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
 	type DefaultLiquidationPenalty = DefaultLiquidationPenalty;
 	type MinimumDebitValue = MinimumDebitValue;
+	type MinimumCollateralValue = MinimumCollateralValue;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
+	type KeeperBountyRate = KeeperBountyRate;
 	type UnsignedPriority = runtime_common::CdpEngineUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type UnixTime = Timestamp;
@@ -1031,12 +1068,17 @@ impl module_honzon::Config for Runtime {
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const RefundDelayPeriod: BlockNumber = 10 * MINUTES;
+}
+
 impl module_emergency_shutdown::Config for Runtime {
 	type Event = Event;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type PriceSource = Prices;
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
+	type RefundDelayPeriod = RefundDelayPeriod;
 	type ShutdownOrigin = EnsureRootOrHalfGeneralCouncil;
 	type WeightInfo = weights::module_emergency_shutdown::WeightInfo<Runtime>;
 }
@@ -1044,6 +1086,10 @@ impl module_emergency_shutdown::Config for Runtime {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 1000);	// 0.1%
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxTradingPathLimit: u32 = 6;
+	pub const MaxBatchSwaps: u32 = 10;
+	pub const MinimumLiquidity: Balance = 1_000;
+	pub const MaxTwapWindow: BlockNumber = HOURS;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, ACA).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -1057,9 +1103,14 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPathLimit = MaxTradingPathLimit;
+	type MaxBatchSwaps = MaxBatchSwaps;
+	type MinimumLiquidity = MinimumLiquidity;
 	type PalletId = DEXPalletId;
+	type MaxTwapWindow = MaxTwapWindow;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type DEXIncentives = Incentives;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 }
@@ -1079,12 +1130,15 @@ impl module_cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = HonzonTreasuryAccount;
+	type EmergencyShutdown = EmergencyShutdown;
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	// Sort by fee charge order
 	pub DefaultFeeSwapPathList: Vec<Vec<CurrencyId>> = vec![vec![AUSD, ACA], vec![AUSD, LDOT], vec![AUSD, DOT], vec![AUSD, RENBTC]];
+	// The share of tips goes to the block author
+	pub const TipAuthorShare: Percent = Percent::from_percent(50);
 }
 
 type NegativeImbalance = <Balances as PalletCurrency<AccountId>>::NegativeImbalance;
@@ -1093,9 +1147,16 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
 	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
 		if let Some(mut fees) = fees_then_tips.next() {
 			if let Some(tips) = fees_then_tips.next() {
-				tips.merge_into(&mut fees);
+				// for tips, `TipAuthorShare` to block author, the rest is handled as fees.
+				// if the author account doesn't exist, its share is handled as fees too.
+				let to_author_amount = TipAuthorShare::get() * tips.peek();
+				let (to_author, rest) = tips.split(to_author_amount);
+				if let Err(to_author) = Balances::resolve_into_existing(&Authorship::author(), to_author) {
+					to_author.merge_into(&mut fees);
+				}
+				rest.merge_into(&mut fees);
 			}
-			// for fees and tips, 80% to treasury, 20% to collator-selection pot.
+			// for fees and the rest of tips, 80% to treasury, 20% to collator-selection pot.
 			let split = fees.ration(80, 20);
 			Treasury::on_unbalanced(split.0);
 
@@ -1133,8 +1194,11 @@ impl module_evm_accounts::Config for Runtime {
 }
 
 impl module_evm_manager::Config for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type UpdateOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_evm_manager::WeightInfo<Runtime>;
 }
 
 impl orml_rewards::Config for Runtime {
@@ -1146,21 +1210,27 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const MaxPoolsPerBlock: u32 = 100;
+	pub const ExpectedMaxPools: u32 = 500;
 }
 
 impl module_incentives::Config for Runtime {
 	type Event = Event;
 	type RelaychainAccountId = AccountId;
 	type NativeRewardsSource = UnreleasedNativeVaultAccountId;
+	type AlternativeRewardsSource = TreasuryAccount;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type StableCurrencyId = GetStableCurrencyId;
 	type LiquidCurrencyId = GetLiquidCurrencyId;
 	type AccumulatePeriod = AccumulatePeriod;
+	type MaxPoolsPerBlock = MaxPoolsPerBlock;
+	type ExpectedMaxPools = ExpectedMaxPools;
 	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
 	type CDPTreasury = CdpTreasury;
 	type Currency = Currencies;
 	type DEX = Dex;
 	type EmergencyShutdown = EmergencyShutdown;
+	type PriceSource = Prices;
 	type PalletId = IncentivesPalletId;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
@@ -1225,6 +1295,7 @@ parameter_types! {
 	pub RelaychainSovereignSubAccount: MultiLocation = create_x2_parachain_multilocation(RELAYCHAIN_SUB_ACCOUNT_ID);
 	pub MaxRewardPerEra: Permill = Permill::from_rational(411u32, 1_000_000u32); // 15% / 365 = 0.0004109
 	pub MintFee: Balance = millicent(DOT);
+	pub const RelaychainEraLength: BlockNumber = 14_400; // 1 day in relaychain blocks
 }
 impl module_homa_lite::Config for Runtime {
 	type Event = Event;
@@ -1239,6 +1310,8 @@ impl module_homa_lite::Config for Runtime {
 	type DefaultExchangeRate = DefaultExchangeRate;
 	type MaxRewardPerEra = MaxRewardPerEra;
 	type MintFee = MintFee;
+	type RelaychainBlockNumber = RelaychainBlockNumberProvider<Runtime>;
+	type RelaychainEraLength = RelaychainEraLength;
 }
 
 parameter_types! {
@@ -1297,6 +1370,7 @@ impl module_nft::Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type BlockNumberProvider = RelaychainBlockNumberProvider<Runtime>;
 	type WeightInfo = weights::module_nft::WeightInfo<Runtime>;
 }
 
@@ -1308,8 +1382,8 @@ parameter_types! {
 impl orml_nft::Config for Runtime {
 	type ClassId = u32;
 	type TokenId = u64;
-	type ClassData = module_nft::ClassData<Balance>;
-	type TokenData = module_nft::TokenData<Balance>;
+	type ClassData = module_nft::ClassData<Balance, AccountId>;
+	type TokenData = module_nft::TokenData<Balance, BlockNumber>;
 	type MaxClassMetadata = MaxClassMetadata;
 	type MaxTokenMetadata = MaxTokenMetadata;
 }
@@ -1353,7 +1427,9 @@ impl InstanceFilter<Call> for ProxyType {
 				matches!(
 					c,
 					Call::Dex(module_dex::Call::swap_with_exact_supply(..))
+						| Call::Dex(module_dex::Call::swap_with_exact_supply_and_deadline(..))
 						| Call::Dex(module_dex::Call::swap_with_exact_target(..))
+						| Call::Dex(module_dex::Call::batch_swap(..))
 				)
 			}
 			ProxyType::Loan => {
@@ -1363,6 +1439,17 @@ impl InstanceFilter<Call> for ProxyType {
 						| Call::Honzon(module_honzon::Call::close_loan_has_debit_by_dex(..))
 				)
 			}
+			ProxyType::DemocracyVoteOnly => {
+				matches!(
+					c,
+					Call::Democracy(pallet_democracy::Call::vote(..))
+						| Call::Democracy(pallet_democracy::Call::remove_vote(..))
+						| Call::Democracy(pallet_democracy::Call::unlock(..))
+				)
+			}
+			ProxyType::HomaLite => {
+				matches!(c, Call::HomaLite(module_homa_lite::Call::mint(..)))
+			}
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -1370,6 +1457,7 @@ impl InstanceFilter<Call> for ProxyType {
 			(x, y) if x == y => true,
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
+			(ProxyType::Governance, ProxyType::DemocracyVoteOnly) => true,
 			_ => false,
 		}
 	}
@@ -1466,6 +1554,8 @@ pub type OraclePrecompile = runtime_common::OraclePrecompile<
 	EvmAddressMapping<Runtime>,
 	EvmCurrencyIdMapping<Runtime>,
 	module_prices::RealTimePriceProvider<Runtime>,
+	AggregatedDataProvider,
+	Timestamp,
 >;
 pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 	AccountId,
@@ -1713,11 +1803,16 @@ impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
 	fn convert(id: CurrencyId) -> Option<MultiLocation> {
 		use CurrencyId::Token;
 		use TokenSymbol::*;
-		match id {
+		let location = match id {
 			Token(DOT) => Some(X1(Parent)),
 			Token(ACA) | Token(AUSD) | Token(LDOT) | Token(RENBTC) => Some(native_currency_location(id)),
 			_ => None,
-		}
+		};
+		// every cross-chain currency must be converted back from its location
+		debug_assert!(location
+			.clone()
+			.map_or(true, |location| Self::convert(location) == Some(id)));
+		location
 	}
 }
 impl Convert<MultiLocation, Option<CurrencyId>> for CurrencyIdConvert {
@@ -1752,6 +1847,12 @@ impl Convert<MultiAsset, Option<CurrencyId>> for CurrencyIdConvert {
 	}
 }
 
+/// Whether `id` is a cross-chain currency which converts to a `MultiLocation` and back to itself
+/// through `CurrencyIdConvert`.
+pub fn assert_currency_roundtrip(id: CurrencyId) -> bool {
+	CurrencyIdConvert::convert(id).map_or(false, |location| CurrencyIdConvert::convert(location) == Some(id))
+}
+
 parameter_types! {
 	pub SelfLocation: MultiLocation = X2(Parent, Parachain(ParachainInfo::get().into()));
 }
@@ -1770,6 +1871,33 @@ parameter_types! {
 	pub const BaseXcmWeight: Weight = 100_000_000;
 }
 
+parameter_type_with_key! {
+	pub MinXcmTransferAmount: |currency_id: CurrencyId| -> Balance {
+		match currency_id {
+			CurrencyId::Token(TokenSymbol::DOT) => cent(*currency_id),
+			CurrencyId::Token(TokenSymbol::ACA) => 10 * cent(*currency_id),
+			CurrencyId::Token(TokenSymbol::AUSD) => 10 * cent(*currency_id),
+			_ => Zero::zero(),
+		}
+	};
+}
+
+/// Rejects the cross-chain transfers below the `MinXcmTransferAmount` of the
+/// currency, with a clear error by `CheckXcmTransferAmount` and by the
+/// `BaseCallFilter` for the nested calls.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct XcmTransferAboveMinimum;
+impl Filter<Call> for XcmTransferAboveMinimum {
+	fn filter(call: &Call) -> bool {
+		match call {
+			Call::XTokens(orml_xtokens::Call::transfer(currency_id, amount, ..)) => {
+				*amount >= MinXcmTransferAmount::get(currency_id)
+			}
+			_ => true,
+		}
+	}
+}
+
 impl orml_xtokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -1813,6 +1941,7 @@ pub type SignedExtra = (
 	frame_system::CheckWeight<Runtime>,
 	module_transaction_payment::ChargeTransactionPayment<Runtime>,
 	module_evm::SetEvmOrigin<Runtime>,
+	CheckXcmTransferAmount<Runtime, XcmTransferAboveMinimum>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -1824,6 +1953,32 @@ pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExt
 pub type Executive =
 	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPallets, ()>;
 
+/// The balance of `who` still locked by the vesting schedules at the current relay chain
+/// block number, as vesting uses `RelaychainBlockNumberProvider`.
+pub fn vesting_locked_balance(who: &AccountId) -> Balance {
+	let now = RelaychainBlockNumberProvider::<Runtime>::current_block_number();
+	Vesting::vesting_schedules(who)
+		.iter()
+		.fold(Zero::zero(), |locked: Balance, schedule| {
+			locked.saturating_add(schedule.locked_amount(now))
+		})
+}
+
+/// Convert an EVM `gas_limit` into the native fee charged for it: the gas is converted by
+/// `GasToWeight` and priced by `module_transaction_payment` (`WeightToFee` adjusted by the
+/// next fee multiplier). `gas_price` is charged per unit of gas on top, as a tip.
+pub fn evm_gas_to_fee(gas_limit: u64, gas_price: u64) -> Balance {
+	let weight_fee = <TransactionPayment as Convert<Weight, Balance>>::convert(GasToWeight::convert(gas_limit));
+	let tip = Balance::from(gas_limit).saturating_mul(gas_price.into());
+	weight_fee.saturating_add(tip)
+}
+
+/// Synthetic EIP-1559 base fee reported to EVM tooling: the fee `evm_gas_to_fee` charges per unit
+/// of gas at the next fee multiplier, in 18 decimals. It's informational only.
+pub fn evm_base_fee_per_gas() -> U256 {
+	U256::from(evm_gas_to_fee(EVM_DECIMALS_SCALE, 0))
+}
+
 #[allow(clippy::large_enum_variant)]
 construct_runtime! {
 	pub enum Runtime where
@@ -1842,6 +1997,7 @@ construct_runtime! {
 		Currencies: module_currencies::{Pallet, Call, Event<T>} = 12,
 		Vesting: orml_vesting::{Pallet, Storage, Call, Event<T>, Config<T>} = 13,
 		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage} = 14,
+		VestingManager: module_vesting_manager::{Pallet, Call, Event<T>} = 15,
 
 		// Treasury
 		Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>} = 20,
@@ -1905,8 +2061,8 @@ construct_runtime! {
 		HomaLite: module_homa_lite::{Pallet, Call, Storage, Event<T>} = 135,
 
 		// Acala Other
-		Incentives: module_incentives::{Pallet, Storage, Call, Event<T>} = 140,
-		NFT: module_nft::{Pallet, Call, Event<T>} = 141,
+		Incentives: module_incentives::{Pallet, Storage, Call, Config, Event<T>} = 140,
+		NFT: module_nft::{Pallet, Call, Storage, Config, Event<T>} = 141,
 		AirDrop: module_airdrop::{Pallet, Call, Storage, Event<T>, Config<T>} = 142,
 
 		// Ecosystem modules
@@ -1933,7 +2089,7 @@ construct_runtime! {
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>} = 180,
 		EVMBridge: module_evm_bridge::{Pallet} = 181,
 		EvmAccounts: module_evm_accounts::{Pallet, Call, Storage, Event<T>} = 182,
-		EvmManager: module_evm_manager::{Pallet, Storage} = 183,
+		EvmManager: module_evm_manager::{Pallet, Call, Storage, Event<T>} = 183,
 
 		// Collator support. the order of these 4 are important and shall not change.
 		Authorship: pallet_authorship::{Pallet, Call, Storage} = 190,
@@ -2047,6 +2203,36 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::CallCostApi<
+		Block,
+		Call,
+		Balance,
+	> for Runtime {
+		fn estimate_call_cost(call: Call, len: u32) -> (Weight, Balance) {
+			TransactionPayment::estimate_call_cost(call, len)
+		}
+	}
+
+	impl module_dex_rpc_runtime_api::DexApi<
+		Block,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn quote_swap(path: Vec<CurrencyId>, supply_amount: Balance) -> Option<(Balance, Balance)> {
+			Dex::quote_swap(&path, supply_amount)
+		}
+	}
+
+	impl vesting_rpc_runtime_api::VestingApi<
+		Block,
+		AccountId,
+		Balance,
+	> for Runtime {
+		fn locked_balance(who: AccountId) -> Balance {
+			vesting_locked_balance(&who)
+		}
+	}
+
 	impl orml_oracle_rpc_runtime_api::OracleApi<
 		Block,
 		DataProviderId,
@@ -2084,6 +2270,41 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_evm_accounts_rpc_runtime_api::EvmAccountsApi<Block, AccountId> for Runtime {
+		fn evm_address_of(who: AccountId) -> Option<H160> {
+			EvmAccounts::evm_address_of(&who)
+		}
+	}
+
+	impl module_cdp_engine_rpc_runtime_api::CdpEngineApi<Block, AccountId, RiskManagementParams> for Runtime {
+		fn unsafe_cdps(currency_id: CurrencyId, max: u32) -> Vec<AccountId> {
+			CdpEngine::unsafe_cdps(currency_id, max)
+		}
+
+		fn simulate_collateral_ratio(
+			currency_id: CurrencyId,
+			collateral_adjustment: Amount,
+			debit_adjustment: Amount,
+			who: AccountId,
+		) -> Option<Ratio> {
+			CdpEngine::simulate_collateral_ratio(currency_id, collateral_adjustment, debit_adjustment, &who)
+		}
+
+		fn debit_exchange_rate(currency_id: CurrencyId) -> ExchangeRate {
+			CdpEngine::get_debit_exchange_rate(currency_id)
+		}
+
+		fn all_collateral_params() -> Vec<(CurrencyId, RiskManagementParams)> {
+			CdpEngine::all_collateral_params()
+		}
+	}
+
+	impl module_homa_lite_rpc_runtime_api::HomaLiteApi<Block> for Runtime {
+		fn estimate_mint_output(staking_amount: Balance) -> Balance {
+			HomaLite::estimate_mint_output(staking_amount)
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn call(
 			from: H160,
@@ -2092,6 +2313,7 @@ impl_runtime_apis! {
 			value: Balance,
 			gas_limit: u64,
 			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
 			estimate: bool,
 		) -> Result<CallInfo, sp_runtime::DispatchError> {
 			let config = if estimate {
@@ -2110,6 +2332,7 @@ impl_runtime_apis! {
 				value,
 				gas_limit,
 				storage_limit,
+				access_list.unwrap_or_default(),
 				config.as_ref().unwrap_or(<Runtime as module_evm::Config>::config()),
 			)
 		}
@@ -2120,6 +2343,7 @@ impl_runtime_apis! {
 			value: Balance,
 			gas_limit: u64,
 			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
 			estimate: bool,
 		) -> Result<CreateInfo, sp_runtime::DispatchError> {
 			let config = if estimate {
@@ -2136,6 +2360,7 @@ impl_runtime_apis! {
 				value,
 				gas_limit,
 				storage_limit,
+				access_list.unwrap_or_default(),
 				config.as_ref().unwrap_or(<Runtime as module_evm::Config>::config()),
 			)
 		}
@@ -2170,6 +2395,18 @@ impl_runtime_apis! {
 
 			request.ok_or(sp_runtime::DispatchError::Other("Invalid parameter extrinsic, not evm Call"))
 		}
+
+		fn gas_to_fee(gas_limit: u64, gas_price: u64) -> Balance {
+			evm_gas_to_fee(gas_limit, gas_price)
+		}
+
+		fn base_fee_per_gas() -> U256 {
+			evm_base_fee_per_gas()
+		}
+
+		fn chain_id() -> u64 {
+			EVM::chain_id()
+		}
 	}
 
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
@@ -2234,9 +2471,11 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, module_incentives, benchmarking::incentives);
 			orml_add_benchmark!(params, batches, module_prices, benchmarking::prices);
 			orml_add_benchmark!(params, batches, module_evm_accounts, benchmarking::evm_accounts);
+			orml_add_benchmark!(params, batches, module_evm_manager, benchmarking::evm_manager);
 			orml_add_benchmark!(params, batches, module_homa, benchmarking::homa);
 			orml_add_benchmark!(params, batches, module_currencies, benchmarking::currencies);
 			orml_add_benchmark!(params, batches, module_session_manager, benchmarking::session_manager);
+			orml_add_benchmark!(params, batches, module_vesting_manager, benchmarking::vesting_manager);
 
 			orml_add_benchmark!(params, batches, orml_tokens, benchmarking::tokens);
 			orml_add_benchmark!(params, batches, orml_vesting, benchmarking::vesting);
@@ -2286,6 +2525,8 @@ cumulus_pallet_parachain_system::register_validate_block!(
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use frame_support::traits::GenesisBuild;
+	use frame_support::weights::WeightToFeePolynomial;
 	use frame_system::offchain::CreateSignedTransaction;
 
 	#[test]
@@ -2299,6 +2540,87 @@ mod tests {
 		is_submit_signed_transaction::<Runtime>();
 	}
 
+	#[test]
+	fn deal_with_fees_pays_tip_share_to_author() {
+		let author = AccountId::from([1u8; 32]);
+		let native_currency_id = GetNativeCurrencyId::get();
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![(author.clone(), dollar(native_currency_id))],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		module_collator_selection::GenesisConfig::<Runtime> {
+			invulnerables: vec![author.clone()],
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		pallet_session::GenesisConfig::<Runtime> {
+			keys: vec![(
+				author.clone(),
+				author.clone(),
+				SessionKeys {
+					aura: AuraId::from(sp_core::sr25519::Public::from_raw([1u8; 32])),
+				},
+			)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::from(t).execute_with(|| {
+			// the author is found by the slot in the pre-runtime digest
+			System::deposit_log(sp_runtime::DigestItem::PreRuntime(
+				sp_consensus_aura::AURA_ENGINE_ID,
+				sp_consensus_aura::Slot::from(0u64).encode(),
+			));
+			assert_eq!(Authorship::author(), author);
+
+			let fees = Balances::issue(10 * dollar(native_currency_id));
+			let tips = Balances::issue(10 * dollar(native_currency_id));
+			DealWithFees::on_unbalanceds(vec![fees, tips].into_iter());
+
+			assert_eq!(Balances::free_balance(&author), 6 * dollar(native_currency_id));
+			assert_eq!(
+				Balances::free_balance(&Treasury::account_id()),
+				12 * dollar(native_currency_id)
+			);
+			assert_eq!(
+				Balances::free_balance(&CollatorSelection::account_id()),
+				3 * dollar(native_currency_id)
+			);
+		});
+	}
+
+	#[test]
+	fn deal_with_fees_handles_tip_share_of_missing_author_as_fees() {
+		let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap()
+			.into();
+		t.execute_with(|| {
+			let native_currency_id = GetNativeCurrencyId::get();
+			let fees = Balances::issue(10 * dollar(native_currency_id));
+			let tips = Balances::issue(10 * dollar(native_currency_id));
+			DealWithFees::on_unbalanceds(vec![fees, tips].into_iter());
+
+			// no author is found without pre-runtime digest, the default account doesn't exist
+			assert_eq!(Balances::free_balance(&Authorship::author()), 0);
+			assert_eq!(
+				Balances::free_balance(&Treasury::account_id()),
+				16 * dollar(native_currency_id)
+			);
+			assert_eq!(
+				Balances::free_balance(&CollatorSelection::account_id()),
+				4 * dollar(native_currency_id)
+			);
+			// nothing is burned
+			assert_eq!(Balances::total_issuance(), 20 * dollar(native_currency_id));
+		});
+	}
+
 	#[test]
 	fn ensure_can_create_contract() {
 		// Ensure that the `ExistentialDeposit` for creating the contract >= account `ExistentialDeposit`.
@@ -2321,6 +2643,26 @@ mod tests {
 			) > 0
 		);
 	}
+
+	#[test]
+	fn evm_gas_to_fee_works() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let gas_limit = 2_100_000;
+			let weight_fee = WeightToFee::calc(&GasToWeight::convert(gas_limit));
+			assert!(weight_fee > 0);
+
+			assert_eq!(evm_gas_to_fee(gas_limit, 0), weight_fee);
+			assert_eq!(evm_gas_to_fee(gas_limit, 2), weight_fee + 2 * gas_limit as Balance);
+
+			// the weight fee follows the transaction payment fee multiplier
+			module_transaction_payment::NextFeeMultiplier::<Runtime>::put(Multiplier::saturating_from_integer(2));
+			assert_eq!(evm_gas_to_fee(gas_limit, 0), 2 * weight_fee);
+			assert_eq!(
+				evm_base_fee_per_gas(),
+				U256::from(2 * WeightToFee::calc(&GasToWeight::convert(EVM_DECIMALS_SCALE)))
+			);
+		});
+	}
 }
 
 #[test]
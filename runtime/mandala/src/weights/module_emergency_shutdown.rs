@@ -52,13 +52,18 @@ impl<T: frame_system::Config> module_emergency_shutdown::WeightInfo for WeightIn
 			// Standard Error: 377_000
 			.saturating_add((24_569_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().reads(31 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
 	}
+	fn shutdown_collateral() -> Weight {
+		(38_527_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	fn open_collateral_refund() -> Weight {
 		(54_094_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(7 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn refund_collaterals(c: u32, ) -> Weight {
 		(139_364_000 as Weight)
@@ -25,18 +25,22 @@ use codec::Encode;
 use cumulus_test_relay_sproof_builder::RelayStateSproofBuilder;
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{schedule::DispatchTime, Currency, GenesisBuild, OnFinalize, OnInitialize, OriginTrait, ValidatorSet},
+	traits::{
+		schedule::DispatchTime, Currency, Filter, GenesisBuild, OnFinalize, OnInitialize, OriginTrait, ValidatorSet,
+	},
 };
 use frame_system::RawOrigin;
 use mandala_runtime::{
-	create_x2_parachain_multilocation, dollar, get_all_module_accounts, AcalaOracle, AccountId, AuctionManager,
-	Authority, AuthoritysOriginId, Balance, Balances, BlockNumber, Call, CdpEngine, CdpTreasury, CreateClassDeposit,
-	CreateTokenDeposit, Currencies, CurrencyId, CurrencyIdConvert, DataDepositPerByte, Dex, EVMBridge,
-	EmergencyShutdown, EnabledTradingPairs, Event, EvmAccounts, EvmCurrencyIdMapping, ExistentialDeposits, Get,
-	GetNativeCurrencyId, Loans, MultiLocation, NativeTokenExistentialDeposit, NetworkId, NftPalletId, Origin,
-	OriginCaller, ParachainInfo, ParachainSystem, Perbill, Proxy, Runtime, Scheduler, Session, SessionManager,
-	SevenDays, System, TokenSymbol, Tokens, TreasuryAccount, TreasuryPalletId, TreasuryReservePalletId, Vesting,
-	XcmConfig, XcmExecutor, ACA, AUSD, DOT, EVM, LDOT, NFT, RENBTC,
+	assert_currency_roundtrip, create_x2_parachain_multilocation, dollar, get_all_module_accounts, AcalaOracle,
+	AccountId, AuctionManager, Authority, AuthoritysOriginId, Balance, Balances, BaseCallFilter, BlockNumber, Call,
+	CdpEngine, CdpTreasury, CollateralCurrencyIds, CreateClassDeposit, CreateTokenDeposit, Currencies, CurrencyId,
+	CurrencyIdConvert, DataDepositPerByte, DefaultDebitExchangeRate, Democracy, Dex, EVMBridge, EmergencyShutdown,
+	EnabledTradingPairs, Event, EvmAccounts, EvmCurrencyIdMapping, EvmManager, ExistentialDeposits, Get,
+	GetNativeCurrencyId, HomaLite, Loans, MinXcmTransferAmount, MultiLocation, NativeTokenExistentialDeposit,
+	NetworkId, NftPalletId, Origin, OriginCaller, ParachainInfo, ParachainSystem, Perbill, Prices, Proxy, ProxyType,
+	RefundDelayPeriod, Runtime, Scheduler, Session, SessionManager, SevenDays, System, Timestamp, TokenSymbol, Tokens,
+	TreasuryAccount, TreasuryPalletId, TreasuryReservePalletId, Vesting, VestingManager, XcmConfig, XcmExecutor, ACA,
+	AUSD, DOT, EVM, LDOT, NFT, RENBTC,
 };
 use module_cdp_engine::LiquidationStrategy;
 use module_evm_accounts::EvmAddressMapping;
@@ -55,7 +59,7 @@ use sp_runtime::{
 	traits::{AccountIdConversion, BadOrigin, Convert, Zero},
 	DispatchError, DispatchResult, FixedPointNumber, MultiAddress,
 };
-use std::str::FromStr;
+use std::{convert::TryFrom, str::FromStr};
 use xcm::{
 	opaque::v0::prelude::{BuyExecution, DepositAsset},
 	v0::{
@@ -75,6 +79,7 @@ const ORACLE3: [u8; 32] = [2u8; 32];
 
 const ALICE: [u8; 32] = [4u8; 32];
 const BOB: [u8; 32] = [5u8; 32];
+const CHARLIE: [u8; 32] = [6u8; 32];
 const LPTOKEN: CurrencyId =
 	CurrencyId::DexShare(DexShare::Token(TokenSymbol::AUSD), DexShare::Token(TokenSymbol::RENBTC));
 
@@ -341,12 +346,29 @@ fn emergency_shutdown_and_cdp_treasury() {
 				EmergencyShutdown::refund_collaterals(Origin::signed(AccountId::from(ALICE)), 1_000_000),
 				module_emergency_shutdown::Error::<Runtime>::CanNotRefund,
 			);
+			System::set_block_number(1);
 			assert_ok!(EmergencyShutdown::emergency_shutdown(Origin::root()));
+			assert_eq!(EmergencyShutdown::shutdown_progress(), (0, 0));
+			assert_noop!(
+				EmergencyShutdown::open_collateral_refund(Origin::root()),
+				module_emergency_shutdown::Error::<Runtime>::RefundDelayNotElapsed,
+			);
+			System::set_block_number(RefundDelayPeriod::get());
+			assert_noop!(
+				EmergencyShutdown::open_collateral_refund(Origin::root()),
+				module_emergency_shutdown::Error::<Runtime>::RefundDelayNotElapsed,
+			);
+			System::set_block_number(1 + RefundDelayPeriod::get());
 			assert_ok!(EmergencyShutdown::open_collateral_refund(Origin::root()));
+			assert_eq!(EmergencyShutdown::shutdown_progress(), (0, 241_000_000));
 			assert_ok!(EmergencyShutdown::refund_collaterals(
 				Origin::signed(AccountId::from(ALICE)),
 				1_000_000
 			));
+			assert_eq!(EmergencyShutdown::shutdown_progress(), (24_100_000, 241_000_000));
+			System::assert_last_event(Event::EmergencyShutdown(
+				module_emergency_shutdown::Event::ShutdownProgress(24_100_000, 241_000_000),
+			));
 
 			assert_eq!(CdpTreasury::total_collaterals(RENBTC), 900_000);
 			assert_eq!(CdpTreasury::total_collaterals(DOT), 180_000_000);
@@ -358,6 +380,56 @@ fn emergency_shutdown_and_cdp_treasury() {
 		});
 }
 
+#[test]
+fn shutdown_single_collateral_blocks_new_debit() {
+	ExtBuilder::default()
+		.balances(vec![
+			(AccountId::from(ALICE), RENBTC, 10 * dollar(RENBTC)),
+			(AccountId::from(ALICE), DOT, 1_000 * dollar(DOT)),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(set_oracle_price(vec![
+				(RENBTC, Price::saturating_from_rational(10000, 1)),
+				(DOT, Price::saturating_from_rational(100, 1)),
+			]));
+			for currency_id in &[RENBTC, DOT] {
+				assert_ok!(CdpEngine::set_collateral_params(
+					Origin::root(),
+					*currency_id,
+					Change::NewValue(Some(Rate::zero())),
+					Change::NewValue(Some(Ratio::saturating_from_rational(200, 100))),
+					Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
+					Change::NewValue(Some(Ratio::saturating_from_rational(200, 100))),
+					Change::NewValue(1_000_000 * dollar(AUSD)),
+				));
+			}
+
+			assert_ok!(EmergencyShutdown::shutdown_collateral(Origin::root(), RENBTC));
+			assert!(!EmergencyShutdown::is_shutdown());
+			assert_noop!(
+				CdpEngine::adjust_position(
+					&AccountId::from(ALICE),
+					RENBTC,
+					dollar(RENBTC) as i128,
+					(1_000 * dollar(AUSD)) as i128,
+				),
+				module_cdp_engine::Error::<Runtime>::CollateralShutdown,
+			);
+
+			assert_ok!(CdpEngine::adjust_position(
+				&AccountId::from(ALICE),
+				DOT,
+				(100 * dollar(DOT)) as i128,
+				(1_000 * dollar(AUSD)) as i128,
+			));
+			assert_eq!(
+				Loans::positions(DOT, AccountId::from(ALICE)).debit,
+				1_000 * dollar(AUSD)
+			);
+		});
+}
+
 #[test]
 fn liquidate_cdp() {
 	ExtBuilder::default()
@@ -474,6 +546,25 @@ fn liquidate_cdp() {
 		});
 }
 
+#[test]
+fn test_oracle_on_new_data_updates_prices() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+		assert_eq!(Prices::last_accepted_price(RENBTC), None);
+		assert_eq!(Prices::last_accepted_price(DOT), None);
+		assert_eq!(Prices::last_accepted_price(LDOT), None);
+
+		assert_ok!(set_oracle_price(vec![(RENBTC, Price::saturating_from_integer(10000))]));
+		assert!(Prices::last_accepted_price(RENBTC).is_some());
+		assert_eq!(Prices::last_accepted_price(DOT), None);
+
+		System::set_block_number(11);
+		assert_ok!(set_oracle_price(vec![(DOT, Price::saturating_from_rational(10, 1))]));
+		assert!(Prices::last_accepted_price(DOT).is_some());
+		assert!(Prices::last_accepted_price(LDOT).is_some());
+	});
+}
+
 #[test]
 fn test_dex_module() {
 	ExtBuilder::default()
@@ -531,7 +622,7 @@ fn test_dex_module() {
 				10000000,
 				RENBTC,
 				10000,
-				20000000,
+				19999000,
 			));
 			assert!(System::events()
 				.iter()
@@ -539,7 +630,7 @@ fn test_dex_module() {
 
 			assert_eq!(Dex::get_liquidity_pool(RENBTC, AUSD), (10000, 10000000));
 			assert_eq!(Currencies::total_issuance(LPTOKEN), 20000000);
-			assert_eq!(Currencies::free_balance(LPTOKEN, &AccountId::from(ALICE)), 20000000);
+			assert_eq!(Currencies::free_balance(LPTOKEN, &AccountId::from(ALICE)), 19999000);
 			assert_ok!(Dex::add_liquidity(
 				Origin::signed(AccountId::from(BOB)),
 				RENBTC,
@@ -645,8 +736,8 @@ fn test_dex_module() {
 				Origin::signed(EvmAddressMapping::<Runtime>::get_account_id(&alice_evm_addr())),
 				CurrencyId::Erc20(erc20_address_0()),
 				CurrencyId::Erc20(erc20_address_1()),
-				100,
-				1000,
+				990,
+				1900,
 			));
 			assert_ok!(Dex::end_provisioning(
 				Origin::signed(AccountId::from(BOB)),
@@ -658,7 +749,7 @@ fn test_dex_module() {
 					CurrencyId::Erc20(erc20_address_0()),
 					CurrencyId::Erc20(erc20_address_1())
 				),
-				(110, 1100)
+				(1000, 2000)
 			);
 
 			assert_eq!(
@@ -666,7 +757,7 @@ fn test_dex_module() {
 					DexShare::Erc20(erc20_address_0()),
 					DexShare::Erc20(erc20_address_1())
 				)),
-				220
+				2000
 			);
 
 			assert_ok!(Dex::claim_dex_share(
@@ -680,7 +771,7 @@ fn test_dex_module() {
 					CurrencyId::DexShare(DexShare::Erc20(erc20_address_0()), DexShare::Erc20(erc20_address_1())),
 					&EvmAddressMapping::<Runtime>::get_account_id(&alice_evm_addr())
 				),
-				220
+				1000
 			);
 
 			assert_ok!(Dex::remove_liquidity(
@@ -698,7 +789,7 @@ fn test_dex_module() {
 					CurrencyId::Erc20(erc20_address_0()),
 					CurrencyId::Erc20(erc20_address_1())
 				),
-				(110, 1096)
+				(1000, 1999)
 			);
 
 			assert_eq!(
@@ -706,7 +797,7 @@ fn test_dex_module() {
 					DexShare::Erc20(erc20_address_0()),
 					DexShare::Erc20(erc20_address_1())
 				)),
-				219
+				1999
 			);
 
 			assert_eq!(
@@ -714,7 +805,7 @@ fn test_dex_module() {
 					CurrencyId::DexShare(DexShare::Erc20(erc20_address_0()), DexShare::Erc20(erc20_address_1())),
 					&EvmAddressMapping::<Runtime>::get_account_id(&alice_evm_addr())
 				),
-				219
+				999
 			);
 		});
 }
@@ -829,6 +920,10 @@ fn test_cdp_engine_module() {
 			);
 			assert_eq!(new_collateral_params.maximum_total_debit_value, 10_000 * dollar(AUSD));
 
+			let all_collateral_params = CdpEngine::all_collateral_params();
+			assert_eq!(all_collateral_params.len(), CollateralCurrencyIds::get().len());
+			assert!(all_collateral_params.contains(&(RENBTC, new_collateral_params)));
+
 			assert_eq!(
 				CdpEngine::calculate_collateral_ratio(
 					RENBTC,
@@ -896,6 +991,38 @@ fn test_cdp_engine_module() {
 			assert_eq!(Loans::positions(RENBTC, AccountId::from(ALICE)).debit, 0);
 			assert_eq!(CdpTreasury::debit_pool(), 10 * dollar(AUSD));
 			assert_eq!(CdpTreasury::total_collaterals(RENBTC), 333_333_333);
+
+			// the debit exchange rate compounds by `interest_rate_per_sec` as time goes on
+			assert_ok!(CdpEngine::adjust_position(
+				&AccountId::from(ALICE),
+				RENBTC,
+				0,
+				(100 * dollar(AUSD)) as i128
+			));
+			let interest_rate_per_sec = CdpEngine::get_interest_rate_per_sec(RENBTC);
+			let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(RENBTC);
+			assert_eq!(debit_exchange_rate, DefaultDebitExchangeRate::get());
+
+			Timestamp::set_timestamp(100_000);
+			CdpEngine::on_initialize(2);
+			let new_debit_exchange_rate = CdpEngine::get_debit_exchange_rate(RENBTC);
+			assert!(new_debit_exchange_rate > debit_exchange_rate);
+			assert_eq!(
+				new_debit_exchange_rate,
+				debit_exchange_rate.saturating_add(
+					debit_exchange_rate.saturating_mul(CdpEngine::compound_interest_rate(interest_rate_per_sec, 100))
+				)
+			);
+
+			Timestamp::set_timestamp(200_000);
+			CdpEngine::on_initialize(3);
+			assert_eq!(
+				CdpEngine::get_debit_exchange_rate(RENBTC),
+				new_debit_exchange_rate.saturating_add(
+					new_debit_exchange_rate
+						.saturating_mul(CdpEngine::compound_interest_rate(interest_rate_per_sec, 100))
+				)
+			);
 		});
 }
 
@@ -1134,6 +1261,7 @@ fn test_nft_module() {
 						| module_nft::ClassProperty::Mintable
 				),
 				Default::default(),
+				None,
 			));
 			let deposit =
 				Proxy::deposit(1u32) + CreateClassDeposit::get() + DataDepositPerByte::get() * (metadata.len() as u128);
@@ -1157,7 +1285,8 @@ fn test_nft_module() {
 				0,
 				metadata.clone(),
 				Default::default(),
-				1
+				1,
+				None
 			));
 			assert_ok!(NFT::burn(Origin::signed(AccountId::from(BOB)), (0, 0)));
 			assert_eq!(
@@ -1187,6 +1316,291 @@ fn test_nft_module() {
 		});
 }
 
+#[test]
+fn test_nft_class_owner_transfer() {
+	ExtBuilder::default()
+		.balances(vec![(AccountId::from(ALICE), ACA, 1_000 * dollar(ACA))])
+		.build()
+		.execute_with(|| {
+			let metadata = vec![1];
+			let class_owner: AccountId = NftPalletId::get().into_sub_account(0);
+			assert_ok!(NFT::create_class(
+				Origin::signed(AccountId::from(ALICE)),
+				metadata.clone(),
+				module_nft::Properties(module_nft::ClassProperty::Transferable | module_nft::ClassProperty::Mintable),
+				Default::default(),
+				None,
+			));
+			assert_ok!(Balances::deposit_into_existing(
+				&class_owner,
+				1 * (CreateTokenDeposit::get() + DataDepositPerByte::get())
+			));
+			let mint_call = Box::new(Call::NFT(module_nft::Call::mint(
+				MultiAddress::Id(AccountId::from(CHARLIE)),
+				0,
+				metadata.clone(),
+				Default::default(),
+				1,
+				None,
+			)));
+
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(ALICE)),
+				class_owner.clone(),
+				None,
+				Box::new(Call::NFT(module_nft::Call::propose_class_owner(
+					0,
+					MultiAddress::Id(AccountId::from(BOB))
+				)))
+			));
+			assert_noop!(
+				NFT::accept_class_owner(Origin::signed(AccountId::from(CHARLIE)), 0),
+				module_nft::Error::<Runtime>::NoPermission
+			);
+			assert_noop!(
+				Proxy::proxy(
+					Origin::signed(AccountId::from(BOB)),
+					class_owner.clone(),
+					None,
+					mint_call.clone()
+				),
+				pallet_proxy::Error::<Runtime>::NotProxy
+			);
+
+			assert_ok!(NFT::accept_class_owner(Origin::signed(AccountId::from(BOB)), 0));
+			assert_noop!(
+				Proxy::proxy(
+					Origin::signed(AccountId::from(ALICE)),
+					class_owner.clone(),
+					None,
+					mint_call.clone()
+				),
+				pallet_proxy::Error::<Runtime>::NotProxy
+			);
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(BOB)),
+				class_owner,
+				None,
+				mint_call
+			));
+			System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Ok(()))));
+		});
+}
+
+#[test]
+fn test_democracy_vote_only_proxy() {
+	ExtBuilder::default()
+		.balances(vec![
+			(AccountId::from(ALICE), ACA, 1_000 * dollar(ACA)),
+			(AccountId::from(BOB), ACA, 1_000 * dollar(ACA)),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Proxy::add_proxy(
+				Origin::signed(AccountId::from(ALICE)),
+				AccountId::from(BOB),
+				ProxyType::DemocracyVoteOnly,
+				0
+			));
+
+			let proposal_hash = sp_core::H256::repeat_byte(1);
+			let ref_index = Democracy::internal_start_referendum(
+				proposal_hash,
+				pallet_democracy::VoteThreshold::SuperMajorityApprove,
+				0,
+			);
+			let vote = pallet_democracy::AccountVote::Standard {
+				vote: pallet_democracy::Vote {
+					aye: true,
+					conviction: pallet_democracy::Conviction::Locked1x,
+				},
+				balance: 10 * dollar(ACA),
+			};
+
+			// vote passes
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(ALICE),
+				None,
+				Box::new(Call::Democracy(pallet_democracy::Call::vote(ref_index, vote)))
+			));
+			System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Ok(()))));
+			assert_eq!(
+				Democracy::referendum_status(ref_index).unwrap().tally.ayes,
+				10 * dollar(ACA)
+			);
+
+			// proposal is filtered
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(ALICE),
+				None,
+				Box::new(Call::Democracy(pallet_democracy::Call::propose(
+					proposal_hash,
+					100 * dollar(ACA)
+				)))
+			));
+			System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Err(BadOrigin.into()))));
+
+			// delegation is filtered
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(ALICE),
+				None,
+				Box::new(Call::Democracy(pallet_democracy::Call::delegate(
+					AccountId::from(BOB),
+					pallet_democracy::Conviction::Locked1x,
+					10 * dollar(ACA)
+				)))
+			));
+			System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Err(BadOrigin.into()))));
+		});
+}
+
+#[test]
+fn test_homa_lite_proxy() {
+	ExtBuilder::default()
+		.balances(vec![
+			(AccountId::from(ALICE), ACA, 1_000 * dollar(ACA)),
+			(AccountId::from(ALICE), DOT, 1_000 * dollar(DOT)),
+			(AccountId::from(BOB), ACA, 1_000 * dollar(ACA)),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Proxy::add_proxy(
+				Origin::signed(AccountId::from(ALICE)),
+				AccountId::from(BOB),
+				ProxyType::HomaLite,
+				0
+			));
+			assert_ok!(HomaLite::set_minting_cap(Origin::root(), 1_000 * dollar(DOT)));
+
+			// mint passes the proxy filter and is dispatched to HomaLite
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(ALICE),
+				None,
+				Box::new(Call::HomaLite(module_homa_lite::Call::mint(0, 0)))
+			));
+			System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Err(
+				module_homa_lite::Error::<Runtime>::MintAmountBelowMinimumThreshold.into(),
+			))));
+
+			// mint via the proxy succeeds on behalf of ALICE
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(ALICE),
+				None,
+				Box::new(Call::HomaLite(module_homa_lite::Call::mint(
+					10 * dollar(DOT),
+					1_000_000_000
+				)))
+			));
+			System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Ok(()))));
+			assert!(System::events().iter().any(|record| matches!(
+				record.event,
+				Event::HomaLite(module_homa_lite::Event::Minted(ref who, amount, _))
+					if *who == AccountId::from(ALICE) && amount == 10 * dollar(DOT)
+			)));
+			assert_eq!(
+				Currencies::free_balance(DOT, &AccountId::from(ALICE)),
+				990 * dollar(DOT)
+			);
+			assert!(Currencies::free_balance(LDOT, &AccountId::from(ALICE)) > 0);
+			assert_eq!(HomaLite::total_staking_currency(), 10 * dollar(DOT));
+
+			// transfer is filtered
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(ALICE),
+				None,
+				Box::new(Call::Balances(pallet_balances::Call::transfer(
+					MultiAddress::Id(AccountId::from(BOB)),
+					10 * dollar(ACA)
+				)))
+			));
+			System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Err(BadOrigin.into()))));
+			assert_eq!(Balances::free_balance(AccountId::from(BOB)), 1_000 * dollar(ACA));
+		});
+}
+
+#[test]
+fn test_swap_proxy() {
+	ExtBuilder::default()
+		.balances(vec![
+			(AccountId::from(ALICE), ACA, 1_000 * dollar(ACA)),
+			(AccountId::from(ALICE), AUSD, 1_000 * dollar(AUSD)),
+			(AccountId::from(ALICE), RENBTC, 1_000 * dollar(RENBTC)),
+			(AccountId::from(BOB), ACA, 1_000 * dollar(ACA)),
+		])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(10);
+			assert_ok!(Dex::add_liquidity(
+				Origin::signed(AccountId::from(ALICE)),
+				RENBTC,
+				AUSD,
+				100 * dollar(RENBTC),
+				100 * dollar(AUSD),
+				0,
+				false,
+			));
+			assert_ok!(Proxy::add_proxy(
+				Origin::signed(AccountId::from(ALICE)),
+				AccountId::from(BOB),
+				ProxyType::Swap,
+				0
+			));
+
+			// the expired swap passes the proxy filter and is rejected by Dex
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(ALICE),
+				None,
+				Box::new(Call::Dex(module_dex::Call::swap_with_exact_supply_and_deadline(
+					vec![RENBTC, AUSD],
+					dollar(RENBTC),
+					0,
+					9
+				)))
+			));
+			System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Err(
+				module_dex::Error::<Runtime>::SwapExpired.into(),
+			))));
+
+			// the swap before deadline is executed for the proxied account
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(ALICE),
+				None,
+				Box::new(Call::Dex(module_dex::Call::swap_with_exact_supply_and_deadline(
+					vec![RENBTC, AUSD],
+					dollar(RENBTC),
+					0,
+					10
+				)))
+			));
+			System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Ok(()))));
+			assert_eq!(
+				Currencies::free_balance(RENBTC, &AccountId::from(ALICE)),
+				899 * dollar(RENBTC)
+			);
+			assert!(Currencies::free_balance(AUSD, &AccountId::from(ALICE)) > 900 * dollar(AUSD));
+
+			// transfer is filtered
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(ALICE),
+				None,
+				Box::new(Call::Balances(pallet_balances::Call::transfer(
+					MultiAddress::Id(AccountId::from(BOB)),
+					10 * dollar(ACA)
+				)))
+			));
+			System::assert_last_event(Event::Proxy(pallet_proxy::Event::ProxyExecuted(Err(BadOrigin.into()))));
+		});
+}
+
 #[test]
 fn test_evm_accounts_module() {
 	ExtBuilder::default()
@@ -1240,6 +1654,151 @@ fn test_evm_accounts_module() {
 		});
 }
 
+#[test]
+fn test_evm_accounts_claim_default_evm_address() {
+	let default_evm_address = EvmAddressMapping::<Runtime>::get_default_evm_address(&AccountId::from(ALICE));
+	let padded_account_id = MockAddressMapping::get_account_id(&default_evm_address);
+	ExtBuilder::default()
+		.balances(vec![(padded_account_id.clone(), ACA, 1_000 * dollar(ACA))])
+		.build()
+		.execute_with(|| {
+			assert_eq!(Balances::free_balance(AccountId::from(ALICE)), 0);
+			assert_eq!(Balances::free_balance(&padded_account_id), 1_000 * dollar(ACA));
+
+			// evm padded address will transfer_all to origin.
+			assert_ok!(EvmAccounts::claim_default_evm_address(Origin::signed(AccountId::from(
+				ALICE
+			))));
+			System::assert_last_event(Event::EvmAccounts(module_evm_accounts::Event::ClaimAccount(
+				AccountId::from(ALICE),
+				default_evm_address,
+			)));
+			assert_eq!(System::providers(&padded_account_id), 0);
+			assert_eq!(System::providers(&AccountId::from(ALICE)), 1);
+			assert_eq!(Balances::free_balance(&padded_account_id), 0);
+			assert_eq!(Balances::free_balance(AccountId::from(ALICE)), 1_000 * dollar(ACA));
+			assert_eq!(
+				EvmAddressMapping::<Runtime>::get_account_id(&default_evm_address),
+				AccountId::from(ALICE)
+			);
+
+			// claim another eth address
+			assert_noop!(
+				EvmAccounts::claim_account(
+					Origin::signed(AccountId::from(ALICE)),
+					EvmAccounts::eth_address(&alice_key()),
+					EvmAccounts::eth_sign(&alice_key(), &AccountId::from(ALICE).encode(), &[][..])
+				),
+				module_evm_accounts::Error::<Runtime>::AccountIdHasMapped
+			);
+			assert_noop!(
+				EvmAccounts::claim_default_evm_address(Origin::signed(AccountId::from(ALICE))),
+				module_evm_accounts::Error::<Runtime>::AccountIdHasMapped
+			);
+		});
+}
+
+#[test]
+fn test_register_erc20_as_currency() {
+	ExtBuilder::default()
+		.balances(vec![(
+			// NetworkContractSource
+			MockAddressMapping::get_account_id(&H160::from_low_u64_be(0)),
+			ACA,
+			1_000 * dollar(ACA),
+		)])
+		.build()
+		.execute_with(|| {
+			deploy_erc20_contracts();
+			let erc20 = CurrencyId::Erc20(erc20_address_0());
+			let lp_currency_id = TradingPair::from_currency_ids(erc20, AUSD)
+				.unwrap()
+				.dex_share_currency_id();
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(erc20_address_0()),
+				None
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::encode_evm_address(lp_currency_id),
+				None
+			);
+
+			assert_ok!(EvmManager::register_erc20(Origin::root(), erc20_address_0()));
+			System::assert_last_event(Event::EvmManager(module_evm_manager::Event::Erc20Registered(
+				erc20_address_0(),
+			)));
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(erc20_address_0()),
+				Some(erc20)
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::symbol(erc20),
+				Some(b"TestToken".to_vec())
+			);
+			assert_eq!(EvmCurrencyIdMapping::<Runtime>::decimals(erc20), Some(17));
+
+			// usable in currencies
+			assert_ok!(Currencies::transfer(
+				Origin::signed(MockAddressMapping::get_account_id(&H160::from_low_u64_be(0))),
+				MultiAddress::Id(AccountId::from(ALICE)),
+				erc20,
+				100
+			));
+			assert_eq!(Currencies::free_balance(erc20, &AccountId::from(ALICE)), 100);
+
+			// listable on DEX, the dex share is mapped to evm address
+			assert_ok!(Dex::enable_trading_pair(Origin::root(), erc20, AUSD));
+			assert!(EvmCurrencyIdMapping::<Runtime>::encode_evm_address(lp_currency_id).is_some());
+		});
+}
+
+#[test]
+fn test_evm_accounts_claim_keeps_erc20_balance() {
+	ExtBuilder::default()
+		.balances(vec![
+			(
+				// NetworkContractSource
+				MockAddressMapping::get_account_id(&H160::from_low_u64_be(0)),
+				ACA,
+				1_000 * dollar(ACA),
+			),
+			(bob(), ACA, 1_000 * dollar(ACA)),
+		])
+		.build()
+		.execute_with(|| {
+			deploy_erc20_contracts();
+			let erc20 = CurrencyId::Erc20(erc20_address_0());
+
+			assert_ok!(Currencies::transfer(
+				Origin::signed(MockAddressMapping::get_account_id(&H160::from_low_u64_be(0))),
+				MultiAddress::Id(bob()),
+				erc20,
+				100
+			));
+			assert_eq!(Currencies::free_balance(erc20, &bob()), 100);
+			assert_eq!(Currencies::free_balance(erc20, &AccountId::from(BOB)), 0);
+
+			// evm padded address will transfer_all to origin, and the erc20 balance
+			// follows the claimed evm address.
+			assert_ok!(EvmAccounts::claim_account(
+				Origin::signed(AccountId::from(BOB)),
+				EvmAccounts::eth_address(&bob_key()),
+				EvmAccounts::eth_sign(&bob_key(), &AccountId::from(BOB).encode(), &[][..])
+			));
+			assert_eq!(Balances::free_balance(bob()), 0);
+			assert_eq!(Balances::free_balance(&AccountId::from(BOB)), 1_000 * dollar(ACA));
+			assert_eq!(Currencies::free_balance(erc20, &AccountId::from(BOB)), 100);
+
+			assert_ok!(Currencies::transfer(
+				Origin::signed(AccountId::from(BOB)),
+				MultiAddress::Id(AccountId::from(ALICE)),
+				erc20,
+				40
+			));
+			assert_eq!(Currencies::free_balance(erc20, &AccountId::from(BOB)), 60);
+		});
+}
+
 #[cfg(not(feature = "with-ethereum-compatibility"))]
 #[test]
 fn test_evm_module() {
@@ -1377,8 +1936,8 @@ fn test_multicurrency_precompile_module() {
 				Origin::signed(MockAddressMapping::get_account_id(&alice_evm_addr())),
 				CurrencyId::Erc20(erc20_address_0()),
 				CurrencyId::Erc20(erc20_address_1()),
-				100,
 				1000,
+				2000,
 			));
 			assert_ok!(Dex::end_provisioning(
 				Origin::signed(AccountId::from(ALICE)),
@@ -1390,7 +1949,7 @@ fn test_multicurrency_precompile_module() {
 					CurrencyId::Erc20(erc20_address_0()),
 					CurrencyId::Erc20(erc20_address_1())
 				),
-				(100, 1000)
+				(1000, 2000)
 			);
 
 			assert_eq!(
@@ -1398,7 +1957,7 @@ fn test_multicurrency_precompile_module() {
 					DexShare::Erc20(erc20_address_0()),
 					DexShare::Erc20(erc20_address_1())
 				)),
-				200
+				2000
 			);
 
 			assert_ok!(Dex::claim_dex_share(
@@ -1412,7 +1971,7 @@ fn test_multicurrency_precompile_module() {
 					CurrencyId::DexShare(DexShare::Erc20(erc20_address_0()), DexShare::Erc20(erc20_address_1())),
 					&MockAddressMapping::get_account_id(&alice_evm_addr())
 				),
-				200
+				1000
 			);
 
 			let invoke_context = module_support::InvokeContext {
@@ -1435,19 +1994,19 @@ fn test_multicurrency_precompile_module() {
 			);
 			assert_eq!(
 				EVMBridge::total_supply(invoke_context),
-				Ok(200)
+				Ok(2000)
 			);
 			assert_eq!(
 				EVMBridge::balance_of(invoke_context, alice_evm_addr()),
-				Ok(200)
+				Ok(1000)
 			);
 			assert_eq!(
 				EVMBridge::total_supply(invoke_context),
-				Ok(200)
+				Ok(2000)
 			);
 			assert_eq!(
 				EVMBridge::balance_of(invoke_context, alice_evm_addr()),
-				Ok(200)
+				Ok(1000)
 			);
 			assert_eq!(
 				EVMBridge::transfer(invoke_context, bob_evm_addr(), 1),
@@ -1455,7 +2014,7 @@ fn test_multicurrency_precompile_module() {
 			);
 			assert_eq!(
 				EVMBridge::balance_of(invoke_context, alice_evm_addr()),
-				Ok(199)
+				Ok(999)
 			);
 			assert_eq!(
 				EVMBridge::balance_of(invoke_context, bob_evm_addr()),
@@ -1632,6 +2191,187 @@ fn test_vesting_use_relaychain_block_number() {
 	});
 }
 
+#[test]
+fn test_vesting_locked_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		let treasury: AccountId = TreasuryPalletId::get().into_account();
+
+		assert_ok!(Balances::set_balance(
+			Origin::root(),
+			treasury.clone().into(),
+			1_000 * dollar(ACA),
+			0
+		));
+
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(treasury.clone()),
+			alice().into(),
+			VestingSchedule {
+				start: 10,
+				period: 2,
+				period_count: 5,
+				per_period: 3 * dollar(ACA),
+			}
+		));
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(treasury),
+			alice().into(),
+			VestingSchedule {
+				start: 20,
+				period: 10,
+				period_count: 2,
+				per_period: 10 * dollar(ACA),
+			}
+		));
+		assert_eq!(mandala_runtime::vesting_locked_balance(&alice()), 35 * dollar(ACA));
+		assert_eq!(mandala_runtime::vesting_locked_balance(&bob()), 0);
+
+		set_relaychain_block_number(12);
+		assert_eq!(mandala_runtime::vesting_locked_balance(&alice()), 32 * dollar(ACA));
+
+		set_relaychain_block_number(20);
+		assert_eq!(mandala_runtime::vesting_locked_balance(&alice()), 20 * dollar(ACA));
+
+		set_relaychain_block_number(30);
+		assert_eq!(mandala_runtime::vesting_locked_balance(&alice()), 10 * dollar(ACA));
+
+		// claiming doesn't change the locked balance
+		assert_ok!(Vesting::claim(Origin::signed(alice())));
+		assert_eq!(mandala_runtime::vesting_locked_balance(&alice()), 10 * dollar(ACA));
+		assert_eq!(Balances::usable_balance(&alice()), 25 * dollar(ACA));
+
+		set_relaychain_block_number(40);
+		assert_eq!(mandala_runtime::vesting_locked_balance(&alice()), 0);
+	});
+}
+
+#[test]
+fn test_vesting_manager_merge_and_claim_use_relaychain_block_number() {
+	ExtBuilder::default().build().execute_with(|| {
+		let treasury: AccountId = TreasuryPalletId::get().into_account();
+
+		assert_ok!(Balances::set_balance(
+			Origin::root(),
+			treasury.clone().into(),
+			1_000 * dollar(ACA),
+			0
+		));
+
+		let schedules = vec![
+			VestingSchedule {
+				start: 10,
+				period: 2,
+				period_count: 5,
+				per_period: 3 * dollar(ACA),
+			},
+			VestingSchedule {
+				start: 14,
+				period: 2,
+				period_count: 3,
+				per_period: 2 * dollar(ACA),
+			},
+			VestingSchedule {
+				start: 11,
+				period: 3,
+				period_count: 3,
+				per_period: dollar(ACA),
+			},
+		];
+		for schedule in schedules.iter() {
+			assert_ok!(Vesting::vested_transfer(
+				Origin::signed(treasury.clone()),
+				alice().into(),
+				schedule.clone()
+			));
+		}
+
+		assert_eq!(Balances::free_balance(&alice()), 24 * dollar(ACA));
+		assert_eq!(Balances::usable_balance(&alice()), 0);
+
+		set_relaychain_block_number(15);
+
+		// the first two schedules end at 20 with the same period and are merged
+		assert_ok!(VestingManager::merge_and_claim(Origin::signed(alice())));
+		assert_eq!(
+			Vesting::vesting_schedules(&alice()).to_vec(),
+			vec![
+				VestingSchedule {
+					start: 14,
+					period: 2,
+					period_count: 3,
+					per_period: 5 * dollar(ACA),
+				},
+				VestingSchedule {
+					start: 14,
+					period: 3,
+					period_count: 2,
+					per_period: dollar(ACA),
+				},
+			]
+		);
+		assert_eq!(Balances::usable_balance(&alice()), 7 * dollar(ACA));
+
+		set_relaychain_block_number(18);
+
+		assert_ok!(Vesting::claim(Origin::signed(alice())));
+		assert_eq!(Balances::usable_balance(&alice()), 18 * dollar(ACA));
+
+		set_relaychain_block_number(20);
+
+		assert_ok!(VestingManager::merge_and_claim(Origin::signed(alice())));
+		assert!(Vesting::vesting_schedules(&alice()).is_empty());
+		assert_eq!(Balances::usable_balance(&alice()), 24 * dollar(ACA));
+	});
+}
+
+#[test]
+fn test_vesting_manager_vested_transfer_with_cliff_use_relaychain_block_number() {
+	ExtBuilder::default().build().execute_with(|| {
+		let treasury: AccountId = TreasuryPalletId::get().into_account();
+
+		assert_ok!(Balances::set_balance(
+			Origin::root(),
+			treasury.clone().into(),
+			1_000 * dollar(ACA),
+			0
+		));
+
+		set_relaychain_block_number(5);
+
+		// vest over 3 periods after the cliff at 10
+		assert_ok!(VestingManager::vested_transfer_with_cliff(
+			Origin::signed(treasury),
+			alice().into(),
+			10,
+			2,
+			3,
+			15 * dollar(ACA)
+		));
+		assert_eq!(Balances::free_balance(&alice()), 15 * dollar(ACA));
+		assert_eq!(Balances::usable_balance(&alice()), 0);
+
+		set_relaychain_block_number(11);
+
+		assert_ok!(Vesting::claim(Origin::signed(alice())));
+		assert_eq!(Balances::usable_balance(&alice()), 0);
+
+		set_relaychain_block_number(12);
+
+		assert_ok!(Vesting::claim(Origin::signed(alice())));
+		assert_eq!(Balances::usable_balance(&alice()), 5 * dollar(ACA));
+
+		set_relaychain_block_number(14);
+
+		assert_ok!(Vesting::claim(Origin::signed(alice())));
+		assert_eq!(Balances::usable_balance(&alice()), 10 * dollar(ACA));
+
+		set_relaychain_block_number(16);
+
+		assert_ok!(Vesting::claim(Origin::signed(alice())));
+		assert_eq!(Balances::usable_balance(&alice()), 15 * dollar(ACA));
+	});
+}
+
 #[test]
 fn test_session_manager_module() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1671,6 +2411,69 @@ fn test_session_manager_module() {
 	});
 }
 
+#[test]
+fn test_session_manager_ramp() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(10);
+		assert_eq!(Session::session_index(), 1);
+		assert_eq!(SessionManager::session_duration(), 10);
+
+		assert_ok!(SessionManager::schedule_session_duration_ramp(
+			RawOrigin::Root.into(),
+			2,
+			4,
+			10,
+			16
+		));
+
+		run_to_block(20);
+		assert_eq!(Session::session_index(), 2);
+		assert_eq!(SessionManager::session_duration(), 10);
+
+		run_to_block(29);
+		assert_eq!(Session::session_index(), 2);
+
+		run_to_block(30);
+		assert_eq!(Session::session_index(), 3);
+		assert_eq!(SessionManager::session_duration(), 13);
+
+		run_to_block(42);
+		assert_eq!(Session::session_index(), 3);
+
+		run_to_block(43);
+		assert_eq!(Session::session_index(), 4);
+		assert_eq!(SessionManager::session_duration(), 16);
+
+		run_to_block(58);
+		assert_eq!(Session::session_index(), 4);
+
+		run_to_block(59);
+		assert_eq!(Session::session_index(), 5);
+		assert_eq!(SessionManager::session_duration(), 16);
+	});
+}
+
+#[test]
+fn test_session_manager_force_new_session() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(10);
+		assert_eq!(Session::session_index(), 1);
+
+		run_to_block(13);
+		assert_ok!(SessionManager::force_new_session(RawOrigin::Root.into(), 0));
+
+		run_to_block(14);
+		assert_eq!(Session::session_index(), 2);
+		assert_eq!(SessionManager::session_duration(), 10);
+
+		run_to_block(23);
+		assert_eq!(Session::session_index(), 2);
+
+		run_to_block(24);
+		assert_eq!(Session::session_index(), 3);
+	});
+}
+
 #[test]
 fn treasury_should_take_xcm_execution_revenue() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1867,6 +2670,59 @@ fn currency_id_convert() {
 	});
 }
 
+#[test]
+fn currency_id_convert_round_trip() {
+	ExtBuilder::default().build().execute_with(|| {
+		let cross_chain_currencies = vec![DOT, ACA, AUSD, LDOT, RENBTC];
+
+		for symbol in (0..=u8::MAX).filter_map(|v| TokenSymbol::try_from(v).ok()) {
+			let currency_id = CurrencyId::Token(symbol);
+			if cross_chain_currencies.contains(&currency_id) {
+				assert!(assert_currency_roundtrip(currency_id));
+			} else {
+				assert!(!assert_currency_roundtrip(currency_id));
+				assert_eq!(CurrencyIdConvert::convert(currency_id), None);
+			}
+		}
+
+		assert!(!assert_currency_roundtrip(LPTOKEN));
+		assert_eq!(CurrencyIdConvert::convert(LPTOKEN), None);
+		assert!(!assert_currency_roundtrip(CurrencyId::Erc20(EvmAddress::default())));
+		assert_eq!(
+			CurrencyIdConvert::convert(CurrencyId::Erc20(EvmAddress::default())),
+			None
+		);
+	});
+}
+
+#[test]
+fn xcm_transfer_below_minimum_is_filtered() {
+	ExtBuilder::default().build().execute_with(|| {
+		let dest = X2(
+			Parent,
+			Junction::AccountId32 {
+				network: NetworkId::Any,
+				id: BOB,
+			},
+		);
+		let transfer = |currency_id: CurrencyId, amount: Balance| {
+			Call::XTokens(orml_xtokens::Call::transfer(
+				currency_id,
+				amount,
+				dest.clone(),
+				1_000_000_000,
+			))
+		};
+
+		for currency_id in vec![DOT, AUSD] {
+			let min_amount = MinXcmTransferAmount::get(&currency_id);
+			assert!(!min_amount.is_zero());
+			assert!(!BaseCallFilter::filter(&transfer(currency_id, min_amount - 1)));
+			assert!(BaseCallFilter::filter(&transfer(currency_id, min_amount)));
+		}
+	});
+}
+
 #[test]
 fn parachain_subaccounts_are_unique() {
 	ExtBuilder::default().build().execute_with(|| {